@@ -16,7 +16,7 @@
  * along with this program.  If not, see <http://www.gnu.org/licenses/>.
  */
 
-use panopticon_core::{ControlFlowGraph, ControlFlowRef, ControlFlowTarget, Function, Guard, Lvalue, Operation, Result, Rvalue, Statement};
+use panopticon_core::{ControlFlowGraph, ControlFlowRef, ControlFlowTarget, Function, Guard, Lvalue, Operation, Result, Rvalue};
 use panopticon_data_flow::flag_operations;
 use panopticon_graph_algos::{BidirectionalGraphTrait, GraphTrait, IncidenceGraphTrait, VertexListGraphTrait};
 use panopticon_graph_algos::dominator::immediate_dominator;
@@ -153,12 +153,14 @@ pub fn approximate<A: Avalue>(func: &Function, fixed: &HashMap<(Cow<'static, str
             let mut pos = 0usize;
             bb.execute(
                 |i| {
-                    if let Statement {
-                               ref op,
-                               assignee: Lvalue::Variable { ref name, subscript: Some(ref subscript), .. },
-                           } = *i {
+                    // intrinsics assign to all of their outputs
+                    for lv in i.assignees() {
+                        let (name, subscript) = match lv {
+                            &Lvalue::Variable { ref name, subscript: Some(ref subscript), .. } => (name, subscript),
+                            _ => continue,
+                        };
                         let pp = ProgramPoint { address: bb.area.start, position: pos };
-                        let op = lift(op, &|x| res::<A>(x, sizes, &ret, fixed));
+                        let op = lift(&i.op, &|x| res::<A>(x, sizes, &ret, fixed));
                         let new = A::execute(&pp, &op);
                         let assignee = (name.clone(), *subscript);
                         let cur = ret.get(&assignee).cloned();
@@ -231,10 +233,12 @@ pub fn approximate<A: Avalue>(func: &Function, fixed: &HashMap<(Cow<'static, str
     for vx in func.cfg().vertices() {
         if let Some(&ControlFlowTarget::Resolved(ref bb)) = func.cfg().vertex_label(vx) {
             bb.execute(
-                |i| if let Lvalue::Variable { ref name, ref size, .. } = i.assignee {
-                    let t = *size;
-                    let s = *sizes.get(name).unwrap_or(&t);
-                    sizes.insert(name.clone(), max(s, t));
+                |i| for lv in i.assignees() {
+                    if let &Lvalue::Variable { ref name, ref size, .. } = lv {
+                        let t = *size;
+                        let s = *sizes.get(name).unwrap_or(&t);
+                        sizes.insert(name.clone(), max(s, t));
+                    }
                 }
             );
         }
@@ -356,8 +360,10 @@ pub fn results<A: Avalue>(func: &Function, vals: &HashMap<Lvalue, A>) -> HashMap
     for vx in cfg.vertices() {
         if let Some(&ControlFlowTarget::Resolved(ref bb)) = cfg.vertex_label(vx) {
             bb.execute(
-                |i| if let Lvalue::Variable { ref name, .. } = i.assignee {
-                    names.insert(name.clone());
+                |i| for lv in i.assignees() {
+                    if let &Lvalue::Variable { ref name, .. } = lv {
+                        names.insert(name.clone());
+                    }
                 }
             );
         }
@@ -372,13 +378,15 @@ pub fn results<A: Avalue>(func: &Function, vals: &HashMap<Lvalue, A>) -> HashMap
                     loop {
                         let mut hit = false;
                         bb.execute_backwards(
-                            |i| if let Lvalue::Variable { ref name, ref size, .. } = i.assignee {
-                                if name == lv {
-                                    hit = true;
-                                    ret.insert(
-                                        (name.clone(), *size),
-                                        vals.get(&i.assignee).unwrap_or(&A::initial()).clone(),
-                                    );
+                            |i| for assignee in i.assignees() {
+                                if let &Lvalue::Variable { ref name, ref size, .. } = assignee {
+                                    if name == lv {
+                                        hit = true;
+                                        ret.insert(
+                                            (name.clone(), *size),
+                                            vals.get(assignee).unwrap_or(&A::initial()).clone(),
+                                        );
+                                    }
                                 }
                             }
                         );
@@ -443,6 +451,20 @@ pub fn lift<A, B, F>(op: &Operation<B>, m: &F) -> Operation<A>
         &Operation::ZeroExtend(ref sz, _) => Operation::ZeroExtend(*sz, args[0].clone()),
         &Operation::SignExtend(ref sz, _) => Operation::SignExtend(*sz, args[0].clone()),
         &Operation::Initialize(ref r, sz) => Operation::Initialize(r.clone(), sz),
+        &Operation::FloatAdd(m, _, _) => Operation::FloatAdd(m, args[0].clone(), args[1].clone()),
+        &Operation::FloatSubtract(m, _, _) => Operation::FloatSubtract(m, args[0].clone(), args[1].clone()),
+        &Operation::FloatMultiply(m, _, _) => Operation::FloatMultiply(m, args[0].clone(), args[1].clone()),
+        &Operation::FloatDivide(m, _, _) => Operation::FloatDivide(m, args[0].clone(), args[1].clone()),
+        &Operation::FloatSquareRoot(m, _) => Operation::FloatSquareRoot(m, args[0].clone()),
+        &Operation::FloatRound(m, _) => Operation::FloatRound(m, args[0].clone()),
+        &Operation::FloatEqual(_, _) => Operation::FloatEqual(args[0].clone(), args[1].clone()),
+        &Operation::FloatLess(_, _) => Operation::FloatLess(args[0].clone(), args[1].clone()),
+        &Operation::FloatLessOrEqual(_, _) => Operation::FloatLessOrEqual(args[0].clone(), args[1].clone()),
+        &Operation::FloatUnordered(_, _) => Operation::FloatUnordered(args[0].clone(), args[1].clone()),
+        &Operation::IntToFloat(m, sz, _) => Operation::IntToFloat(m, sz, args[0].clone()),
+        &Operation::FloatToInt(m, sz, _) => Operation::FloatToInt(m, sz, args[0].clone()),
+        &Operation::FloatToFloat(m, sz, _) => Operation::FloatToFloat(m, sz, args[0].clone()),
+        &Operation::Intrinsic(ref name, _, ref outputs) => Operation::Intrinsic(name.clone(), args, outputs.clone()),
     }
 }
 
@@ -23,6 +23,7 @@
 use {Avalue, Constraint, ProgramPoint};
 
 use panopticon_core::{Operation, Rvalue, execute};
+use std::cell::Cell;
 use std::collections::HashSet;
 use std::fmt;
 use std::iter::FromIterator;
@@ -165,6 +166,32 @@ impl Avalue for Kset {
                 _a.clone()
             }
         };
+        // floating point operations are undefined if their result can't be computed exactly.
+        // Unlike integer operations this doesn't mean the program point is unreachable.
+        fn permute_float(a: &Kset, b: &Kset, f: &Fn(Rvalue, Rvalue) -> Rvalue) -> Kset {
+            let undef = Cell::new(false);
+            let ret = permute(a, b, &|a, b| {
+                let r = f(a, b);
+                if r == Rvalue::Undefined {
+                    undef.set(true);
+                }
+                r
+            });
+
+            if undef.get() { Kset::Join } else { ret }
+        }
+        fn map_float(a: &Kset, f: &Fn(Rvalue) -> Rvalue) -> Kset {
+            let undef = Cell::new(false);
+            let ret = map(a, &|a| {
+                let r = f(a);
+                if r == Rvalue::Undefined {
+                    undef.set(true);
+                }
+                r
+            });
+
+            if undef.get() { Kset::Join } else { ret }
+        }
 
         match *op {
             Operation::And(ref a, ref b) => permute(a, b, &|a, b| execute(Operation::And(a, b))),
@@ -196,6 +223,22 @@ impl Avalue for Kset {
             Operation::Load(ref r,e, sz, ref a) => map(a, &|a| execute(Operation::Load(r.clone(),e, sz, a))),
             Operation::Store(ref r,e, sz, ref a,ref b) => permute(a, b, &|a, b| execute(Operation::Store(r.clone(), e, sz, a, b))),
 
+            Operation::FloatAdd(m, ref a, ref b) => permute_float(a, b, &|a, b| execute(Operation::FloatAdd(m, a, b))),
+            Operation::FloatSubtract(m, ref a, ref b) => permute_float(a, b, &|a, b| execute(Operation::FloatSubtract(m, a, b))),
+            Operation::FloatMultiply(m, ref a, ref b) => permute_float(a, b, &|a, b| execute(Operation::FloatMultiply(m, a, b))),
+            Operation::FloatDivide(m, ref a, ref b) => permute_float(a, b, &|a, b| execute(Operation::FloatDivide(m, a, b))),
+            Operation::FloatSquareRoot(m, ref a) => map_float(a, &|a| execute(Operation::FloatSquareRoot(m, a))),
+            Operation::FloatRound(m, ref a) => map_float(a, &|a| execute(Operation::FloatRound(m, a))),
+            Operation::FloatEqual(ref a, ref b) => permute_float(a, b, &|a, b| execute(Operation::FloatEqual(a, b))),
+            Operation::FloatLess(ref a, ref b) => permute_float(a, b, &|a, b| execute(Operation::FloatLess(a, b))),
+            Operation::FloatLessOrEqual(ref a, ref b) => permute_float(a, b, &|a, b| execute(Operation::FloatLessOrEqual(a, b))),
+            Operation::FloatUnordered(ref a, ref b) => permute_float(a, b, &|a, b| execute(Operation::FloatUnordered(a, b))),
+            Operation::IntToFloat(m, sz, ref a) => map_float(a, &|a| execute(Operation::IntToFloat(m, sz, a))),
+            Operation::FloatToInt(m, sz, ref a) => map_float(a, &|a| execute(Operation::FloatToInt(m, sz, a))),
+            Operation::FloatToFloat(m, sz, ref a) => map_float(a, &|a| execute(Operation::FloatToFloat(m, sz, a))),
+
+            Operation::Intrinsic(_, _, _) => Kset::Join,
+
             Operation::Phi(ref ops) => {
                 match ops.len() {
                     0 => unreachable!("Phi function w/o arguments"),
@@ -677,4 +720,75 @@ mod tests {
             println!("{:?}", i);
         }
     }
+
+    #[test]
+    fn intrinsic_clobbers() {
+        let a_var = Lvalue::Variable { name: Cow::Borrowed("a"), size: 32, subscript: None };
+        let b_var = Lvalue::Variable { name: Cow::Borrowed("b"), size: 32, subscript: None };
+        let x_var = Lvalue::Variable { name: Cow::Borrowed("x"), size: 32, subscript: None };
+        let y_var = Lvalue::Variable { name: Cow::Borrowed("y"), size: 32, subscript: None };
+        let bb0 = BasicBlock::from_vec(
+            vec![
+                Mnemonic::new(
+                    0..1,
+                    "init".to_string(),
+                    "".to_string(),
+                    vec![].iter(),
+                    vec![
+                        Statement { op: Operation::Move(Rvalue::new_u32(1)), assignee: a_var.clone() },
+                        Statement { op: Operation::Move(Rvalue::new_u32(2)), assignee: b_var.clone() },
+                    ]
+                            .iter(),
+                )
+                        .ok()
+                        .unwrap(),
+                Mnemonic::new(
+                    1..2,
+                    "cpuid".to_string(),
+                    "".to_string(),
+                    vec![].iter(),
+                    vec![
+                        Statement {
+                            op: Operation::Intrinsic(Cow::Borrowed("cpuid"), vec![a_var.clone().into()], vec![a_var.clone()]),
+                            assignee: Lvalue::Undefined,
+                        },
+                    ]
+                            .iter(),
+                )
+                        .ok()
+                        .unwrap(),
+                Mnemonic::new(
+                    2..3,
+                    "use".to_string(),
+                    "".to_string(),
+                    vec![].iter(),
+                    vec![
+                        Statement { op: Operation::Move(a_var.clone().into()), assignee: x_var.clone() },
+                        Statement { op: Operation::Move(b_var.clone().into()), assignee: y_var.clone() },
+                    ]
+                            .iter(),
+                )
+                        .ok()
+                        .unwrap(),
+            ]
+        );
+        let mut cfg = ControlFlowGraph::new();
+        let v0 = cfg.add_vertex(ControlFlowTarget::Resolved(bb0));
+        let mut func = Function::undefined(0, None, &Region::undefined("ram".to_owned(), 100), Some("test".to_owned()));
+
+        *func.cfg_mut() = cfg;
+        func.set_entry_point_ref(v0);
+
+        assert!(ssa_convertion(&mut func).is_ok());
+
+        let vals = approximate::<Kset>(&func, &HashMap::new()).ok().unwrap();
+        let get = |n: &str| {
+            vals.iter()
+                .find(|&(k, _)| if let &Lvalue::Variable { ref name, .. } = k { name == n } else { false })
+                .map(|(_, v)| v.clone())
+        };
+
+        assert_eq!(get("x"), Some(Kset::Join));
+        assert_eq!(get("y"), Some(Kset::Set(vec![(2, 32)])));
+    }
 }
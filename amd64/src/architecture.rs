@@ -16,7 +16,7 @@
  * along with this program.  If not, see <http://www.gnu.org/licenses/>.
  */
 
-use panopticon_core::{Architecture, Match, Region, Result};
+use panopticon_core::{Architecture, Machine, Match, Region, Result};
 
 #[derive(Clone,Debug)]
 pub enum Amd64 {}
@@ -55,18 +55,10 @@ impl Architecture for Amd64 {
     }
 
     fn decode(reg: &Region, start: u64, cfg: &Self::Configuration) -> Result<Match<Self>> {
-        let data = reg.iter();
-        let mut buf: Vec<u8> = vec![];
-        let mut i = data.seek(start);
+        // instructions are at most 15 bytes long
+        let buf = reg.read(start, 15).into_iter().take_while(Option::is_some).map(Option::unwrap).collect::<Vec<u8>>();
         let p = start;
 
-        while let Some(Some(b)) = i.next() {
-            buf.push(b);
-            if buf.len() == 15 {
-                break;
-            }
-        }
-
         debug!("disass @ {:#x}: {:?}", p, buf);
 
         let ret = ::disassembler::read(*cfg, &buf, p).and_then(
@@ -86,4 +78,12 @@ impl Architecture for Amd64 {
 
         ret
     }
+
+    fn machine(cfg: &Self::Configuration) -> Option<Machine> {
+        match cfg {
+            &Mode::Real => Some(Machine::Ia16),
+            &Mode::Protected => Some(Machine::Ia32),
+            &Mode::Long => Some(Machine::Amd64),
+        }
+    }
 }
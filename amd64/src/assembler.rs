@@ -0,0 +1,140 @@
+/*
+ * Panopticon - A libre disassembler
+ * Copyright (C) 2017  Panopticon authors
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Assembler for the instructions commonly used to patch code.
+//!
+//! Only instructions without operands (`nop`, `ret`, `int3`, ...) and direct jumps and calls
+//! (`jmp 401000`, `jne 0x1f4`, `call 4010a0`) are supported. Targets are absolute hexadecimal
+//! addresses. Jumps use the short form if the target is close enough.
+
+use architecture::Mode;
+use panopticon_core::Result;
+
+/// Instructions without operands.
+const PLAIN: &'static [(&'static str, &'static [u8])] = &[
+    ("nop", &[0x90]),
+    ("int3", &[0xcc]),
+    ("hlt", &[0xf4]),
+    ("ret", &[0xc3]),
+    ("leave", &[0xc9]),
+    ("clc", &[0xf8]),
+    ("stc", &[0xf9]),
+    ("cli", &[0xfa]),
+    ("sti", &[0xfb]),
+    ("ud2", &[0x0f, 0x0b]),
+];
+
+/// Condition codes of `jcc`.
+const CONDITIONS: &'static [(&'static str, u8)] = &[
+    ("jo", 0x0),
+    ("jno", 0x1),
+    ("jb", 0x2),
+    ("jc", 0x2),
+    ("jnae", 0x2),
+    ("jae", 0x3),
+    ("jnb", 0x3),
+    ("jnc", 0x3),
+    ("je", 0x4),
+    ("jz", 0x4),
+    ("jne", 0x5),
+    ("jnz", 0x5),
+    ("jbe", 0x6),
+    ("jna", 0x6),
+    ("ja", 0x7),
+    ("jnbe", 0x7),
+    ("js", 0x8),
+    ("jns", 0x9),
+    ("jp", 0xa),
+    ("jpe", 0xa),
+    ("jnp", 0xb),
+    ("jpo", 0xb),
+    ("jl", 0xc),
+    ("jnge", 0xc),
+    ("jge", 0xd),
+    ("jnl", 0xd),
+    ("jle", 0xe),
+    ("jng", 0xe),
+    ("jg", 0xf),
+    ("jnle", 0xf),
+];
+
+/// Encodes the instruction `text` located at `address`.
+pub fn assemble(text: &str, address: u64, mode: Mode) -> Result<Vec<u8>> {
+    let text = text.trim().to_lowercase();
+    let mut toks = text.split_whitespace();
+    let mnemonic = toks.next().unwrap_or("");
+    let operand = toks.next();
+
+    if toks.next().is_some() {
+        return Err(format!("'{}' has too many operands", text).into());
+    }
+
+    if let Some(&(_, bytes)) = PLAIN.iter().find(|&&(m, _)| m == mnemonic) {
+        return match operand {
+            None => Ok(bytes.to_vec()),
+            Some(_) => Err(format!("{} takes no operands", mnemonic).into()),
+        };
+    }
+
+    let target = match operand {
+        Some(op) => parse_target(op)?,
+        None => return Err(format!("Unknown instruction '{}'", text).into()),
+    };
+
+    if mnemonic == "jmp" {
+        branch(address, target, mode, Some(&[0xeb]), &[0xe9])
+    } else if mnemonic == "call" {
+        branch(address, target, mode, None, &[0xe8])
+    } else if let Some(&(_, cc)) = CONDITIONS.iter().find(|&&(m, _)| m == mnemonic) {
+        branch(address, target, mode, Some(&[0x70 | cc]), &[0x0f, 0x80 | cc])
+    } else {
+        Err(format!("Unknown instruction '{}'", text).into())
+    }
+}
+
+fn parse_target(s: &str) -> Result<u64> {
+    u64::from_str_radix(s.trim_left_matches("0x"), 16).map_err(|_| format!("'{}' is not a hexadecimal address", s).into())
+}
+
+/// Encodes a relative jump or call from `address` to `target`. The 8 bit displacement form `short`
+/// is preferred over `near` if possible.
+fn branch(address: u64, target: u64, mode: Mode, short: Option<&[u8]>, near: &[u8]) -> Result<Vec<u8>> {
+    if let Some(short) = short {
+        let disp = target.wrapping_sub(address + short.len() as u64 + 1) as i64;
+
+        if disp >= -0x80 && disp < 0x80 {
+            let mut ret = short.to_vec();
+            ret.push(disp as u8);
+            return Ok(ret);
+        }
+    }
+
+    let width = if mode == Mode::Real { 2 } else { 4 };
+    let disp = target.wrapping_sub(address + near.len() as u64 + width) as i64;
+    let limit = 1i64 << (width * 8 - 1);
+
+    if disp < -limit || disp >= limit {
+        return Err(format!("{:#x} is out of reach of {:#x}", target, address).into());
+    }
+
+    let mut ret = near.to_vec();
+    for i in 0..width {
+        ret.push((disp >> (i * 8)) as u8);
+    }
+    Ok(ret)
+}
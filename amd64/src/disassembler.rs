@@ -1367,7 +1367,7 @@ fn read_operand(
                 read_effective_simd_address(mode, seg, tail, rex, opsz, addrsz, addr, 128)?,
                 seg,
                 addrsz,
-                128,
+                64,
             )
         }
         (&OperandSpec(AddressingMethod::W, OperandType::ss), _) => {
@@ -1375,7 +1375,7 @@ fn read_operand(
                 read_effective_simd_address(mode, seg, tail, rex, opsz, addrsz, addr, 128)?,
                 seg,
                 addrsz,
-                128,
+                32,
             )
         }
         _ => {
@@ -2834,9 +2834,17 @@ pub fn read(mode: Mode, buf: &[u8], addr: u64) -> Result<(u64, Mnemonic, Vec<(Rv
                         return Err(e);
                     }
                 };
+                // single operand instructions like `inc` or `setcc` may write their memory operand, too
+                let writes_first = match ops.get(0) {
+                    Some(&Rvalue::Variable { ref name, .. }) if ops.len() < 2 => {
+                        op_stmts.iter().any(|st| st.assignees().iter().any(|lv| match **lv { Lvalue::Variable { name: ref n, .. } => n == name, _ => false }))
+                    }
+                    _ => ops.len() >= 2,
+                };
+
                 stmts.append(&mut op_stmts);
 
-                if ops.len() >= 2 {
+                if writes_first {
                     stmts.append(&mut wstmts[0]);
                 }
 
@@ -51,3 +51,6 @@ pub use disassembler::{AddressingMethod, JumpSpec, MnemonicSpec, Opcode, Operand
 
 mod architecture;
 pub use architecture::{Amd64, Mode};
+
+mod assembler;
+pub use assembler::assemble;
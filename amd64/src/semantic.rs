@@ -53,7 +53,8 @@
 
 use disassembler::{Condition, JumpSpec};
 
-use panopticon_core::{Guard, Lvalue, Result, Rvalue, Statement};
+use panopticon_core::{Guard, Lvalue, Operation, Result, Rounding, Rvalue, Statement};
+use std::borrow::Cow;
 use std::cmp::max;
 
 /// Sets the adjust flag AF after an addition. Assumes res := a + ?.
@@ -198,6 +199,13 @@ fn extend(a: &Rvalue, b: &Rvalue, sign_ext: bool) -> Result<(Rvalue, Rvalue, usi
                     x.clone()
                 }
             }
+            &Rvalue::Wide { ref value, ref size } => {
+                if *size != s {
+                    Rvalue::new_wide(value.clone(), s)
+                } else {
+                    x.clone()
+                }
+            }
         }
     };
 
@@ -287,6 +295,301 @@ fn reg_variants(name: &str) -> Option<(Lvalue, Lvalue, Lvalue, Lvalue, Lvalue)>
     }
 }
 
+/// Returns the XMM and YMM register sharing the bits of vector register `name`.
+fn vector_variants(name: &str) -> Option<(Lvalue, Lvalue)> {
+    if !name.starts_with("XMM") && !name.starts_with("YMM") {
+        return None;
+    }
+
+    name[3..].parse::<usize>().ok().map(
+        |i| {
+            let xmm = Lvalue::Variable { name: Cow::Owned(format!("XMM{}", i)), subscript: None, size: 128 };
+            let ymm = Lvalue::Variable { name: Cow::Owned(format!("YMM{}", i)), subscript: None, size: 256 };
+            (xmm, ymm)
+        }
+    )
+}
+
+/// All eight x87 stack registers. Used by instructions that push, pop or rotate the FPU stack.
+fn fpu_stack() -> Vec<Rvalue> {
+    (0..8).map(|i| Rvalue::Variable { name: Cow::Owned(format!("ST{}", i)), subscript: None, size: 80, offset: 0 }).collect()
+}
+
+/// All SSE/AVX vector registers.
+fn simd_registers() -> Vec<Rvalue> {
+    (0..16).map(|i| Rvalue::Variable { name: Cow::Owned(format!("YMM{}", i)), subscript: None, size: 256, offset: 0 }).collect()
+}
+
+/// All general purpose registers except RSP.
+fn general_registers() -> Vec<Rvalue> {
+    vec![
+        rreil_rvalue!{ RAX:64 },
+        rreil_rvalue!{ RBX:64 },
+        rreil_rvalue!{ RCX:64 },
+        rreil_rvalue!{ RDX:64 },
+        rreil_rvalue!{ RSI:64 },
+        rreil_rvalue!{ RDI:64 },
+        rreil_rvalue!{ RBP:64 },
+        rreil_rvalue!{ R8:64 },
+        rreil_rvalue!{ R9:64 },
+        rreil_rvalue!{ R10:64 },
+        rreil_rvalue!{ R11:64 },
+        rreil_rvalue!{ R12:64 },
+        rreil_rvalue!{ R13:64 },
+        rreil_rvalue!{ R14:64 },
+        rreil_rvalue!{ R15:64 },
+    ]
+}
+
+/// The arithmetic flags, as inputs of instructions that read them.
+fn arith_flags() -> Vec<Rvalue> {
+    vec![
+        rreil_rvalue!{ CF:1 },
+        rreil_rvalue!{ OF:1 },
+        rreil_rvalue!{ SF:1 },
+        rreil_rvalue!{ ZF:1 },
+        rreil_rvalue!{ AF:1 },
+        rreil_rvalue!{ PF:1 },
+    ]
+}
+
+/// Models an instruction as an opaque intrinsic that reads `inputs` and sets `outputs` and the
+/// 1 bit `flags` to unknown values. All aliases of output registers (e.g. RAX, EAX, AX, AH and AL)
+/// are clobbered.
+fn intrinsic(name: &'static str, inputs: Vec<Rvalue>, outputs: Vec<Rvalue>, flags: &[&'static str]) -> Result<Vec<Statement>> {
+    let mut outs = Vec::<Lvalue>::new();
+
+    for o in outputs.iter() {
+        let lvs = match o {
+            &Rvalue::Variable { ref name, ref size, .. } => {
+                match (reg_variants(name), vector_variants(name)) {
+                    (Some((reg8l, reg8h, reg16, reg32, reg64)), _) => vec![reg8l, reg8h, reg16, reg32, reg64],
+                    (None, Some((xmm, ymm))) => vec![xmm, ymm],
+                    (None, None) => vec![Lvalue::Variable { name: name.clone(), subscript: None, size: *size }],
+                }
+            }
+            _ => vec![],
+        };
+
+        for lv in lvs {
+            if lv != Lvalue::Undefined && !outs.contains(&lv) {
+                outs.push(lv);
+            }
+        }
+    }
+
+    for f in flags.iter() {
+        outs.push(Lvalue::Variable { name: Cow::Borrowed(*f), subscript: None, size: 1 });
+    }
+
+    Ok(vec![Statement { op: Operation::Intrinsic(Cow::Borrowed(name), inputs, outs), assignee: Lvalue::Undefined }])
+}
+
+/// Writes `res` into the low bits of the XMM register `dst` and copies the remaining bits from
+/// `base`. VEX encoded instructions (`vex`) clear bits 128 and above of the YMM register, legacy
+/// SSE instructions leave them unchanged.
+fn write_scalar(dst: &Rvalue, base: &Rvalue, res: &Lvalue, vex: bool) -> Result<Vec<Statement>> {
+    let (xmm, ymm) = match dst {
+        &Rvalue::Variable { ref name, .. } => {
+            match vector_variants(name) {
+                Some(v) => v,
+                None => return Err(format!("Internal error: {} is not a vector register", name).into()),
+            }
+        }
+        _ => return Err("Internal error: scalar SSE result must be written to a register".into()),
+    };
+    let base = base.extract(128, 0)?;
+    let xmm_rv: Rvalue = xmm.clone().into();
+    let res: Rvalue = res.clone().into();
+    let mut stmts = vec![];
+
+    if base != xmm_rv {
+        stmts.append(&mut rreil!{ mov (xmm), (base); }?);
+    }
+    stmts.append(&mut rreil!{ sel/0 (xmm), (res); }?);
+
+    if vex {
+        stmts.append(&mut rreil!{ zext/256 (ymm), (xmm); }?);
+    } else {
+        stmts.append(&mut rreil!{ sel/0 (ymm), (xmm); }?);
+    }
+
+    Ok(stmts)
+}
+
+/// Semantics of a scalar SSE instruction `a = f(b, ...)` with a `sz` bit result. `f` is called
+/// with the low `sz` bits of the first source and the variable it must assign. Legacy encodings
+/// have no VEX.vvvv operand (`b` is undefined), their destination is the first source too.
+///
+/// SSE rounds according to MXCSR, we assume the default of rounding to nearest even.
+fn scalar_sse<F>(a: Rvalue, b: Rvalue, sz: usize, f: F) -> Result<(Vec<Statement>, JumpSpec)>
+where
+    F: FnOnce(Rvalue, Lvalue) -> Result<Vec<Statement>>,
+{
+    let (base, vex) = if b == Rvalue::Undefined { (a.clone(), false) } else { (b, true) };
+    let res = Lvalue::Variable { name: Cow::Borrowed("res"), subscript: None, size: sz };
+    let mut stmts = f(base.extract(sz, 0)?, res.clone())?;
+
+    stmts.append(&mut write_scalar(&a, &base, &res, vex)?);
+    Ok((stmts, JumpSpec::FallThru))
+}
+
+/// Scalar SSE arithmetic `a = op(b, c)` on the low `sz` bits of the operands.
+fn scalar_float<F>(a: Rvalue, b: Rvalue, c: Rvalue, sz: usize, op: F) -> Result<(Vec<Statement>, JumpSpec)>
+where
+    F: FnOnce(Rvalue, Rvalue) -> Operation<Rvalue>,
+{
+    let y = c.extract(sz, 0)?;
+    scalar_sse(a, b, sz, |x, res| Ok(vec![Statement { op: op(x, y), assignee: res }]))
+}
+
+/// MINSS/MINSD and MAXSS/MAXSD. Returns the first source if it's less (`max` unset) or greater
+/// (`max` set) than the second one. Otherwise, including if either is NaN, the second source is
+/// returned.
+fn scalar_min_max(a: Rvalue, b: Rvalue, c: Rvalue, sz: usize, max: bool) -> Result<(Vec<Statement>, JumpSpec)> {
+    let y = c.extract(sz, 0)?;
+
+    scalar_sse(
+        a,
+        b,
+        sz,
+        |x, res| {
+            let cond = if max { Operation::FloatLess(y.clone(), x.clone()) } else { Operation::FloatLess(x.clone(), y.clone()) };
+            let mut stmts = vec![Statement { op: cond, assignee: rreil_lvalue!{ cond:1 } }];
+
+            stmts.append(
+                &mut rreil!{
+                zext/sz mask:sz, cond:1;
+                sub mask:sz, [0]:sz, mask:sz;
+                xor diff:sz, (x), (y);
+                and diff:sz, diff:sz, mask:sz;
+                xor (res), (y), diff:sz;
+            }?
+            );
+            Ok(stmts)
+        }
+    )
+}
+
+/// CMPSS/CMPSD. Sets the low `sz` bits of `a` to all ones if predicate `pred` holds for the
+/// first and second source and to zero otherwise. Legacy encodings only use the first eight
+/// predicates, the VEX encoded ones add the remaining 24. Predicates 16 to 31 only differ from
+/// 0 to 15 in the exceptions raised.
+fn scalar_compare(a: Rvalue, b: Rvalue, c: Rvalue, pred: Rvalue, sz: usize) -> Result<(Vec<Statement>, JumpSpec)> {
+    let vex = b != Rvalue::Undefined;
+    let pred = match pred {
+        Rvalue::Constant { value, .. } if vex => value & 0xf,
+        Rvalue::Constant { value, .. } => value & 0x7,
+        _ => return Err("Internal error: comparison predicate must be a constant".into()),
+    };
+    let y = c.extract(sz, 0)?;
+
+    scalar_sse(
+        a,
+        b,
+        sz,
+        |x, res| {
+            // (comparison, true if unordered, negate)
+            let (cmp, unordered, negate) = match pred {
+                0 => (Some(Operation::FloatEqual(x.clone(), y.clone())), false, false),
+                1 => (Some(Operation::FloatLess(x.clone(), y.clone())), false, false),
+                2 => (Some(Operation::FloatLessOrEqual(x.clone(), y.clone())), false, false),
+                3 => (Some(Operation::FloatUnordered(x.clone(), y.clone())), false, false),
+                4 => (Some(Operation::FloatEqual(x.clone(), y.clone())), false, true),
+                5 => (Some(Operation::FloatLess(x.clone(), y.clone())), false, true),
+                6 => (Some(Operation::FloatLessOrEqual(x.clone(), y.clone())), false, true),
+                7 => (Some(Operation::FloatUnordered(x.clone(), y.clone())), false, true),
+                8 => (Some(Operation::FloatEqual(x.clone(), y.clone())), true, false),
+                9 => (Some(Operation::FloatLess(x.clone(), y.clone())), true, false),
+                10 => (Some(Operation::FloatLessOrEqual(x.clone(), y.clone())), true, false),
+                11 => (None, false, false),
+                12 => (Some(Operation::FloatEqual(x.clone(), y.clone())), true, true),
+                13 => (Some(Operation::FloatLessOrEqual(y.clone(), x.clone())), false, false),
+                14 => (Some(Operation::FloatLess(y.clone(), x.clone())), false, false),
+                _ => (None, false, true),
+            };
+            let mut stmts = match cmp {
+                Some(op) => vec![Statement { op: op, assignee: rreil_lvalue!{ cond:1 } }],
+                None => rreil!{ mov cond:1, [0]:1; }?,
+            };
+
+            if unordered {
+                stmts.push(Statement { op: Operation::FloatUnordered(x.clone(), y.clone()), assignee: rreil_lvalue!{ unord:1 } });
+                stmts.append(&mut rreil!{ or cond:1, cond:1, unord:1; }?);
+            }
+            if negate {
+                stmts.append(&mut rreil!{ xor cond:1, cond:1, [1]:1; }?);
+            }
+
+            stmts.append(
+                &mut rreil!{
+                zext/sz (res), cond:1;
+                sub (res), [0]:sz, (res);
+            }?
+            );
+            Ok(stmts)
+        }
+    )
+}
+
+/// ROUNDSS/ROUNDSD. Rounds the low `sz` bits of `b` to an integral value in the rounding mode
+/// selected by `imm`. Immediates that select the MXCSR rounding mode round to nearest even.
+fn scalar_round(a: Rvalue, b: Rvalue, imm: Rvalue, sz: usize) -> Result<(Vec<Statement>, JumpSpec)> {
+    let rounding = match imm {
+        Rvalue::Constant { value, .. } if value & 0b100 != 0 => Rounding::NearestEven,
+        Rvalue::Constant { value, .. } => {
+            match value & 0b11 {
+                0 => Rounding::NearestEven,
+                1 => Rounding::TowardNegative,
+                2 => Rounding::TowardPositive,
+                _ => Rounding::TowardZero,
+            }
+        }
+        _ => return Err("Internal error: rounding mode must be a constant".into()),
+    };
+    let src = b.extract(sz, 0)?;
+
+    scalar_sse(a, Rvalue::Undefined, sz, |_, res| Ok(vec![Statement { op: Operation::FloatRound(rounding, src), assignee: res }]))
+}
+
+/// COMISS/COMISD and UCOMISS/UCOMISD. Compares the low `sz` bits of `a` and `b` and sets ZF, PF
+/// and CF like an unsigned integer comparison would. All three are set if the operands are
+/// unordered.
+fn scalar_flags(a: Rvalue, b: Rvalue, sz: usize) -> Result<(Vec<Statement>, JumpSpec)> {
+    let x = a.extract(sz, 0)?;
+    let y = b.extract(sz, 0)?;
+    let mut stmts = vec![
+        Statement { op: Operation::FloatUnordered(x.clone(), y.clone()), assignee: rreil_lvalue!{ PF:1 } },
+        Statement { op: Operation::FloatEqual(x.clone(), y.clone()), assignee: rreil_lvalue!{ eq:1 } },
+        Statement { op: Operation::FloatLess(x, y), assignee: rreil_lvalue!{ lt:1 } },
+    ];
+
+    stmts.append(
+        &mut rreil!{
+        or ZF:1, eq:1, PF:1;
+        or CF:1, lt:1, PF:1;
+        mov OF:1, [0]:1;
+        mov SF:1, [0]:1;
+        mov AF:1, [0]:1;
+    }?
+    );
+    Ok((stmts, JumpSpec::FallThru))
+}
+
+/// CVTSS2SI/CVTSD2SI and their truncating variants. Converts the low `sz` bits of `b` into a
+/// signed integer of the size of `a`.
+fn scalar_to_int(a: Rvalue, b: Rvalue, sz: usize, rounding: Rounding) -> Result<(Vec<Statement>, JumpSpec)> {
+    let dsz = match a.size() {
+        Some(s) => s,
+        None => return Err("Internal error: conversion result must be written to a register".into()),
+    };
+    let res = Lvalue::Variable { name: Cow::Borrowed("res"), subscript: None, size: dsz };
+    let mut stmts = vec![Statement { op: Operation::FloatToInt(rounding, dsz, b.extract(sz, 0)?), assignee: res.clone() }];
+
+    stmts.append(&mut write_reg(&a, &res.into(), dsz)?);
+    Ok((stmts, JumpSpec::FallThru))
+}
+
 /// Assigns `val:sz` to `reg`. This function makes sure all that e.g. EAX is written when RAX is.
 fn write_reg(reg: &Rvalue, val: &Rvalue, _sz: usize) -> Result<Vec<Statement>> {
     use std::cmp;
@@ -439,7 +742,7 @@ fn write_reg(reg: &Rvalue, val: &Rvalue, _sz: usize) -> Result<Vec<Statement>> {
 }
 
 pub fn aaa() -> Result<(Vec<Statement>, JumpSpec)> {
-    return Ok((vec![], JumpSpec::FallThru));
+    Ok((intrinsic("aaa", vec![rreil_rvalue!{ AX:16 }], vec![rreil_rvalue!{ AX:16 }], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
     /*  rreil!{
         and y:8, AL:8, [0xf]:8;
 
@@ -469,8 +772,8 @@ pub fn aaa() -> Result<(Vec<Statement>, JumpSpec)> {
     cg.mod_i(&AX,&AX.clone().into(),&Rvalue::Constant(0x100));*/
 }
 
-pub fn aam(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    return Ok((vec![], JumpSpec::FallThru));
+pub fn aam(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("aam", vec![a, rreil_rvalue!{ AX:16 }], vec![rreil_rvalue!{ AX:16 }], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
     /*   let temp_al = new_temp(16);
 
     cg.assign(&temp_al,&AL.clone().into());
@@ -478,8 +781,8 @@ pub fn aam(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     cg.mod_i(&*AL,&temp_al,&a);*/
 }
 
-pub fn aad(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    return Ok((vec![], JumpSpec::FallThru));
+pub fn aad(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("aad", vec![a, rreil_rvalue!{ AX:16 }], vec![rreil_rvalue!{ AX:16 }], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
     /*   let x = new_temp(16);
 
     cg.mul_i(&x,&AH.clone().into(),&a);
@@ -488,7 +791,7 @@ pub fn aad(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
 }
 
 pub fn aas() -> Result<(Vec<Statement>, JumpSpec)> {
-    return Ok((vec![], JumpSpec::FallThru));
+    Ok((intrinsic("aas", vec![rreil_rvalue!{ AX:16 }], vec![rreil_rvalue!{ AX:16 }], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
     /*   let y1 = new_temp(16);
     let x1 = new_temp(1);
     let x2 = new_temp(1);
@@ -607,146 +910,40 @@ pub fn and(a_: Rvalue, b_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((stmts, JumpSpec::FallThru))
 }
 
-pub fn arpl(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn arpl(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("arpl", vec![a.clone(), b], vec![a], &["ZF"])?, JumpSpec::FallThru))
 }
 
 pub fn bound(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
 
-pub fn bsf(_a: Rvalue, _b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    return Ok((vec![], JumpSpec::FallThru));
-    // let (_,b,sz,_) = try!(sign_extend(&_a,&_b));
-    // let res = rreil_lvalue!{ res:sz };
-    // let mut stmts = try!(rreil!{
-    //     cmpeq ZF:1, (b), [0]:sz;
-    //     mov res:sz, ?;
-    // });
-
-    // stmts.append(&mut try!(write_reg(&_a,&res.clone().into(),sz)));
-    // Ok((stmts,JumpSpec::FallThru))
+pub fn bsf(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("bsf", vec![b], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 
-pub fn bsr(_a: Rvalue, _b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    return Ok((vec![], JumpSpec::FallThru));
-    // let (_,b,sz,_) = try!(sign_extend(&_a,&_b));
-    // let res = rreil_lvalue!{ res:sz };
-    // let mut stmts = try!(rreil!{
-    //     cmpeq ZF:1, (b), [0]:sz;
-    //     mov res:sz, ?;
-    // });
-
-    // stmts.append(&mut try!(write_reg(&_a,&res.clone().into(),sz)));
-    // Ok((stmts,JumpSpec::FallThru))
+pub fn bsr(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("bsr", vec![b], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 
-pub fn bswap(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    return Ok((vec![], JumpSpec::FallThru));
-    //unimplemented!()
-    /*
-    using dsl::operator*;
-
-    size_t const a_w = bitwidth(a);
-    size_t byte = 0;
-
-    rvalue tmp = undefined();
-
-    while(byte < a_w / 8)
-    {
-        unsigned int lsb = byte * 8;
-        unsigned int div = (1 << lsb), mul = (1 << (a_w - byte * 8));
-
-        tmp = tmp + (((a / div) % Rvalue::Constant(0x100)) * mul);
-        ++byte;
-    }
-
-    m.assign(to_lvalue(a),tmp);*/
-
-
-
-
-
+pub fn bswap(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("bswap", vec![a.clone()], vec![a], &[])?, JumpSpec::FallThru))
 }
 
-pub fn bt(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    return Ok((vec![], JumpSpec::FallThru));
-    //unimplemented!()
-    /*
-    using dsl::operator<<;
-    rvalue mod = (Rvalue::Constant(1) << (b % constant(bitwidth(a))));
-
-    m.assign(to_lvalue(CF), (a / mod) % 2);
-    m.assign(to_lvalue(PF), undefined());
-    m.assign(to_lvalue(OF), undefined());
-    m.assign(to_lvalue(SF), undefined());
-    m.assign(to_lvalue(AF), undefined());*/
-
-
-
-
-
+pub fn bt(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("bt", vec![a, b], vec![], &["CF", "OF", "SF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 
-pub fn btc(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    return Ok((vec![], JumpSpec::FallThru));
-    //unimplemented!()
-    /*
-    using dsl::operator<<;
-    rvalue mod = (Rvalue::Constant(1) << (b % constant(bitwidth(a))));
-
-    m.assign(to_lvalue(CF), (a / mod) % 2);
-    m.assign(to_lvalue(PF), undefined());
-    m.assign(to_lvalue(OF), undefined());
-    m.assign(to_lvalue(SF), undefined());
-    m.assign(to_lvalue(AF), undefined());
-    m.assign(to_lvalue(a),a ^ mod);*/
-
-
-
-
-
+pub fn btc(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("btc", vec![a.clone(), b], vec![a], &["CF", "OF", "SF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 
-pub fn btr(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    return Ok((vec![], JumpSpec::FallThru));
-    //unimplemented!()
-    /*
-    using dsl::operator<<;
-    size_t const a_w = bitwidth(a);
-    rvalue mod =  ((Rvalue::Constant(1) << (b % constant(bitwidth(a)))));
-
-    m.assign(to_lvalue(CF), (a / mod) % 2);
-    m.assign(to_lvalue(PF), undefined());
-    m.assign(to_lvalue(OF), undefined());
-    m.assign(to_lvalue(SF), undefined());
-    m.assign(to_lvalue(AF), undefined());
-    m.assign(to_lvalue(a),(a & (Rvalue::Constant(0xffffffffffffffff) ^ mod)) % constant(1 << a_w));*/
-
-
-
-
-
+pub fn btr(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("btr", vec![a.clone(), b], vec![a], &["CF", "OF", "SF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 
-pub fn bts(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    return Ok((vec![], JumpSpec::FallThru));
-    //unimplemented!()
-    /*
-    using dsl::operator<<;
-    rvalue mod = (Rvalue::Constant(1) << (b % constant(bitwidth(a))));
-
-    m.assign(to_lvalue(CF), (a / mod) % 2);
-    m.assign(to_lvalue(PF), undefined());
-    m.assign(to_lvalue(OF), undefined());
-    m.assign(to_lvalue(SF), undefined());
-    m.assign(to_lvalue(AF), undefined());
-    m.assign(to_lvalue(a),a & mod);*/
-
-
-
-
-
+pub fn bts(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("bts", vec![a.clone(), b], vec![a], &["CF", "OF", "SF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 
 pub fn call(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
@@ -760,8 +957,8 @@ pub fn call(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((stmts, JumpSpec::FallThru))
 }
 
-pub fn cmovcc(_: Rvalue, _: Rvalue, _: Condition) -> Result<(Vec<Statement>, JumpSpec)> {
-    return Ok((vec![], JumpSpec::FallThru));
+pub fn cmovcc(a: Rvalue, b: Rvalue, _: Condition) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("cmovcc", [vec![a.clone(), b], arith_flags()].concat(), vec![a], &[])?, JumpSpec::FallThru))
     /*   let a = Lvalue::from_rvalue(&_a).unwrap();
     let fun = |f: &Lvalue,| {
         let l = new_temp(bitwidth(&a.clone().into()));
@@ -867,10 +1064,24 @@ pub fn cmp(a_: Rvalue, b_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
 }
 
 pub fn cmpsw() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "cmpsw",
+        vec![rreil_rvalue!{ RSI:64 }, rreil_rvalue!{ RDI:64 }],
+        vec![rreil_rvalue!{ RSI:64 }, rreil_rvalue!{ RDI:64 }, rreil_rvalue!{ RCX:64 }],
+        &["CF", "OF", "SF", "ZF", "AF", "PF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 pub fn cmpsb() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "cmpsb",
+        vec![rreil_rvalue!{ RSI:64 }, rreil_rvalue!{ RDI:64 }],
+        vec![rreil_rvalue!{ RSI:64 }, rreil_rvalue!{ RDI:64 }, rreil_rvalue!{ RCX:64 }],
+        &["CF", "OF", "SF", "ZF", "AF", "PF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 
 /*pub fn cmps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>,JumpSpec)> {
@@ -908,8 +1119,15 @@ pub fn cmpsb() -> Result<(Vec<Statement>, JumpSpec)> {
     cg.add_i(&bo,&boff,&off);*/
 }*/
 
-pub fn cmpxchg(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    return Ok((vec![], JumpSpec::FallThru));
+pub fn cmpxchg(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    let stmts = intrinsic(
+        "cmpxchg",
+        vec![a.clone(), b, rreil_rvalue!{ RAX:64 }],
+        vec![a, rreil_rvalue!{ RAX:64 }],
+        &["CF", "OF", "SF", "ZF", "AF", "PF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
     /*   cg.equal_i(&*ZF,&a,&EAX.clone().into());
 
     let n = new_temp(1);
@@ -1031,39 +1249,53 @@ pub fn xor(_a: Rvalue, _b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
 }
 
 //pub fn cmpxchg8b(_: Rvalue) -> Result<(Vec<Statement>,JumpSpec)> { Ok((vec![],JumpSpec::FallThru)) }
-pub fn cmpxchg16b(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cmpxchg16b(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    let stmts = intrinsic(
+        "cmpxchg16b",
+        vec![a.clone(), rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }, rreil_rvalue!{ RBX:64 }, rreil_rvalue!{ RCX:64 }],
+        vec![a, rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }],
+        &["ZF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 pub fn cpuid() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "cpuid",
+        vec![rreil_rvalue!{ EAX:32 }, rreil_rvalue!{ ECX:32 }],
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RBX:64 }, rreil_rvalue!{ RCX:64 }, rreil_rvalue!{ RDX:64 }],
+        &[],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 pub fn clc() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("clc", vec![], vec![], &["CF"])?, JumpSpec::FallThru))
 }
 pub fn cld() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("cld", vec![], vec![], &["DF"])?, JumpSpec::FallThru))
 }
 pub fn cli() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("cli", vec![], vec![], &["IF"])?, JumpSpec::FallThru))
 }
 pub fn cmc() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("cmc", vec![rreil_rvalue!{ CF:1 }], vec![], &["CF"])?, JumpSpec::FallThru))
 }
 pub fn std() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("std", vec![], vec![], &["DF"])?, JumpSpec::FallThru))
 }
 pub fn sti() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("sti", vec![], vec![], &["IF"])?, JumpSpec::FallThru))
 }
 pub fn stc() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("stc", vec![], vec![], &["CF"])?, JumpSpec::FallThru))
 }
 
 pub fn cbw() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("cbw", vec![rreil_rvalue!{ AL:8 }], vec![rreil_rvalue!{ AX:16 }], &[])?, JumpSpec::FallThru))
 }
 pub fn cwd() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("cwd", vec![rreil_rvalue!{ AX:16 }], vec![rreil_rvalue!{ DX:16 }], &[])?, JumpSpec::FallThru))
 }
 pub fn clts() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
@@ -1092,19 +1324,47 @@ pub fn conv2() -> Result<(Vec<Statement>,JumpSpec)> {
 }*/
 
 pub fn daa() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "daa",
+        vec![rreil_rvalue!{ AL:8 }, rreil_rvalue!{ CF:1 }, rreil_rvalue!{ AF:1 }],
+        vec![rreil_rvalue!{ AL:8 }],
+        &["CF", "OF", "SF", "ZF", "AF", "PF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 pub fn das() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "das",
+        vec![rreil_rvalue!{ AL:8 }, rreil_rvalue!{ CF:1 }, rreil_rvalue!{ AF:1 }],
+        vec![rreil_rvalue!{ AL:8 }],
+        &["CF", "OF", "SF", "ZF", "AF", "PF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
-pub fn dec(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn dec(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("dec", vec![a.clone()], vec![a], &["OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn div(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn div(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    let stmts = intrinsic(
+        "div",
+        vec![a, rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }],
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }],
+        &["CF", "OF", "SF", "ZF", "AF", "PF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 pub fn enter(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "enter",
+        vec![rreil_rvalue!{ RSP:64 }, rreil_rvalue!{ RBP:64 }],
+        vec![rreil_rvalue!{ RSP:64 }, rreil_rvalue!{ RBP:64 }],
+        &[],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 
 pub fn hlt() -> Result<(Vec<Statement>, JumpSpec)> {
@@ -1123,11 +1383,25 @@ pub fn int1() -> Result<(Vec<Statement>, JumpSpec)> {
 pub fn invd() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn idiv(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn idiv(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    let stmts = intrinsic(
+        "idiv",
+        vec![a, rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }],
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }],
+        &["CF", "OF", "SF", "ZF", "AF", "PF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
-pub fn imul1(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn imul1(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    let stmts = intrinsic(
+        "imul",
+        vec![a, rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }],
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }],
+        &["CF", "OF", "SF", "ZF", "AF", "PF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 
 pub fn imul2(a_: Rvalue, b_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
@@ -1160,21 +1434,35 @@ pub fn imul2(a_: Rvalue, b_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
 
 }
 
-pub fn imul3(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn imul3(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("imul", vec![b, c], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn in_(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn in_(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("in", vec![b], vec![a], &[])?, JumpSpec::FallThru))
 }
 //pub fn icebp() -> Result<(Vec<Statement>,JumpSpec)> { Ok((vec![],JumpSpec::FallThru)) }
-pub fn inc(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn inc(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("inc", vec![a.clone()], vec![a], &["OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 pub fn insb() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "insb",
+        vec![rreil_rvalue!{ RDX:64 }, rreil_rvalue!{ RDI:64 }],
+        vec![rreil_rvalue!{ RDI:64 }, rreil_rvalue!{ RCX:64 }],
+        &[],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 pub fn insw() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "insw",
+        vec![rreil_rvalue!{ RDX:64 }, rreil_rvalue!{ RDI:64 }],
+        vec![rreil_rvalue!{ RDI:64 }, rreil_rvalue!{ RCX:64 }],
+        &[],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 pub fn int(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
@@ -1194,8 +1482,8 @@ pub fn iret() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::DeadEnd))
 }
 
-pub fn setcc(_: Rvalue, _: Condition) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn setcc(a: Rvalue, _: Condition) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("setcc", arith_flags(), vec![a], &[])?, JumpSpec::FallThru))
 }
 pub fn seto(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     setcc(a, Condition::Overflow)
@@ -1379,7 +1667,7 @@ pub fn jcxz(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
 //pub fn jrcxz(_: Rvalue) -> Result<(Vec<Statement>,JumpSpec)> { Ok((vec![],JumpSpec::FallThru)) }
 
 pub fn lahf() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("lahf", arith_flags(), vec![rreil_rvalue!{ AH:8 }], &[])?, JumpSpec::FallThru))
 }
 
 pub fn sahf() -> Result<(Vec<Statement>, JumpSpec)> {
@@ -1394,11 +1682,11 @@ pub fn sahf() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((stmts, JumpSpec::FallThru))
 }
 
-pub fn lsl(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn lsl(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("lsl", vec![b], vec![a], &["ZF"])?, JumpSpec::FallThru))
 }
-pub fn lar(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn lar(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("lar", vec![b], vec![a], &["ZF"])?, JumpSpec::FallThru))
 }
 pub fn lds(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     lxs(a, b, rreil_rvalue!{ DS:16 })
@@ -1415,8 +1703,8 @@ pub fn lfs(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
 pub fn lgs(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     lxs(a, b, rreil_rvalue!{ GS:16 })
 }
-pub fn lxs(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn lxs(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("lxs", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
 pub fn lea(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     let (_, b, sz, mut stmts) = zero_extend(&a, &b)?;
@@ -1434,11 +1722,18 @@ pub fn leave() -> Result<(Vec<Statement>, JumpSpec)> {
     st.mnemonic(len,"leave","{u}",Ok((vec![],JumpSpec::FallThru)),&|| {} );
     st.jump(Rvalue::new_u64(next),Guard::always());
     true*/
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("leave", vec![rreil_rvalue!{ RBP:64 }], vec![rreil_rvalue!{ RSP:64 }, rreil_rvalue!{ RBP:64 }], &[])?, JumpSpec::FallThru))
 }
 
 pub fn lodsw() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "lodsw",
+        vec![rreil_rvalue!{ RSI:64 }],
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RSI:64 }, rreil_rvalue!{ RCX:64 }],
+        &[],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 
 pub fn lodsb() -> Result<(Vec<Statement>, JumpSpec)> {
@@ -1449,7 +1744,14 @@ pub fn lodsb() -> Result<(Vec<Statement>, JumpSpec)> {
     st.mnemonic(len,"lodsb","{u}",Ok((vec![],JumpSpec::FallThru)),&|| {} );
     st.jump(Rvalue::new_u64(next),Guard::always());
     true*/
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "lodsb",
+        vec![rreil_rvalue!{ RSI:64 }],
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RSI:64 }, rreil_rvalue!{ RCX:64 }],
+        &[],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 
 /*pub fn lods() -> Result<(Vec<Statement>,JumpSpec)> {
@@ -1470,7 +1772,7 @@ pub fn loop_(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     st.mnemonic(len,"loop","{u}",Ok((vec![],JumpSpec::FallThru)),&|| {} );
     st.jump(Rvalue::new_u64(next),Guard::always());
     true*/
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("loop", vec![rreil_rvalue!{ RCX:64 }], vec![rreil_rvalue!{ RCX:64 }], &[])?, JumpSpec::FallThru))
 }
 
 pub fn loope(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
@@ -1481,7 +1783,7 @@ pub fn loope(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     st.mnemonic(len,"loope","{u}",Ok((vec![],JumpSpec::FallThru)),&|| {} );
     st.jump(Rvalue::new_u64(next),Guard::always());
     true*/
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("loope", vec![rreil_rvalue!{ RCX:64 }], vec![rreil_rvalue!{ RCX:64 }], &[])?, JumpSpec::FallThru))
 }
 
 pub fn loopne(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
@@ -1492,7 +1794,7 @@ pub fn loopne(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     st.mnemonic(len,"loopne","{u}",Ok((vec![],JumpSpec::FallThru)),&|| {} );
     st.jump(Rvalue::new_u64(next),Guard::always());
     true*/
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("loopne", vec![rreil_rvalue!{ RCX:64 }], vec![rreil_rvalue!{ RCX:64 }], &[])?, JumpSpec::FallThru))
 }
 
 pub fn mov(a_: Rvalue, b_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
@@ -1503,8 +1805,8 @@ pub fn mov(a_: Rvalue, b_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((stmts, JumpSpec::FallThru))
 }
 
-pub fn movbe(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movbe(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movbe", vec![b], vec![a], &[])?, JumpSpec::FallThru))
 }
 
 pub fn movsb() -> Result<(Vec<Statement>, JumpSpec)> {
@@ -1515,11 +1817,25 @@ pub fn movsb() -> Result<(Vec<Statement>, JumpSpec)> {
     st.mnemonic(len,"movsb","{u}",Ok((vec![],JumpSpec::FallThru)),&|| {} );
     st.jump(Rvalue::new_u64(next),Guard::always());
     true*/
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "movsb",
+        vec![rreil_rvalue!{ RSI:64 }, rreil_rvalue!{ RDI:64 }],
+        vec![rreil_rvalue!{ RSI:64 }, rreil_rvalue!{ RDI:64 }, rreil_rvalue!{ RCX:64 }],
+        &[],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 
 pub fn movsw() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "movsw",
+        vec![rreil_rvalue!{ RSI:64 }, rreil_rvalue!{ RDI:64 }],
+        vec![rreil_rvalue!{ RSI:64 }, rreil_rvalue!{ RDI:64 }, rreil_rvalue!{ RCX:64 }],
+        &[],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 
 /*pub fn movs() -> Result<(Vec<Statement>,JumpSpec)> {
@@ -1548,11 +1864,18 @@ pub fn movzx(a_: Rvalue, b_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((stmts, JumpSpec::FallThru))
 }
 
-pub fn mul(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn mul(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    let stmts = intrinsic(
+        "mul",
+        vec![a, rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }],
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }],
+        &["CF", "OF", "SF", "ZF", "AF", "PF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
-pub fn neg(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn neg(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("neg", vec![a.clone()], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 pub fn nop(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
@@ -1566,25 +1889,39 @@ pub fn rep() -> Result<(Vec<Statement>, JumpSpec)> {
 pub fn repne() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn not(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn not(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("not", vec![a.clone()], vec![a], &[])?, JumpSpec::FallThru))
 }
 pub fn out(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
 
 pub fn outsb() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "outsb",
+        vec![rreil_rvalue!{ RSI:64 }, rreil_rvalue!{ RDX:64 }],
+        vec![rreil_rvalue!{ RSI:64 }, rreil_rvalue!{ RCX:64 }],
+        &[],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 pub fn outsw() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "outsw",
+        vec![rreil_rvalue!{ RSI:64 }, rreil_rvalue!{ RDX:64 }],
+        vec![rreil_rvalue!{ RSI:64 }, rreil_rvalue!{ RCX:64 }],
+        &[],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 
 pub fn popfw() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("popfw", vec![rreil_rvalue!{ RSP:64 }], vec![rreil_rvalue!{ RSP:64 }], &["CF", "OF", "SF", "ZF", "AF", "PF", "DF"])?, JumpSpec::FallThru))
 }
 pub fn pushfw() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("pushfw", [vec![rreil_rvalue!{ RSP:64 }], arith_flags()].concat(), vec![rreil_rvalue!{ RSP:64 }], &[])?, JumpSpec::FallThru))
 }
 pub fn pop(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     use std::borrow::Cow;
@@ -1632,11 +1969,11 @@ pub fn popa() -> Result<(Vec<Statement>, JumpSpec)> {
     st.mnemonic(len,"popa","",Ok((vec![],JumpSpec::FallThru)),&|| {} );
     st.jump(Rvalue::new_u64(next),Guard::always());
     true*/
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("popa", vec![rreil_rvalue!{ RSP:64 }], [general_registers(), vec![rreil_rvalue!{ RSP:64 }]].concat(), &[])?, JumpSpec::FallThru))
 }
 
-pub fn popcnt(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn popcnt(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("popcnt", vec![b], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 //pub fn popf(_: Rvalue) -> Result<(Vec<Statement>,JumpSpec)> { Ok((vec![],JumpSpec::FallThru)) }
 
@@ -1680,15 +2017,15 @@ pub fn pusha() -> Result<(Vec<Statement>, JumpSpec)> {
     st.mnemonic(len,"pusha","",Ok((vec![],JumpSpec::FallThru)),&|| {} );
     st.jump(Rvalue::new_u64(next),Guard::always());
     true*/
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("pusha", [general_registers(), vec![rreil_rvalue!{ RSP:64 }]].concat(), vec![rreil_rvalue!{ RSP:64 }], &[])?, JumpSpec::FallThru))
 }
 
 //pub fn pushf(_: Rvalue) -> Result<(Vec<Statement>,JumpSpec)> { Ok((vec![],JumpSpec::FallThru)) }
-pub fn rcl(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn rcl(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("rcl", vec![a.clone(), b, rreil_rvalue!{ CF:1 }], vec![a], &["CF", "OF"])?, JumpSpec::FallThru))
 }
-pub fn rcr(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn rcr(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("rcr", vec![a.clone(), b, rreil_rvalue!{ CF:1 }], vec![a], &["CF", "OF"])?, JumpSpec::FallThru))
 }
 
 pub fn ret() -> Result<(Vec<Statement>, JumpSpec)> {
@@ -1719,10 +2056,10 @@ pub fn retnf(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::DeadEnd))
 }
 
-pub fn ror(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn ror(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("ror", vec![a.clone(), b], vec![a], &["CF", "OF"])?, JumpSpec::FallThru))
 }
-pub fn rol(_a: Rvalue, _b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+pub fn rol(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     /*    let (a,b,sz,mut stmts) = try!(sign_extend(&a_,&b_));
     let res = rreil_lvalue!{ res:sz };
     let mut stmts = vec![];
@@ -1744,24 +2081,38 @@ pub fn rol(_a: Rvalue, _b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
 
     Ok((stmts,JumpSpec::FallThru))
 */
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("rol", vec![a.clone(), b], vec![a], &["CF", "OF"])?, JumpSpec::FallThru))
 }
 
-pub fn sal(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn sal(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("sal", vec![a.clone(), b], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 pub fn salc() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("salc", vec![rreil_rvalue!{ CF:1 }], vec![rreil_rvalue!{ AL:8 }], &[])?, JumpSpec::FallThru))
 }
-pub fn sar(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn sar(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("sar", vec![a.clone(), b], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 
 pub fn scasw() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "scasw",
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDI:64 }],
+        vec![rreil_rvalue!{ RDI:64 }, rreil_rvalue!{ RCX:64 }],
+        &["CF", "OF", "SF", "ZF", "AF", "PF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 pub fn scasb() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "scasb",
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDI:64 }],
+        vec![rreil_rvalue!{ RDI:64 }, rreil_rvalue!{ RCX:64 }],
+        &["CF", "OF", "SF", "ZF", "AF", "PF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 
 pub fn scas() -> Result<(Vec<Statement>, JumpSpec)> {
@@ -1771,23 +2122,44 @@ pub fn scas() -> Result<(Vec<Statement>, JumpSpec)> {
     st.mnemonic(len,"scas","{u}",Ok((vec![],JumpSpec::FallThru)),&|| {} );
     st.jump(Rvalue::new_u64(next),Guard::always());
     true*/
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "scas",
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDI:64 }],
+        vec![rreil_rvalue!{ RDI:64 }, rreil_rvalue!{ RCX:64 }],
+        &["CF", "OF", "SF", "ZF", "AF", "PF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 
-pub fn shl(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn shl(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("shl", vec![a.clone(), b], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn shr(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn shr(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("shr", vec![a.clone(), b], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 //pub fn shld(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>,JumpSpec)> { Ok((vec![],JumpSpec::FallThru)) }
 //pub fn shrd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>,JumpSpec)> { Ok((vec![],JumpSpec::FallThru)) }
 
 pub fn stosb() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "stosb",
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDI:64 }],
+        vec![rreil_rvalue!{ RDI:64 }, rreil_rvalue!{ RCX:64 }],
+        &[],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 pub fn stosw() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "stosw",
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDI:64 }],
+        vec![rreil_rvalue!{ RDI:64 }, rreil_rvalue!{ RCX:64 }],
+        &[],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 /*pub fn stos() -> Result<(Vec<Statement>,JumpSpec)> {
     /*let next = st.address + (st.tokens.len() as u64);
@@ -1799,8 +2171,8 @@ pub fn stosw() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![],JumpSpec::FallThru))
 }*/
 
-pub fn test(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn test(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("test", vec![a, b], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 pub fn ud1() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
@@ -1808,20 +2180,20 @@ pub fn ud1() -> Result<(Vec<Statement>, JumpSpec)> {
 pub fn ud2() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn xadd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn xadd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("xadd", vec![a.clone(), b.clone()], vec![a, b], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn xchg(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn xchg(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("xchg", vec![a.clone(), b.clone()], vec![a, b], &[])?, JumpSpec::FallThru))
 }
 pub fn rdtsc() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("rdtsc", vec![], vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }], &[])?, JumpSpec::FallThru))
 }
 pub fn xgetbv(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("xgetbv", vec![rreil_rvalue!{ ECX:32 }], vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }], &[])?, JumpSpec::FallThru))
 }
 pub fn xlatb() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("xlatb", vec![rreil_rvalue!{ AL:8 }, rreil_rvalue!{ RBX:64 }], vec![rreil_rvalue!{ AL:8 }], &[])?, JumpSpec::FallThru))
 }
 pub fn wait() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
@@ -1841,84 +2213,126 @@ pub fn movsxd(a_: Rvalue, b_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
 }
 
 pub fn syscall() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "syscall",
+        vec![rreil_rvalue!{ RAX:64 }],
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RCX:64 }, rreil_rvalue!{ R11:64 }],
+        &[],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 pub fn sysret() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("sysret", vec![rreil_rvalue!{ RCX:64 }, rreil_rvalue!{ R11:64 }], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 
-pub fn movapd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movapd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movapd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
 pub fn wrmsr() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
 pub fn rdmsr() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("rdmsr", vec![rreil_rvalue!{ ECX:32 }], vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }], &[])?, JumpSpec::FallThru))
 }
 pub fn rdpmc() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("rdpmc", vec![rreil_rvalue!{ ECX:32 }], vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }], &[])?, JumpSpec::FallThru))
 }
 pub fn sysenter() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("sysenter", vec![], vec![rreil_rvalue!{ RSP:64 }], &[])?, JumpSpec::FallThru))
 }
 pub fn sysexit() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("sysexit", vec![rreil_rvalue!{ RCX:64 }, rreil_rvalue!{ RDX:64 }], vec![rreil_rvalue!{ RSP:64 }], &[])?, JumpSpec::FallThru))
 }
 pub fn getsec() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "getsec",
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RBX:64 }],
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RBX:64 }, rreil_rvalue!{ RCX:64 }, rreil_rvalue!{ RDX:64 }],
+        &[],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
-pub fn vmread(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmread(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmread", vec![b], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn vmwrite(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmwrite(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmwrite", vec![a, b], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 //pub fn vmenter() -> Result<(Vec<Statement>,JumpSpec)> { Ok((vec![],JumpSpec::FallThru)) }
 //pub fn vmexit() -> Result<(Vec<Statement>,JumpSpec)> { Ok((vec![],JumpSpec::FallThru)) }
 
 pub fn montmul() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "montmul",
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RSI:64 }, rreil_rvalue!{ RCX:64 }],
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }, rreil_rvalue!{ RCX:64 }],
+        &[],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 pub fn xcryptecb() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "xcryptecb",
+        vec![rreil_rvalue!{ RBX:64 }, rreil_rvalue!{ RCX:64 }, rreil_rvalue!{ RDX:64 }, rreil_rvalue!{ RSI:64 }, rreil_rvalue!{ RDI:64 }],
+        vec![rreil_rvalue!{ RCX:64 }, rreil_rvalue!{ RSI:64 }, rreil_rvalue!{ RDI:64 }],
+        &[],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 pub fn rsm() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "rsm",
+        vec![],
+        [general_registers(), vec![rreil_rvalue!{ RSP:64 }]].concat(),
+        &["CF", "OF", "SF", "ZF", "AF", "PF", "DF", "IF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
-pub fn pmaddwd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmaddwd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmaddwd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn tzcnt(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn tzcnt(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("tzcnt", vec![b], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn lzcnt(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn lzcnt(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("lzcnt", vec![b], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn crc32(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn crc32(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("crc32", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
 
 pub fn vmcall() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("vmcall", vec![], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 pub fn vmlaunch() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("vmlaunch", vec![], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 pub fn vmresume() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("vmresume", vec![], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 pub fn vmxoff() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("vmxoff", vec![], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 pub fn clac() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("clac", vec![], vec![], &["AC"])?, JumpSpec::FallThru))
 }
 pub fn stac() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("stac", vec![], vec![], &["AC"])?, JumpSpec::FallThru))
 }
 pub fn encls() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "encls",
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RBX:64 }, rreil_rvalue!{ RCX:64 }, rreil_rvalue!{ RDX:64 }],
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RBX:64 }, rreil_rvalue!{ RCX:64 }, rreil_rvalue!{ RDX:64 }],
+        &["CF", "OF", "SF", "ZF", "AF", "PF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 pub fn xsetbv() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
@@ -1930,52 +2344,66 @@ pub fn xend() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
 pub fn xtest() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("xtest", vec![], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 pub fn enclu() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    let stmts = intrinsic(
+        "enclu",
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RBX:64 }, rreil_rvalue!{ RCX:64 }, rreil_rvalue!{ RDX:64 }],
+        vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RBX:64 }, rreil_rvalue!{ RCX:64 }, rreil_rvalue!{ RDX:64 }],
+        &["CF", "OF", "SF", "ZF", "AF", "PF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
 pub fn swapgs() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
 pub fn rdtscp() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("rdtscp", vec![], vec![rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RCX:64 }, rreil_rvalue!{ RDX:64 }], &[])?, JumpSpec::FallThru))
 }
-pub fn rdrand(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn rdrand(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("rdrand", vec![], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn rdseed(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn rdseed(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("rdseed", vec![], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn rdpid(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn rdpid(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("rdpid", vec![], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn cmpxch8b(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cmpxch8b(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    let stmts = intrinsic(
+        "cmpxchg8b",
+        vec![a.clone(), rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }, rreil_rvalue!{ RBX:64 }, rreil_rvalue!{ RCX:64 }],
+        vec![a, rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }],
+        &["ZF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
-pub fn vmptrld(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmptrld(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmptrld", vec![a], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn vmptrst(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmptrst(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmptrst", vec![], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn vmclear(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmclear(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmclear", vec![a], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn vmxon(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmxon(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmxon", vec![a], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 pub fn xabort(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
 pub fn xbegin(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("xbegin", vec![], vec![rreil_rvalue!{ RAX:64 }], &[])?, JumpSpec::FallThru))
 }
-pub fn rdfsbase(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn rdfsbase(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("rdfsbase", vec![], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn rdgsbase(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn rdgsbase(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("rdgsbase", vec![], vec![a], &[])?, JumpSpec::FallThru))
 }
 pub fn wrfsbase(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
@@ -1987,13 +2415,13 @@ pub fn fxsave() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
 pub fn fxstor() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fxstor", vec![], [fpu_stack(), simd_registers()].concat(), &[])?, JumpSpec::FallThru))
 }
 pub fn xsave() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
 pub fn xrstor() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("xrstor", vec![], [fpu_stack(), simd_registers()].concat(), &[])?, JumpSpec::FallThru))
 }
 pub fn xsaveopt() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
@@ -2001,75 +2429,75 @@ pub fn xsaveopt() -> Result<(Vec<Statement>, JumpSpec)> {
 pub fn clflush() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn blsr(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn blsr(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("blsr", vec![a.clone(), b], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn blsmsk(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn blsmsk(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("blsmsk", vec![a.clone(), b], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn blsi(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn blsi(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("blsi", vec![a.clone(), b], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 
-pub fn andn(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn andn(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("andn", vec![a.clone(), b], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn bextr(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn bextr(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("bextr", vec![a.clone(), b], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn blendd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn blendd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("blendd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn blendvb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn blendvb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("blendvb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn bzhi(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn bzhi(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("bzhi", vec![a.clone(), b], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 pub fn clgi() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn cvtph2ps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvtph2ps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("cvtph2ps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn extracti128(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn extracti128(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("extracti128", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fist(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fist(a: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fist", fpu_stack(), vec![a], &[])?, JumpSpec::FallThru))
 }
 pub fn fldl2g() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fldl2g", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fperm() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fperm", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fperm1() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fperm1", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn frndintx() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("frndintx", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fsubrp(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fsubrp(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fsubrp", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn gatherdd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn gatherdd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("gatherdd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn gatherdps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn gatherdps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("gatherdps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn gatherqd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn gatherqd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("gatherqd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn gatherqps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn gatherqps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("gatherqps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn inserti128(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn inserti128(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("inserti128", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn invept(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn invept(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("invept", vec![a, b], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 pub fn invlpg(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
@@ -2077,8 +2505,8 @@ pub fn invlpg(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
 pub fn invpcid(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn invvpid(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn invvpid(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("invvpid", vec![a, b], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 pub fn lgdt(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
@@ -2095,411 +2523,411 @@ pub fn lmsw(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
 pub fn ltr(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn maskmovpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn maskmovpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("maskmovpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn maskmovps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn maskmovps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("maskmovps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pavgusb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pavgusb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pavgusb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pblendw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pblendw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pblendw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pclmulqdq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pclmulqdq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pclmulqdq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pcmpgtq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pcmpgtq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pcmpgtq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn perm2f128(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn perm2f128(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("perm2f128", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn perm2i128(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn perm2i128(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("perm2i128", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn permd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn permd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("permd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn permilp(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn permilp(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("permilp", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn permilpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn permilpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("permilpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn permilps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn permilps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("permilps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn permpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn permpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("permpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn permq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn permq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("permq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pf2id(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pf2id(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pf2id", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pf2iw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pf2iw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pf2iw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pfacc(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pfacc(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pfacc", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pfadd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pfadd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pfadd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pfcmpeq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pfcmpeq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pfcmpeq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pfcmpge(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pfcmpge(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pfcmpge", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pfcmpgt(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pfcmpgt(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pfcmpgt", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pfmax(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pfmax(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pfmax", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pfmin(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pfmin(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pfmin", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pfmul(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pfmul(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pfmul", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pfnacc(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pfnacc(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pfnacc", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pfpnacc(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pfpnacc(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pfpnacc", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pfrcp(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pfrcp(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pfrcp", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pfrcpit1(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pfrcpit1(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pfrcpit1", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pfrcpit2(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pfrcpit2(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pfrcpit2", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pfrsqit1(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pfrsqit1(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pfrsqit1", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pfrsqrt(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pfrsqrt(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pfrsqrt", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pfsub(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pfsub(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pfsub", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pfsubr(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pfsubr(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pfsubr", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn phminposuw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn phminposuw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("phminposuw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pi2fd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pi2fd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pi2fd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pi2fw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pi2fw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pi2fw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmaddubsw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmaddubsw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmaddubsw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmaskmovd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmaskmovd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmaskmovd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmovsxbd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmovsxbd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmovsxbd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmovsxbq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmovsxbq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmovsxbq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmovsxbw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmovsxbw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmovsxbw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmovsxdq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmovsxdq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmovsxdq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmovsxwd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmovsxwd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmovsxwd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmovsxwq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmovsxwq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmovsxwq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmovzxbd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmovzxbd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmovzxbd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmovzxbq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmovzxbq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmovzxbq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmovzxbw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmovzxbw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmovzxbw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmovzxdq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmovzxdq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmovzxdq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmovzxwd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmovzxwd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmovzxwd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmovzxwq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmovzxwq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmovzxwq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmulhrsw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmulhrsw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmulhrsw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmulhrw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmulhrw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmulhrw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psignb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psignb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psignb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psignd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psignd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psignd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psignw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psignw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psignw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pswapd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pswapd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pswapd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn punpckldq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn punpckldq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("punpckldq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn sgdt(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn sgdt(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("sgdt", vec![], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn sha1msg1(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn sha1msg1(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("sha1msg1", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn sha1msg2(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn sha1msg2(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("sha1msg2", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn sha1nexte(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn sha1nexte(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("sha1nexte", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn sha1rnds4(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn sha1rnds4(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("sha1rnds4", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn sha256msg1(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn sha256msg1(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("sha256msg1", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn sha256msg2(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn sha256msg2(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("sha256msg2", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn sha256rnds2(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn sha256rnds2(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("sha256rnds2", vec![a.clone(), b, rreil_rvalue!{ XMM0:128 }], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn shlx(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn shlx(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("shlx", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn sidt(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn sidt(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("sidt", vec![], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn sldt(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn sldt(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("sldt", vec![], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn smsw(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn smsw(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("smsw", vec![], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn str(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn str(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("str", vec![], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn testpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn testpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("testpd", vec![a, b], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn testps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn testps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("testps", vec![a, b], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn verr(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn verr(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("verr", vec![a], vec![], &["ZF"])?, JumpSpec::FallThru))
 }
-pub fn verw(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn verw(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("verw", vec![a], vec![], &["ZF"])?, JumpSpec::FallThru))
 }
 
 // MMX
 pub fn emms() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn packsswb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn packsswb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("packsswb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn packssdw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn packssdw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("packssdw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn packuswb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn packuswb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("packuswb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn paddb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn paddb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("paddb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn paddw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn paddw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("paddw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn paddd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn paddd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("paddd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn paddsb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn paddsb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("paddsb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn paddsw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn paddsw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("paddsw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn paddusb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn paddusb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("paddusb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn paddusw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn paddusw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("paddusw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pand(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pand(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pand", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pandn(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pandn(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pandn", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pcmpeqb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pcmpeqb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pcmpeqb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pcmpeqw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pcmpeqw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pcmpeqw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pcmpeqd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pcmpeqd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pcmpeqd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pcmpgtb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pcmpgtb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pcmpgtb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pcmpgtw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pcmpgtw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pcmpgtw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pcmpgtd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pcmpgtd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pcmpgtd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmadwd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmadwd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmadwd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmulhw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmulhw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmulhw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmullw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmullw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmullw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn por(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn por(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("por", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psraw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psraw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psraw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psrad(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psrad(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psrad", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psrlw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psrlw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psrlw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psrld(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psrld(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psrld", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psrlq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psrlq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psrlq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psllw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psllw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psllw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pslld(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pslld(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pslld", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psllq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psllq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psllq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psubb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psubb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psubb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psubw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psubw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psubw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psubd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psubd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psubd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psubsb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psubsb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psubsb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psubsw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psubsw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psubsw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psubusb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psubusb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psubusb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psubusw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psubusw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psubusw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn punpckhbw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn punpckhbw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("punpckhbw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn punpckhwd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn punpckhwd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("punpckhwd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn punpckhdq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn punpckhdq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("punpckhdq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn punpcklbw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn punpcklbw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("punpcklbw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn punpcklwd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn punpcklwd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("punpcklwd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn punpcklqdq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn punpcklqdq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("punpcklqdq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pxor(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pxor(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pxor", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
 
 // SSE 1
-pub fn addps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn addps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("addps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn addss(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn addss(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vaddss(a, Rvalue::Undefined, b)
 }
-pub fn andnps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn andnps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("andnps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn andps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn andps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("andps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn cmpps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cmpps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("cmpps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn cmpss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cmpss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vcmpss(a, Rvalue::Undefined, b, c)
 }
-pub fn comiss(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn comiss(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_flags(a, b, 32)
 }
-pub fn cvtpi2ps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvtpi2ps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("cvtpi2ps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn cvtps2pi(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvtps2pi(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("cvtps2pi", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn cvtsi2ss(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvtsi2ss(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vcvtsi2ss(a, Rvalue::Undefined, b)
 }
-pub fn cvtss2si(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvtss2si(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_to_int(a, b, 32, Rounding::NearestEven)
 }
-pub fn cvttps2pi(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvttps2pi(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("cvttps2pi", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn cvttss2si(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvttss2si(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_to_int(a, b, 32, Rounding::TowardZero)
 }
-pub fn divps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn divps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("divps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn divss(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn divss(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vdivss(a, Rvalue::Undefined, b)
 }
 pub fn ldmxcsr() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
@@ -2507,80 +2935,80 @@ pub fn ldmxcsr() -> Result<(Vec<Statement>, JumpSpec)> {
 pub fn maskmovq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn maxps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn maxps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("maxps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn maxss(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn maxss(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vmaxss(a, Rvalue::Undefined, b)
 }
-pub fn minps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn minps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("minps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn minss(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn minss(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vminss(a, Rvalue::Undefined, b)
 }
-pub fn movaps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movaps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movaps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn minhps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn minhps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("minhps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movlps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movlps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movlps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movmskps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movmskps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movmskps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movntps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movntps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movntps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movntq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movntq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movntq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movss(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movss(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movss", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movups(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movups(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movups", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn mulps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn mulps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("mulps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn mulss(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn mulss(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vmulss(a, Rvalue::Undefined, b)
 }
-pub fn orps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn orps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("orps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pavgb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pavgb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pavgb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pavgw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pavgw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pavgw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pextrw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pextrw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pextrw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pinsrw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pinsrw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pinsrw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmaxsw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmaxsw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmaxsw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmaxub(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmaxub(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmaxub", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pminsw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pminsw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pminsw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pminub(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pminub(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pminub", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmovmskb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmovmskb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmovmskb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmulhuw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmulhuw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmulhuw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
 pub fn prefetchnta(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
@@ -2600,139 +3028,139 @@ pub fn prefetchw(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
 pub fn prefetchwt1(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn psadbw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psadbw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psadbw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pshufw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pshufw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pshufw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pshufb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pshufb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pshufb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn rcpps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn rcpps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("rcpps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn rcpss(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn rcpss(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("rcpss", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn rsqrtps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn rsqrtps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("rsqrtps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn rsqrtss(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn rsqrtss(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("rsqrtss", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
 pub fn sfence() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn shufps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn shufps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("shufps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn sqrtps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn sqrtps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("sqrtps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn sqrtss(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn sqrtss(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vsqrtss(a, Rvalue::Undefined, b)
 }
 pub fn stmxcsr() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn subps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn subps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("subps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn subss(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn subss(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vsubss(a, Rvalue::Undefined, b)
 }
-pub fn ucomiss(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn ucomiss(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_flags(a, b, 32)
 }
-pub fn unpckhps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn unpckhps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("unpckhps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn unpcklps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn unpcklps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("unpcklps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn xorps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn xorps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("xorps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
 
 // SSE 2
-pub fn addpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn addpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("addpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn addsd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn addsd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vaddsd(a, Rvalue::Undefined, b)
 }
-pub fn andnpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn andnpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("andnpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn andpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn andpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("andpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
 pub fn cflush(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn cmppd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cmppd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("cmppd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn cmpsd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cmpsd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vcmpsd(a, Rvalue::Undefined, b, c)
 }
-pub fn comisd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn comisd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_flags(a, b, 64)
 }
-pub fn cvtdq2pd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvtdq2pd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("cvtdq2pd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn cvtdq2ps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvtdq2ps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("cvtdq2ps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn cvtpd2dq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvtpd2dq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("cvtpd2dq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn cvtpd2pi(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvtpd2pi(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("cvtpd2pi", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn cvtpd2ps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvtpd2ps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("cvtpd2ps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn cvtpi2pd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvtpi2pd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("cvtpi2pd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn cvtps2dq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvtps2dq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("cvtps2dq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn cvtps2pd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvtps2pd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("cvtps2pd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn cvtsd2si(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvtsd2si(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_to_int(a, b, 64, Rounding::NearestEven)
 }
-pub fn cvtsd2ss(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvtsd2ss(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vcvtsd2ss(a, Rvalue::Undefined, b)
 }
-pub fn cvtsi2sd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvtsi2sd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vcvtsi2sd(a, Rvalue::Undefined, b)
 }
-pub fn cvtss2sd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvtss2sd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vcvtss2sd(a, Rvalue::Undefined, b)
 }
-pub fn cvttpd2dq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvttpd2dq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("cvttpd2dq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn cvttpd2pi(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvttpd2pi(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("cvttpd2pi", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn cvttps2dq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvttps2dq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("cvttps2dq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn cvttsd2si(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn cvttsd2si(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_to_int(a, b, 64, Rounding::TowardZero)
 }
-pub fn divpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn divpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("divpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn divsd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn divsd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vdivsd(a, Rvalue::Undefined, b)
 }
 pub fn lfence() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
@@ -2740,406 +3168,420 @@ pub fn lfence() -> Result<(Vec<Statement>, JumpSpec)> {
 pub fn maskmovdqu(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn maxpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn maxpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("maxpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn maxsd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn maxsd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vmaxsd(a, Rvalue::Undefined, b)
 }
 pub fn mfence() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn minpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn minpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("minpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn minsd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn minsd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vminsd(a, Rvalue::Undefined, b)
 }
-pub fn movd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movdq2q(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movdq2q(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movdq2q", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movdaq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movdaq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movdaq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movdqa(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movdqa(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movdqa", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movdqu(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movdqu(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movdqu", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movhpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movhpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movhpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movhps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movhps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movhps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movlpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movlpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movlpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movmskpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movmskpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movmskpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movntdq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movntdq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movntdq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movntdqa(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movntdqa(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movntdqa", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movnti(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movnti(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movnti", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movntpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movntpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movntpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movq2dq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movq2dq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movq2dq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movsd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movsd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movsd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movupd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movupd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movupd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn mulpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn mulpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("mulpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn mulsd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn mulsd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vmulsd(a, Rvalue::Undefined, b)
 }
-pub fn orpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn orpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("orpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pabsb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pabsb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pabsb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pabsw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pabsw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pabsw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pabsd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pabsd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pabsd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn paddq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn paddq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("paddq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
 pub fn pause() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn pmuludq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmuludq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmuludq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pshufd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pshufd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pshufd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pshufhw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pshufhw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pshufhw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pshuflw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pshuflw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pshuflw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pslldq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pslldq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pslldq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psarw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psarw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psarw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psrldq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psrldq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psrldq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn psubq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn psubq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("psubq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pusbsw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pusbsw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pusbsw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn punckhwd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn punckhwd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("punckhwd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn punpckhqdq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn punpckhqdq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("punpckhqdq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn puncklqdq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn puncklqdq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("puncklqdq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn puncklwd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn puncklwd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("puncklwd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn shufpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn shufpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("shufpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn sqrtpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn sqrtpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("sqrtpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn sqrtsd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn sqrtsd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vsqrtsd(a, Rvalue::Undefined, b)
 }
-pub fn subpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn subpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("subpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn subsd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn subsd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    vsubsd(a, Rvalue::Undefined, b)
 }
-pub fn ucomisd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn ucomisd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_flags(a, b, 64)
 }
-pub fn unpckhpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn unpckhpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("unpckhpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn unpcklpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn unpcklpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("unpcklpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn xorpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn xorpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("xorpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
 
 // SSE 4
-pub fn blendpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn blendpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("blendpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn blendps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn blendps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("blendps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn blendvpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn blendvpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("blendvpd", vec![a.clone(), b, rreil_rvalue!{ XMM0:128 }], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn blendvps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn blendvps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("blendvps", vec![a.clone(), b, rreil_rvalue!{ XMM0:128 }], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn dppd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn dppd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("dppd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn dpps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn dpps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("dpps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn extractps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn extractps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("extractps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn insertps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn insertps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("insertps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn mpsadbw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn mpsadbw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("mpsadbw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pblendbw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pblendbw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pblendbw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pcmpestri(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pcmpestri(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    let stmts = intrinsic(
+        "pcmpestri",
+        vec![a, b, c, rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }],
+        vec![rreil_rvalue!{ RCX:64 }],
+        &["CF", "OF", "SF", "ZF", "AF", "PF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
-pub fn pcmpestrm(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pcmpestrm(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    let stmts = intrinsic(
+        "pcmpestrm",
+        vec![a, b, c, rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }],
+        vec![rreil_rvalue!{ XMM0:128 }],
+        &["CF", "OF", "SF", "ZF", "AF", "PF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
-pub fn pcmpistri(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pcmpistri(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pcmpistri", vec![a, b, c], vec![rreil_rvalue!{ RCX:64 }], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn pcmpistrm(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pcmpistrm(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pcmpistrm", vec![a, b, c], vec![rreil_rvalue!{ XMM0:128 }], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn pextrb(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pextrb(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pextrb", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pextrd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pextrd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pextrd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pextrq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pextrq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pextrq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pinsrb(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pinsrb(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pinsrb", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pinsrd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pinsrd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pinsrd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pinsrq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pinsrq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pinsrq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn roundpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn roundpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("roundpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn roundps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn roundps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("roundps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn roundsd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn roundsd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_round(a, b, c, 64)
 }
-pub fn roundss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn roundss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_round(a, b, c, 32)
 }
-pub fn pmovsx(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmovsx(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmovsx", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmovzx(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmovzx(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmovzx", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pminsd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pminsd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pminsd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pminsb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pminsb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pminsb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pminud(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pminud(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pminud", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pminuw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pminuw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pminuw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmaxsd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmaxsd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmaxsd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmaxsb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmaxsb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmaxsb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmaxud(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmaxud(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmaxud", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmaxuw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmaxuw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmaxuw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn ptest(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn ptest(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("ptest", vec![a, b], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn pmulld(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmulld(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmulld", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pmuldq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pmuldq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pmuldq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn phaddw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn phaddw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("phaddw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn phaddsw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn phaddsw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("phaddsw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn phaddd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn phaddd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("phaddd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn phsubw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn phsubw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("phsubw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn phsubsw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn phsubsw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("phsubsw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn phsubd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn phsubd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("phsubd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn packusdw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn packusdw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("packusdw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pblendvb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pblendvb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pblendvb", vec![a.clone(), b, rreil_rvalue!{ XMM0:128 }], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pcmpeqq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pcmpeqq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pcmpeqq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn phminpushuw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn phminpushuw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("phminpushuw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
 
 // SSE 3
-pub fn addsubpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn addsubpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("addsubpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn addsubps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn addsubps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("addsubps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn haddpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn haddpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("haddpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn haddps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn haddps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("haddps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn hsubpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn hsubpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("hsubpd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn hsubps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn hsubps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("hsubps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn lddqu(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn lddqu(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("lddqu", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
 pub fn monitor() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn movddup(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movddup(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movddup", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movshdup(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movshdup(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movshdup", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn movsldup(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn movsldup(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("movsldup", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
 pub fn mwait() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn palignr(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn palignr(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("palignr", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
 
 // AVX
-pub fn aesdec(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn aesdec(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("aesdec", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vmovd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmovd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmovd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn aesdeclast(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn aesdeclast(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("aesdeclast", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn aesenc(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn aesenc(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("aesenc", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn aesenclast(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn aesenclast(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("aesenclast", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn aesimc(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn aesimc(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("aesimc", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn aeskeygenassist(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn aeskeygenassist(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("aeskeygenassist", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vboradcastss(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vboradcastss(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vboradcastss", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vboradcastsd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vboradcastsd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vboradcastsd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vboradcastf128(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vboradcastf128(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vboradcastf128", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
 pub fn vzeroupper() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("vzeroupper", vec![], simd_registers(), &[])?, JumpSpec::FallThru))
 }
 
 // FPU
 pub fn f2xm1() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("f2xm1", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fabs() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fabs", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fadd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fadd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fadd", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn faddp(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn faddp(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("faddp", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fiadd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fiadd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fiadd", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fbld(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fbld(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fbld", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fbstp(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fbstp(a: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fbstp", fpu_stack(), [vec![a], fpu_stack()].concat(), &[])?, JumpSpec::FallThru))
 }
 pub fn fchs() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fchs", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fclex() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
@@ -3147,74 +3589,74 @@ pub fn fclex() -> Result<(Vec<Statement>, JumpSpec)> {
 pub fn fnclex(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn fcmovb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fcmovb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fcmovb", [vec![a, b], fpu_stack(), arith_flags()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fcmove(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fcmove(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fcmove", [vec![a, b], fpu_stack(), arith_flags()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fcmovbe(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fcmovbe(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fcmovbe", [vec![a, b], fpu_stack(), arith_flags()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fcmovu(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fcmovu(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fcmovu", [vec![a, b], fpu_stack(), arith_flags()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fcmovnb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fcmovnb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fcmovnb", [vec![a, b], fpu_stack(), arith_flags()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fcmovne(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fcmovne(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fcmovne", [vec![a, b], fpu_stack(), arith_flags()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fcmovnbe(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fcmovnbe(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fcmovnbe", [vec![a, b], fpu_stack(), arith_flags()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fcmovnu(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fcmovnu(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fcmovnu", [vec![a, b], fpu_stack(), arith_flags()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fcom(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn fcomp(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fcomp(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fcomp", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fcompp() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fcompp", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fcomi(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fcomi(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fcomi", vec![a, b], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn fcomip(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fcomip(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fcomip", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn fucomi(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fucomi(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fucomi", vec![a, b], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn fucomip(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fucomip(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fucomip", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 pub fn fcos() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fcos", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fdecstp() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fdecstp", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fdiv(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fdiv(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fdiv", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fdivp(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fdivp(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fdivp", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fidiv(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fidiv(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fidiv", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fdivr(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fdivr(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fdivr", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fdivrp(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fdivrp(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fdivrp", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fidivr(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fidivr(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fidivr", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn ffree(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
@@ -3222,146 +3664,146 @@ pub fn ffree(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
 pub fn ficom(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn ficomp(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn ficomp(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("ficomp", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fild(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fild(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fild", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fincstp() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fincstp", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn finit() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("finit", vec![], fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fninit(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fninit", vec![], fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fistp(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fistp(a: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fistp", fpu_stack(), [vec![a], fpu_stack()].concat(), &[])?, JumpSpec::FallThru))
 }
-pub fn fisttp(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fisttp(a: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fisttp", fpu_stack(), [vec![a], fpu_stack()].concat(), &[])?, JumpSpec::FallThru))
 }
-pub fn fld(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fld(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fld", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fld1() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fld1", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fldl2t() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fldl2t", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fldl2e() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fldl2e", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fldpi() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fldpi", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fldlg2() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fldlg2", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fldln2() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fldln2", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fldz() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fldz", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fldcw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn fmul(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmul(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmul", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fmulp(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmulp(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmulp", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fimul(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fimul(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fimul", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fnop() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
 pub fn fpatan() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fpatan", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fprem() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fprem", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fprem1() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fprem1", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fptan() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fptan", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn frndint() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("frndint", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn frstor(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn frstor(a: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("frstor", vec![a], fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fsave(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fsave(a: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fsave", fpu_stack(), [vec![a], fpu_stack()].concat(), &[])?, JumpSpec::FallThru))
 }
-pub fn fnsave(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fnsave(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fnsave", fpu_stack(), [vec![a], fpu_stack()].concat(), &[])?, JumpSpec::FallThru))
 }
 pub fn fscale() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fscale", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fsin() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fsin", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fsincos() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fsincos", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fsqrt() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fsqrt", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fst1(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fst1(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fst", fpu_stack(), vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fst2(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fst2(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fst", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fstp(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fstp(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fstp", fpu_stack(), [vec![a], fpu_stack()].concat(), &[])?, JumpSpec::FallThru))
 }
-pub fn fstcw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fstcw(a: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fstcw", fpu_stack(), vec![a], &[])?, JumpSpec::FallThru))
 }
 pub fn fldenv(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn fstenv(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fstenv(a: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fstenv", fpu_stack(), vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fnstenv(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fnstenv(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fnstenv", vec![], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fstsw1(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fstsw1(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fstsw", vec![], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fstsw2(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fstsw2(a: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fstsw", fpu_stack(), vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fnstsw(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fnstsw(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fnstsw", vec![], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fsub(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fsub(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fsub", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fsubp(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fsubp(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fsubp", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fisub(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fisub(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fisub", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fsubr(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fsubr(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fsubr", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
-pub fn fisubr(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fisubr(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fisubr", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn ftst() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
@@ -3369,26 +3811,26 @@ pub fn ftst() -> Result<(Vec<Statement>, JumpSpec)> {
 pub fn fucom(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn fucomp(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fucomp(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fucomp", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fucompp() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fucompp", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fxam() -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn fxch(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fxch(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fxch", [vec![a, b], fpu_stack()].concat(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fxtract() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fxtract", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fyl2x() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fyl2x", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 pub fn fyl2xp1() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("fyl2xp1", fpu_stack(), fpu_stack(), &[])?, JumpSpec::FallThru))
 }
 
 // MPX
@@ -3401,14 +3843,14 @@ pub fn bndcu(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
 pub fn bndcn(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn bndmov(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn bndmov(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("bndmov", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn bndmk(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn bndmk(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("bndmk", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn bndldx(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn bndldx(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("bndldx", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
 pub fn bndstx(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
@@ -3425,981 +3867,997 @@ pub fn noop_binary(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
 }
 
 // FMA
-pub fn fmadd132ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmadd132ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmadd132ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmadd132ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmadd132ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmadd132ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmadd213ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmadd213ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmadd213ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmadd213ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmadd213ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmadd213ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmadd231ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmadd231ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmadd231ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmadd231ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmadd231ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmadd231ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmaddsub132ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmaddsub132ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmaddsub132ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmaddsub231ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmaddsub231ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmaddsub231ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmaddsub232ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmaddsub232ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmaddsub232ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmnadd132ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmnadd132ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmnadd132ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmnsub132ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmnsub132ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmnsub132ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmsub132ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmsub132ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmsub132ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmsub132ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmsub132ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmsub132ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmsub213ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmsub213ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmsub213ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmsub213ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmsub213ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmsub213ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmsub231ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmsub231ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmsub231ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmsub231ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmsub231ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmsub231ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmsubadd132ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmsubadd132ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmsubadd132ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmsubadd231ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmsubadd231ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmsubadd231ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fmsubadd232ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fmsubadd232ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fmsubadd232ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fnmadd213ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fnmadd213ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fnmadd213ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fnmadd213ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fnmadd213ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fnmadd213ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fnmadd231ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fnmadd231ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fnmadd231ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fnmadd231ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fnmadd231ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fnmadd231ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fnmsub213ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fnmsub213ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fnmsub213ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fnmsub213ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fnmsub213ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fnmsub213ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fnmsub231ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fnmsub231ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fnmsub231ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fnmsub231ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fnmsub231ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fnmsub231ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
 
 // AVX
-pub fn vaddpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vaddpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vaddpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vaddps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vaddps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vaddps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vaddsd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vaddsd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_float(a, b, c, 64, |x, y| Operation::FloatAdd(Rounding::NearestEven, x, y))
 }
-pub fn vaddss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vaddss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_float(a, b, c, 32, |x, y| Operation::FloatAdd(Rounding::NearestEven, x, y))
 }
-pub fn vaddsubpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vaddsubpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vaddsubpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vaddsubps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vaddsubps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vaddsubps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vaesdec(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vaesdec(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vaesdec", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vaesdeclast(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vaesdeclast(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vaesdeclast", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vaesenc(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vaesenc(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vaesenc", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vaesenclast(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vaesenclast(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vaesenclast", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vaesimc(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vaesimc(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vaesimc", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vaeskeygenassist(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vaeskeygenassist(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vaeskeygenassist", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vandpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vandpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vandpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vandps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vandps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vandps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vandnpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vandnpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vandnpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vandnps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vandnps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vandnps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vblendpd(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vblendpd(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vblendpd", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vblendps(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vblendps(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vblendps", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vblendvpd(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vblendvpd(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vblendvpd", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vblendvps(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vblendvps(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vblendvps", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vcmppd(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcmppd(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vcmppd", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vcmpps(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcmpps(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vcmpps", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vcmpsd(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcmpsd(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_compare(a, b, c, d, 64)
 }
-pub fn vcmpss(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcmpss(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_compare(a, b, c, d, 32)
 }
-pub fn vcomisd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcomisd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_flags(a, b, 64)
 }
-pub fn vcomiss(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcomiss(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_flags(a, b, 32)
 }
-pub fn vcvtdq2pd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcvtdq2pd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vcvtdq2pd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vcvtdq2ps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcvtdq2ps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vcvtdq2ps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vcvtpd2dq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcvtpd2dq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vcvtpd2dq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vcvtpd2ps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcvtpd2ps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vcvtpd2ps", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vcvtps2dq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcvtps2dq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vcvtps2dq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vcvtps2pd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcvtps2pd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vcvtps2pd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vcvtsd2si(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcvtsd2si(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_to_int(a, b, 64, Rounding::NearestEven)
 }
-pub fn vcvtsd2ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcvtsd2ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    let src = c.extract(64, 0)?;
+    scalar_sse(a, b, 32, |_, res| Ok(vec![Statement { op: Operation::FloatToFloat(Rounding::NearestEven, 32, src), assignee: res }]))
 }
-pub fn vcvtsi2sd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcvtsi2sd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_sse(a, b, 64, |_, res| Ok(vec![Statement { op: Operation::IntToFloat(Rounding::NearestEven, 64, c), assignee: res }]))
 }
-pub fn vcvtss2sd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcvtss2sd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    let src = c.extract(32, 0)?;
+    scalar_sse(a, b, 64, |_, res| Ok(vec![Statement { op: Operation::FloatToFloat(Rounding::NearestEven, 64, src), assignee: res }]))
 }
-pub fn vcvtsi2ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcvtsi2ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_sse(a, b, 32, |_, res| Ok(vec![Statement { op: Operation::IntToFloat(Rounding::NearestEven, 32, c), assignee: res }]))
 }
-pub fn vcvttpd2dq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcvttpd2dq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vcvttpd2dq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vcvttps2dq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcvttps2dq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vcvttps2dq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vcvttsd2si(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcvttsd2si(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_to_int(a, b, 64, Rounding::TowardZero)
 }
-pub fn vcvttss2si(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcvttss2si(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_to_int(a, b, 32, Rounding::TowardZero)
 }
-pub fn vdivps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vdivps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vdivps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vdivpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vdivpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vdivpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vdivss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vdivss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_float(a, b, c, 32, |x, y| Operation::FloatDivide(Rounding::NearestEven, x, y))
 }
-pub fn vdivsd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vdivsd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_float(a, b, c, 64, |x, y| Operation::FloatDivide(Rounding::NearestEven, x, y))
 }
-pub fn vdppd(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vdppd(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vdppd", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vdpps(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vdpps(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vdpps", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vextractps(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vextractps(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vextractps", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vhaddpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vhaddpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vhaddpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vhaddps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vhaddps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vhaddps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vhsubpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vhsubpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vhsubpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vhsubps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vhsubps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vhsubps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vinsertps(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vinsertps(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vinsertps", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vlddqu(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vlddqu(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vlddqu", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
 pub fn vldmxcsr(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
     Ok((vec![], JumpSpec::FallThru))
 }
-pub fn vmaxpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmaxpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmaxpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vmaxsd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmaxsd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_min_max(a, b, c, 64, true)
 }
-pub fn vmaxps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmaxps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmaxps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vmaxss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmaxss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_min_max(a, b, c, 32, true)
 }
-pub fn vminpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vminpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vminpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vminsd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vminsd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_min_max(a, b, c, 64, false)
 }
-pub fn vminps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vminps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vminps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vminss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vminss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_min_max(a, b, c, 32, false)
 }
-pub fn vmovhpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmovhpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmovhpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vmovhps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmovhps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmovhps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vmovlpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmovlpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmovlpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vmovlps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmovlps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmovlps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vmovsd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmovsd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmovsd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vmovss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmovss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmovss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vmpsadbw(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmpsadbw(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmpsadbw", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vorpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vorpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vorpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vorps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vorps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vorps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpabsb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpabsb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpabsb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpabsw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpabsw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpabsw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpabsd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpabsd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpabsd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpacksswb(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpacksswb(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpacksswb", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpackssdw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpackssdw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpackssdw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpackusdw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpackusdw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpackusdw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpackuswb(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpackuswb(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpackuswb", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpaddb(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpaddb(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpaddb", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpaddw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpaddw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpaddw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpaddd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpaddd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpaddd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpaddq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpaddq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpaddq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpaddsb(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpaddsb(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpaddsb", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpaddsw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpaddsw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpaddsw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpaddusb(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpaddusb(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpaddusb", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpaddusw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpaddusw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpaddusw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpalignr(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpalignr(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpalignr", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpand(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpand(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpand", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpandn(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpandn(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpandn", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpavgb(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpavgb(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpavgb", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpavgw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpavgw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpavgw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpblendvb(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpblendvb(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpblendvb", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpblendw(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpblendw(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpblendw", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpclmulqdq(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpclmulqdq(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpclmulqdq", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpcmpeqb(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpcmpeqb(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpcmpeqb", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpcmpeqw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpcmpeqw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpcmpeqw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpcmpeqd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpcmpeqd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpcmpeqd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpcmpeqq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpcmpeqq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpcmpeqq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpcmpgtb(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpcmpgtb(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpcmpgtb", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpcmpgtw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpcmpgtw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpcmpgtw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpcmpgtd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpcmpgtd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpcmpgtd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpcmpgtq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpcmpgtq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpcmpgtq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vphaddw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vphaddw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vphaddw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vphaddd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vphaddd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vphaddd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vphaddsw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vphaddsw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vphaddsw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vphminposuw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vphminposuw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vphminposuw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vphsubw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vphsubw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vphsubw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vphsubd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vphsubd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vphsubd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vphsubsw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vphsubsw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vphsubsw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpinsrb(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpinsrb(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpinsrb", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpinsrd(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpinsrd(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpinsrd", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpinsrw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpinsrw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpinsrw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpmaddubsw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpmaddubsw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpmaddubsw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpmadwd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpmadwd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpmadwd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpmaxsb(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpmaxsb(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpmaxsb", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpmaxsd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpmaxsd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpmaxsd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpmaxsw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpmaxsw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpmaxsw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpmaxub(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpmaxub(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpmaxub", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpmaxud(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpmaxud(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpmaxud", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpmaxuw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpmaxuw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpmaxuw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpminsb(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpminsb(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpminsb", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpminsd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpminsd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpminsd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpminsw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpminsw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpminsw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpminub(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpminub(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpminub", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpminud(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpminud(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpminud", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpminuw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpminuw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpminuw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpmuldq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpmuldq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpmuldq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpmulhrsw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpmulhrsw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpmulhrsw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpmulhuw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpmulhuw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpmulhuw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpmulhw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpmulhw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpmulhw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpmulld(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpmulld(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpmulld", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpmullw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpmullw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpmullw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpmuludq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpmuludq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpmuludq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpor(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpor(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpor", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsadbw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsadbw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsadbw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsignb(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsignb(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsignb", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsignw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsignw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsignw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsignd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsignd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsignd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpslldq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpslldq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpslldq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsllw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsllw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsllw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpslld(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpslld(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpslld", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsllq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsllq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsllq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsrad(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsrad(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsrad", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsarw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsarw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsarw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsrldq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsrldq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsrldq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsrlw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsrlw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsrlw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsrld(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsrld(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsrld", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsrlq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsrlq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsrlq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsubb(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsubb(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsubb", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsubw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsubw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsubw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsubd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsubd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsubd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsubq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsubq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsubq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsubsb(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsubsb(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsubsb", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpusbsw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpusbsw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpusbsw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsubusb(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsubusb(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsubusb", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsubusw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsubusw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsubusw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
 pub fn vptest() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("vptest", vec![], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn vpunpckhbw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpunpckhbw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpunpckhbw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpunckhwd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpunckhwd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpunckhwd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpunpckhdq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpunpckhdq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpunpckhdq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpunpckhqdq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpunpckhqdq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpunpckhqdq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpunpcklbw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpunpcklbw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpunpcklbw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpunpckldq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpunpckldq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpunpckldq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpuncklqdq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpuncklqdq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpuncklqdq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpuncklwd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpuncklwd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpuncklwd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpxor(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpxor(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpxor", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vrcpps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vrcpps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vrcpps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vroundpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vroundpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vroundpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vroundps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vroundps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vroundps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vroundsd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vroundsd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_round(a, b, c, 64)
 }
-pub fn vroundss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vroundss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_round(a, b, c, 32)
 }
-pub fn vrsqrtps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vrsqrtps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vrsqrtps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vrsqrtss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vrsqrtss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vrsqrtss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vsqrtss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vsqrtss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_float(a, b, c, 32, |_, y| Operation::FloatSquareRoot(Rounding::NearestEven, y))
 }
-pub fn vsqrtsd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vsqrtsd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_float(a, b, c, 64, |_, y| Operation::FloatSquareRoot(Rounding::NearestEven, y))
 }
-pub fn vshufps(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vshufps(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vshufps", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vshufpd(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vshufpd(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vshufpd", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vsubps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vsubps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vsubps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vsubss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vsubss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_float(a, b, c, 32, |x, y| Operation::FloatSubtract(Rounding::NearestEven, x, y))
 }
-pub fn vsubpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vsubpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vsubpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vsubsd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vsubsd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_float(a, b, c, 64, |x, y| Operation::FloatSubtract(Rounding::NearestEven, x, y))
 }
-pub fn vunpckhps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vunpckhps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vunpckhps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vunpcklps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vunpcklps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vunpcklps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vunpckhpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vunpckhpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vunpckhpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vunpcklpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vunpcklpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vunpcklpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vbroadcastss(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vbroadcastss(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vbroadcastss", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vbroadcastsd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vbroadcastsd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vbroadcastsd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vbroadcastf128(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vbroadcastf128(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vbroadcastf128", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vextractf128(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vextractf128(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vextractf128", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vextracti128(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vextracti128(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vextracti128", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vgatherdd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vgatherdd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vgatherdd", vec![a.clone(), b, c.clone()], vec![a, c], &[])?, JumpSpec::FallThru))
 }
-pub fn vgatherdp(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vgatherdp(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vgatherdp", vec![a.clone(), b, c.clone()], vec![a, c], &[])?, JumpSpec::FallThru))
 }
-pub fn vgatherpdp(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vgatherpdp(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vgatherpdp", vec![a.clone(), b, c.clone()], vec![a, c], &[])?, JumpSpec::FallThru))
 }
-pub fn vgatherqpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vgatherqpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vgatherqpd", vec![a.clone(), b, c.clone()], vec![a, c], &[])?, JumpSpec::FallThru))
 }
-pub fn vinsertf128(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vinsertf128(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vinsertf128", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vinserti128(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vinserti128(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vinserti128", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vmaskmovps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmaskmovps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmaskmovps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vmaskmovpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmaskmovpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmaskmovpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vmulps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmulps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmulps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vmulss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmulss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_float(a, b, c, 32, |x, y| Operation::FloatMultiply(Rounding::NearestEven, x, y))
 }
-pub fn vmulpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmulpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmulpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vmulsd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmulsd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    scalar_float(a, b, c, 64, |x, y| Operation::FloatMultiply(Rounding::NearestEven, x, y))
 }
-pub fn vblendd(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vblendd(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vblendd", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpboradcastb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpboradcastb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpboradcastb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpboradcastw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpboradcastw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpboradcastw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpboradcastd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpboradcastd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpboradcastd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpboradcastq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpboradcastq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpboradcastq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpboradcasti128(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpboradcasti128(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpboradcasti128", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpermd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpermd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpermd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpermpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpermpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpermpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpermps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpermps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpermps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpermq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpermq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpermq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vperm2i128(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vperm2i128(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vperm2i128", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpermilpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpermilpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpermilpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpermilps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpermilps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpermilps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vperm2f128(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vperm2f128(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vperm2f128", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpmaskmovd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpmaskmovd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpmaskmovd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpmaskmovq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpmaskmovq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpmaskmovq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsllvd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsllvd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsllvd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsravd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsravd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsravd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsrlvd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsrlvd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsrlvd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vtestpd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vtestpd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vtestpd", vec![a, b], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn vtestps(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vtestps(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vtestps", vec![a, b], vec![], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
 pub fn vzeroall() -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+    Ok((intrinsic("vzeroall", vec![], simd_registers(), &[])?, JumpSpec::FallThru))
 }
-pub fn vxorps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vxorps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vxorps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vxorpd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vxorpd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vxorpd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
 
-pub fn broadcastf128(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn broadcastf128(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("broadcastf128", vec![a.clone()], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn broadcasti128(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn broadcasti128(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("broadcasti128", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn broadcastsd(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn broadcastsd(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("broadcastsd", vec![a.clone()], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn broadcastss(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn broadcastss(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("broadcastss", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fst(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fst(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fst", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn fstp1(_: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fstp1(a: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fstp", fpu_stack(), [vec![a], fpu_stack()].concat(), &[])?, JumpSpec::FallThru))
 }
-pub fn fstp2(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn fstp2(a: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("fstp", fpu_stack(), [vec![a], fpu_stack()].concat(), &[])?, JumpSpec::FallThru))
 }
-pub fn pboradcastw(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pboradcastw(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pboradcastw", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pbroadcastb(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pbroadcastb(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pbroadcastb", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pbroadcastd(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pbroadcastd(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pbroadcastd", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn pbroadcastq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn pbroadcastq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("pbroadcastq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vandn(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vandn(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vandn", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vbextr(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vbextr(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vbextr", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vblendvb(_: Rvalue, _: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vblendvb(a: Rvalue, b: Rvalue, c: Rvalue, d: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vblendvb", vec![a.clone(), b, c, d], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vbzhi(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vbzhi(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vbzhi", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vcvtph2ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vcvtph2ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vcvtph2ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmadd132ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmadd132ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmadd132ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmadd132ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmadd132ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmadd132ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmadd213ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmadd213ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmadd213ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmadd213ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmadd213ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmadd213ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmadd231ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmadd231ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmadd231ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmadd231ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmadd231ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmadd231ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmaddsub132ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmaddsub132ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmaddsub132ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmaddsub231ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmaddsub231ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmaddsub231ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmaddsub232ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmaddsub232ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmaddsub232ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmnadd132ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmnadd132ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmnadd132ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmnsub132ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmnsub132ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmnsub132ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmsub132ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmsub132ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmsub132ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmsub132ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmsub132ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmsub132ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmsub213ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmsub213ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmsub213ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmsub213ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmsub213ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmsub213ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmsub231ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmsub231ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmsub231ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmsub231ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmsub231ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmsub231ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmsubadd132ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmsubadd132ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmsubadd132ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmsubadd231ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmsubadd231ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmsubadd231ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfmsubadd232ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfmsubadd232ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfmsubadd232ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfnmadd213ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfnmadd213ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfnmadd213ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfnmadd213ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfnmadd213ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfnmadd213ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfnmadd231ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfnmadd231ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfnmadd231ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfnmadd231ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfnmadd231ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfnmadd231ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfnmsub213ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfnmsub213ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfnmsub213ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfnmsub213ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfnmsub213ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfnmsub213ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfnmsub231ps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfnmsub231ps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfnmsub231ps", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vfnmsub231ss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vfnmsub231ss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vfnmsub231ss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vgatherdps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vgatherdps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vgatherdps", vec![a.clone(), b, c.clone()], vec![a, c], &[])?, JumpSpec::FallThru))
 }
-pub fn vgatherqd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vgatherqd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vgatherqd", vec![a.clone(), b, c.clone()], vec![a, c], &[])?, JumpSpec::FallThru))
 }
-pub fn vgatherqps(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vgatherqps(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vgatherqps", vec![a.clone(), b, c.clone()], vec![a, c], &[])?, JumpSpec::FallThru))
 }
-pub fn vmovq2dq(_: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vmovq2dq(a: Rvalue, b: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vmovq2dq", vec![a.clone(), b], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpcmpestri(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpcmpestri(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    let stmts = intrinsic(
+        "vpcmpestri",
+        vec![a, b, c, rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }],
+        vec![rreil_rvalue!{ RCX:64 }],
+        &["CF", "OF", "SF", "ZF", "AF", "PF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
-pub fn vpcmpestrm(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpcmpestrm(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    let stmts = intrinsic(
+        "vpcmpestrm",
+        vec![a, b, c, rreil_rvalue!{ RAX:64 }, rreil_rvalue!{ RDX:64 }],
+        vec![rreil_rvalue!{ XMM0:128 }],
+        &["CF", "OF", "SF", "ZF", "AF", "PF"],
+    )?;
+
+    Ok((stmts, JumpSpec::FallThru))
 }
-pub fn vpcmpistri(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpcmpistri(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpcmpistri", vec![a, b, c], vec![rreil_rvalue!{ RCX:64 }], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn vpcmpistrm(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpcmpistrm(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpcmpistrm", vec![a, b, c], vec![rreil_rvalue!{ XMM0:128 }], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn vpermilp(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpermilp(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpermilp", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpextrw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpextrw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpextrw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpmaddwd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpmaddwd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpmaddwd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpshufb(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpshufb(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpshufb", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpshufd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpshufd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpshufd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpshufhw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpshufhw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpshufhw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpshuflw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpshuflw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpshuflw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpshufw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpshufw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpshufw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsraw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsraw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsraw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpsubsw(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpsubsw(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpsubsw", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpunpckhwd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpunpckhwd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpunpckhwd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpunpcklqdq(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpunpcklqdq(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpunpcklqdq", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vpunpcklwd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vpunpcklwd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vpunpcklwd", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vrcpss(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vrcpss(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vrcpss", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vsha1rnds4(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vsha1rnds4(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vsha1rnds4", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vshld(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vshld(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vshld", vec![a.clone(), b, c], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
-pub fn vshlx(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vshlx(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vshlx", vec![a.clone(), b, c], vec![a], &[])?, JumpSpec::FallThru))
 }
-pub fn vshrd(_: Rvalue, _: Rvalue, _: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
-    Ok((vec![], JumpSpec::FallThru))
+pub fn vshrd(a: Rvalue, b: Rvalue, c: Rvalue) -> Result<(Vec<Statement>, JumpSpec)> {
+    Ok((intrinsic("vshrd", vec![a.clone(), b, c], vec![a], &["CF", "OF", "SF", "ZF", "AF", "PF"])?, JumpSpec::FallThru))
 }
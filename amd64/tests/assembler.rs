@@ -0,0 +1,82 @@
+/*
+ * Panopticon - A libre disassembler
+ * Copyright (C) 2017  Panopticon authors
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+extern crate panopticon_core;
+extern crate panopticon_amd64;
+
+use panopticon_amd64 as amd64;
+use panopticon_core::{Architecture, Bound, Layer, Region, Rvalue};
+
+/// Assembles `text` at `address` and decodes the result again. Returns the encoding and the
+/// constant jump targets and operands of the decoded instruction.
+fn round_trip(text: &str, address: u64, mode: amd64::Mode) -> (Vec<u8>, Vec<u64>) {
+    let bytes = amd64::assemble(text, address, mode).unwrap();
+    let mut reg = Region::undefined("ram".to_string(), 0x1000000);
+
+    assert!(reg.cover(Bound::new(address, address + bytes.len() as u64), Layer::wrap(bytes.clone())));
+
+    let m = <amd64::Amd64 as Architecture>::decode(&reg, address, &mode).unwrap();
+    let targets = m.jumps
+        .iter()
+        .map(|&(_, ref target, _)| target)
+        .chain(m.mnemonics.iter().flat_map(|mne| mne.operands.iter()))
+        .filter_map(
+            |target| match target {
+                &Rvalue::Constant { value, .. } => Some(value),
+                _ => None,
+            }
+        )
+        .collect::<Vec<_>>();
+
+    assert_eq!(m.tokens, bytes);
+    (bytes, targets)
+}
+
+#[test]
+fn assemble_plain() {
+    assert_eq!(round_trip("nop", 0x1000, amd64::Mode::Long).0, vec![0x90]);
+    assert_eq!(round_trip("  INT3 ", 0x1000, amd64::Mode::Long).0, vec![0xcc]);
+    assert_eq!(round_trip("ret", 0x1000, amd64::Mode::Protected).0, vec![0xc3]);
+    assert!(amd64::assemble("nop 1", 0x1000, amd64::Mode::Long).is_err());
+    assert!(amd64::assemble("mov eax, 1", 0x1000, amd64::Mode::Long).is_err());
+}
+
+#[test]
+fn assemble_branches() {
+    let (bytes, targets) = round_trip("jmp 1010", 0x1000, amd64::Mode::Long);
+    assert_eq!(bytes, vec![0xeb, 0x0e]);
+    assert!(targets.contains(&0x1010));
+
+    let (bytes, targets) = round_trip("jmp 0x800000", 0x1000, amd64::Mode::Protected);
+    assert_eq!(bytes.len(), 5);
+    assert!(targets.contains(&0x800000));
+
+    let (bytes, targets) = round_trip("jne ff0", 0x1000, amd64::Mode::Long);
+    assert_eq!(bytes, vec![0x75, 0xee]);
+    assert!(targets.contains(&0xff0));
+
+    let (bytes, targets) = round_trip("jz 2000", 0x1000, amd64::Mode::Long);
+    assert_eq!(bytes, vec![0x0f, 0x84, 0xfa, 0x0f, 0x00, 0x00]);
+    assert!(targets.contains(&0x2000));
+
+    let (bytes, targets) = round_trip("call 1100", 0x100, amd64::Mode::Real);
+    assert_eq!(bytes, vec![0xe8, 0xfd, 0x0f]);
+    assert!(targets.contains(&0x1100));
+
+    assert!(amd64::assemble("jmp 20000", 0x100, amd64::Mode::Real).is_err());
+}
@@ -22,7 +22,7 @@ extern crate panopticon_amd64;
 extern crate env_logger;
 
 use panopticon_amd64 as amd64;
-use panopticon_core::{Architecture, Region};
+use panopticon_core::{Architecture, Lvalue, Operation, Region, Rvalue, Statement};
 use std::path::Path;
 
 #[test]
@@ -76,3 +76,81 @@ fn ia32_opcodes() {
         }
     }
 }
+
+fn semantics(bytes: Vec<u8>) -> Vec<Statement> {
+    let reg = Region::wrap("code".to_string(), bytes);
+    let m = <amd64::Amd64 as Architecture>::decode(&reg, 0, &amd64::Mode::Long).unwrap();
+
+    m.mnemonics.into_iter().flat_map(|mne| mne.instructions.into_iter()).collect()
+}
+
+fn clobbers(stmts: &[Statement], reg: &str) -> bool {
+    stmts.iter().any(
+        |st| {
+            st.assignees().iter().any(
+                |lv| match **lv {
+                    Lvalue::Variable { ref name, .. } => name == reg,
+                    _ => false,
+                }
+            )
+        }
+    )
+}
+
+#[test]
+fn unimplemented_semantics_clobber_outputs() {
+    // rdmsr
+    let stmts = semantics(vec![0x0f, 0x32]);
+    assert!(clobbers(&stmts, "EAX") && clobbers(&stmts, "RDX"));
+
+    // pcmpgtq xmm1, xmm2
+    let stmts = semantics(vec![0x66, 0x0f, 0x38, 0x37, 0xca]);
+    assert!(clobbers(&stmts, "XMM1") && clobbers(&stmts, "YMM1"));
+    assert!(!clobbers(&stmts, "XMM2"));
+
+    // bts dword ptr [rax], ecx
+    let stmts = semantics(vec![0x0f, 0xab, 0x08]);
+    assert!(clobbers(&stmts, "CF"));
+    assert!(stmts.iter().any(|st| if let Operation::Store(..) = st.op { true } else { false }));
+
+    // inc byte ptr [rax]
+    let stmts = semantics(vec![0xfe, 0x00]);
+    assert!(clobbers(&stmts, "ZF"));
+    assert!(stmts.iter().any(|st| if let Operation::Store(..) = st.op { true } else { false }));
+}
+
+fn has_op<F: Fn(&Operation<Rvalue>) -> bool>(stmts: &[Statement], f: F) -> bool {
+    stmts.iter().any(|st| f(&st.op))
+}
+
+#[test]
+fn scalar_sse_semantics() {
+    // addsd xmm0, xmm1
+    let stmts = semantics(vec![0xf2, 0x0f, 0x58, 0xc1]);
+    assert!(has_op(&stmts, |op| if let &Operation::FloatAdd(..) = op { true } else { false }));
+    assert!(!has_op(&stmts, |op| if let &Operation::Intrinsic(..) = op { true } else { false }));
+    assert!(clobbers(&stmts, "XMM0") && clobbers(&stmts, "YMM0"));
+    assert!(!clobbers(&stmts, "XMM1"));
+
+    // sqrtsd xmm0, qword ptr [rax]
+    let stmts = semantics(vec![0xf2, 0x0f, 0x51, 0x00]);
+    assert!(has_op(&stmts, |op| if let &Operation::FloatSquareRoot(..) = op { true } else { false }));
+    assert!(has_op(&stmts, |op| if let &Operation::Load(_, _, 64, _) = op { true } else { false }));
+
+    // ucomisd xmm0, xmm1
+    let stmts = semantics(vec![0x66, 0x0f, 0x2e, 0xc1]);
+    assert!(has_op(&stmts, |op| if let &Operation::FloatUnordered(..) = op { true } else { false }));
+    assert!(clobbers(&stmts, "ZF") && clobbers(&stmts, "PF") && clobbers(&stmts, "CF"));
+    assert!(!clobbers(&stmts, "XMM0"));
+
+    // cvttsd2si eax, xmm1
+    let stmts = semantics(vec![0xf2, 0x0f, 0x2c, 0xc1]);
+    assert!(has_op(&stmts, |op| if let &Operation::FloatToInt(..) = op { true } else { false }));
+    assert!(clobbers(&stmts, "EAX"));
+
+    // addpd xmm0, xmm1 and fadd st0, st1 stay opaque
+    let stmts = semantics(vec![0x66, 0x0f, 0x58, 0xc1]);
+    assert!(has_op(&stmts, |op| if let &Operation::Intrinsic(..) = op { true } else { false }));
+    let stmts = semantics(vec![0xd8, 0xc1]);
+    assert!(has_op(&stmts, |op| if let &Operation::Intrinsic(..) = op { true } else { false }));
+}
@@ -18,7 +18,7 @@
 
 use futures::{Future, Sink, Stream, stream};
 use futures::sync::mpsc;
-use panopticon_core::{Architecture, CallTarget, Error, Function, Program, Result, Region, Rvalue};
+use panopticon_core::{Architecture, Bound, CallTarget, Error, Function, Program, Result, Region, Rvalue};
 use panopticon_data_flow::ssa_convertion;
 use std::collections::HashSet;
 use std::fmt::Debug;
@@ -43,6 +43,7 @@ where
         name: Option<String>,
         entry: u64,
         uuid: Uuid,
+        extent: Option<Bound>,
     }
 
     let attempts = CHashMap::<u64, result::Result<(), Error>>::new();
@@ -56,7 +57,7 @@ where
         .filter_map(
             |ct| match ct {
                 &CallTarget::Todo(Rvalue::Constant { value: entry, .. }, ref name, ref uuid) => {
-                    Some(Init { entry, name: name.clone(), uuid: *uuid })
+                    Some(Init { entry, name: name.clone(), uuid: *uuid, extent: program.extents.get(&entry).cloned() })
                 }
                 _ => None,
             }
@@ -66,11 +67,11 @@ where
     let program = Mutex::new(program);
 
     info!("begin first wave {}", functions.len());
-    functions.into_par_iter().for_each(| Init { entry, name, uuid }| {
+    functions.into_par_iter().for_each(| Init { entry, name, uuid, extent }| {
         let name = &name;
         attempts.upsert(entry,
                         || {
-                            match Function::with_uuid::<A>(entry, &uuid, &region, name.clone(), config.clone()) {
+                            match Function::with_uuid::<A>(entry, &uuid, extent.as_ref(), &region, name.clone(), config.clone()) {
                                 Ok(mut f) => {
                                     for address in f.collect_call_addresses() {
                                         targets.upsert(address, || { true }, |_| ());
@@ -154,7 +155,7 @@ where
                 match ct {
                     &CallTarget::Todo(Rvalue::Constant { value: entry, .. }, ref maybe_name, ref uuid) => {
                         finished_functions.insert(entry);
-                        match Function::with_uuid::<A>(entry, uuid, &region, maybe_name.clone(), config.clone()) {
+                        match Function::with_uuid::<A>(entry, uuid, program.extents.get(&entry), &region, maybe_name.clone(), config.clone()) {
                             Ok(mut f) => {
                                 let addresses = f.collect_call_addresses();
                                 targets.extend_from_slice(&addresses);
@@ -16,7 +16,7 @@
  * along with this program.  If not, see <http://www.gnu.org/licenses/>.
  */
 
-use panopticon_core::{Architecture, Guard, Lvalue, Match, Region, Result, Rvalue, State, Statement};
+use panopticon_core::{Architecture, Guard, Lvalue, Machine, Match, Region, Result, Rvalue, State, Statement};
 use std::borrow::Cow;
 use std::convert::Into;
 use syntax;
@@ -36,13 +36,18 @@ impl Architecture for Avr {
         info!("disass @ {:x}", addr);
         let disass = syntax::disassembler();
 
-        if let Some(st) = disass.next_match(&mut reg.iter().seek(addr), addr, cfg.clone()) {
+        // the longest instructions are two words
+        if let Some(st) = disass.next_match(&mut reg.read(addr, 4).into_iter(), addr, cfg.clone()) {
             info!("    res: {:?}", st);
             Ok(st.into())
         } else {
             Err("Unrecognized instruction".into())
         }
     }
+
+    fn machine(_: &Self::Configuration) -> Option<Machine> {
+        Some(Machine::Avr)
+    }
 }
 
 #[derive(Clone,Debug)]
@@ -471,9 +476,9 @@ pub fn binary_ptr(
                 .unwrap();
 
             let (fmt, rd, rr) = if ptr_first {
-                ("{p:ram}, {u}", addr_reg.clone().into(), reg.clone().into())
+                ("{p:sram}, {u}", addr_reg.clone().into(), reg.clone().into())
             } else {
-                ("{u}, {p:ram}", reg.clone().into(), addr_reg.clone().into())
+                ("{u}, {p:sram}", reg.clone().into(), addr_reg.clone().into())
             };
 
             st.mnemonic(
@@ -473,10 +473,9 @@ pub fn elpm(rd: Lvalue, off: usize, st: &mut State<Avr>) -> bool {
         .unwrap();
 
     let arg = if rd == rreil_lvalue!{ R0:8 } { vec![] } else { vec![zreg.clone().into()] };
-    st.mnemonic(2,"elpm","{p:sram}",arg,&|_: &mut Mcu| {
+    st.mnemonic(2,"elpm","{p:flash}",arg,&|_: &mut Mcu| {
         let mut stmts = try!(rreil!{
-            load/sram/be/24 ptr:24, (zreg);
-            load/flash/be/8 (rd), ptr:24;
+            load/flash/be/8 (rd), (zreg);
         });
 
         if off <= 1 {
@@ -590,9 +589,9 @@ pub fn icall(st: &mut State<Avr>) -> bool {
         }
     }).unwrap();
 
-    st.mnemonic(2,"icall","{p:sram}",vec![],&|_: &mut Mcu| {
+    st.mnemonic(2,"icall","",vec![],&|_: &mut Mcu| {
         rreil!{
-            load/sram/be/24 ptr:24, (zreg);
+            zext/24 ptr:24, (zreg);
             call ptr:24;
         }
     }).unwrap();
@@ -630,7 +629,7 @@ pub fn _in(st: &mut State<Avr>) -> bool {
     let rd = reg(st,"D");
     let rr = Rvalue::Constant{ value: st.get_group("A"), size: 8 };
 
-    st.mnemonic(2,"in","{u}, {u}",vec!(rd.clone().into(),rr.clone().into()),&|_: &mut Mcu| {
+    st.mnemonic(2,"in","{u}, {p:io}",vec!(rd.clone().into(),rr.clone().into()),&|_: &mut Mcu| {
         rreil!{
             load/io/be/8 (rd), (rr);
         }
@@ -769,10 +768,9 @@ pub fn lpm(rd: Lvalue, off: usize, st: &mut State<Avr>) -> bool {
         .unwrap();
 
     let arg = if rd == rreil_lvalue!{ R0:8 } { vec![] } else { vec![zreg.clone().into()] };
-    st.mnemonic(2,"lpm","{p:sram}",arg,&|_: &mut Mcu| {
+    st.mnemonic(2,"lpm","{p:flash}",arg,&|_: &mut Mcu| {
         let mut stmts = try!(rreil!{
-            load/sram/be/16 ptr:16, (zreg);
-            load/flash/be/8 (rd), ptr:16;
+            load/flash/be/8 (rd), (zreg);
         });
 
                 if off <= 1 {
@@ -936,7 +934,7 @@ pub fn out(st: &mut State<Avr>) -> bool {
     let rr = reg(st, "R");
     let next = st.configuration.wrap(st.address + st.tokens.len() as u64 * 2);
 
-    st.mnemonic(2,"out","{u}, {u}",vec!(rd.clone().into(),rr.clone().into()),&|_: &mut Mcu| {
+    st.mnemonic(2,"out","{p:io}, {u}",vec!(rd.clone().into(),rr.clone().into()),&|_: &mut Mcu| {
         rreil!{
             store/io/be/8 (rd), (rr);
         }
@@ -953,7 +951,7 @@ pub fn pop(rd: Lvalue, _: &mut Mcu) -> Result<Vec<Statement>> {
         zext/16 stack:16, spl:8;
         sel/8 stack:16, sph:8;
         add stack:16, stack:16, [1]:16;
-        load/ram/be/8 (rd), stack:16;
+        load/sram/be/8 (rd), stack:16;
         mov spl:8, stack:8;
         mov sph:8, stack:8/8;
     }
@@ -963,7 +961,7 @@ pub fn push(rd: Lvalue, _: &mut Mcu) -> Result<Vec<Statement>> {
     rreil!{
         zext/16 stack:16, spl:8;
         sel/8 stack:16, sph:8;
-        load/ram/be/8 (rd), stack:16;
+        store/sram/be/8 (rd), stack:16;
         sub stack:16, stack:16, [1]:16;
         mov spl:8, stack:8;
         mov sph:8, stack:8/8;
@@ -1154,10 +1152,9 @@ pub fn spm(rd: Lvalue, off: usize, st: &mut State<Avr>) -> bool {
         .unwrap();
 
     let arg = if off == 0 { vec![] } else { vec![zreg.clone().into()] };
-    st.mnemonic(len,"spm","{p:sram}",arg,&|_: &mut Mcu| {
+    st.mnemonic(len,"spm","{p:flash}",arg,&|_: &mut Mcu| {
         let mut stmts = try!(rreil!{
-            load/sram/be/16 ptr:16, (zreg);
-            load/flash/be/8 (rd), ptr:16;
+            store/flash/be/8 (rd), (zreg);
         });
 
                 if off <= 1 {
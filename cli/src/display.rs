@@ -2,7 +2,7 @@ use std::io::Write;
 use termcolor::WriteColor;
 use termcolor::Color::*;
 
-use panopticon_core::{Function, BasicBlock, Mnemonic, MnemonicFormatToken, Operation, Program, Rvalue, Result, Statement};
+use panopticon_core::{Function, BasicBlock, DebugInfo, Hit, Mnemonic, MnemonicFormatToken, Operation, Program, Project, Region, Rvalue, Result, Segment, Statement, demangle};
 
 macro_rules! color_bold {
     ($fmt:ident, $color:ident, $str:expr) => ({
@@ -39,7 +39,7 @@ pub fn print_rreil<W: Write + WriteColor>(fmt: &mut W, bbs: &[&BasicBlock]) -> R
 pub fn print_address_and_mnemonic<W: Write + WriteColor>(fmt: &mut W, mnemonic: &Mnemonic) -> Result<()> {
     color_bold!(fmt, White, format!("{:8x}", mnemonic.area.start as usize))?;
     write!(fmt, ": (")?;
-    print_mnemonic(fmt, mnemonic, None)?;
+    print_mnemonic(fmt, mnemonic, None, None)?;
     writeln!(fmt, ")")?;
     Ok(())
 }
@@ -284,10 +284,7 @@ pub fn print_statement<W: Write + WriteColor>(fmt: &mut W, statement: &Statement
             color!(fmt, White, statement.assignee)?;
             color_bold!(fmt, Green, ",")?;
             write!(fmt, " ")?;
-            color!(fmt, White, r)?;
-            color_bold!(fmt, Green, ",")?;
-            write!(fmt, " ")?;
-            color!(fmt, White, sz)?;
+            color!(fmt, White, format!("{}:{}", r, sz))?;
         },
         Operation::Load(ref r, e, sz, ref b) => {
             color_bold!(fmt, White, format!("load/{}/{}/{}", r, e, sz))?;
@@ -304,18 +301,39 @@ pub fn print_statement<W: Write + WriteColor>(fmt: &mut W, statement: &Statement
             color_bold!(fmt, Green, ",")?;
             write!(fmt, " ")?;
             color!(fmt, White, a)?;
+            color_bold!(fmt, Green, ",")?;
             write!(fmt, " ")?;
             color!(fmt, White, b)?;
         },
+        Operation::FloatAdd(m, ref a, ref b) => print_operation(fmt, format!("fadd/{}", m), statement, &[a, b])?,
+        Operation::FloatSubtract(m, ref a, ref b) => print_operation(fmt, format!("fsub/{}", m), statement, &[a, b])?,
+        Operation::FloatMultiply(m, ref a, ref b) => print_operation(fmt, format!("fmul/{}", m), statement, &[a, b])?,
+        Operation::FloatDivide(m, ref a, ref b) => print_operation(fmt, format!("fdiv/{}", m), statement, &[a, b])?,
+        Operation::FloatSquareRoot(m, ref a) => print_operation(fmt, format!("fsqrt/{}", m), statement, &[a])?,
+        Operation::FloatRound(m, ref a) => print_operation(fmt, format!("fround/{}", m), statement, &[a])?,
+        Operation::FloatEqual(ref a, ref b) => print_operation(fmt, "fcmpeq".to_string(), statement, &[a, b])?,
+        Operation::FloatLess(ref a, ref b) => print_operation(fmt, "fcmplt".to_string(), statement, &[a, b])?,
+        Operation::FloatLessOrEqual(ref a, ref b) => print_operation(fmt, "fcmple".to_string(), statement, &[a, b])?,
+        Operation::FloatUnordered(ref a, ref b) => print_operation(fmt, "fcmpuo".to_string(), statement, &[a, b])?,
+        Operation::IntToFloat(m, sz, ref a) => print_operation(fmt, format!("itof/{}/{}", m, sz), statement, &[a])?,
+        Operation::FloatToInt(m, sz, ref a) => print_operation(fmt, format!("ftoi/{}/{}", m, sz), statement, &[a])?,
+        Operation::FloatToFloat(m, sz, ref a) => print_operation(fmt, format!("ftof/{}/{}", m, sz), statement, &[a])?,
+        Operation::Intrinsic(ref name, ref inputs, ref outputs) => {
+            print_operation(fmt, format!("intrinsic/{}", name), statement, &inputs.iter().collect::<Vec<_>>())?;
+            for (i, x) in outputs.iter().enumerate() {
+                color_bold!(fmt, Green, if i == 0 { " ->" } else { "," })?;
+                write!(fmt, " ")?;
+                color!(fmt, Red, x)?;
+            }
+        }
         Operation::Phi(ref vec) => {
             color_bold!(fmt, White, format!("phi"))?;
             write!(fmt, " ")?;
-            for (i, x) in vec.iter().enumerate() {
+            color!(fmt, White, statement.assignee)?;
+            for x in vec.iter() {
+                color_bold!(fmt, Green, ",")?;
+                write!(fmt, " ")?;
                 color!(fmt, White, format!("{}", x))?;
-                if i < vec.len() - 1 {
-                    color_bold!(fmt, Green, ",")?;
-                    write!(fmt, " ")?;
-                }
             }
         }
     }
@@ -323,31 +341,135 @@ pub fn print_statement<W: Write + WriteColor>(fmt: &mut W, statement: &Statement
     Ok(())
 }
 
-/// Prints the function in a human readable format, using `program`, with colors
-pub fn print_function<W: Write + WriteColor>(fmt: &mut W, function: &Function, bbs: &[&BasicBlock], program: &Program) -> Result<()> {
+/// Prints `mnemonic` followed by the assignee of `statement` and `args`
+fn print_operation<W: Write + WriteColor>(fmt: &mut W, mnemonic: String, statement: &Statement, args: &[&Rvalue]) -> Result<()> {
+    color_bold!(fmt, White, mnemonic)?;
+    write!(fmt, " ")?;
+    color!(fmt, White, statement.assignee)?;
+    for arg in args {
+        color_bold!(fmt, Green, ",")?;
+        write!(fmt, " ")?;
+        color!(fmt, White, arg)?;
+    }
+    Ok(())
+}
+
+/// Prints the function in a human readable format, using `program` and the memory banks of `proj`, with colors
+pub fn print_function<W: Write + WriteColor>(fmt: &mut W, function: &Function, bbs: &[&BasicBlock], program: &Program, proj: &Project, segment: Option<&Segment>, debug_info: &DebugInfo) -> Result<()> {
     write!(fmt, "{:0>8x} <", function.start())?;
-    color_bold!(fmt, Yellow, function.name)?;
-    writeln!(fmt, ">:")?;
+    color_bold!(fmt, Yellow, function.display_name())?;
+    write!(fmt, ">")?;
+    if function.demangled_name().is_some() {
+        write!(fmt, " [{}]", function.name)?;
+    }
+    if let Some(segment) = segment {
+        write!(fmt, " in ")?;
+        color!(fmt, Cyan, segment.name)?;
+    }
+    writeln!(fmt, ":")?;
     for bb in bbs {
-        print_basic_block(fmt, &bb, program)?;
+        print_basic_block(fmt, &bb, program, proj, debug_info)?;
+    }
+    Ok(())
+}
+
+/// Prints the section and segment map of a binary into `fmt`
+pub fn print_segments<W: Write + WriteColor>(fmt: &mut W, segments: &[Segment]) -> Result<()> {
+    for segment in segments {
+        color_bold!(fmt, Cyan, format!("{: <20} ", segment.name))?;
+        color_bold!(fmt, Red, format!("{:>16x}-{:<16x} ", segment.bound.start, segment.bound.end))?;
+        write!(fmt, "{} ", segment.permissions())?;
+        match segment.file_offset {
+            Some(off) => color!(fmt, Green, format!("{:#x}", off))?,
+            None => write!(fmt, "-")?,
+        }
+        writeln!(fmt)?;
+    }
+    Ok(())
+}
+
+/// Prints the layer stack of `region` into `fmt`, lowest layer first
+pub fn print_layers<W: Write + WriteColor>(fmt: &mut W, region: &Region) -> Result<()> {
+    for (idx, &(ref bound, ref layer)) in region.stack().iter().enumerate() {
+        color_bold!(fmt, Green, format!("{: >3} ", idx))?;
+        color_bold!(fmt, Red, format!("{:>16x}-{:<16x} ", bound.start, bound.end))?;
+        writeln!(fmt, "{}", layer)?;
     }
     Ok(())
 }
 
-/// Prints the basic block into `fmt`, in disassembly order, in human readable form, and looks up any functions calls in `program`
-pub fn print_basic_block<W: Write + WriteColor>(fmt: &mut W, basic_block: &BasicBlock, program: &Program) -> Result<()> {
+/// Prints the patched bytes of `proj` into `fmt`, one line per run of consecutive addresses
+pub fn print_patches<W: Write + WriteColor>(fmt: &mut W, proj: &Project) -> Result<()> {
+    let mut runs: Vec<(u64, Vec<u8>)> = Vec::new();
+
+    for (addr, b) in proj.patched_bytes() {
+        let extend = match runs.last() {
+            Some(&(start, ref bytes)) => start + bytes.len() as u64 == addr,
+            None => false,
+        };
+
+        if extend {
+            runs.last_mut().unwrap().1.push(b);
+        } else {
+            runs.push((addr, vec![b]));
+        }
+    }
+
+    for (addr, bytes) in runs {
+        color_bold!(fmt, Red, format!("{:>16x} ", addr))?;
+        match proj.file_offset(addr) {
+            Some(off) => color!(fmt, Green, format!("{:>10x} ", off))?,
+            None => color!(fmt, Green, format!("{:>10} ", "-"))?,
+        }
+        writeln!(fmt, "{}", bytes.iter().map(|b| format!("{:02x}", b)).collect::<Vec<_>>().join(" "))?;
+    }
+    Ok(())
+}
+
+/// Prints search results into `fmt`, one per line. The name of the region searched is printed if
+/// `print_region` is set.
+pub fn print_hits<W: Write + WriteColor>(fmt: &mut W, hits: &[Hit], print_region: bool) -> Result<()> {
+    for hit in hits {
+        if print_region {
+            color!(fmt, Cyan, format!("{} ", hit.region))?;
+        }
+        color_bold!(fmt, Red, format!("{:>16x} ", hit.address))?;
+        color!(fmt, Green, format!("{:<10} ", hit.section.as_ref().map(|s| &s[..]).unwrap_or("-")))?;
+        match hit.function {
+            Some(ref function) => color_bold!(fmt, Yellow, format!("{} ", demangle::display_name(function)))?,
+            None => write!(fmt, "- ")?,
+        }
+        match hit.text {
+            Some(ref text) => writeln!(fmt, "{:?}", text)?,
+            None => writeln!(fmt, "{}", hit.bytes.iter().map(|b| format!("{:02x}", b)).collect::<Vec<_>>().join(" "))?,
+        }
+    }
+    Ok(())
+}
+
+/// Prints the basic block into `fmt`, in disassembly order, in human readable form, and looks up any functions calls in `program`.
+/// Source lines from `debug_info` are printed before the first mnemonic generated from them.
+pub fn print_basic_block<W: Write + WriteColor>(fmt: &mut W, basic_block: &BasicBlock, program: &Program, proj: &Project, debug_info: &DebugInfo) -> Result<()> {
+    let mut last_line = None;
     for mnemonic in basic_block.mnemonics.iter() {
         if !mnemonic.opcode.starts_with("__") {
+            let line = debug_info.source_line(mnemonic.area.start);
+            if line.is_some() && line != last_line {
+                color!(fmt, Cyan, format!("{: >10}{}", "; ", line.unwrap()))?;
+                writeln!(fmt)?;
+                last_line = line;
+            }
             write!(fmt, "{:8x}: ", mnemonic.area.start)?;
-            print_mnemonic(fmt, &mnemonic, Some(program))?;
+            print_mnemonic(fmt, &mnemonic, Some(program), Some(proj))?;
             writeln!(fmt)?;
         }
     }
     Ok(())
 }
 
-/// Prints the mnemonic into `fmt`, in human readable form, and looks up any functions calls in `program`
-pub fn print_mnemonic<W: Write + WriteColor>(fmt: &mut W, mnemonic: &Mnemonic, program: Option<&Program>) -> Result<()> {
+/// Prints the mnemonic into `fmt`, in human readable form, and looks up any functions calls in `program`.
+/// Data pointers are followed by the byte they point to in the memory bank of `proj`, if it's known.
+pub fn print_mnemonic<W: Write + WriteColor>(fmt: &mut W, mnemonic: &Mnemonic, program: Option<&Program>, proj: Option<&Project>) -> Result<()> {
     let mut ops = mnemonic.operands.iter();
     color_bold!(fmt, Blue, mnemonic.opcode)?;
     write!(fmt, " ")?;
@@ -374,12 +496,15 @@ pub fn print_mnemonic<W: Write + WriteColor>(fmt: &mut W, mnemonic: &Mnemonic, p
                     Some(&Rvalue::Variable{ ref name, subscript: Some(ref _subscript),.. }) => {
                         color_bold!(fmt, White, &name.to_lowercase())?;
                     },
+                    Some(rv @ &Rvalue::Wide{ .. }) => {
+                        color!(fmt, Red, wide_hex(rv))?;
+                    },
                     _ => {
                         color!(fmt, Black, "?")?;
                     }
                 }
             },
-            &MnemonicFormatToken::Pointer{ is_code,.. } => {
+            &MnemonicFormatToken::Pointer{ is_code, ref bank } => {
                 match ops.next() {
                     Some(&Rvalue::Constant{ value: c, size: s }) => {
                         let val =
@@ -392,7 +517,7 @@ pub fn print_mnemonic<W: Write + WriteColor>(fmt: &mut W, mnemonic: &Mnemonic, p
                                 if let Some(function) = program.find_function_by(|f| { f.start() == val }) {
                                     color!(fmt, Red, format!("{:x}",val))?;
                                     write!(fmt, " <", )?;
-                                    color_bold!(fmt, Yellow, function.name)?;
+                                    color_bold!(fmt, Yellow, function.display_name())?;
                                     write!(fmt, ">")?;
                                 } else {
                                     color_bold!(fmt, Magenta, format!("{:x}",val))?;
@@ -402,6 +527,9 @@ pub fn print_mnemonic<W: Write + WriteColor>(fmt: &mut W, mnemonic: &Mnemonic, p
                             }
                         } else {
                             write!(fmt, "{}", format!("{:#x}",val))?;
+                            if let Some(Some(byte)) = proj.and_then(|p| p.bank(bank).read(val, 1).first().cloned()) {
+                                color!(fmt, Cyan, format!(" [{:02x}]", byte))?;
+                            }
                         }
                     },
                     Some(&Rvalue::Variable{ ref name, subscript: Some(_),.. }) => {
@@ -410,6 +538,9 @@ pub fn print_mnemonic<W: Write + WriteColor>(fmt: &mut W, mnemonic: &Mnemonic, p
                     Some(&Rvalue::Variable{ ref name, .. }) => {
                         color!(fmt, Yellow, name.to_lowercase())?;
                     },
+                    Some(rv @ &Rvalue::Wide { .. }) => {
+                        write!(fmt, "0x{}", wide_hex(rv))?;
+                    },
                     Some(&Rvalue::Undefined) => {
                         color_bold!(fmt, Red, "undefined")?;
                     },
@@ -422,3 +553,11 @@ pub fn print_mnemonic<W: Write + WriteColor>(fmt: &mut W, mnemonic: &Mnemonic, p
     }
     Ok(())
 }
+
+/// Hexadecimal digits of the wide constant `rv`, without prefix and size.
+fn wide_hex(rv: &Rvalue) -> String {
+    let s = format!("{}", rv);
+    let digits = s.trim_left_matches("0x");
+
+    digits[..digits.rfind(':').unwrap_or(digits.len())].to_string()
+}
@@ -18,7 +18,7 @@ extern crate atty;
 use panopticon_amd64 as amd64;
 use panopticon_analysis::analyze;
 use panopticon_avr as avr;
-use panopticon_core::{Machine, Function, FunctionKind, Program, Result, loader};
+use panopticon_core::{Bound, Codec, DebugInfo, Hit, Layer, Machine, Function, FunctionKind, Pattern, Program, Project, Result, Segment, StringKind, Transform, demangle, loader, search, segment};
 use std::path::Path;
 use std::result;
 use structopt::StructOpt;
@@ -51,11 +51,68 @@ struct Args {
     #[structopt(short = "c", long = "calls", help = "Print every address of every function this function calls")]
     calls: bool,
     /// The specific function to disassemble
-    #[structopt(short = "f", long = "function", help = "Disassemble the given function, or any of its aliases")]
+    #[structopt(short = "f", long = "function", help = "Disassemble the given function, or any of its aliases. Mangled and demangled names are accepted")]
     function_filter: Option<String>,
     /// The specific function address to disassemble
     #[structopt(short = "a", long = "address", help = "Disassemble the function at the given address")]
     address_filter: Option<String>,
+    /// The slice of a fat Mach-o binary to disassemble
+    #[structopt(long = "slice", help = "Disassemble the n-th architecture slice of a fat Mach-o binary instead of the first supported one")]
+    slice: Option<usize>,
+    /// List the slices of a fat Mach-o binary
+    #[structopt(long = "list-slices", help = "Print the architecture slices of a fat Mach-o binary and exit")]
+    list_slices: bool,
+    /// Print the section and segment map
+    #[structopt(long = "sections", help = "Print the sections and segments of the binary and exit")]
+    sections: bool,
+    /// Load the binary as a headerless blob
+    #[structopt(long = "raw", help = "Load the binary as headerless blob. Requires --arch")]
+    raw: bool,
+    /// CPU of a raw binary
+    #[structopt(long = "arch", help = "CPU the raw binary is intended for: avr, ia16, ia32 or amd64")]
+    arch: Option<String>,
+    /// Load address
+    #[structopt(long = "base", help = "Address the binary is loaded to (hex). Raw binaries default to 0, position independent ELF and PE files are rebased")]
+    base: Option<String>,
+    /// Ignore dynamic relocations
+    #[structopt(long = "no-relocs", help = "Disassemble the file contents without dynamic relocations applied")]
+    no_relocs: bool,
+    /// Entry points of a raw binary
+    #[structopt(long = "entry", help = "Entry point inside the raw binary (hex), can be given multiple times. Defaults to the load address")]
+    entry: Vec<String>,
+    /// Transform layers
+    #[structopt(long = "transform", help = "Decode or decrypt the bytes in START-END (hex) before disassembling, e.g. 1000-1200=xor:5a. Supports xor:KEY, add:N, sub:N, rol:N, ror:N, rc4:KEY, base64 and hex. Can be given multiple times")]
+    transform: Vec<String>,
+    /// Compressed data
+    #[structopt(long = "decompress", help = "Decompress the bytes in START-END (hex) into a new region and disassemble it starting at its first byte, e.g. 400-1a00=lzma. Supports zlib, deflate, lzma, nrv2b and nrv2e. Can be given multiple times")]
+    decompress: Vec<String>,
+    /// Print the layer stack
+    #[structopt(long = "layers", help = "Print the layers of the binary's region and exit")]
+    layers: bool,
+    /// Byte patches
+    #[structopt(long = "patch", help = "Overwrite the bytes at ADDR (hex) before disassembling, e.g. 401000=9090. Can be given multiple times")]
+    patch: Vec<String>,
+    /// Instruction patches
+    #[structopt(long = "assemble", help = "Assemble an instruction at ADDR (hex) and patch it in, e.g. '401000=jmp 401020'. Supports jumps, calls and instructions without operands on x86. Can be given multiple times")]
+    assemble: Vec<String>,
+    /// Print the patches
+    #[structopt(long = "patches", help = "Print the patched bytes and their offsets in the file and exit")]
+    patches: bool,
+    /// Export the patched binary
+    #[structopt(long = "write-patched", help = "Write a copy of the binary with all patches applied to the given file and exit")]
+    write_patched: Option<String>,
+    /// Byte pattern to search for
+    #[structopt(long = "search", help = "Print every occurrence of the hex byte pattern, e.g. '48 8b ?5 ??', with the section and function containing it and exit. A ? matches any nibble")]
+    search: Option<String>,
+    /// Extract ASCII strings
+    #[structopt(long = "strings", help = "Print all printable ASCII strings and exit")]
+    strings: bool,
+    /// Extract UTF-16 strings
+    #[structopt(long = "utf16", help = "Print all printable UTF-16LE strings and exit")]
+    utf16: bool,
+    /// Minimum string length
+    #[structopt(long = "min-length", help = "Minimum number of characters of strings printed by --strings and --utf16. Defaults to 4")]
+    min_length: Option<usize>,
     /// The binary to disassemble
     #[structopt(help = "The binary to disassemble")]
     binary: String,
@@ -80,7 +137,7 @@ impl Filter {
     }
     pub fn is_match(&self, func: &Function) -> bool {
         if let Some(ref name) = self.name {
-            if name == &func.name || func.aliases().contains(name){ return true }
+            if func.is_named(name) { return true }
         }
         if let Some(ref addr) = self.addr {
             return *addr == func.start()
@@ -89,7 +146,7 @@ impl Filter {
     }
     pub fn is_match_with(&self, name: &str, addr: u64) -> bool {
         if let Some(ref name_) = self.name {
-            if name == name_ { return true }
+            if demangle::matches(name, name_) { return true }
         }
         if let Some(ref addr_) = self.addr {
             return addr == *addr_
@@ -145,13 +202,13 @@ fn print_reverse_deps<W: Write + WriteColor>(mut fmt: W, program: &Program, filt
             write!(fmt, "Found ")?;
             color!(fmt, Green, reverse_deps.len().to_string())?;
             write!(fmt, " reverse dependencies for ")?;
-            color_bold!(fmt, Yellow, name)?;
+            color_bold!(fmt, Yellow, demangle::display_name(name))?;
             write!(fmt, " @ ")?;
             color_bold!(fmt, Red, format!("{:#x}", addr))?;
             writeln!(fmt, "")?;
             for (addr, name) in reverse_deps {
                color_bold!(fmt, Red, format!("{: >16x} ", addr))?;
-               color_bold!(fmt, Yellow, name)?;
+               color_bold!(fmt, Yellow, demangle::display_name(&name))?;
                writeln!(fmt, "")?;
             }
         },
@@ -162,20 +219,180 @@ fn print_reverse_deps<W: Write + WriteColor>(mut fmt: W, program: &Program, filt
     Ok(())
 }
 
-fn disassemble(binary: &str) -> Result<Program> {
-    let (mut proj, machine) = loader::load(Path::new(&binary))?;
-    let program = proj.code.pop().unwrap();
-    let reg = proj.region().clone();
+fn print_slices<W: Write + WriteColor>(mut fmt: W, binary: &str) -> Result<()> {
+    let slices = loader::slices(Path::new(&binary))?;
+
+    if slices.is_empty() {
+        return Err(format!("'{}' is not a fat Mach-o binary", binary).into());
+    }
+
+    for (idx, slice) in slices.iter().enumerate() {
+        color_bold!(fmt, Green, format!("{: >3} ", idx))?;
+        color_bold!(fmt, Yellow, format!("{: <10} ", slice.cpu_name()))?;
+        color!(fmt, Red, format!("{:#x}+{:#x}", slice.offset, slice.size))?;
+        if slice.machine().is_none() {
+            write!(fmt, " (unsupported)")?;
+        }
+        writeln!(fmt, "")?;
+    }
+    Ok(())
+}
+
+fn parse_address(s: &str) -> Result<u64> {
+    let digits = s.trim_left_matches("0x");
+    u64::from_str_radix(digits, 16).map_err(|_| format!("'{}' is not a hexadecimal address", s).into())
+}
+
+/// Parses `START-END=SPEC` into the area and the specification.
+fn parse_area_spec(s: &str) -> Result<(Bound, &str)> {
+    let mut parts = s.splitn(2, '=');
+    let range = parts.next().unwrap_or("");
+    let spec = match parts.next() {
+        Some(spec) => spec,
+        None => return Err(format!("'{}' is not of the form START-END=SPEC", s).into()),
+    };
+    let mut bounds = range.splitn(2, '-');
+    let start = parse_address(bounds.next().unwrap_or(""))?;
+    let end = match bounds.next() {
+        Some(end) => parse_address(end)?,
+        None => return Err(format!("'{}' is not of the form START-END", range).into()),
+    };
+
+    if start >= end {
+        return Err(format!("'{}' is an empty range", range).into());
+    }
+
+    Ok((Bound::new(start, end), spec))
+}
+
+/// Parses `ADDR=SPEC` into the address and the specification.
+fn parse_patch_spec(s: &str) -> Result<(u64, &str)> {
+    let mut parts = s.splitn(2, '=');
+    let addr = parse_address(parts.next().unwrap_or(""))?;
+
+    match parts.next() {
+        Some(spec) => Ok((addr, spec)),
+        None => Err(format!("'{}' is not of the form ADDR=SPEC", s).into()),
+    }
+}
+
+fn assemble(machine: Machine, insn: &str, addr: u64) -> Result<Vec<u8>> {
+    match machine {
+        Machine::Ia16 => amd64::assemble(insn, addr, amd64::Mode::Real),
+        Machine::Ia32 => amd64::assemble(insn, addr, amd64::Mode::Protected),
+        Machine::Amd64 => amd64::assemble(insn, addr, amd64::Mode::Long),
+        Machine::Avr => Err("Assembling AVR instructions is not supported".into()),
+    }
+}
+
+/// Searches every region of `proj` for the pattern and strings requested in `args`. Returns the
+/// hits of each region separately, root region first.
+fn find_hits(proj: &Project, args: &Args) -> Result<Vec<Vec<Hit>>> {
+    let pattern = match args.search {
+        Some(ref pat) => Some(pat.parse::<Pattern>()?),
+        None => None,
+    };
+    let min_len = args.min_length.unwrap_or(4);
+    let raw = if args.no_relocs { Some(proj.raw_region()) } else { None };
+    let mut ret = vec![];
+
+    for (idx, reg) in proj.data.regions().into_iter().enumerate() {
+        let reg = match raw {
+            Some(ref raw) if idx == 0 => raw,
+            _ => reg,
+        };
+        let mut hits = vec![];
+
+        if let Some(ref pattern) = pattern {
+            hits.extend(pattern.find(reg));
+        }
+        if args.strings {
+            hits.extend(search::strings(reg, StringKind::Ascii, min_len));
+        }
+        if args.utf16 {
+            hits.extend(search::strings(reg, StringKind::Utf16Le, min_len));
+        }
+
+        hits.sort_by_key(|h| h.address);
+        ret.push(hits);
+    }
+
+    Ok(ret)
+}
+
+fn load_raw(args: &Args) -> Result<(Project, Machine)> {
+    let machine = match args.arch {
+        Some(ref arch) => arch.parse::<Machine>()?,
+        None => return Err("--raw requires the CPU to be set with --arch".into()),
+    };
+    let base = match args.base {
+        Some(ref base) => parse_address(base)?,
+        None => 0,
+    };
+    let entries = if args.entry.is_empty() {
+        vec![base]
+    } else {
+        args.entry.iter().map(|e| parse_address(e)).collect::<Result<Vec<_>>>()?
+    };
+    let path = Path::new(&args.binary);
+    match machine {
+        Machine::Avr => loader::load_raw::<avr::Avr>(path, base, &entries, &avr::Mcu::atmega103()),
+        Machine::Ia32 => loader::load_raw::<amd64::Amd64>(path, base, &entries, &amd64::Mode::Protected),
+        Machine::Amd64 => loader::load_raw::<amd64::Amd64>(path, base, &entries, &amd64::Mode::Long),
+        Machine::Ia16 => loader::load_raw::<amd64::Amd64>(path, base, &entries, &amd64::Mode::Real),
+    }
+}
+
+fn load(args: &Args) -> Result<(Project, Machine)> {
+    let binary = &args.binary;
+    if args.raw {
+        load_raw(args)
+    } else {
+        match (args.slice, args.base.as_ref()) {
+            (Some(_), Some(_)) => Err("--base cannot be used together with --slice".into()),
+            (Some(slice), None) => loader::load_slice(Path::new(binary), slice),
+            (None, Some(base)) => loader::load_at(Path::new(binary), parse_address(base)?),
+            (None, None) => loader::load(Path::new(binary)),
+        }
+    }
+}
+
+fn disassemble(proj: &mut Project, machine: Machine, relocated: bool) -> Result<Vec<Program>> {
+    for addr in proj.remove_non_executable_entries() {
+        info!("skipping entry point {:#x}, it's not executable", addr);
+    }
+
+    let root = if relocated { proj.region().clone() } else { proj.raw_region() };
+    let regions = proj.code
+        .iter()
+        .map(
+            |program| match program.region {
+                // decompressed code
+                Some(ref name) => proj.region_of(program).cloned().ok_or(format!("Unknown region '{}'", name).into()),
+                None => Ok(root.clone()),
+            }
+        )
+        .collect::<Result<Vec<_>>>()?;
     info!("disassembly thread started");
-    Ok(match machine {
-        Machine::Avr => analyze::<avr::Avr>(program, reg.clone(), avr::Mcu::atmega103()),
-        Machine::Ia32 => analyze::<amd64::Amd64>(program, reg.clone(), amd64::Mode::Protected),
-        Machine::Amd64 => analyze::<amd64::Amd64>(program, reg.clone(), amd64::Mode::Long),
-    }?)
+    // static libraries have one program per member object
+    proj.code
+        .drain(..)
+        .zip(regions.into_iter())
+        .map(
+            |(program, reg)| {
+                Ok(match machine {
+                    Machine::Avr => analyze::<avr::Avr>(program, reg, avr::Mcu::atmega103()),
+                    Machine::Ia32 => analyze::<amd64::Amd64>(program, reg, amd64::Mode::Protected),
+                    Machine::Amd64 => analyze::<amd64::Amd64>(program, reg, amd64::Mode::Long),
+                    Machine::Ia16 => analyze::<amd64::Amd64>(program, reg, amd64::Mode::Real),
+                }?)
+            }
+        )
+        .collect()
 }
 
-fn app_logic(fmt: &mut termcolor::Buffer, program: Program, args: Args) -> Result<()> {
-    let filter = Filter { name: args.function_filter, addr: args.address_filter.map(|addr| u64::from_str_radix(&addr, 16).unwrap()) };
+fn app_logic(fmt: &mut termcolor::Buffer, program: Program, proj: &Project, segments: &[Segment], debug_info: &DebugInfo, args: &Args) -> Result<()> {
+    let filter = Filter { name: args.function_filter.clone(), addr: args.address_filter.as_ref().map(|addr| u64::from_str_radix(addr, 16).unwrap()) };
 
     debug!("Program.imports: {:#?}", program.imports);
     if args.reverse_deps && filter.filtering() {
@@ -195,7 +412,8 @@ fn app_logic(fmt: &mut termcolor::Buffer, program: Program, args: Args) -> Resul
         // sort them by start so we can use them later
         bbs.sort_by(|bb1, bb2| bb1.area.start.cmp(&bb2.area.start));
 
-        display::print_function(fmt, &function, &bbs, &program)?;
+        let segment = segment::segment_at(segments, function.start());
+        display::print_function(fmt, &function, &bbs, &program, proj, segment, debug_info)?;
         if args.calls {
             let calls = function.collect_call_addresses();
             write!(fmt, "Calls (")?;
@@ -216,11 +434,88 @@ fn app_logic(fmt: &mut termcolor::Buffer, program: Program, args: Args) -> Resul
 
 fn run(args: Args) -> Result<()> {
     exists_path_val(&args.binary)?;
-    let program = disassemble(&args.binary)?;
     let cc = if args.color || atty::is(atty::Stream::Stdout) { ColorChoice::Auto } else { ColorChoice::Never };
     let writer = BufferWriter::stdout(cc);
     let mut fmt = writer.buffer();
-    app_logic(&mut fmt, program, args)?;
+    if args.list_slices {
+        print_slices(&mut fmt, &args.binary)?;
+        writer.print(&fmt)?;
+        return Ok(());
+    }
+    let (mut proj, machine) = load(&args)?;
+    for transform in args.transform.iter() {
+        let (area, transform) = parse_area_spec(transform)?;
+        proj.cover(area, Layer::Transform(transform.parse::<Transform>()?))?;
+    }
+    for decompress in args.decompress.iter() {
+        let (area, codec) = parse_area_spec(decompress)?;
+        proj.decompress(area, codec.parse::<Codec>()?, &[0])?;
+    }
+    for patch in args.patch.iter() {
+        let (addr, bytes) = parse_patch_spec(patch)?;
+        proj.patch(addr, &search::parse_hex(bytes)?)?;
+    }
+    for insn in args.assemble.iter() {
+        let (addr, insn) = parse_patch_spec(insn)?;
+        proj.patch(addr, &assemble(machine, insn, addr)?)?;
+    }
+    if let Some(ref output) = args.write_patched {
+        proj.write_patched(Path::new(&args.binary), Path::new(output))?;
+        return Ok(());
+    }
+    if args.patches {
+        display::print_patches(&mut fmt, &proj)?;
+        writer.print(&fmt)?;
+        return Ok(());
+    }
+    if args.layers {
+        display::print_layers(&mut fmt, proj.region())?;
+        writer.print(&fmt)?;
+        return Ok(());
+    }
+    if args.sections {
+        display::print_segments(&mut fmt, &proj.segments)?;
+        writer.print(&fmt)?;
+        return Ok(());
+    }
+    let searching = args.search.is_some() || args.strings || args.utf16;
+    let mut hits = if searching { find_hits(&proj, &args)? } else { vec![] };
+    let root = proj.region().name().clone();
+    let segments = proj.segments.clone();
+    let debug_info = proj.debug_info.clone();
+    let programs = disassemble(&mut proj, machine, !args.no_relocs)?;
+    if searching {
+        for hits in hits.iter_mut() {
+            let region = match hits.first() {
+                Some(hit) => hit.region.clone(),
+                None => continue,
+            };
+            let functions = programs
+                .iter()
+                .filter(|p| p.region.as_ref().unwrap_or(&root) == &region)
+                .flat_map(|p| p.functions())
+                .collect::<Vec<_>>();
+            // sections only describe the root region
+            let segments = if region == root { &segments[..] } else { &[][..] };
+
+            search::locate(hits, segments, &functions);
+            display::print_hits(&mut fmt, hits, region != root)?;
+        }
+        writer.print(&fmt)?;
+        return Ok(());
+    }
+    let print_names = programs.len() > 1;
+    for program in programs {
+        if print_names {
+            color_bold!(fmt, White, "Program")?;
+            write!(fmt, " ")?;
+            color_bold!(fmt, Yellow, program.name)?;
+            writeln!(fmt, ":")?;
+        }
+        // sections only describe the root region
+        let segments = if program.region.is_some() { &[][..] } else { &segments[..] };
+        app_logic(&mut fmt, program, &proj, segments, &debug_info, &args)?;
+    }
     writer.print(&fmt)?;
     Ok(())
 }
@@ -0,0 +1,707 @@
+/*
+ * Panopticon - A libre disassembler
+ * Copyright (C) 2017  Panopticon authors
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Decompression of data embedded in `Region`s.
+//!
+//! Packed executables and firmware images often carry their code compressed. A `Codec` turns the
+//! compressed bytes back into the original ones, see `World::decompress` for creating a new
+//! `Region` from them.
+//!
+//! Supported are zlib and raw deflate streams, LZMA streams with the 13 byte header written by
+//! `lzma` and `xz --format=lzma` and the NRV2B and NRV2E algorithms used by UPX.
+//!
+//! ```
+//! use panopticon_core::Codec;
+//!
+//! // zlib compressed "Hello"
+//! let packed = vec![0x78, 0x9c, 0xf3, 0x48, 0xcd, 0xc9, 0xc9, 0x07, 0x00, 0x05, 0x8c, 0x01, 0xf5];
+//!
+//! assert_eq!(Codec::Zlib.decompress(&packed).ok(), Some(b"Hello".to_vec()));
+//! ```
+
+use {Error, Result};
+use flate2::read::{DeflateDecoder, ZlibDecoder};
+use std::fmt;
+use std::io::Read;
+use std::str::FromStr;
+
+/// Compression algorithm.
+#[derive(Clone,Copy,Debug,PartialEq,Eq,Serialize,Deserialize)]
+pub enum Codec {
+    /// Deflate stream with zlib header and checksum (RFC 1950).
+    Zlib,
+    /// Raw deflate stream (RFC 1951).
+    Deflate,
+    /// LZMA stream with properties, dictionary size and uncompressed size header.
+    Lzma,
+    /// UCL NRV2B with 32 bit little endian bit buffer, as used by UPX.
+    Nrv2b,
+    /// UCL NRV2E with 32 bit little endian bit buffer, as used by UPX.
+    Nrv2e,
+}
+
+impl Codec {
+    /// Decompresses `input`. Trailing bytes after the end of the compressed stream are ignored.
+    pub fn decompress(&self, input: &[u8]) -> Result<Vec<u8>> {
+        let mut ret = Vec::new();
+
+        match *self {
+            Codec::Zlib => {
+                ret = read_limited(ZlibDecoder::new(input), MAX_OUTPUT)?;
+            }
+            Codec::Deflate => {
+                ret = read_limited(DeflateDecoder::new(input), MAX_OUTPUT)?;
+            }
+            Codec::Lzma => {
+                ret = lzma::decompress(input)?;
+            }
+            Codec::Nrv2b | Codec::Nrv2e => {
+                ret = nrv::decompress(input, *self == Codec::Nrv2e)?;
+            }
+        }
+
+        Ok(ret)
+    }
+}
+
+impl FromStr for Codec {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Codec> {
+        match s.to_lowercase().as_str() {
+            "zlib" => Ok(Codec::Zlib),
+            "deflate" => Ok(Codec::Deflate),
+            "lzma" => Ok(Codec::Lzma),
+            "nrv2b" | "upx" => Ok(Codec::Nrv2b),
+            "nrv2e" => Ok(Codec::Nrv2e),
+            _ => Err(format!("Unknown codec '{}', expected one of zlib, deflate, lzma, nrv2b or nrv2e", s).into()),
+        }
+    }
+}
+
+impl fmt::Display for Codec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            Codec::Zlib => "zlib",
+            Codec::Deflate => "deflate",
+            Codec::Lzma => "lzma",
+            Codec::Nrv2b => "nrv2b",
+            Codec::Nrv2e => "nrv2e",
+        };
+
+        f.write_str(s)
+    }
+}
+
+/// Upper bound of the decompressed size. Protects against corrupted streams with huge matches.
+const MAX_OUTPUT: usize = 1 << 30;
+
+/// Reads `rd` to the end. Fails if it returns more than `limit` bytes.
+fn read_limited<R: Read>(rd: R, limit: usize) -> Result<Vec<u8>> {
+    let mut ret = Vec::new();
+
+    rd.take(limit as u64 + 1).read_to_end(&mut ret)?;
+    if ret.len() > limit {
+        return Err(format!("Decompressed data is larger than {} bytes", limit).into());
+    }
+
+    Ok(ret)
+}
+
+/// Appends the literal `b` to `out`.
+fn push_literal(out: &mut Vec<u8>, b: u8) -> Result<()> {
+    if out.len() >= MAX_OUTPUT {
+        return Err(format!("Decompressed data is larger than {} bytes", MAX_OUTPUT).into());
+    }
+
+    out.push(b);
+    Ok(())
+}
+
+/// Copies `len` bytes starting `dist` bytes before the end of `out` to its end.
+fn copy_match(out: &mut Vec<u8>, dist: usize, len: usize) -> Result<()> {
+    if dist == 0 || dist > out.len() {
+        return Err(format!("Match distance {} points before the start of the output", dist).into());
+    }
+    if out.len().checked_add(len).map(|l| l > MAX_OUTPUT).unwrap_or(true) {
+        return Err(format!("Decompressed data is larger than {} bytes", MAX_OUTPUT).into());
+    }
+
+    let start = out.len() - dist;
+
+    for i in 0..len {
+        let b = out[start + i];
+        out.push(b);
+    }
+
+    Ok(())
+}
+
+mod nrv {
+    use Result;
+    use super::{copy_match, push_literal};
+
+    /// Bit reader for the UCL `getbit_le32` scheme. Bits are read MSB first from 32 bit little
+    /// endian words that are interleaved with the literal bytes.
+    struct Input<'a> {
+        data: &'a [u8],
+        pos: usize,
+        word: u32,
+        bits: u32,
+    }
+
+    /// Returns `a + b`, failing if the stream encodes a value that does not fit into 32 bits.
+    fn add(a: u32, b: u32) -> Result<u32> {
+        a.checked_add(b).ok_or_else(|| "NRV stream is corrupted".into())
+    }
+
+    /// Appends the next bit of `input` to `v`, failing if the result does not fit into 32 bits.
+    fn shift_in(v: u32, input: &mut Input) -> Result<u32> {
+        match v.checked_mul(2) {
+            Some(v) => add(v, input.bit()?),
+            None => Err("NRV stream is corrupted".into()),
+        }
+    }
+
+    impl<'a> Input<'a> {
+        fn byte(&mut self) -> Result<u8> {
+            match self.data.get(self.pos) {
+                Some(&b) => {
+                    self.pos += 1;
+                    Ok(b)
+                }
+                None => Err("Compressed data is truncated".into()),
+            }
+        }
+
+        fn bit(&mut self) -> Result<u32> {
+            if self.bits == 0 {
+                let mut w = 0u32;
+
+                for i in 0..4 {
+                    w |= (self.byte()? as u32) << (i * 8);
+                }
+
+                self.word = w;
+                self.bits = 32;
+            }
+
+            self.bits -= 1;
+            Ok((self.word >> self.bits) & 1)
+        }
+    }
+
+    pub fn decompress(data: &[u8], nrv2e: bool) -> Result<Vec<u8>> {
+        let mut input = Input { data: data, pos: 0, word: 0, bits: 0 };
+        let mut out = Vec::new();
+        let mut last_off = 1u32;
+
+        loop {
+            while input.bit()? == 1 {
+                let b = input.byte()?;
+                push_literal(&mut out, b)?;
+            }
+
+            let mut off = 1u32;
+            let mut len;
+
+            if nrv2e {
+                loop {
+                    off = off.wrapping_mul(2).wrapping_add(input.bit()?);
+                    if input.bit()? == 1 {
+                        break;
+                    }
+                    off = off.wrapping_sub(1).wrapping_mul(2).wrapping_add(input.bit()?);
+                }
+            } else {
+                loop {
+                    off = off.wrapping_mul(2).wrapping_add(input.bit()?);
+                    if input.bit()? == 1 {
+                        break;
+                    }
+                }
+            }
+
+            if off == 2 {
+                off = last_off;
+                len = if nrv2e { input.bit()? } else { 0 };
+            } else {
+                off = off.wrapping_sub(3).wrapping_mul(256).wrapping_add(input.byte()? as u32);
+
+                if off == 0xffffffff {
+                    break;
+                }
+
+                if nrv2e {
+                    len = (off ^ 0xffffffff) & 1;
+                    off >>= 1;
+                } else {
+                    len = 0;
+                }
+
+                off = add(off, 1)?;
+                last_off = off;
+            }
+
+            if nrv2e {
+                if len != 0 {
+                    len = 1 + input.bit()?;
+                } else if input.bit()? == 1 {
+                    len = 3 + input.bit()?;
+                } else {
+                    len = 1;
+                    loop {
+                        len = shift_in(len, &mut input)?;
+                        if input.bit()? == 1 {
+                            break;
+                        }
+                    }
+                    len = add(len, 3)?;
+                }
+
+                if off > 0x500 {
+                    len = add(len, 1)?;
+                }
+            } else {
+                len = input.bit()?;
+                len = len * 2 + input.bit()?;
+
+                if len == 0 {
+                    len = 1;
+                    loop {
+                        len = shift_in(len, &mut input)?;
+                        if input.bit()? == 1 {
+                            break;
+                        }
+                    }
+                    len = add(len, 2)?;
+                }
+
+                if off > 0xd00 {
+                    len = add(len, 1)?;
+                }
+            }
+
+            copy_match(&mut out, off as usize, add(len, 1)? as usize)?;
+        }
+
+        Ok(out)
+    }
+}
+
+mod lzma {
+    use Result;
+    use super::{MAX_OUTPUT, copy_match, push_literal};
+
+    const NUM_STATES: usize = 12;
+    const NUM_POS_BITS_MAX: usize = 4;
+    const NUM_LEN_TO_POS_STATES: usize = 4;
+    const END_POS_MODEL_INDEX: u32 = 14;
+    const NUM_FULL_DISTANCES: usize = 1 << (END_POS_MODEL_INDEX >> 1);
+    const MATCH_MIN_LEN: usize = 2;
+    const PROB_INIT: u16 = 1 << 10;
+
+    struct RangeDecoder<'a> {
+        data: &'a [u8],
+        pos: usize,
+        range: u32,
+        code: u32,
+    }
+
+    impl<'a> RangeDecoder<'a> {
+        fn new(data: &'a [u8]) -> Result<RangeDecoder<'a>> {
+            let mut rc = RangeDecoder { data: data, pos: 0, range: 0xffffffff, code: 0 };
+
+            if rc.byte()? != 0 {
+                return Err("LZMA stream is corrupted".into());
+            }
+
+            for _ in 0..4 {
+                rc.code = (rc.code << 8) | rc.byte()? as u32;
+            }
+
+            if rc.code == rc.range {
+                return Err("LZMA stream is corrupted".into());
+            }
+
+            Ok(rc)
+        }
+
+        fn byte(&mut self) -> Result<u8> {
+            match self.data.get(self.pos) {
+                Some(&b) => {
+                    self.pos += 1;
+                    Ok(b)
+                }
+                None => Err("Compressed data is truncated".into()),
+            }
+        }
+
+        fn normalize(&mut self) -> Result<()> {
+            if self.range < (1 << 24) {
+                self.range <<= 8;
+                self.code = (self.code << 8) | self.byte()? as u32;
+            }
+            Ok(())
+        }
+
+        fn direct_bits(&mut self, num: u32) -> Result<u32> {
+            let mut res = 0u32;
+
+            for _ in 0..num {
+                self.range >>= 1;
+                self.code = self.code.wrapping_sub(self.range);
+
+                let t = 0u32.wrapping_sub(self.code >> 31);
+
+                self.code = self.code.wrapping_add(self.range & t);
+                self.normalize()?;
+                res = (res << 1).wrapping_add(t.wrapping_add(1));
+            }
+
+            Ok(res)
+        }
+
+        fn bit(&mut self, prob: &mut u16) -> Result<u32> {
+            let bound = (self.range >> 11) * (*prob as u32);
+            let ret = if self.code < bound {
+                *prob += ((1 << 11) - *prob) >> 5;
+                self.range = bound;
+                0
+            } else {
+                *prob -= *prob >> 5;
+                self.code -= bound;
+                self.range -= bound;
+                1
+            };
+
+            self.normalize()?;
+            Ok(ret)
+        }
+
+        fn bit_tree(&mut self, probs: &mut [u16], num: u32) -> Result<u32> {
+            let mut m = 1usize;
+
+            for _ in 0..num {
+                m = (m << 1) + self.bit(&mut probs[m])? as usize;
+            }
+
+            Ok(m as u32 - (1 << num))
+        }
+
+        fn bit_tree_reverse(&mut self, probs: &mut [u16], num: u32) -> Result<u32> {
+            let mut m = 1usize;
+            let mut sym = 0u32;
+
+            for i in 0..num {
+                let bit = self.bit(&mut probs[m])?;
+
+                m = (m << 1) + bit as usize;
+                sym |= bit << i;
+            }
+
+            Ok(sym)
+        }
+    }
+
+    struct LenDecoder {
+        choice: u16,
+        choice2: u16,
+        low: Vec<Vec<u16>>,
+        mid: Vec<Vec<u16>>,
+        high: Vec<u16>,
+    }
+
+    impl LenDecoder {
+        fn new() -> LenDecoder {
+            LenDecoder {
+                choice: PROB_INIT,
+                choice2: PROB_INIT,
+                low: vec![vec![PROB_INIT; 1 << 3]; 1 << NUM_POS_BITS_MAX],
+                mid: vec![vec![PROB_INIT; 1 << 3]; 1 << NUM_POS_BITS_MAX],
+                high: vec![PROB_INIT; 1 << 8],
+            }
+        }
+
+        fn decode(&mut self, rc: &mut RangeDecoder, pos_state: usize) -> Result<usize> {
+            if rc.bit(&mut self.choice)? == 0 {
+                return Ok(rc.bit_tree(&mut self.low[pos_state], 3)? as usize);
+            }
+            if rc.bit(&mut self.choice2)? == 0 {
+                return Ok(8 + rc.bit_tree(&mut self.mid[pos_state], 3)? as usize);
+            }
+            Ok(16 + rc.bit_tree(&mut self.high, 8)? as usize)
+        }
+    }
+
+    /// Decodes a stream starting with the 13 byte `.lzma` header.
+    pub fn decompress(data: &[u8]) -> Result<Vec<u8>> {
+        if data.len() < 13 {
+            return Err("LZMA header is truncated".into());
+        }
+
+        let mut d = data[0] as usize;
+
+        if d >= 9 * 5 * 5 {
+            return Err("Invalid LZMA properties".into());
+        }
+
+        let lc = d % 9;
+        d /= 9;
+        let lp = d % 5;
+        let pb = d / 5;
+        let dict_size = (0..4).fold(0u32, |acc, i| acc | (data[1 + i] as u32) << (i * 8));
+        let unpack_size = (0..8).fold(0u64, |acc, i| acc | (data[5 + i] as u64) << (i * 8));
+        let size_known = unpack_size != 0xffffffffffffffff;
+
+        if size_known && unpack_size > MAX_OUTPUT as u64 {
+            return Err(format!("Decompressed data is larger than {} bytes", MAX_OUTPUT).into());
+        }
+        let dict_size = if dict_size < (1 << 12) { 1 << 12 } else { dict_size };
+
+        let mut rc = RangeDecoder::new(&data[13..])?;
+        let mut out = Vec::with_capacity(if size_known { ::std::cmp::min(unpack_size, 1 << 24) as usize } else { 0 });
+        let mut literal_probs = vec![PROB_INIT; 0x300 << (lc + lp)];
+        let mut pos_slot = vec![vec![PROB_INIT; 1 << 6]; NUM_LEN_TO_POS_STATES];
+        let mut pos_decoders = vec![PROB_INIT; 1 + NUM_FULL_DISTANCES - END_POS_MODEL_INDEX as usize];
+        let mut align = vec![PROB_INIT; 1 << 4];
+        let mut is_match = vec![PROB_INIT; NUM_STATES << NUM_POS_BITS_MAX];
+        let mut is_rep = vec![PROB_INIT; NUM_STATES];
+        let mut is_rep_g0 = vec![PROB_INIT; NUM_STATES];
+        let mut is_rep_g1 = vec![PROB_INIT; NUM_STATES];
+        let mut is_rep_g2 = vec![PROB_INIT; NUM_STATES];
+        let mut is_rep0_long = vec![PROB_INIT; NUM_STATES << NUM_POS_BITS_MAX];
+        let mut len_decoder = LenDecoder::new();
+        let mut rep_len_decoder = LenDecoder::new();
+        let mut reps = [0u32; 4];
+        let mut state = 0usize;
+
+        loop {
+            if size_known && out.len() as u64 == unpack_size {
+                break;
+            }
+
+            let pos_state = out.len() & ((1 << pb) - 1);
+
+            if rc.bit(&mut is_match[(state << NUM_POS_BITS_MAX) + pos_state])? == 0 {
+                // literal
+                let prev = out.last().cloned().unwrap_or(0) as usize;
+                let lit_state = ((out.len() & ((1 << lp) - 1)) << lc) + (prev >> (8 - lc));
+                let probs = &mut literal_probs[0x300 * lit_state..0x300 * (lit_state + 1)];
+                let mut sym = 1usize;
+
+                if state >= 7 {
+                    let mut match_byte = match out.len().checked_sub(reps[0] as usize + 1) {
+                        Some(p) => out[p] as usize,
+                        None => return Err("LZMA stream is corrupted".into()),
+                    };
+
+                    while sym < 0x100 {
+                        let match_bit = (match_byte >> 7) & 1;
+
+                        match_byte <<= 1;
+
+                        let bit = rc.bit(&mut probs[((1 + match_bit) << 8) + sym])? as usize;
+
+                        sym = (sym << 1) | bit;
+                        if match_bit != bit {
+                            break;
+                        }
+                    }
+                }
+
+                while sym < 0x100 {
+                    sym = (sym << 1) | rc.bit(&mut probs[sym])? as usize;
+                }
+
+                push_literal(&mut out, (sym - 0x100) as u8)?;
+                state = if state < 4 {
+                    0
+                } else if state < 10 {
+                    state - 3
+                } else {
+                    state - 6
+                };
+                continue;
+            }
+
+            let len;
+
+            if rc.bit(&mut is_rep[state])? != 0 {
+                if out.is_empty() {
+                    return Err("LZMA stream is corrupted".into());
+                }
+
+                if rc.bit(&mut is_rep_g0[state])? == 0 {
+                    if rc.bit(&mut is_rep0_long[(state << NUM_POS_BITS_MAX) + pos_state])? == 0 {
+                        // short rep
+                        state = if state < 7 { 9 } else { 11 };
+                        copy_match(&mut out, reps[0] as usize + 1, 1)?;
+                        continue;
+                    }
+                } else {
+                    let dist;
+
+                    if rc.bit(&mut is_rep_g1[state])? == 0 {
+                        dist = reps[1];
+                    } else {
+                        if rc.bit(&mut is_rep_g2[state])? == 0 {
+                            dist = reps[2];
+                        } else {
+                            dist = reps[3];
+                            reps[3] = reps[2];
+                        }
+                        reps[2] = reps[1];
+                    }
+                    reps[1] = reps[0];
+                    reps[0] = dist;
+                }
+
+                len = rep_len_decoder.decode(&mut rc, pos_state)?;
+                state = if state < 7 { 8 } else { 11 };
+            } else {
+                reps[3] = reps[2];
+                reps[2] = reps[1];
+                reps[1] = reps[0];
+                len = len_decoder.decode(&mut rc, pos_state)?;
+                state = if state < 7 { 7 } else { 10 };
+
+                // distance
+                let len_state = ::std::cmp::min(len, NUM_LEN_TO_POS_STATES - 1);
+                let slot = rc.bit_tree(&mut pos_slot[len_state], 6)?;
+
+                reps[0] = if slot < 4 {
+                    slot
+                } else {
+                    let num_direct = (slot >> 1) - 1;
+                    let dist = (2 | (slot & 1)) << num_direct;
+
+                    if slot < END_POS_MODEL_INDEX {
+                        let base = (dist - slot) as usize;
+                        dist + rc.bit_tree_reverse(&mut pos_decoders[base..], num_direct)?
+                    } else {
+                        let high = rc.direct_bits(num_direct - 4)? << 4;
+                        dist.wrapping_add(high).wrapping_add(rc.bit_tree_reverse(&mut align, 4)?)
+                    }
+                };
+
+                if reps[0] == 0xffffffff {
+                    // end marker
+                    break;
+                }
+
+                if reps[0] >= dict_size {
+                    return Err("LZMA match distance exceeds the dictionary".into());
+                }
+            }
+
+            let len = len + MATCH_MIN_LEN;
+
+            if size_known && out.len() as u64 + len as u64 > unpack_size {
+                return Err("LZMA stream is longer than its header says".into());
+            }
+
+            copy_match(&mut out, reps[0] as usize + 1, len)?;
+        }
+
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_codec() {
+        for s in &["zlib", "deflate", "lzma", "nrv2b", "nrv2e"] {
+            let c = s.parse::<Codec>().unwrap();
+            assert_eq!(format!("{}", c), *s);
+        }
+
+        assert_eq!("UPX".parse::<Codec>().ok(), Some(Codec::Nrv2b));
+        assert!("gzip".parse::<Codec>().is_err());
+    }
+
+    #[test]
+    fn deflate() {
+        let packed = vec![0xf3, 0x48, 0xcd, 0xc9, 0xc9, 0x07, 0x00];
+
+        assert_eq!(Codec::Deflate.decompress(&packed).ok(), Some(b"Hello".to_vec()));
+        assert!(Codec::Zlib.decompress(&packed).is_err());
+    }
+
+    #[test]
+    fn lzma() {
+        // written by `xz --format=lzma`, unknown size and end marker
+        let packed = vec![
+            0x5d, 0x00, 0x00, 0x80, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x24, 0x19, 0x49, 0x98, 0x6f, 0x16, 0x02, 0x89,
+            0x0a, 0x98, 0xe7, 0x3f, 0xa8, 0xc2, 0x74, 0xc6, 0x73, 0x8c, 0x1d, 0xdf, 0x0c, 0x8b, 0x30, 0x4d, 0x44, 0x6a, 0x2c, 0x38, 0x49, 0x15,
+            0xb0, 0x4f, 0x24, 0x70, 0x0d, 0xff, 0xff, 0xfd, 0x71, 0x20, 0x00,
+        ];
+        let line = b"Hello, World! Hello, World! Hello, Panopticon!\n";
+        let unpacked = line.iter().chain(line.iter()).chain(line.iter()).cloned().collect::<Vec<u8>>();
+
+        assert_eq!(Codec::Lzma.decompress(&packed).ok(), Some(unpacked));
+        assert!(Codec::Lzma.decompress(&packed[0..30]).is_err());
+        assert!(Codec::Lzma.decompress(&[0xff; 20]).is_err());
+    }
+
+    #[test]
+    fn nrv() {
+        let n2b = vec![
+            0x00, 0xff, 0xc2, 0xfe, 0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x2c, 0x20, 0x06, 0x57, 0x6f, 0x72, 0x6c, 0x64, 0x21, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x80, 0x04, 0xff,
+        ];
+        let n2e = vec![
+            0x12, 0xfe, 0xc4, 0xfe, 0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x2c, 0x20, 0x0d, 0x57, 0x6f, 0x72, 0x6c, 0x64, 0x21, 0x54, 0x92, 0x24, 0x49,
+            0xff,
+        ];
+
+        assert_eq!(Codec::Nrv2b.decompress(&n2b).ok(), Some(b"Hello, Hello, Hello, World!".to_vec()));
+        assert_eq!(Codec::Nrv2e.decompress(&n2e).ok(), Some(b"Hello, Hello, Hello, World!".to_vec()));
+        assert!(Codec::Nrv2b.decompress(&n2b[0..20]).is_err());
+
+        // "A" followed by a match with a match length that overflows 32 bits
+        let overflow = vec![0xaa, 0xaa, 0xaa, 0x92, 0x41, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0xa8, 0xaa, 0xaa];
+
+        assert!(Codec::Nrv2b.decompress(&overflow).is_err());
+    }
+
+    #[test]
+    fn match_limit() {
+        let mut out = vec![0x41];
+
+        assert!(copy_match(&mut out, 1, MAX_OUTPUT).is_err());
+        assert!(copy_match(&mut out, 1, ::std::usize::MAX).is_err());
+        assert_eq!(out, vec![0x41]);
+    }
+
+    #[test]
+    fn output_limit() {
+        let packed = vec![0xf3, 0x48, 0xcd, 0xc9, 0xc9, 0x07, 0x00];
+
+        assert_eq!(read_limited(DeflateDecoder::new(&packed[..]), 5).ok(), Some(b"Hello".to_vec()));
+        assert!(read_limited(DeflateDecoder::new(&packed[..]), 4).is_err());
+
+        // LZMA header claiming 2^40 bytes of output
+        let mut lzma = vec![0x5d, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00];
+        lzma.extend_from_slice(&[0; 16]);
+        assert!(Codec::Lzma.decompress(&lzma).is_err());
+    }
+}
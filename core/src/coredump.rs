@@ -0,0 +1,281 @@
+/*
+ * Panopticon - A libre disassembler
+ * Copyright (C) 2017  Panopticon authors
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Process state recorded in ELF core dumps.
+//!
+//! Besides the memory of the crashed process, Linux core dumps carry a `PT_NOTE` segment with the
+//! register state of each thread (`NT_PRSTATUS`) and the list of memory mapped files (`NT_FILE`).
+//! The loader parses these into a `CoreDump` and stores it in the `Project`.
+
+use {Bound, Machine, Result};
+use byteorder::{ByteOrder, LittleEndian};
+
+/// Register state of a thread at the time of the dump.
+#[derive(Clone,Debug,PartialEq,Eq,Serialize,Deserialize)]
+pub struct Thread {
+    /// Thread ID.
+    pub pid: u32,
+    /// Signal that caused the dump or 0 for threads not receiving it.
+    pub signal: u16,
+    /// General purpose registers in the order the kernel saves them.
+    pub registers: Vec<(String, u64)>,
+    /// Address of the next instruction to execute.
+    pub ip: u64,
+}
+
+impl Thread {
+    /// Returns the value of register `name`.
+    pub fn register(&self, name: &str) -> Option<u64> {
+        self.registers.iter().find(|r| r.0 == name).map(|r| r.1)
+    }
+}
+
+/// File mapped into the address space of the dumped process.
+#[derive(Clone,Debug,PartialEq,Eq,Serialize,Deserialize)]
+pub struct FileMapping {
+    /// Addresses occupied by the mapping.
+    pub bound: Bound,
+    /// Offset of the first mapped byte in the file.
+    pub file_offset: u64,
+    /// Path of the mapped file.
+    pub path: String,
+}
+
+/// Process state recorded in the notes of a core dump.
+#[derive(Clone,Debug,PartialEq,Eq,Serialize,Deserialize,Default)]
+pub struct CoreDump {
+    /// All threads of the process. The first one received the signal.
+    pub threads: Vec<Thread>,
+    /// Memory mapped files.
+    pub files: Vec<FileMapping>,
+}
+
+const NT_PRSTATUS: u32 = 1;
+const NT_FILE: u32 = 0x4649_4c45;
+
+const AMD64_REGISTERS: &'static [&'static str] = &[
+    "r15",
+    "r14",
+    "r13",
+    "r12",
+    "rbp",
+    "rbx",
+    "r11",
+    "r10",
+    "r9",
+    "r8",
+    "rax",
+    "rcx",
+    "rdx",
+    "rsi",
+    "rdi",
+    "orig_rax",
+    "rip",
+    "cs",
+    "eflags",
+    "rsp",
+    "ss",
+    "fs_base",
+    "gs_base",
+    "ds",
+    "es",
+    "fs",
+    "gs",
+];
+
+const IA32_REGISTERS: &'static [&'static str] = &[
+    "ebx",
+    "ecx",
+    "edx",
+    "esi",
+    "edi",
+    "ebp",
+    "eax",
+    "ds",
+    "es",
+    "fs",
+    "gs",
+    "orig_eax",
+    "eip",
+    "cs",
+    "eflags",
+    "esp",
+    "ss",
+];
+
+impl CoreDump {
+    /// Parses the contents of a `PT_NOTE` segment of a core dump for `machine` and adds all threads and
+    /// file mappings found to `self`. Unknown notes are ignored.
+    pub fn parse_notes(&mut self, buf: &[u8], machine: Machine) -> Result<()> {
+        // offset of pr_pid and pr_reg in struct elf_prstatus, register names and the instruction pointer
+        let (word, pid_offset, regs_offset, names, ip) = match machine {
+            Machine::Amd64 => (8, 32, 112, AMD64_REGISTERS, "rip"),
+            Machine::Ia32 => (4, 24, 72, IA32_REGISTERS, "eip"),
+            _ => return Err(format!("Core dumps for {:?} are not supported", machine).into()),
+        };
+        let read_word = |b: &[u8]| if word == 8 { LittleEndian::read_u64(b) } else { LittleEndian::read_u32(b) as u64 };
+        let mut pos = 0;
+
+        while pos + 12 <= buf.len() {
+            let namesz = LittleEndian::read_u32(&buf[pos..]) as usize;
+            let descsz = LittleEndian::read_u32(&buf[pos + 4..]) as usize;
+            let ty = LittleEndian::read_u32(&buf[pos + 8..]);
+            let desc = pos + 12 + ((namesz + 3) & !3);
+
+            if desc + descsz > buf.len() {
+                return Err("Note extends past the end of the segment".into());
+            }
+
+            let name = &buf[pos + 12..pos + 12 + namesz];
+            let desc_buf = &buf[desc..desc + descsz];
+
+            pos = desc + ((descsz + 3) & !3);
+
+            if name != b"CORE\0" {
+                continue;
+            }
+
+            match ty {
+                NT_PRSTATUS => {
+                    if regs_offset + names.len() * word > desc_buf.len() {
+                        return Err("NT_PRSTATUS note too short".into());
+                    }
+
+                    let registers = names
+                        .iter()
+                        .enumerate()
+                        .map(|(i, n)| (n.to_string(), read_word(&desc_buf[regs_offset + i * word..])))
+                        .collect::<Vec<_>>();
+                    let ip = registers.iter().find(|r| r.0 == ip).unwrap().1;
+
+                    self.threads
+                        .push(
+                            Thread {
+                                pid: LittleEndian::read_u32(&desc_buf[pid_offset..]),
+                                signal: LittleEndian::read_u16(&desc_buf[12..]),
+                                registers: registers,
+                                ip: ip,
+                            }
+                        );
+                }
+                NT_FILE => {
+                    if desc_buf.len() < 2 * word {
+                        return Err("NT_FILE note too short".into());
+                    }
+
+                    let count = read_word(desc_buf) as usize;
+                    let page_size = read_word(&desc_buf[word..]);
+                    let paths = match count.checked_mul(3 * word).and_then(|x| x.checked_add(2 * word)) {
+                        Some(paths) if paths <= desc_buf.len() => paths,
+                        _ => return Err("NT_FILE note too short".into()),
+                    };
+
+                    let mut names = desc_buf[paths..].split(|&b| b == 0);
+
+                    for i in 0..count {
+                        let ent = &desc_buf[2 * word + i * 3 * word..];
+                        let start = read_word(ent);
+                        let end = read_word(&ent[word..]);
+                        let offset = read_word(&ent[2 * word..]);
+                        let path = names.next().map(|p| String::from_utf8_lossy(p).to_string()).unwrap_or_default();
+
+                        self.files
+                            .push(
+                                FileMapping {
+                                    bound: Bound::new(start, end),
+                                    file_offset: offset.wrapping_mul(page_size),
+                                    path: path,
+                                }
+                            );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the file mapped at `addr`.
+    pub fn file_at(&self, addr: u64) -> Option<&FileMapping> {
+        self.files.iter().find(|f| f.bound.start <= addr && addr < f.bound.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note(ty: u32, desc: &[u8]) -> Vec<u8> {
+        let mut ret = vec![0u8; 12];
+
+        LittleEndian::write_u32(&mut ret[0..], 5);
+        LittleEndian::write_u32(&mut ret[4..], desc.len() as u32);
+        LittleEndian::write_u32(&mut ret[8..], ty);
+        ret.extend_from_slice(b"CORE\0\0\0\0");
+        ret.extend_from_slice(desc);
+        while ret.len() % 4 != 0 {
+            ret.push(0);
+        }
+        ret
+    }
+
+    #[test]
+    fn ia32_notes() {
+        let mut prstatus = vec![0u8; 72 + 17 * 4];
+        LittleEndian::write_u16(&mut prstatus[12..], 11);
+        LittleEndian::write_u32(&mut prstatus[24..], 42);
+        LittleEndian::write_u32(&mut prstatus[72 + 12 * 4..], 0x8048123);
+        LittleEndian::write_u32(&mut prstatus[72 + 15 * 4..], 0xbfff0000);
+
+        let mut file = vec![0u8; 8 + 12];
+        LittleEndian::write_u32(&mut file[0..], 1);
+        LittleEndian::write_u32(&mut file[4..], 0x1000);
+        LittleEndian::write_u32(&mut file[8..], 0x8048000);
+        LittleEndian::write_u32(&mut file[12..], 0x8049000);
+        LittleEndian::write_u32(&mut file[16..], 2);
+        file.extend_from_slice(b"/bin/true\0");
+
+        let mut buf = note(NT_PRSTATUS, &prstatus);
+        buf.extend(note(6, &[1, 2, 3]));
+        buf.extend(note(NT_FILE, &file));
+
+        let mut core = CoreDump::default();
+        core.parse_notes(&buf, Machine::Ia32).unwrap();
+
+        assert_eq!(core.threads.len(), 1);
+        assert_eq!(core.threads[0].pid, 42);
+        assert_eq!(core.threads[0].signal, 11);
+        assert_eq!(core.threads[0].ip, 0x8048123);
+        assert_eq!(core.threads[0].register("esp"), Some(0xbfff0000));
+        assert_eq!(core.files.len(), 1);
+        assert_eq!(core.files[0].file_offset, 0x2000);
+        assert_eq!(core.file_at(0x8048800).map(|f| f.path.as_str()), Some("/bin/true"));
+        assert!(core.parse_notes(&buf, Machine::Avr).is_err());
+    }
+
+    #[test]
+    fn file_count_overflow() {
+        let mut file = vec![0u8; 16];
+        LittleEndian::write_u64(&mut file[0..], u64::max_value() / 3);
+        LittleEndian::write_u64(&mut file[8..], 0x1000);
+
+        let mut core = CoreDump::default();
+        assert!(core.parse_notes(&note(NT_FILE, &file), Machine::Amd64).is_err());
+    }
+}
@@ -0,0 +1,2423 @@
+/*
+ * Panopticon - A libre disassembler
+ * Copyright (C) 2017  Panopticon authors
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Symbol name demangling.
+//!
+//! Compilers encode the namespace, template arguments and parameter types of C++ and Rust functions
+//! into their symbol names. This module decodes the common schemes back into source-level names:
+//!
+//! - Itanium C++ ABI (GCC, Clang; `_ZN3foo3barEv` is `foo::bar()`),
+//! - Microsoft Visual C++ (`?bar@foo@@QEAAXH@Z` is `void foo::bar(int)`),
+//! - Rust legacy (`_ZN3foo3bar17h0123456789abcdefE` is `foo::bar`) and
+//! - Rust v0 (`_RNvCs123_3foo3bar` is `foo::bar`).
+//!
+//! Demangling is best effort. Names that are not mangled or use unsupported parts of a scheme are
+//! left alone. Functions keep the mangled name, the demangled form is computed on demand.
+
+use std::char;
+
+/// Maximum recursion depth. Protects against stack overflows on malicious symbol names.
+const MAX_DEPTH: usize = 128;
+
+/// Maximum number of bytes Rust v0 back references may expand to. Nested back references grow the
+/// output exponentially with the symbol length.
+const MAX_BACKREF_OUTPUT: usize = 1_000_000;
+
+/// Returns the demangled form of the symbol `name` or `None` if it isn't mangled or can't be
+/// decoded. Import names of the form `library!symbol` keep their library prefix.
+pub fn demangle(name: &str) -> Option<String> {
+    if let Some(idx) = name.find('!') {
+        if idx > 0 && !name.starts_with('?') {
+            return demangle(&name[idx + 1..]).map(|s| format!("{}!{}", &name[..idx], s));
+        }
+    }
+
+    // Mach-o prefixes all symbols with an underscore
+    let sym = if name.starts_with("__Z") || name.starts_with("__R") { &name[1..] } else { name };
+
+    if sym.starts_with("_R") {
+        rust_v0(&sym[2..])
+    } else if sym.starts_with("_ZN") && rust_legacy(&sym[3..]).is_some() {
+        rust_legacy(&sym[3..])
+    } else if sym.starts_with("_Z") {
+        itanium(&sym[2..])
+    } else if sym.starts_with('?') {
+        msvc(&sym[1..])
+    } else {
+        None
+    }
+}
+
+/// Returns the demangled form of `name` or `name` itself if it isn't mangled.
+pub fn display_name(name: &str) -> String {
+    demangle(name).unwrap_or_else(|| name.to_string())
+}
+
+/// Returns true if `query` is the symbol `name` in either its mangled or demangled form. The
+/// parameter list of a demangled name is optional, i.e. `foo::bar` matches `_ZN3foo3barEi`.
+pub fn matches(name: &str, query: &str) -> bool {
+    if name == query {
+        return true;
+    }
+
+    match demangle(name) {
+        Some(demangled) => demangled == query || strip_parameters(&demangled) == query,
+        None => false,
+    }
+}
+
+/// Removes the return type, parameter list and qualifiers from the demangled function name `name`.
+fn strip_parameters(name: &str) -> &str {
+    let mut depth = 0isize;
+    let mut start = 0;
+
+    for (idx, c) in name.char_indices() {
+        match c {
+            '<' | '[' => depth += 1,
+            '>' | ']' => depth -= 1,
+            ' ' if depth == 0 => {
+                // skip the return type, but not the space in `operator new`
+                if !name[..idx].ends_with("operator") {
+                    start = idx + 1;
+                }
+            }
+            '(' if depth == 0 && idx > start && !name[..idx].ends_with("operator") => return &name[start..idx],
+            _ => {}
+        }
+    }
+
+    &name[start..]
+}
+
+/// Returns `args` formatted as a template argument list.
+fn template_list(args: &[String]) -> String {
+    let list = args.join(", ");
+
+    if list.ends_with('>') {
+        format!("<{} >", list)
+    } else {
+        format!("<{}>", list)
+    }
+}
+
+/// Byte cursor shared by all demanglers.
+struct Cursor<'a> {
+    input: &'a [u8],
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(input: &'a str) -> Cursor<'a> {
+        Cursor { input: input.as_bytes(), pos: 0, depth: 0 }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).cloned()
+    }
+
+    fn peek_at(&self, off: usize) -> Option<u8> {
+        self.input.get(self.pos + off).cloned()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let ret = self.peek();
+
+        if ret.is_some() {
+            self.pos += 1;
+        }
+        ret
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        if self.input[self.pos..].starts_with(s.as_bytes()) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> Option<()> {
+        if self.eat(c) { Some(()) } else { None }
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.input.len()
+    }
+
+    fn rest(&self) -> &'a [u8] {
+        &self.input[self.pos..]
+    }
+
+    fn decimal(&mut self) -> Option<usize> {
+        let start = self.pos;
+        let mut ret = 0usize;
+
+        while let Some(c) = self.peek() {
+            if c < b'0' || c > b'9' {
+                break;
+            }
+            ret = ret.checked_mul(10)?.checked_add((c - b'0') as usize)?;
+            self.pos += 1;
+        }
+
+        if self.pos > start { Some(ret) } else { None }
+    }
+
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.pos.checked_add(len)? <= self.input.len() {
+            let ret = &self.input[self.pos..self.pos + len];
+            self.pos += len;
+            Some(ret)
+        } else {
+            None
+        }
+    }
+
+    fn enter(&mut self) -> Option<()> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH { None } else { Some(()) }
+    }
+
+    fn leave(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+// Itanium C++ ABI
+
+/// C++ type. Declarators like pointers to functions are printed inside out, so types are kept as a
+/// tree until they're formatted.
+#[derive(Clone,Debug)]
+enum Type {
+    Name(String),
+    Qualified(Box<Type>, String),
+    Pointer(Box<Type>, &'static str),
+    Function(Box<Type>, Vec<Type>, String),
+    Array(Box<Type>, String),
+    MemberPointer(Box<Type>, Box<Type>),
+    Pack(Box<Type>),
+    List(Vec<Type>),
+}
+
+impl Type {
+    fn name(s: &str) -> Type {
+        Type::Name(s.to_string())
+    }
+
+    /// Splits the formatted type into the part left and right of the declarator.
+    fn split(&self) -> (String, String) {
+        match self {
+            &Type::Name(ref s) => (s.clone(), String::new()),
+            &Type::Qualified(ref inner, ref q) => {
+                let (l, r) = inner.split();
+                (format!("{}{}", l, q), r)
+            }
+            &Type::Pointer(ref inner, op) => {
+                let (l, r) = inner.split();
+
+                match **inner {
+                    Type::Function(..) | Type::Array(..) => (format!("{}({}", with_space(l), op), format!("){}", r)),
+                    _ => (format!("{}{}", l, op), r),
+                }
+            }
+            &Type::Function(ref ret, ref params, ref quals) => (format!("{} ", ret.to_string()), format!("({}){}", params_list(params), quals)),
+            &Type::Array(ref inner, ref dim) => {
+                let (l, r) = inner.split();
+                (l, format!(" [{}]{}", dim, r))
+            }
+            &Type::MemberPointer(ref class, ref member) => {
+                let (l, r) = member.split();
+
+                match **member {
+                    Type::Function(..) => (format!("{}({}::*", with_space(l), class.to_string()), format!("){}", r)),
+                    _ => (format!("{} {}::*", l, class.to_string()), r),
+                }
+            }
+            &Type::Pack(ref inner) => {
+                let (l, r) = inner.split();
+                (l, format!("{}...", r))
+            }
+            &Type::List(ref types) => (params_list(types), String::new()),
+        }
+    }
+
+    /// Returns the number of elements of the first argument pack referenced by this type.
+    fn pack_len(&self) -> Option<usize> {
+        match self {
+            &Type::Name(_) => None,
+            &Type::Qualified(ref inner, _) | &Type::Pointer(ref inner, _) | &Type::Array(ref inner, _) | &Type::Pack(ref inner) => inner.pack_len(),
+            &Type::Function(ref ret, ref params, _) => ret.pack_len().or_else(|| params.iter().filter_map(|t| t.pack_len()).next()),
+            &Type::MemberPointer(ref class, ref member) => class.pack_len().or_else(|| member.pack_len()),
+            &Type::List(ref types) => Some(types.len()),
+        }
+    }
+
+    /// Returns this type with all argument packs replaced by their `idx`th element.
+    fn pack_element(&self, idx: usize) -> Type {
+        match self {
+            &Type::Name(_) => self.clone(),
+            &Type::Qualified(ref inner, ref q) => Type::Qualified(Box::new(inner.pack_element(idx)), q.clone()),
+            &Type::Pointer(ref inner, op) => pointer(inner.pack_element(idx), op),
+            &Type::Array(ref inner, ref dim) => Type::Array(Box::new(inner.pack_element(idx)), dim.clone()),
+            &Type::Pack(ref inner) => Type::Pack(Box::new(inner.pack_element(idx))),
+            &Type::Function(ref ret, ref params, ref q) => {
+                let params = params.iter().map(|t| t.pack_element(idx)).collect();
+                Type::Function(Box::new(ret.pack_element(idx)), params, q.clone())
+            }
+            &Type::MemberPointer(ref class, ref member) => Type::MemberPointer(Box::new(class.pack_element(idx)), Box::new(member.pack_element(idx))),
+            &Type::List(ref types) => types.get(idx).cloned().unwrap_or_else(|| Type::List(Vec::new())),
+        }
+    }
+
+    fn to_string(&self) -> String {
+        let (l, r) = self.split();
+        format!("{}{}", l, r)
+    }
+}
+
+fn with_space(s: String) -> String {
+    if s.ends_with(' ') { s } else { format!("{} ", s) }
+}
+
+/// Returns `params` with argument packs expanded.
+fn flatten(params: &[Type]) -> Vec<String> {
+    let mut ret = Vec::new();
+
+    for t in params {
+        match t {
+            &Type::List(ref types) => ret.extend(flatten(types)),
+            t => ret.push(t.to_string()),
+        }
+    }
+    ret
+}
+
+fn params_list(params: &[Type]) -> String {
+    flatten(params).join(", ")
+}
+
+fn ends_with_empty_pack(args: &[Type]) -> bool {
+    match args.last() {
+        Some(&Type::List(ref pack)) => pack.is_empty() || ends_with_empty_pack(pack),
+        _ => false,
+    }
+}
+
+fn args_list(args: &[Type]) -> String {
+    let list = flatten(args);
+
+    // c++filt doesn't separate the closing brackets if the last argument is an empty pack
+    if ends_with_empty_pack(args) {
+        format!("<{}>", list.join(", "))
+    } else {
+        template_list(&list)
+    }
+}
+
+/// Returns a pointer or reference to `inner`. References to references collapse like in C++.
+fn pointer(inner: Type, op: &'static str) -> Type {
+    match (inner, op) {
+        (Type::Pointer(inner, "&"), "&") | (Type::Pointer(inner, "&"), "&&") | (Type::Pointer(inner, "&&"), "&") => Type::Pointer(inner, "&"),
+        (Type::Pointer(inner, "&&"), "&&") => Type::Pointer(inner, "&&"),
+        (inner, op) => Type::Pointer(Box::new(inner), op),
+    }
+}
+
+/// Properties of a demangled name needed to format the rest of the encoding.
+#[derive(Default)]
+struct NameInfo {
+    /// Template arguments of the last name component.
+    template_args: Option<Vec<Type>>,
+    /// Constructors, destructors and conversion operators have no return type.
+    no_return_type: bool,
+    /// Qualifiers of member functions, e.g. ` const`.
+    qualifiers: String,
+}
+
+struct Itanium<'a> {
+    cur: Cursor<'a>,
+    subs: Vec<Type>,
+    template_args: Vec<Type>,
+    last_source_name: String,
+}
+
+fn itanium(sym: &str) -> Option<String> {
+    let mut p = Itanium {
+        cur: Cursor::new(sym),
+        subs: Vec::new(),
+        template_args: Vec::new(),
+        last_source_name: String::new(),
+    };
+    let mut ret = p.encoding()?;
+
+    // clones created by optimizations, e.g. `.isra.0` or `.cold`
+    if p.cur.peek() == Some(b'.') {
+        let suffix = String::from_utf8_lossy(p.cur.rest()).to_string();
+        let mut clones: Vec<String> = Vec::new();
+
+        for part in suffix[1..].split('.') {
+            let numeric = !part.is_empty() && part.bytes().all(|b| b >= b'0' && b <= b'9');
+
+            if numeric {
+                if let Some(last) = clones.last_mut() {
+                    last.push('.');
+                    last.push_str(part);
+                    continue;
+                }
+            }
+            clones.push(format!(".{}", part));
+        }
+        for clone in clones {
+            ret = format!("{} [clone {}]", ret, clone);
+        }
+    } else if !p.cur.at_end() {
+        return None;
+    }
+
+    Some(ret)
+}
+
+impl<'a> Itanium<'a> {
+    fn encoding(&mut self) -> Option<String> {
+        self.encoding_with(true)
+    }
+
+    /// Functions enclosing local names are printed without return type.
+    fn encoding_with(&mut self, return_type: bool) -> Option<String> {
+        self.cur.enter()?;
+
+        let ret = match (self.cur.peek(), self.cur.peek_at(1)) {
+            (Some(b'T'), _) | (Some(b'G'), Some(b'V')) | (Some(b'G'), Some(b'R')) | (Some(b'G'), Some(b'T')) => self.special_name(),
+            _ => {
+                let (name, info) = self.name()?;
+
+                match self.cur.peek() {
+                    None | Some(b'E') | Some(b'.') => Some(name),
+                    _ => {
+                        if let Some(ref args) = info.template_args {
+                            self.template_args = args.clone();
+                        }
+
+                        let mut types = Vec::new();
+
+                        while let Some(c) = self.cur.peek() {
+                            if c == b'E' || c == b'.' {
+                                break;
+                            }
+                            types.push(self.type_()?);
+                        }
+
+                        let ret = if info.template_args.is_some() && !info.no_return_type && !types.is_empty() {
+                            let ret = types.remove(0);
+
+                            if return_type { format!("{} ", ret.to_string()) } else { String::new() }
+                        } else {
+                            String::new()
+                        };
+                        let params = match types.first() {
+                            Some(&Type::Name(ref s)) if types.len() == 1 && s == "void" => String::new(),
+                            _ => params_list(&types),
+                        };
+
+                        Some(format!("{}{}({}){}", ret, name, params, info.qualifiers))
+                    }
+                }
+            }
+        };
+
+        self.cur.leave();
+        ret
+    }
+
+    fn call_offset(&mut self) -> Option<()> {
+        match self.cur.next()? {
+            b'h' => {
+                self.number()?;
+                self.cur.expect(b'_')
+            }
+            b'v' => {
+                self.number()?;
+                self.cur.expect(b'_')?;
+                self.number()?;
+                self.cur.expect(b'_')
+            }
+            _ => None,
+        }
+    }
+
+    fn special_name(&mut self) -> Option<String> {
+        let first = self.cur.next()?;
+        let second = self.cur.next()?;
+
+        match (first, second) {
+            (b'T', b'V') => Some(format!("vtable for {}", self.type_()?.to_string())),
+            (b'T', b'T') => Some(format!("VTT for {}", self.type_()?.to_string())),
+            (b'T', b'I') => Some(format!("typeinfo for {}", self.type_()?.to_string())),
+            (b'T', b'S') => Some(format!("typeinfo name for {}", self.type_()?.to_string())),
+            (b'T', b'W') => Some(format!("TLS wrapper function for {}", self.name()?.0)),
+            (b'T', b'H') => Some(format!("TLS init function for {}", self.name()?.0)),
+            (b'T', b'h') | (b'T', b'v') => {
+                self.cur.pos -= 1;
+                self.call_offset()?;
+                let prefix = if second == b'h' { "non-virtual" } else { "virtual" };
+                Some(format!("{} thunk to {}", prefix, self.encoding()?))
+            }
+            (b'T', b'c') => {
+                self.call_offset()?;
+                self.call_offset()?;
+                Some(format!("covariant return thunk to {}", self.encoding()?))
+            }
+            (b'G', b'V') => Some(format!("guard variable for {}", self.name()?.0)),
+            (b'G', b'T') => {
+                self.cur.next()?;
+                Some(format!("transaction clone for {}", self.encoding()?))
+            }
+            (b'G', b'R') => {
+                let name = self.name()?.0;
+                while self.cur.peek().map(|c| c != b'_') == Some(true) {
+                    self.cur.pos += 1;
+                }
+                self.cur.expect(b'_')?;
+                Some(format!("reference temporary for {}", name))
+            }
+            _ => None,
+        }
+    }
+
+    fn number(&mut self) -> Option<isize> {
+        let neg = self.cur.eat(b'n');
+        let n = self.cur.decimal()? as isize;
+
+        Some(if neg { -n } else { n })
+    }
+
+    fn name(&mut self) -> Option<(String, NameInfo)> {
+        self.cur.enter()?;
+
+        let ret = match self.cur.peek()? {
+            b'N' => self.nested_name(),
+            b'Z' => self.local_name(),
+            _ => {
+                let mut info = NameInfo::default();
+                let mut name = if self.cur.peek() == Some(b'S') && self.cur.peek_at(1) != Some(b't') {
+                    // unscoped template name from the substitution table
+                    let sub = self.substitution()?.to_string();
+
+                    if self.cur.peek() != Some(b'I') {
+                        return None;
+                    }
+                    sub
+                } else {
+                    let std = self.cur.eat_str("St");
+                    let (name, no_ret) = self.unqualified_name()?;
+
+                    info.no_return_type = no_ret;
+                    if std { format!("std::{}", name) } else { name }
+                };
+
+                if self.cur.peek() == Some(b'I') {
+                    self.subs.push(Type::Name(name.clone()));
+
+                    let saved = self.last_source_name.clone();
+                    let args = self.template_args()?;
+
+                    self.last_source_name = saved;
+
+                    name = format!("{}{}", name, args_list(&args));
+                    info.template_args = Some(args);
+                }
+
+                Some((name, info))
+            }
+        };
+
+        self.cur.leave();
+        ret
+    }
+
+    fn qualifiers(&mut self) -> String {
+        let restrict = self.cur.eat(b'r');
+        let volatile = self.cur.eat(b'V');
+        let konst = self.cur.eat(b'K');
+        let mut ret = String::new();
+
+        if konst {
+            ret.push_str(" const");
+        }
+        if volatile {
+            ret.push_str(" volatile");
+        }
+        if restrict {
+            ret.push_str(" restrict");
+        }
+        ret
+    }
+
+    fn nested_name(&mut self) -> Option<(String, NameInfo)> {
+        self.cur.expect(b'N')?;
+
+        let mut info = NameInfo::default();
+        let mut prefix: Option<String> = None;
+
+        info.qualifiers = self.qualifiers();
+        if self.cur.eat(b'R') {
+            info.qualifiers.push_str(" &");
+        } else if self.cur.eat(b'O') {
+            info.qualifiers.push_str(" &&");
+        }
+
+        loop {
+            let c = self.cur.peek()?;
+
+            if c == b'E' {
+                self.cur.pos += 1;
+                break;
+            }
+
+            match c {
+                b'S' if self.cur.peek_at(1) == Some(b't') => {
+                    self.cur.pos += 2;
+                    prefix = Some("std".to_string());
+                    continue;
+                }
+                b'S' => {
+                    let sub = self.substitution()?.to_string();
+
+                    self.last_source_name = strip_template(&sub).rsplit("::").next().unwrap_or("").to_string();
+                    prefix = Some(sub);
+                    continue;
+                }
+                b'I' => {
+                    let p = prefix.take()?;
+                    let saved = self.last_source_name.clone();
+                    let args = self.template_args()?;
+
+                    self.last_source_name = saved;
+                    let list = args_list(&args);
+
+                    prefix = Some(format!("{}{}", p, list));
+                    info.template_args = Some(args);
+                }
+                b'T' => {
+                    prefix = Some(self.template_param()?.to_string());
+                    info.template_args = None;
+                }
+                b'M' => {
+                    // data member prefix of closures in initializers
+                    self.cur.pos += 1;
+                    continue;
+                }
+                _ => {
+                    let (name, no_ret) = self.unqualified_name()?;
+
+                    prefix = Some(
+                        match prefix {
+                            Some(p) => format!("{}::{}", p, name),
+                            None => name,
+                        }
+                    );
+                    info.template_args = None;
+                    info.no_return_type = no_ret;
+                }
+            }
+
+            if self.cur.peek() != Some(b'E') {
+                self.subs.push(Type::Name(prefix.clone()?));
+            }
+        }
+
+        prefix.map(|p| (p, info))
+    }
+
+    fn local_name(&mut self) -> Option<(String, NameInfo)> {
+        self.cur.expect(b'Z')?;
+
+        let saved = self.template_args.clone();
+        let func = self.encoding_with(false)?;
+
+        self.template_args = saved;
+        self.cur.expect(b'E')?;
+
+        let (entity, info) = if self.cur.eat(b's') {
+            ("string literal".to_string(), NameInfo::default())
+        } else {
+            if self.cur.eat(b'd') {
+                self.cur.decimal();
+                self.cur.expect(b'_')?;
+            }
+            self.name()?
+        };
+
+        // discriminator
+        if self.cur.eat(b'_') {
+            if self.cur.eat(b'_') {
+                self.cur.decimal()?;
+                self.cur.expect(b'_')?;
+            } else {
+                self.cur.next()?;
+            }
+        }
+
+        Some((format!("{}::{}", func, entity), info))
+    }
+
+    fn source_name(&mut self) -> Option<String> {
+        let len = self.cur.decimal()?;
+        let ident = String::from_utf8_lossy(self.cur.bytes(len)?).to_string();
+
+        // `_GLOBAL__N_1`, with `.` or `$` instead of `_` on some platforms
+        if ident.starts_with("_GLOBAL_") && ident.as_bytes().get(9) == Some(&b'N') {
+            Some("(anonymous namespace)".to_string())
+        } else {
+            Some(ident)
+        }
+    }
+
+    /// Returns the name and whenever it's a constructor, destructor or conversion operator.
+    fn unqualified_name(&mut self) -> Option<(String, bool)> {
+        let c = self.cur.peek()?;
+        let (mut name, special) = match c {
+            b'0'...b'9' => {
+                let name = self.source_name()?;
+                self.last_source_name = name.clone();
+                (name, false)
+            }
+            b'L' => {
+                // internal linkage
+                self.cur.pos += 1;
+                return self.unqualified_name();
+            }
+            b'C' => {
+                self.cur.pos += 1;
+                if self.cur.eat(b'I') {
+                    self.cur.next()?;
+                    self.type_()?;
+                } else {
+                    self.cur.next()?;
+                }
+                (self.last_source_name.clone(), true)
+            }
+            b'D' if self.cur.peek_at(1).map(|c| c >= b'0' && c <= b'5') == Some(true) => {
+                self.cur.pos += 2;
+                (format!("~{}", self.last_source_name), true)
+            }
+            b'U' => {
+                self.cur.pos += 1;
+                match self.cur.next()? {
+                    b't' => {
+                        let n = self.cur.decimal().map(|n| n + 2).unwrap_or(1);
+                        self.cur.expect(b'_')?;
+                        (format!("{{unnamed type#{}}}", n), false)
+                    }
+                    b'l' => {
+                        let mut params = Vec::new();
+
+                        while !self.cur.eat(b'E') {
+                            params.push(self.type_()?);
+                        }
+
+                        let n = self.cur.decimal().map(|n| n + 2).unwrap_or(1);
+                        let params = match params.first() {
+                            Some(&Type::Name(ref s)) if params.len() == 1 && s == "void" => String::new(),
+                            _ => params_list(&params),
+                        };
+
+                        self.cur.expect(b'_')?;
+                        (format!("{{lambda({})#{}}}", params, n), false)
+                    }
+                    _ => return None,
+                }
+            }
+            b'a'...b'z' => self.operator_name()?,
+            _ => return None,
+        };
+
+        // ABI tags
+        while self.cur.eat(b'B') {
+            name = format!("{}[abi:{}]", name, self.source_name()?);
+        }
+
+        Some((name, special))
+    }
+
+    fn operator_name(&mut self) -> Option<(String, bool)> {
+        const OPERATORS: &'static [(&'static str, &'static str)] = &[
+            ("nw", "new"),
+            ("na", "new[]"),
+            ("dl", "delete"),
+            ("da", "delete[]"),
+            ("ps", "+"),
+            ("ng", "-"),
+            ("ad", "&"),
+            ("de", "*"),
+            ("co", "~"),
+            ("pl", "+"),
+            ("mi", "-"),
+            ("ml", "*"),
+            ("dv", "/"),
+            ("rm", "%"),
+            ("an", "&"),
+            ("or", "|"),
+            ("eo", "^"),
+            ("aS", "="),
+            ("pL", "+="),
+            ("mI", "-="),
+            ("mL", "*="),
+            ("dV", "/="),
+            ("rM", "%="),
+            ("aN", "&="),
+            ("oR", "|="),
+            ("eO", "^="),
+            ("ls", "<<"),
+            ("rs", ">>"),
+            ("lS", "<<="),
+            ("rS", ">>="),
+            ("eq", "=="),
+            ("ne", "!="),
+            ("lt", "<"),
+            ("gt", ">"),
+            ("le", "<="),
+            ("ge", ">="),
+            ("ss", "<=>"),
+            ("nt", "!"),
+            ("aa", "&&"),
+            ("oo", "||"),
+            ("pp", "++"),
+            ("mm", "--"),
+            ("cm", ","),
+            ("pm", "->*"),
+            ("pt", "->"),
+            ("cl", "()"),
+            ("ix", "[]"),
+            ("qu", "?"),
+        ];
+
+        if self.cur.eat_str("cv") {
+            let ty = self.type_()?;
+            return Some((format!("operator {}", ty.to_string()), true));
+        }
+        if self.cur.eat_str("li") {
+            return Some((format!("operator\"\" {}", self.source_name()?), false));
+        }
+        if self.cur.peek() == Some(b'v') && self.cur.peek_at(1).map(|c| c >= b'0' && c <= b'9') == Some(true) {
+            self.cur.pos += 2;
+            return Some((format!("operator {}", self.source_name()?), false));
+        }
+
+        for &(code, op) in OPERATORS {
+            if self.cur.eat_str(code) {
+                let name = if op.starts_with("new") || op.starts_with("delete") {
+                    format!("operator {}", op)
+                } else if op.ends_with('<') && self.cur.peek() == Some(b'I') {
+                    // avoid confusion with the template argument list
+                    format!("operator{} ", op)
+                } else {
+                    format!("operator{}", op)
+                };
+
+                return Some((name, false));
+            }
+        }
+
+        None
+    }
+
+    fn template_args(&mut self) -> Option<Vec<Type>> {
+        self.cur.expect(b'I')?;
+
+        let mut ret = Vec::new();
+
+        while !self.cur.eat(b'E') {
+            ret.push(self.template_arg()?);
+        }
+        Some(ret)
+    }
+
+    fn template_arg(&mut self) -> Option<Type> {
+        self.cur.enter()?;
+
+        let ret = match self.cur.peek()? {
+            b'L' => self.expr_primary().map(Type::Name),
+            b'X' => {
+                self.cur.pos += 1;
+                let e = self.expression()?;
+                self.cur.expect(b'E')?;
+                Some(Type::Name(e))
+            }
+            b'J' => {
+                self.cur.pos += 1;
+
+                let mut args = Vec::new();
+
+                while !self.cur.eat(b'E') {
+                    args.push(self.template_arg()?);
+                }
+                Some(Type::List(args))
+            }
+            _ => self.type_(),
+        };
+
+        self.cur.leave();
+        ret
+    }
+
+    fn expression(&mut self) -> Option<String> {
+        self.cur.enter()?;
+
+        let ret = match (self.cur.peek()?, self.cur.peek_at(1).unwrap_or(0)) {
+            (b'T', _) => Some(self.template_param()?.to_string()),
+            (b'L', _) => self.expr_primary(),
+            (b'0'...b'9', _) => {
+                let mut name = self.source_name()?;
+
+                if self.cur.peek() == Some(b'I') {
+                    name = format!("{}{}", name, args_list(&self.template_args()?));
+                }
+                Some(name)
+            }
+            (b'f', b'p') => {
+                self.cur.pos += 2;
+                self.qualifiers();
+
+                let n = if self.cur.peek() == Some(b'_') { 1 } else { self.cur.decimal()? + 2 };
+
+                self.cur.expect(b'_')?;
+                Some(format!("{{parm#{}}}", n))
+            }
+            (b's', b'p') => {
+                self.cur.pos += 2;
+                self.expression()
+            }
+            (b's', b'r') => {
+                self.cur.pos += 2;
+                self.unresolved_name()
+            }
+            (a, b) => {
+                let op = [a, b];
+                let unary = match &op {
+                    b"nt" => Some("!"),
+                    b"ng" => Some("-"),
+                    b"ps" => Some("+"),
+                    b"co" => Some("~"),
+                    b"ad" => Some("&"),
+                    b"de" => Some("*"),
+                    _ => None,
+                };
+                let binary = match &op {
+                    b"pl" => Some("+"),
+                    b"mi" => Some("-"),
+                    b"ml" => Some("*"),
+                    b"dv" => Some("/"),
+                    b"rm" => Some("%"),
+                    b"an" => Some("&"),
+                    b"or" => Some("|"),
+                    b"eo" => Some("^"),
+                    b"ls" => Some("<<"),
+                    b"rs" => Some(">>"),
+                    b"eq" => Some("=="),
+                    b"ne" => Some("!="),
+                    b"lt" => Some("<"),
+                    b"gt" => Some(">"),
+                    b"le" => Some("<="),
+                    b"ge" => Some(">="),
+                    b"aa" => Some("&&"),
+                    b"oo" => Some("||"),
+                    _ => None,
+                };
+
+                if let Some(op) = unary {
+                    self.cur.pos += 2;
+                    Some(format!("{}{}", op, self.expression()?))
+                } else if let Some(op) = binary {
+                    self.cur.pos += 2;
+
+                    let a = self.expression()?;
+                    let b = self.expression()?;
+
+                    Some(format!("({}){}({})", a, op, b))
+                } else {
+                    None
+                }
+            }
+        };
+
+        self.cur.leave();
+        ret
+    }
+
+    /// Names of dependent members like `T::value` that can't be resolved at mangling time.
+    fn unresolved_name(&mut self) -> Option<String> {
+        let mut ret = if self.cur.eat(b'N') {
+            let mut ret = self.type_()?.to_string();
+
+            if self.cur.peek() == Some(b'I') {
+                ret = format!("{}{}", ret, args_list(&self.template_args()?));
+            }
+            while !self.cur.eat(b'E') {
+                ret = format!("{}::{}", ret, self.simple_id()?);
+            }
+            ret
+        } else {
+            self.type_()?.to_string()
+        };
+
+        ret = format!("{}::{}", ret, self.simple_id()?);
+        Some(ret)
+    }
+
+    fn simple_id(&mut self) -> Option<String> {
+        let name = self.source_name()?;
+
+        if self.cur.peek() == Some(b'I') {
+            Some(format!("{}{}", name, args_list(&self.template_args()?)))
+        } else {
+            Some(name)
+        }
+    }
+
+    fn expr_primary(&mut self) -> Option<String> {
+        self.cur.expect(b'L')?;
+
+        if self.cur.eat_str("_Z") {
+            let ret = self.encoding()?;
+            self.cur.expect(b'E')?;
+            return Some(ret);
+        }
+
+        let code = self.cur.peek()?;
+        let ty = self.type_()?;
+        let neg = self.cur.eat(b'n');
+        let start = self.cur.pos;
+
+        while self.cur.peek()? != b'E' {
+            self.cur.pos += 1;
+        }
+
+        let value = String::from_utf8_lossy(&self.cur.input[start..self.cur.pos]).to_string();
+        let value = if neg { format!("-{}", value) } else { value };
+
+        self.cur.pos += 1;
+        match code {
+            b'b' if value == "0" => Some("false".to_string()),
+            b'b' if value == "1" => Some("true".to_string()),
+            b'i' => Some(value),
+            b'j' => Some(format!("{}u", value)),
+            b'l' => Some(format!("{}l", value)),
+            b'm' => Some(format!("{}ul", value)),
+            b'x' => Some(format!("{}ll", value)),
+            b'y' => Some(format!("{}ull", value)),
+            _ => Some(format!("({}){}", ty.to_string(), value)),
+        }
+    }
+
+    fn template_param(&mut self) -> Option<Type> {
+        self.cur.expect(b'T')?;
+
+        let idx = if self.cur.eat(b'_') {
+            0
+        } else {
+            let n = self.cur.decimal()?;
+            self.cur.expect(b'_')?;
+            n + 1
+        };
+
+        self.template_args.get(idx).cloned()
+    }
+
+    fn substitution(&mut self) -> Option<Type> {
+        self.cur.expect(b'S')?;
+
+        let c = self.cur.next()?;
+        let special = match c {
+            b't' => Some("std"),
+            b'a' => Some("std::allocator"),
+            b'b' => Some("std::basic_string"),
+            b's' => Some("std::basic_string<char, std::char_traits<char>, std::allocator<char> >"),
+            b'i' => Some("std::basic_istream<char, std::char_traits<char> >"),
+            b'o' => Some("std::basic_ostream<char, std::char_traits<char> >"),
+            b'd' => Some("std::basic_iostream<char, std::char_traits<char> >"),
+            _ => None,
+        };
+
+        if let Some(s) = special {
+            return Some(Type::name(s));
+        }
+
+        let idx = if c == b'_' {
+            0
+        } else {
+            let mut seq = 0usize;
+            let mut c = c;
+
+            loop {
+                let digit = match c {
+                    b'0'...b'9' => c - b'0',
+                    b'A'...b'Z' => c - b'A' + 10,
+                    b'_' => break,
+                    _ => return None,
+                };
+
+                seq = seq.checked_mul(36)?.checked_add(digit as usize)?;
+                c = self.cur.next()?;
+            }
+            seq + 1
+        };
+
+        self.subs.get(idx).cloned()
+    }
+
+    fn builtin_type(&mut self) -> Option<&'static str> {
+        let ret = match self.cur.peek()? {
+            b'v' => "void",
+            b'w' => "wchar_t",
+            b'b' => "bool",
+            b'c' => "char",
+            b'a' => "signed char",
+            b'h' => "unsigned char",
+            b's' => "short",
+            b't' => "unsigned short",
+            b'i' => "int",
+            b'j' => "unsigned int",
+            b'l' => "long",
+            b'm' => "unsigned long",
+            b'x' => "long long",
+            b'y' => "unsigned long long",
+            b'n' => "__int128",
+            b'o' => "unsigned __int128",
+            b'f' => "float",
+            b'd' => "double",
+            b'e' => "long double",
+            b'g' => "__float128",
+            b'z' => "...",
+            b'D' => {
+                let ret = match self.cur.peek_at(1)? {
+                    b'n' => "decltype(nullptr)",
+                    b'a' => "auto",
+                    b'c' => "decltype(auto)",
+                    b'i' => "char32_t",
+                    b's' => "char16_t",
+                    b'u' => "char8_t",
+                    b'f' => "decimal32",
+                    b'd' => "decimal64",
+                    b'e' => "decimal128",
+                    b'h' => "half",
+                    _ => return None,
+                };
+
+                self.cur.pos += 2;
+                return Some(ret);
+            }
+            _ => return None,
+        };
+
+        self.cur.pos += 1;
+        Some(ret)
+    }
+
+    fn type_(&mut self) -> Option<Type> {
+        if let Some(b) = self.builtin_type() {
+            return Some(Type::name(b));
+        }
+
+        self.cur.enter()?;
+
+        let ret = match self.cur.peek()? {
+            b'r' | b'V' | b'K' => {
+                let q = self.qualifiers();
+                let inner = self.type_()?;
+
+                match inner {
+                    // qualifiers of member function types
+                    Type::Function(ret, params, quals) => Type::Function(ret, params, format!("{}{}", quals, q)),
+                    inner => Type::Qualified(Box::new(inner), q),
+                }
+            }
+            b'P' | b'R' | b'O' => {
+                let op = match self.cur.next()? {
+                    b'P' => "*",
+                    b'R' => "&",
+                    _ => "&&",
+                };
+                pointer(self.type_()?, op)
+            }
+            b'C' => {
+                self.cur.pos += 1;
+                Type::Qualified(Box::new(self.type_()?), " _Complex".to_string())
+            }
+            b'G' => {
+                self.cur.pos += 1;
+                Type::Qualified(Box::new(self.type_()?), " _Imaginary".to_string())
+            }
+            b'u' => {
+                self.cur.pos += 1;
+                Type::Name(self.source_name()?)
+            }
+            b'D' => {
+                match self.cur.peek_at(1)? {
+                    b'p' => {
+                        self.cur.pos += 2;
+                        let pattern = self.type_()?;
+
+                        match pattern.pack_len() {
+                            Some(n) => Type::List((0..n).map(|i| pattern.pack_element(i)).collect()),
+                            None => Type::Pack(Box::new(pattern)),
+                        }
+                    }
+                    b'v' => {
+                        self.cur.pos += 2;
+                        let n = self.cur.decimal()?;
+                        self.cur.expect(b'_')?;
+                        Type::Name(format!("{} __vector({})", self.type_()?.to_string(), n))
+                    }
+                    _ => return None,
+                }
+            }
+            b'F' => {
+                self.cur.pos += 1;
+                self.cur.eat(b'Y');
+
+                let mut types = Vec::new();
+                let mut quals = String::new();
+
+                loop {
+                    if self.cur.eat(b'E') {
+                        break;
+                    }
+                    if self.cur.peek() == Some(b'R') && self.cur.peek_at(1) == Some(b'E') {
+                        self.cur.pos += 2;
+                        quals.push_str(" &");
+                        break;
+                    }
+                    if self.cur.peek() == Some(b'O') && self.cur.peek_at(1) == Some(b'E') {
+                        self.cur.pos += 2;
+                        quals.push_str(" &&");
+                        break;
+                    }
+                    types.push(self.type_()?);
+                }
+
+                if types.is_empty() {
+                    return None;
+                }
+
+                let ret = types.remove(0);
+
+                if types.len() == 1 && types[0].to_string() == "void" {
+                    types.clear();
+                }
+                Type::Function(Box::new(ret), types, quals)
+            }
+            b'A' => {
+                self.cur.pos += 1;
+
+                let dim = if self.cur.peek() == Some(b'_') {
+                    String::new()
+                } else if let Some(n) = self.cur.decimal() {
+                    n.to_string()
+                } else {
+                    self.expression()?
+                };
+
+                self.cur.expect(b'_')?;
+                Type::Array(Box::new(self.type_()?), dim)
+            }
+            b'M' => {
+                self.cur.pos += 1;
+
+                let class = self.type_()?;
+                let member = self.type_()?;
+
+                Type::MemberPointer(Box::new(class), Box::new(member))
+            }
+            b'T' => {
+                let param = self.template_param()?;
+
+                if self.cur.peek() == Some(b'I') {
+                    // template template parameter
+                    self.subs.push(param.clone());
+
+                    let args = self.template_args()?;
+                    let list = args_list(&args);
+
+                    Type::Name(format!("{}{}", param.to_string(), list))
+                } else {
+                    param
+                }
+            }
+            b'S' if self.cur.peek_at(1) != Some(b't') => {
+                let sub = self.substitution()?;
+
+                if self.cur.peek() == Some(b'I') {
+                    let args = self.template_args()?;
+                    let list = args_list(&args);
+
+                    Type::Name(format!("{}{}", sub.to_string(), list))
+                } else {
+                    // substitutions aren't added again
+                    self.cur.leave();
+                    return Some(sub);
+                }
+            }
+            _ => Type::Name(self.name()?.0),
+        };
+
+        self.subs.push(ret.clone());
+        self.cur.leave();
+        Some(ret)
+    }
+}
+
+// Rust legacy
+
+fn rust_legacy(sym: &str) -> Option<String> {
+    let mut cur = Cursor::new(sym);
+    let mut elements = Vec::new();
+
+    while !cur.eat(b'E') {
+        let len = cur.decimal()?;
+        let elem = ::std::str::from_utf8(cur.bytes(len)?).ok()?;
+
+        elements.push(elem);
+    }
+
+    if !cur.at_end() && cur.peek() != Some(b'.') {
+        return None;
+    }
+
+    // the last element is the hash `h0123456789abcdef`
+    match elements.pop() {
+        Some(hash) if hash.len() == 17 && hash.starts_with('h') && hash[1..].bytes().all(|b| (b as char).is_digit(16)) => {}
+        _ => return None,
+    }
+
+    if elements.is_empty() {
+        return None;
+    }
+
+    let mut ret = Vec::new();
+
+    for elem in elements {
+        let mut rest = if elem.starts_with("_$") { &elem[1..] } else { elem };
+        let mut out = String::new();
+
+        while !rest.is_empty() {
+            if rest.starts_with("..") {
+                out.push_str("::");
+                rest = &rest[2..];
+            } else if rest.starts_with('$') {
+                let end = rest[1..].find('$')? + 1;
+                let esc = &rest[1..end];
+                let c = match esc {
+                    "SP" => '@',
+                    "BP" => '*',
+                    "RF" => '&',
+                    "LT" => '<',
+                    "GT" => '>',
+                    "LP" => '(',
+                    "RP" => ')',
+                    "C" => ',',
+                    _ if esc.starts_with('u') => char::from_u32(u32::from_str_radix(&esc[1..], 16).ok()?)?,
+                    _ => return None,
+                };
+
+                out.push(c);
+                rest = &rest[end + 1..];
+            } else {
+                let first = rest.chars().next().map(|c| c.len_utf8()).unwrap_or(1);
+                let end = rest[first..].find(|c| c == '$' || c == '.').map(|i| i + first).unwrap_or(rest.len());
+
+                out.push_str(&rest[..end]);
+                rest = &rest[end..];
+            }
+        }
+        ret.push(out);
+    }
+
+    Some(ret.join("::"))
+}
+
+// Rust v0
+
+struct RustV0<'a> {
+    cur: Cursor<'a>,
+    /// Bytes written by back references so far.
+    expanded: usize,
+}
+
+fn rust_v0(sym: &str) -> Option<String> {
+    // optional encoding version
+    let sym = sym.trim_left_matches(|c: char| c >= '0' && c <= '9');
+    let mut p = RustV0 { cur: Cursor::new(sym), expanded: 0 };
+    let mut ret = String::new();
+
+    p.path(&mut ret, true)?;
+
+    // instantiating crate and vendor suffix are ignored
+    Some(ret)
+}
+
+impl<'a> RustV0<'a> {
+    fn base62(&mut self) -> Option<u64> {
+        if self.cur.eat(b'_') {
+            return Some(0);
+        }
+
+        let mut ret = 0u64;
+
+        loop {
+            let c = self.cur.next()?;
+            let digit = match c {
+                b'0'...b'9' => c - b'0',
+                b'a'...b'z' => c - b'a' + 10,
+                b'A'...b'Z' => c - b'A' + 36,
+                b'_' => break,
+                _ => return None,
+            };
+
+            ret = ret.checked_mul(62)?.checked_add(digit as u64)?;
+        }
+
+        ret.checked_add(1)
+    }
+
+    fn disambiguator(&mut self) -> Option<u64> {
+        if self.cur.eat(b's') { self.base62().and_then(|n| n.checked_add(1)) } else { Some(0) }
+    }
+
+    fn ident(&mut self) -> Option<String> {
+        let punycode = self.cur.eat(b'u');
+        let len = self.cur.decimal()?;
+
+        self.cur.eat(b'_');
+
+        let bytes = self.cur.bytes(len)?;
+        let s = ::std::str::from_utf8(bytes).ok()?;
+
+        if punycode { punycode_decode(s) } else { Some(s.to_string()) }
+    }
+
+    /// Parses the back reference at the cursor and runs `f` at its target. Fails once back
+    /// references wrote more than `MAX_BACKREF_OUTPUT` bytes to their outputs.
+    fn backref<F: FnOnce(&mut Self, &mut String) -> Option<()>>(&mut self, out: &mut String, f: F) -> Option<()> {
+        self.cur.expect(b'B')?;
+
+        let target = self.base62()? as usize;
+
+        if target >= self.cur.pos {
+            return None;
+        }
+
+        let saved = self.cur.pos;
+        let len = out.len();
+
+        self.cur.pos = target;
+        let ret = f(self, out);
+        self.cur.pos = saved;
+        self.expanded += out.len() - len;
+
+        if self.expanded > MAX_BACKREF_OUTPUT { None } else { ret }
+    }
+
+    fn path(&mut self, out: &mut String, in_value: bool) -> Option<()> {
+        self.cur.enter()?;
+
+        match self.cur.next()? {
+            b'C' => {
+                self.disambiguator()?;
+                out.push_str(&self.ident()?);
+            }
+            b'M' => {
+                self.disambiguator()?;
+
+                let mut impl_path = String::new();
+
+                self.path(&mut impl_path, false)?;
+                out.push('<');
+                self.type_(out)?;
+                out.push('>');
+            }
+            b'X' => {
+                self.disambiguator()?;
+
+                let mut impl_path = String::new();
+
+                self.path(&mut impl_path, false)?;
+                out.push('<');
+                self.type_(out)?;
+                out.push_str(" as ");
+                self.path(out, false)?;
+                out.push('>');
+            }
+            b'Y' => {
+                out.push('<');
+                self.type_(out)?;
+                out.push_str(" as ");
+                self.path(out, false)?;
+                out.push('>');
+            }
+            b'N' => {
+                let ns = self.cur.next()?;
+
+                self.path(out, in_value)?;
+
+                let dis = self.disambiguator()?;
+                let name = self.ident()?;
+
+                match ns {
+                    b'a'...b'z' => {
+                        out.push_str("::");
+                        out.push_str(&name);
+                    }
+                    b'A'...b'Z' => {
+                        let kind = match ns {
+                            b'C' => "closure".to_string(),
+                            b'S' => "shim".to_string(),
+                            _ => (ns as char).to_string(),
+                        };
+
+                        out.push_str("::{");
+                        out.push_str(&kind);
+                        if !name.is_empty() {
+                            out.push(':');
+                            out.push_str(&name);
+                        }
+                        out.push_str(&format!("#{}}}", dis));
+                    }
+                    _ => return None,
+                }
+            }
+            b'I' => {
+                self.path(out, in_value)?;
+                if in_value {
+                    out.push_str("::");
+                }
+                out.push('<');
+
+                let mut first = true;
+
+                while !self.cur.eat(b'E') {
+                    if !first {
+                        out.push_str(", ");
+                    }
+                    first = false;
+                    self.generic_arg(out)?;
+                }
+                out.push('>');
+            }
+            b'B' => {
+                self.cur.pos -= 1;
+                self.backref(out, |p, out| p.path(out, in_value))?;
+            }
+            _ => return None,
+        }
+
+        self.cur.leave();
+        Some(())
+    }
+
+    fn generic_arg(&mut self, out: &mut String) -> Option<()> {
+        if self.cur.eat(b'L') {
+            let lt = self.base62()?;
+            out.push_str(&lifetime_name(lt));
+            Some(())
+        } else if self.cur.eat(b'K') {
+            self.const_(out)
+        } else {
+            self.type_(out)
+        }
+    }
+
+    fn const_(&mut self, out: &mut String) -> Option<()> {
+        if self.cur.peek() == Some(b'B') {
+            return self.backref(out, |p, out| p.const_(out));
+        }
+        if self.cur.eat(b'p') {
+            out.push('_');
+            return Some(());
+        }
+
+        let ty = self.cur.next()?;
+        let neg = self.cur.eat(b'n');
+        let start = self.cur.pos;
+
+        while self.cur.peek()? != b'_' {
+            self.cur.pos += 1;
+        }
+
+        let hex = ::std::str::from_utf8(&self.cur.input[start..self.cur.pos]).ok()?;
+
+        self.cur.pos += 1;
+
+        let value = if hex.is_empty() { 0 } else { u64::from_str_radix(hex, 16).ok()? };
+
+        match ty {
+            b'b' => out.push_str(if value != 0 { "true" } else { "false" }),
+            b'c' => out.push_str(&format!("{:?}", char::from_u32(value as u32)?)),
+            _ => {
+                if neg {
+                    out.push('-');
+                }
+                out.push_str(&value.to_string());
+            }
+        }
+        Some(())
+    }
+
+    fn type_(&mut self, out: &mut String) -> Option<()> {
+        let basic = match self.cur.peek()? {
+            b'a' => Some("i8"),
+            b'b' => Some("bool"),
+            b'c' => Some("char"),
+            b'd' => Some("f64"),
+            b'e' => Some("str"),
+            b'f' => Some("f32"),
+            b'h' => Some("u8"),
+            b'i' => Some("isize"),
+            b'j' => Some("usize"),
+            b'l' => Some("i32"),
+            b'm' => Some("u32"),
+            b'n' => Some("i128"),
+            b'o' => Some("u128"),
+            b's' => Some("i16"),
+            b't' => Some("u16"),
+            b'u' => Some("()"),
+            b'v' => Some("..."),
+            b'x' => Some("i64"),
+            b'y' => Some("u64"),
+            b'z' => Some("!"),
+            b'p' => Some("_"),
+            _ => None,
+        };
+
+        if let Some(b) = basic {
+            self.cur.pos += 1;
+            out.push_str(b);
+            return Some(());
+        }
+
+        self.cur.enter()?;
+
+        match self.cur.peek()? {
+            b'C' | b'M' | b'X' | b'Y' | b'N' | b'I' => self.path(out, false)?,
+            b'B' => self.backref(out, |p, out| p.type_(out))?,
+            b'A' => {
+                self.cur.pos += 1;
+                out.push('[');
+                self.type_(out)?;
+                out.push_str("; ");
+                self.const_(out)?;
+                out.push(']');
+            }
+            b'S' => {
+                self.cur.pos += 1;
+                out.push('[');
+                self.type_(out)?;
+                out.push(']');
+            }
+            b'T' => {
+                self.cur.pos += 1;
+                out.push('(');
+
+                let mut n = 0;
+
+                while !self.cur.eat(b'E') {
+                    if n > 0 {
+                        out.push_str(", ");
+                    }
+                    self.type_(out)?;
+                    n += 1;
+                }
+                if n == 1 {
+                    out.push(',');
+                }
+                out.push(')');
+            }
+            b'R' | b'Q' => {
+                let c = self.cur.next()?;
+
+                out.push('&');
+                if self.cur.eat(b'L') {
+                    let lt = self.base62()?;
+
+                    if lt != 0 {
+                        out.push_str(&lifetime_name(lt));
+                        out.push(' ');
+                    }
+                }
+                if c == b'Q' {
+                    out.push_str("mut ");
+                }
+                self.type_(out)?;
+            }
+            b'P' => {
+                self.cur.pos += 1;
+                out.push_str("*const ");
+                self.type_(out)?;
+            }
+            b'O' => {
+                self.cur.pos += 1;
+                out.push_str("*mut ");
+                self.type_(out)?;
+            }
+            b'F' => {
+                self.cur.pos += 1;
+                if self.cur.eat(b'G') {
+                    self.base62()?;
+                }
+                if self.cur.eat(b'U') {
+                    out.push_str("unsafe ");
+                }
+                if self.cur.eat(b'K') {
+                    let abi = if self.cur.eat(b'C') {
+                        "C".to_string()
+                    } else {
+                        self.ident()?.replace('_', "-")
+                    };
+
+                    out.push_str(&format!("extern \"{}\" ", abi));
+                }
+                out.push_str("fn(");
+
+                let mut first = true;
+
+                while !self.cur.eat(b'E') {
+                    if !first {
+                        out.push_str(", ");
+                    }
+                    first = false;
+                    self.type_(out)?;
+                }
+                out.push(')');
+
+                let mut ret = String::new();
+
+                self.type_(&mut ret)?;
+                if ret != "()" {
+                    out.push_str(" -> ");
+                    out.push_str(&ret);
+                }
+            }
+            b'D' => {
+                self.cur.pos += 1;
+                if self.cur.eat(b'G') {
+                    self.base62()?;
+                }
+                out.push_str("dyn ");
+
+                let mut first = true;
+
+                while !self.cur.eat(b'E') {
+                    if !first {
+                        out.push_str(" + ");
+                    }
+                    first = false;
+
+                    let mut trait_path = String::new();
+
+                    self.path(&mut trait_path, false)?;
+
+                    let mut bindings = Vec::new();
+
+                    while self.cur.eat(b'p') {
+                        let name = self.ident()?;
+                        let mut ty = String::new();
+
+                        self.type_(&mut ty)?;
+                        bindings.push(format!("{} = {}", name, ty));
+                    }
+
+                    if !bindings.is_empty() {
+                        // merge with the generic arguments of the trait
+                        if trait_path.ends_with('>') {
+                            trait_path.pop();
+                            trait_path.push_str(", ");
+                        } else {
+                            trait_path.push('<');
+                        }
+                        trait_path.push_str(&bindings.join(", "));
+                        trait_path.push('>');
+                    }
+                    out.push_str(&trait_path);
+                }
+
+                // lifetime bound
+                self.cur.expect(b'L')?;
+                self.base62()?;
+            }
+            _ => return None,
+        }
+
+        self.cur.leave();
+        Some(())
+    }
+}
+
+fn lifetime_name(lt: u64) -> String {
+    if lt == 0 {
+        "'_".to_string()
+    } else if lt <= 26 {
+        format!("'{}", (b'a' + (lt - 1) as u8) as char)
+    } else {
+        format!("'_{}", lt - 1)
+    }
+}
+
+/// Decodes a Rust v0 Punycode identifier. Rust replaces the `-` delimiter with `_`.
+fn punycode_decode(s: &str) -> Option<String> {
+    let (basic, encoded) = match s.rfind('_') {
+        Some(idx) => (&s[..idx], &s[idx + 1..]),
+        None => ("", s),
+    };
+    let mut out: Vec<char> = basic.chars().collect();
+    let mut n = 128u32;
+    let mut i = 0u32;
+    let mut bias = 72u32;
+    let mut bytes = encoded.bytes().peekable();
+    let mut first = true;
+
+    while bytes.peek().is_some() {
+        let old_i = i;
+        let mut w = 1u32;
+        let mut k = 36u32;
+
+        loop {
+            let c = bytes.next()?;
+            let digit = match c {
+                b'a'...b'z' => (c - b'a') as u32,
+                b'0'...b'9' => (c - b'0') as u32 + 26,
+                _ => return None,
+            };
+
+            i = i.checked_add(digit.checked_mul(w)?)?;
+
+            let t = if k <= bias {
+                1
+            } else if k >= bias + 26 {
+                26
+            } else {
+                k - bias
+            };
+
+            if digit < t {
+                break;
+            }
+            w = w.checked_mul(36 - t)?;
+            k += 36;
+        }
+
+        let len = out.len() as u32 + 1;
+        let mut delta = if first { (i - old_i) / 700 } else { (i - old_i) / 2 };
+
+        first = false;
+
+        delta += delta / len;
+
+        let mut k = 0;
+
+        while delta > 455 {
+            delta /= 35;
+            k += 36;
+        }
+        bias = k + (36 * delta) / (delta + 38);
+
+        n = n.checked_add(i / len)?;
+        i %= len;
+        out.insert(i as usize, char::from_u32(n)?);
+        i += 1;
+    }
+
+    Some(out.into_iter().collect())
+}
+
+// Microsoft Visual C++
+
+struct Msvc<'a> {
+    cur: Cursor<'a>,
+    names: Vec<String>,
+    types: Vec<String>,
+}
+
+fn msvc(sym: &str) -> Option<String> {
+    let mut p = Msvc { cur: Cursor::new(sym), names: Vec::new(), types: Vec::new() };
+    let ret = p.symbol()?;
+
+    if p.cur.at_end() { Some(ret) } else { None }
+}
+
+impl<'a> Msvc<'a> {
+    fn symbol(&mut self) -> Option<String> {
+        let (name, special) = self.qualified_name()?;
+        let class = self.cur.next()?;
+
+        match class {
+            // global functions
+            b'Y' | b'Z' => self.function(&name, special, false),
+            // member functions with `this` qualifiers
+            b'A' | b'B' | b'E' | b'F' | b'I' | b'J' | b'M' | b'N' | b'Q' | b'R' | b'U' | b'V' => self.function(&name, special, true),
+            // static member functions
+            b'C' | b'D' | b'K' | b'L' | b'S' | b'T' => self.function(&name, special, false),
+            // variables
+            b'0'...b'4' => {
+                self.type_()?;
+                self.storage_class()?;
+                Some(name)
+            }
+            // virtual tables
+            b'6' | b'7' => {
+                self.storage_class()?;
+                while !self.cur.eat(b'@') {
+                    self.qualified_name()?;
+                }
+                Some(name)
+            }
+            _ => None,
+        }
+    }
+
+    fn storage_class(&mut self) -> Option<String> {
+        self.cur.eat(b'E');
+        let ret = match self.cur.next()? {
+            b'A' => "",
+            b'B' => " const",
+            b'C' => " volatile",
+            b'D' => " const volatile",
+            _ => return None,
+        };
+
+        Some(ret.to_string())
+    }
+
+    fn function(&mut self, name: &str, special: Option<&'static str>, this: bool) -> Option<String> {
+        let quals = if this { self.storage_class()? } else { String::new() };
+
+        // calling convention
+        self.cur.next()?;
+
+        let ret = if self.cur.eat(b'@') {
+            None
+        } else {
+            if self.cur.eat(b'?') {
+                // storage class of class return types
+                self.cur.next()?;
+            }
+            Some(self.type_()?)
+        };
+
+        let params = self.params()?;
+
+        // throw specification
+        if !self.cur.eat(b'Z') {
+            self.params()?;
+        }
+
+        let name = match (special, ret.as_ref()) {
+            (Some("operator "), Some(ret)) => format!("{}{}", name, ret),
+            _ => name.to_string(),
+        };
+
+        match (special, ret) {
+            (Some("operator "), _) | (_, None) => Some(format!("{}({}){}", name, params, quals)),
+            (_, Some(ret)) => Some(format!("{} {}({}){}", ret, name, params, quals)),
+        }
+    }
+
+    fn params(&mut self) -> Option<String> {
+        if self.cur.eat(b'X') {
+            return Some(String::new());
+        }
+
+        let mut ret = Vec::new();
+
+        loop {
+            match self.cur.peek()? {
+                b'@' => {
+                    self.cur.pos += 1;
+                    break;
+                }
+                b'Z' => {
+                    self.cur.pos += 1;
+                    ret.push("...".to_string());
+                    break;
+                }
+                c @ b'0'...b'9' => {
+                    self.cur.pos += 1;
+                    ret.push(self.types.get((c - b'0') as usize)?.clone());
+                }
+                _ => {
+                    let start = self.cur.pos;
+                    let ty = self.type_()?;
+
+                    // only types longer than one character are remembered
+                    if self.cur.pos - start > 1 && self.types.len() < 10 {
+                        self.types.push(ty.clone());
+                    }
+                    ret.push(ty);
+                }
+            }
+        }
+
+        Some(ret.join(", "))
+    }
+
+    /// Parses a name fragment terminated by `@` or a back reference.
+    fn fragment(&mut self) -> Option<String> {
+        self.cur.enter()?;
+
+        let c = self.cur.peek()?;
+        let ret = if c >= b'0' && c <= b'9' {
+            self.cur.pos += 1;
+            self.names.get((c - b'0') as usize).cloned()?
+        } else if self.cur.eat_str("?$") {
+            let saved = ::std::mem::replace(&mut self.names, Vec::new());
+            let name = self.simple_name()?;
+
+            self.names.push(name.clone());
+
+            let saved_types = ::std::mem::replace(&mut self.types, Vec::new());
+            let args = self.template_args()?;
+
+            self.names = saved;
+            self.types = saved_types;
+
+            let ret = format!("{}{}", name, template_list(&args));
+
+            self.remember(&ret);
+            ret
+        } else if self.cur.eat_str("?A") {
+            // anonymous namespace `?A0x1234abcd@`
+            while !self.cur.eat(b'@') {
+                self.cur.next()?;
+            }
+            "`anonymous namespace'".to_string()
+        } else {
+            let ret = self.simple_name()?;
+
+            self.remember(&ret);
+            ret
+        };
+
+        self.cur.leave();
+        Some(ret)
+    }
+
+    fn remember(&mut self, name: &str) {
+        if self.names.len() < 10 && !self.names.iter().any(|n| n == name) {
+            self.names.push(name.to_string());
+        }
+    }
+
+    fn simple_name(&mut self) -> Option<String> {
+        let start = self.cur.pos;
+
+        while self.cur.peek()? != b'@' {
+            self.cur.pos += 1;
+        }
+
+        let ret = String::from_utf8_lossy(&self.cur.input[start..self.cur.pos]).to_string();
+
+        self.cur.pos += 1;
+        if ret.is_empty() { None } else { Some(ret) }
+    }
+
+    fn template_args(&mut self) -> Option<Vec<String>> {
+        let mut ret = Vec::new();
+
+        while !self.cur.eat(b'@') {
+            if self.cur.eat_str("$0") {
+                let neg = self.cur.eat(b'?');
+                let n = self.number()?;
+
+                ret.push(if neg { format!("-{}", n) } else { n.to_string() });
+            } else {
+                ret.push(self.type_()?);
+            }
+        }
+
+        Some(ret)
+    }
+
+    /// Encoded integer: `0`-`9` are 1-10, otherwise hex digits `A`-`P` terminated by `@`.
+    fn number(&mut self) -> Option<u64> {
+        let c = self.cur.next()?;
+
+        if c >= b'0' && c <= b'9' {
+            return Some((c - b'0') as u64 + 1);
+        }
+
+        let mut ret = 0u64;
+        let mut c = c;
+
+        while c != b'@' {
+            if c < b'A' || c > b'P' {
+                return None;
+            }
+            ret = ret.checked_mul(16)?.checked_add((c - b'A') as u64)?;
+            c = self.cur.next()?;
+        }
+        Some(ret)
+    }
+
+    /// Returns the name in source order and the special name kind, if any.
+    fn qualified_name(&mut self) -> Option<(String, Option<&'static str>)> {
+        let mut special = None;
+        let mut first = if self.cur.peek() == Some(b'?') && self.cur.peek_at(1) != Some(b'$') && self.cur.peek_at(1) != Some(b'A') {
+            self.cur.pos += 1;
+
+            let (op, kind) = self.operator()?;
+
+            special = Some(kind);
+            Some(op)
+        } else {
+            None
+        };
+        let mut parts = Vec::new();
+
+        if first.is_none() {
+            first = Some(self.fragment()?);
+        }
+        while !self.cur.eat(b'@') {
+            parts.push(self.fragment()?);
+        }
+
+        let first = first.unwrap();
+        let first = match special {
+            Some("ctor") => parts.first().map(|p| strip_template(p).to_string()).unwrap_or(first),
+            Some("dtor") => format!("~{}", parts.first().map(|p| strip_template(p).to_string()).unwrap_or(first)),
+            _ => first,
+        };
+
+        parts.reverse();
+        parts.push(first);
+        Some((parts.join("::"), special))
+    }
+
+    fn operator(&mut self) -> Option<(String, &'static str)> {
+        let c = self.cur.next()?;
+        let op = if c == b'_' {
+            match self.cur.next()? {
+                b'0' => "/=",
+                b'1' => "%=",
+                b'2' => ">>=",
+                b'3' => "<<=",
+                b'4' => "&=",
+                b'5' => "|=",
+                b'6' => "^=",
+                b'7' => return Some(("`vftable'".to_string(), "table")),
+                b'8' => return Some(("`vbtable'".to_string(), "table")),
+                b'E' => return Some(("`vector deleting destructor'".to_string(), "function")),
+                b'G' => return Some(("`scalar deleting destructor'".to_string(), "function")),
+                b'U' => " new[]",
+                b'V' => " delete[]",
+                _ => return None,
+            }
+        } else {
+            match c {
+                b'0' => return Some((String::new(), "ctor")),
+                b'1' => return Some((String::new(), "dtor")),
+                b'2' => " new",
+                b'3' => " delete",
+                b'4' => "=",
+                b'5' => ">>",
+                b'6' => "<<",
+                b'7' => "!",
+                b'8' => "==",
+                b'9' => "!=",
+                b'A' => "[]",
+                b'B' => return Some(("operator ".to_string(), "operator ")),
+                b'C' => "->",
+                b'D' => "*",
+                b'E' => "++",
+                b'F' => "--",
+                b'G' => "-",
+                b'H' => "+",
+                b'I' => "&",
+                b'J' => "->*",
+                b'K' => "/",
+                b'L' => "%",
+                b'M' => "<",
+                b'N' => "<=",
+                b'O' => ">",
+                b'P' => ">=",
+                b'Q' => ",",
+                b'R' => "()",
+                b'S' => "~",
+                b'T' => "^",
+                b'U' => "|",
+                b'V' => "&&",
+                b'W' => "||",
+                b'X' => "*=",
+                b'Y' => "+=",
+                b'Z' => "-=",
+                _ => return None,
+            }
+        };
+
+        Some((format!("operator{}", op), "function"))
+    }
+
+    fn pointee_qualifiers(&mut self) -> Option<&'static str> {
+        // __ptr64 and friends
+        while self.cur.eat(b'E') || self.cur.eat(b'F') || self.cur.eat(b'I') {}
+
+        Some(
+            match self.cur.next()? {
+                b'A' => "",
+                b'B' => " const",
+                b'C' => " volatile",
+                b'D' => " const volatile",
+                _ => return None,
+            }
+        )
+    }
+
+    fn type_(&mut self) -> Option<String> {
+        self.cur.enter()?;
+
+        let c = self.cur.next()?;
+        let ret = match c {
+            b'C' => "signed char".to_string(),
+            b'D' => "char".to_string(),
+            b'E' => "unsigned char".to_string(),
+            b'F' => "short".to_string(),
+            b'G' => "unsigned short".to_string(),
+            b'H' => "int".to_string(),
+            b'I' => "unsigned int".to_string(),
+            b'J' => "long".to_string(),
+            b'K' => "unsigned long".to_string(),
+            b'M' => "float".to_string(),
+            b'N' => "double".to_string(),
+            b'O' => "long double".to_string(),
+            b'X' => "void".to_string(),
+            b'_' => {
+                match self.cur.next()? {
+                    b'D' => "__int8".to_string(),
+                    b'E' => "unsigned __int8".to_string(),
+                    b'F' => "__int16".to_string(),
+                    b'G' => "unsigned __int16".to_string(),
+                    b'H' => "__int32".to_string(),
+                    b'I' => "unsigned __int32".to_string(),
+                    b'J' => "__int64".to_string(),
+                    b'K' => "unsigned __int64".to_string(),
+                    b'N' => "bool".to_string(),
+                    b'Q' => "char8_t".to_string(),
+                    b'S' => "char16_t".to_string(),
+                    b'U' => "char32_t".to_string(),
+                    b'W' => "wchar_t".to_string(),
+                    _ => return None,
+                }
+            }
+            b'T' | b'U' | b'V' => {
+                let (name, _) = self.qualified_name()?;
+                name
+            }
+            b'W' => {
+                // enum with underlying type
+                self.cur.next()?;
+                let (name, _) = self.qualified_name()?;
+                name
+            }
+            b'P' | b'Q' | b'R' | b'S' | b'A' | b'B' => {
+                let op = if c == b'A' || c == b'B' { "&" } else { "*" };
+                let ptr_quals = match c {
+                    b'Q' => " const",
+                    b'R' => " volatile",
+                    b'S' => " const volatile",
+                    _ => "",
+                };
+
+                if self.cur.eat(b'6') {
+                    // function pointer
+                    self.cur.next()?;
+
+                    let ret = self.type_()?;
+                    let params = self.params()?;
+
+                    if !self.cur.eat(b'Z') {
+                        self.params()?;
+                    }
+                    format!("{} ({}{})({})", ret, op, ptr_quals, params)
+                } else {
+                    let quals = self.pointee_qualifiers()?;
+                    let inner = self.type_()?;
+
+                    format!("{}{}{}{}", inner, quals, op, ptr_quals)
+                }
+            }
+            b'$' => {
+                if self.cur.eat_str("$Q") {
+                    let quals = self.pointee_qualifiers()?;
+                    format!("{}{}&&", self.type_()?, quals)
+                } else if self.cur.eat_str("$T") {
+                    "std::nullptr_t".to_string()
+                } else {
+                    return None;
+                }
+            }
+            _ => return None,
+        };
+
+        self.cur.leave();
+        Some(ret)
+    }
+}
+
+fn strip_template(name: &str) -> &str {
+    match name.find('<') {
+        Some(idx) => &name[..idx],
+        None => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn itanium_names() {
+        let cases = [
+            ("_ZN3foo3barEv", "foo::bar()"),
+            ("_Z3addii", "add(int, int)"),
+            ("_ZNK3Foo3getEv", "Foo::get() const"),
+            ("_ZN3FooC2Ei", "Foo::Foo(int)"),
+            ("_ZN3FooD1Ev", "Foo::~Foo()"),
+            ("_ZNSt6vectorIiSaIiEE9push_backERKi", "std::vector<int, std::allocator<int> >::push_back(int const&)"),
+            ("_Z3maxIiET_S0_S0_", "int max<int>(int, int)"),
+            ("_Z1fPFviEPA3_c", "f(void (*)(int), char (*) [3])"),
+            ("_Z1fM3FooFviE", "f(void (Foo::*)(int))"),
+            ("_ZN12_GLOBAL__N_13bazEv", "(anonymous namespace)::baz()"),
+            ("_ZplRK3VecS1_", "operator+(Vec const&, Vec const&)"),
+            ("_ZN3FoocviEv", "Foo::operator int()"),
+            ("_ZZ4mainE5count", "main::count"),
+            ("_ZTV3Foo", "vtable for Foo"),
+            ("_ZThn8_N3Foo3barEv", "non-virtual thunk to Foo::bar()"),
+            ("_Z3fooPKcz", "foo(char const*, ...)"),
+            ("_ZN3foo3barEv.isra.0.cold", "foo::bar() [clone .isra.0] [clone .cold]"),
+            ("_Z1fILi3ELb1EEvv", "void f<3, true>()"),
+            ("_ZNSt7__cxx1112basic_stringIcSt11char_traitsIcESaIcEED1Ev", "std::__cxx11::basic_string<char, std::char_traits<char>, std::allocator<char> >::~basic_string()"),
+            ("_Z4funcB5cxx11v", "func[abi:cxx11]()"),
+            ("_ZNSt6vectorIiSaIiEE12emplace_backIJiEEERiDpOT_", "int& std::vector<int, std::allocator<int> >::emplace_back<int>(int&&)"),
+            ("_Z1fIJidEEvDpT_", "void f<int, double>(int, double)"),
+            ("_Z1fIJEEvDpT_", "void f<>()"),
+            ("_Z1fIRiEvOT_", "void f<int&>(int&)"),
+        ];
+
+        for &(mangled, demangled) in cases.iter() {
+            assert_eq!(demangle(mangled), Some(demangled.to_string()), "{}", mangled);
+        }
+
+        assert_eq!(demangle("main"), None);
+        assert_eq!(demangle("_Z"), None);
+        assert_eq!(demangle("_ZN3foo"), None);
+        assert_eq!(demangle("__ZN3foo3barEv"), Some("foo::bar()".to_string()));
+    }
+
+    #[test]
+    fn rust_names() {
+        assert_eq!(demangle("_ZN4core3fmt5write17h0123456789abcdefE"), Some("core::fmt::write".to_string()));
+        assert_eq!(
+            demangle("_ZN49_$LT$alloc..vec..Vec$LT$T$GT$$u20$as$u20$Drop$GT$4drop17h0123456789abcdefE"),
+            Some("<alloc::vec::Vec<T> as Drop>::drop".to_string())
+        );
+        assert_eq!(demangle("_RNvCs1234_7mycrate3foo"), Some("mycrate::foo".to_string()));
+        assert_eq!(demangle("_RNvNtCs1234_7mycrate4util3bar"), Some("mycrate::util::bar".to_string()));
+        assert_eq!(demangle("_RINvCs1234_7mycrate3fooxEB2_"), Some("mycrate::foo::<i64>".to_string()));
+        assert_eq!(demangle("_RNCNvCs1234_7mycrate4main0"), Some("mycrate::main::{closure#0}".to_string()));
+        assert_eq!(
+            demangle("_RNvXs_Cs1234_7mycrateNtB4_3FooNtNtCs5678_4core5clone5Clone5clone"),
+            Some("<mycrate::Foo as core::clone::Clone>::clone".to_string())
+        );
+        assert_eq!(demangle("_RNvMCs1234_7mycrateINtB2_3BarRShE3new"), Some("<mycrate::Bar<&[u8]>>::new".to_string()));
+        assert_eq!(demangle("_RNvCs1234_7mycrateu8gdel_5qa"), Some("mycrate::gödel".to_string()));
+    }
+
+    #[test]
+    fn rust_backref_limit() {
+        // each tuple contains the previous one twice
+        let tuples = |n: usize| {
+            let base62 = |mut n: usize| {
+                let digits = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+                let mut ret = Vec::new();
+
+                loop {
+                    ret.insert(0, digits[n % 62]);
+                    n /= 62;
+                    if n == 0 {
+                        break;
+                    }
+                }
+                String::from_utf8(ret).unwrap()
+            };
+            let mut sym = "_RINvCs1234_7mycrate3fooTuuE".to_string();
+
+            for _ in 0..n {
+                let prev = sym.rfind('T').unwrap() - 2;
+                sym.push_str(&format!("TB{}_B{}_E", base62(prev - 1), base62(prev - 1)));
+            }
+            sym.push('E');
+            sym
+        };
+
+        assert_eq!(demangle(&tuples(1)), Some("mycrate::foo::<((), ()), (((), ()), ((), ()))>".to_string()));
+        assert_eq!(demangle(&tuples(64)), None);
+    }
+
+    #[test]
+    fn msvc_names() {
+        let cases = [
+            ("?bar@foo@@QEAAXH@Z", "void foo::bar(int)"),
+            ("?get@Foo@@QBEHXZ", "int Foo::get() const"),
+            ("??0Foo@@QAE@XZ", "Foo::Foo()"),
+            ("??1Foo@@UAE@XZ", "Foo::~Foo()"),
+            ("?x@@3HA", "x"),
+            ("?f@@YAXPBDPAH@Z", "void f(char const*, int*)"),
+            ("?f@@YAXPAUPoint@@0@Z", "void f(Point*, Point*)"),
+            ("??$max@H@@YAHHH@Z", "int max<int>(int, int)"),
+            ("??_7Foo@@6B@", "Foo::`vftable'"),
+            ("??HVec@@QAE?AV0@ABV0@@Z", "Vec Vec::operator+(Vec const&)"),
+            ("?cb@@YAXP6AHH@Z@Z", "void cb(int (*)(int))"),
+        ];
+
+        for &(mangled, demangled) in cases.iter() {
+            assert_eq!(demangle(mangled), Some(demangled.to_string()), "{}", mangled);
+        }
+    }
+
+    #[test]
+    fn matching() {
+        assert!(matches("_ZN3foo3barEi", "_ZN3foo3barEi"));
+        assert!(matches("_ZN3foo3barEi", "foo::bar(int)"));
+        assert!(matches("_ZN3foo3barEi", "foo::bar"));
+        assert!(matches("_Z3maxIiET_S0_S0_", "max<int>"));
+        assert!(matches("KERNEL32.dll!?f@@YAXH@Z", "KERNEL32.dll!void f(int)"));
+        assert!(!matches("_ZN3foo3barEi", "bar"));
+        assert!(!matches("main", "foo"));
+        assert_eq!(display_name("main"), "main");
+        assert_eq!(display_name("_ZN3foo3barEv"), "foo::bar()");
+    }
+}
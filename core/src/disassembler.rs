@@ -86,7 +86,7 @@
 #![macro_use]
 
 
-use {Guard, Mnemonic, Region, Result, Rvalue, Statement};
+use {Guard, Machine, Mnemonic, Region, Result, Rvalue, Statement};
 
 use num::traits::{NumCast, One, Zero};
 use panopticon_graph_algos::{AdjacencyList, EdgeListGraphTrait, GraphTrait, IncidenceGraphTrait, MutableGraphTrait, VertexListGraphTrait};
@@ -115,6 +115,12 @@ pub trait Architecture: Clone {
 
     /// Start to disassemble a single Opcode inside a given region at a given address.
     fn decode(&Region, u64, &Self::Configuration) -> Result<Match<Self>>;
+
+    /// The machine executing code in configuration `cfg`. Used by loaders for files without a
+    /// header to take it from. `None` if no `Machine` describes the architecture.
+    fn machine(_cfg: &Self::Configuration) -> Option<Machine> {
+        None
+    }
 }
 
 /// Result of a single disassembly operation.
@@ -0,0 +1,812 @@
+/*
+ * Panopticon - A libre disassembler
+ * Copyright (C) 2017  Panopticon authors
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! DWARF debug information.
+//!
+//! Reads function, variable, type and line number information from the `.debug_info`,
+//! `.debug_abbrev`, `.debug_str` and `.debug_line` sections of a binary. DWARF versions 2 to 4 are
+//! supported, units of other versions are skipped.
+//!
+//! The result is stored in the `Project` as `DebugInfo`. Loaders use the function information to
+//! seed and name `Function`s, the line table maps each address (and thereby each `Mnemonic`) to its
+//! source file and line.
+
+use {Bound, Result};
+use reader::Reader;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// A position in a source file.
+#[derive(Clone,Debug,PartialEq,Eq,Serialize,Deserialize)]
+pub struct SourceLocation {
+    /// Path of the source file.
+    pub file: String,
+    /// Line number, starting at 1. Zero if unknown.
+    pub line: u64,
+    /// Column number, starting at 1. Zero if unknown.
+    pub column: u64,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.column > 0 {
+            write!(f, "{}:{}:{}", self.file, self.line, self.column)
+        } else {
+            write!(f, "{}:{}", self.file, self.line)
+        }
+    }
+}
+
+/// A global variable or a function parameter.
+#[derive(Clone,Debug,PartialEq,Eq,Serialize,Deserialize)]
+pub struct DebugVariable {
+    /// Name as written in the source.
+    pub name: String,
+    /// Human-readable name of the variable's type.
+    pub type_name: Option<String>,
+    /// Address of global variables with static storage.
+    pub address: Option<u64>,
+    /// Where the variable is declared.
+    pub declaration: Option<SourceLocation>,
+}
+
+/// A function with code in the binary.
+#[derive(Clone,Debug,PartialEq,Eq,Serialize,Deserialize)]
+pub struct DebugFunction {
+    /// Name as written in the source.
+    pub name: String,
+    /// Symbol name, e.g. the mangled name of C++ functions.
+    pub linkage_name: Option<String>,
+    /// Address range of the function's code.
+    pub bound: Bound,
+    /// Formal parameters in declaration order.
+    pub parameters: Vec<DebugVariable>,
+    /// Human-readable name of the return type. `None` for `void` functions.
+    pub return_type: Option<String>,
+    /// Where the function is declared.
+    pub declaration: Option<SourceLocation>,
+}
+
+impl DebugFunction {
+    /// Name of the function's symbol, its source name if it has no linkage name.
+    pub fn symbol_name(&self) -> &str {
+        self.linkage_name.as_ref().unwrap_or(&self.name)
+    }
+}
+
+/// A named type.
+#[derive(Clone,Debug,PartialEq,Eq,Serialize,Deserialize)]
+pub struct DebugType {
+    /// Name as written in the source.
+    pub name: String,
+    /// Size in bytes, if known.
+    pub size: Option<u64>,
+}
+
+/// Debug information of a binary.
+#[derive(Clone,Debug,Default,PartialEq,Eq,Serialize,Deserialize)]
+pub struct DebugInfo {
+    /// Functions with code.
+    pub functions: Vec<DebugFunction>,
+    /// Global variables.
+    pub variables: Vec<DebugVariable>,
+    /// Named types.
+    pub types: Vec<DebugType>,
+    /// Line table. Each address maps to the source location of the code starting there, up to the
+    /// next entry. `None` marks the end of a sequence of instructions.
+    pub lines: BTreeMap<u64, Option<SourceLocation>>,
+}
+
+/// Raw contents of the DWARF sections.
+#[derive(Clone,Copy,Debug,Default)]
+pub struct DwarfSections<'a> {
+    /// `.debug_info`
+    pub debug_info: &'a [u8],
+    /// `.debug_abbrev`
+    pub debug_abbrev: &'a [u8],
+    /// `.debug_str`
+    pub debug_str: &'a [u8],
+    /// `.debug_line`
+    pub debug_line: &'a [u8],
+}
+
+impl DebugInfo {
+    /// Parses little endian DWARF `sections`. All addresses are moved by `bias`.
+    pub fn parse(sections: &DwarfSections, bias: u64) -> Result<DebugInfo> {
+        let mut ret = DebugInfo::default();
+        let mut offset = 0;
+        let mut types = HashMap::<String, Option<u64>>::new();
+
+        while offset < sections.debug_info.len() {
+            let unit = Unit::parse(sections, offset)?;
+
+            offset = unit.end;
+
+            if unit.version < 2 || unit.version > 4 {
+                debug!("Skipping DWARF {} unit at {:#x}", unit.version, unit.offset);
+                continue;
+            }
+
+            unit.import(sections, bias, &mut ret, &mut types)?;
+        }
+
+        ret.functions.sort_by_key(|f| f.bound.start);
+
+        let mut types = types.into_iter().map(|(name, size)| DebugType { name: name, size: size }).collect::<Vec<_>>();
+        types.sort_by(|a, b| a.name.cmp(&b.name));
+        ret.types = types;
+
+        Ok(ret)
+    }
+
+    /// Returns true if no debug information was found.
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty() && self.variables.is_empty() && self.types.is_empty() && self.lines.is_empty()
+    }
+
+    /// Returns the source location of the code at `addr`.
+    pub fn source_line(&self, addr: u64) -> Option<&SourceLocation> {
+        self.lines.range(..addr.saturating_add(1)).next_back().and_then(|(_, loc)| loc.as_ref())
+    }
+
+    /// Returns the function whose code contains `addr`.
+    pub fn function(&self, addr: u64) -> Option<&DebugFunction> {
+        self.functions.iter().find(|f| f.bound.start <= addr && addr < f.bound.end)
+    }
+}
+
+const DW_TAG_ARRAY_TYPE: u64 = 0x01;
+const DW_TAG_CLASS_TYPE: u64 = 0x02;
+const DW_TAG_ENUMERATION_TYPE: u64 = 0x04;
+const DW_TAG_FORMAL_PARAMETER: u64 = 0x05;
+const DW_TAG_POINTER_TYPE: u64 = 0x0f;
+const DW_TAG_REFERENCE_TYPE: u64 = 0x10;
+const DW_TAG_COMPILE_UNIT: u64 = 0x11;
+const DW_TAG_STRUCTURE_TYPE: u64 = 0x13;
+const DW_TAG_SUBROUTINE_TYPE: u64 = 0x15;
+const DW_TAG_TYPEDEF: u64 = 0x16;
+const DW_TAG_UNION_TYPE: u64 = 0x17;
+const DW_TAG_BASE_TYPE: u64 = 0x24;
+const DW_TAG_CONST_TYPE: u64 = 0x26;
+const DW_TAG_SUBPROGRAM: u64 = 0x2e;
+const DW_TAG_VARIABLE: u64 = 0x34;
+const DW_TAG_VOLATILE_TYPE: u64 = 0x35;
+const DW_TAG_RESTRICT_TYPE: u64 = 0x37;
+
+const DW_AT_LOCATION: u64 = 0x02;
+const DW_AT_NAME: u64 = 0x03;
+const DW_AT_BYTE_SIZE: u64 = 0x0b;
+const DW_AT_STMT_LIST: u64 = 0x10;
+const DW_AT_LOW_PC: u64 = 0x11;
+const DW_AT_HIGH_PC: u64 = 0x12;
+const DW_AT_COMP_DIR: u64 = 0x1b;
+const DW_AT_ABSTRACT_ORIGIN: u64 = 0x31;
+const DW_AT_DECL_FILE: u64 = 0x3a;
+const DW_AT_DECL_LINE: u64 = 0x3b;
+const DW_AT_SPECIFICATION: u64 = 0x47;
+const DW_AT_TYPE: u64 = 0x49;
+const DW_AT_LINKAGE_NAME: u64 = 0x6e;
+const DW_AT_MIPS_LINKAGE_NAME: u64 = 0x2007;
+
+const DW_OP_ADDR: u8 = 0x03;
+
+/// Value of a DIE attribute.
+#[derive(Clone,Debug,PartialEq)]
+enum Value {
+    Address(u64),
+    Unsigned(u64),
+    Signed(i64),
+    String(String),
+    /// Offset of a DIE in `.debug_info`.
+    Reference(usize),
+    Block(Vec<u8>),
+    Flag(bool),
+}
+
+impl Value {
+    fn unsigned(&self) -> Option<u64> {
+        match self {
+            &Value::Address(a) | &Value::Unsigned(a) => Some(a),
+            &Value::Signed(a) if a >= 0 => Some(a as u64),
+            _ => None,
+        }
+    }
+}
+
+/// Debugging information entry.
+struct Die {
+    offset: usize,
+    tag: u64,
+    depth: usize,
+    attrs: Vec<(u64, Value)>,
+}
+
+impl Die {
+    fn attr(&self, at: u64) -> Option<&Value> {
+        self.attrs.iter().find(|&&(a, _)| a == at).map(|&(_, ref v)| v)
+    }
+
+    fn string(&self, at: u64) -> Option<String> {
+        match self.attr(at) {
+            Some(&Value::String(ref s)) => Some(s.clone()),
+            _ => None,
+        }
+    }
+
+    fn unsigned(&self, at: u64) -> Option<u64> {
+        self.attr(at).and_then(|v| v.unsigned())
+    }
+
+    fn reference(&self, at: u64) -> Option<usize> {
+        match self.attr(at) {
+            Some(&Value::Reference(r)) => Some(r),
+            _ => None,
+        }
+    }
+}
+
+/// A compilation unit inside `.debug_info`.
+struct Unit {
+    offset: usize,
+    end: usize,
+    version: u16,
+    is_64: bool,
+    address_size: usize,
+    dies: Vec<Die>,
+}
+
+impl Unit {
+    /// Parses the unit header at `offset` and, for supported versions, all its DIEs.
+    fn parse(sections: &DwarfSections, offset: usize) -> Result<Unit> {
+        let mut rd = Reader::new(sections.debug_info, offset);
+        let (len, is_64) = rd.initial_length()?;
+        let end = rd.pos.checked_add(len).ok_or("DWARF unit length overflows")?;
+        let version = rd.u16()?;
+        let mut unit = Unit {
+            offset: offset,
+            end: end,
+            version: version,
+            is_64: is_64,
+            address_size: 0,
+            dies: Vec::new(),
+        };
+
+        if end > sections.debug_info.len() {
+            return Err(format!("DWARF unit at {:#x} exceeds .debug_info", offset).into());
+        }
+        if version < 2 || version > 4 {
+            return Ok(unit);
+        }
+
+        let abbrev_offset = rd.uint(if is_64 { 8 } else { 4 })? as usize;
+        unit.address_size = rd.u8()? as usize;
+
+        let abbrevs = parse_abbrevs(sections.debug_abbrev, abbrev_offset)?;
+        let mut depth = 0usize;
+
+        rd.buf = &sections.debug_info[..end];
+
+        while rd.pos < end {
+            let die_offset = rd.pos;
+            let code = rd.uleb()?;
+
+            if code == 0 {
+                depth = depth.saturating_sub(1);
+                continue;
+            }
+
+            let abbrev = match abbrevs.get(&code) {
+                Some(a) => a,
+                None => return Err(format!("Unknown DWARF abbreviation {} at {:#x}", code, die_offset).into()),
+            };
+            let mut attrs = Vec::with_capacity(abbrev.attrs.len());
+
+            let mut valid = true;
+
+            for &(at, form) in abbrev.attrs.iter() {
+                match unit.value(&mut rd, form, sections)? {
+                    Some(value) => attrs.push((at, value)),
+                    None => valid = false,
+                }
+            }
+
+            if valid {
+                unit.dies.push(Die { offset: die_offset, tag: abbrev.tag, depth: depth, attrs: attrs });
+            } else {
+                debug!("Ignoring DWARF DIE at {:#x}, it references a DIE outside of the address space", die_offset);
+            }
+
+            if abbrev.children {
+                depth += 1;
+            }
+        }
+
+        Ok(unit)
+    }
+
+    /// Reads an attribute value encoded as `form`. Returns `None` for references that overflow.
+    fn value(&self, rd: &mut Reader, form: u64, sections: &DwarfSections) -> Result<Option<Value>> {
+        let offset_size = if self.is_64 { 8 } else { 4 };
+
+        Ok(
+            Some(match form {
+                0x01 => Value::Address(rd.uint(self.address_size)?),
+                0x03 => {
+                    let len = rd.u16()? as usize;
+                    Value::Block(rd.bytes(len)?.to_vec())
+                }
+                0x04 => {
+                    let len = rd.uint(4)? as usize;
+                    Value::Block(rd.bytes(len)?.to_vec())
+                }
+                0x05 => Value::Unsigned(rd.uint(2)?),
+                0x06 => Value::Unsigned(rd.uint(4)?),
+                0x07 => Value::Unsigned(rd.uint(8)?),
+                0x08 => Value::String(rd.cstr()?),
+                0x09 | 0x18 => {
+                    let len = rd.uleb()? as usize;
+                    Value::Block(rd.bytes(len)?.to_vec())
+                }
+                0x0a => {
+                    let len = rd.u8()? as usize;
+                    Value::Block(rd.bytes(len)?.to_vec())
+                }
+                0x0b => Value::Unsigned(rd.uint(1)?),
+                0x0c => Value::Flag(rd.u8()? != 0),
+                0x0d => Value::Signed(rd.sleb()?),
+                0x0e => {
+                    let off = rd.uint(offset_size)? as usize;
+                    Value::String(Reader::new(sections.debug_str, off).cstr()?)
+                }
+                0x0f => Value::Unsigned(rd.uleb()?),
+                0x10 => {
+                    let size = if self.version == 2 { self.address_size } else { offset_size };
+                    Value::Reference(rd.uint(size)? as usize)
+                }
+                0x11 => return Ok(self.reference(rd.uint(1)?)),
+                0x12 => return Ok(self.reference(rd.uint(2)?)),
+                0x13 => return Ok(self.reference(rd.uint(4)?)),
+                0x14 => return Ok(self.reference(rd.uint(8)?)),
+                0x15 => return Ok(self.reference(rd.uleb()?)),
+                0x16 => {
+                    let form = rd.uleb()?;
+                    return self.value(rd, form, sections);
+                }
+                0x17 => Value::Unsigned(rd.uint(offset_size)?),
+                0x19 => Value::Flag(true),
+                0x20 => Value::Unsigned(rd.uint(8)?),
+                form => return Err(format!("Unsupported DWARF form {:#x}", form).into()),
+            })
+        )
+    }
+
+    /// Reference to the DIE at `off`, relative to the start of this unit. `None` if it overflows.
+    fn reference(&self, off: u64) -> Option<Value> {
+        if off > usize::max_value() as u64 {
+            return None;
+        }
+        self.offset.checked_add(off as usize).map(Value::Reference)
+    }
+
+    /// Adds the functions, global variables, types and line table of this unit to `info`.
+    fn import(&self, sections: &DwarfSections, bias: u64, info: &mut DebugInfo, types: &mut HashMap<String, Option<u64>>) -> Result<()> {
+        let index = self.dies.iter().enumerate().map(|(i, d)| (d.offset, i)).collect::<HashMap<_, _>>();
+        let cu = match self.dies.first() {
+            Some(cu) if cu.tag == DW_TAG_COMPILE_UNIT => cu,
+            _ => return Ok(()),
+        };
+        let comp_dir = cu.string(DW_AT_COMP_DIR).unwrap_or_default();
+        let files = match cu.unsigned(DW_AT_STMT_LIST) {
+            Some(off) => parse_line_program(sections.debug_line, off as usize, &comp_dir, bias, &mut info.lines)?,
+            None => Vec::new(),
+        };
+        let decl = |die: &Die| {
+            die.unsigned(DW_AT_DECL_FILE)
+                .and_then(|file| files.get((file as usize).wrapping_sub(1)))
+                .map(|file| SourceLocation { file: file.clone(), line: die.unsigned(DW_AT_DECL_LINE).unwrap_or(0), column: 0 })
+        };
+        let variable = |die: &Die| {
+            let decl_die = self.origin(&index, die);
+            let name = match die.string(DW_AT_NAME).or(decl_die.string(DW_AT_NAME)) {
+                Some(name) => name,
+                None => return None,
+            };
+            let ty = die.reference(DW_AT_TYPE).or(decl_die.reference(DW_AT_TYPE));
+            let address = match die.attr(DW_AT_LOCATION) {
+                Some(&Value::Block(ref expr)) if expr.len() == 1 + self.address_size && expr[0] == DW_OP_ADDR => {
+                    Reader::new(expr, 1).uint(self.address_size).ok().map(|a| a.wrapping_add(bias))
+                }
+                _ => None,
+            };
+
+            Some(
+                DebugVariable {
+                    name: name,
+                    type_name: ty.map(|t| self.type_name(&index, t, 0)),
+                    address: address,
+                    declaration: decl(die).or(decl(decl_die)),
+                }
+            )
+        };
+
+        for (idx, die) in self.dies.iter().enumerate() {
+            match die.tag {
+                DW_TAG_SUBPROGRAM => {
+                    let low = match die.attr(DW_AT_LOW_PC) {
+                        Some(&Value::Address(a)) => a,
+                        _ => continue,
+                    };
+                    let high = match die.attr(DW_AT_HIGH_PC) {
+                        Some(&Value::Address(a)) => a,
+                        Some(v) => low.wrapping_add(v.unsigned().unwrap_or(0)),
+                        None => low,
+                    };
+                    let bound = Bound::new(low.wrapping_add(bias), high.wrapping_add(bias));
+
+                    if bound.end < bound.start {
+                        debug!("Ignoring DWARF function at {:#x}, its end wraps around", low);
+                        continue;
+                    }
+                    let decl_die = self.origin(&index, die);
+                    let name = match die.string(DW_AT_NAME).or(decl_die.string(DW_AT_NAME)) {
+                        Some(name) => name,
+                        None => continue,
+                    };
+                    let linkage_name = die.string(DW_AT_LINKAGE_NAME)
+                        .or(die.string(DW_AT_MIPS_LINKAGE_NAME))
+                        .or(decl_die.string(DW_AT_LINKAGE_NAME))
+                        .or(decl_die.string(DW_AT_MIPS_LINKAGE_NAME));
+                    let parameters = self.dies[idx + 1..]
+                        .iter()
+                        .take_while(|d| d.depth > die.depth)
+                        .filter(|d| d.depth == die.depth + 1 && d.tag == DW_TAG_FORMAL_PARAMETER)
+                        .filter_map(|d| variable(d))
+                        .collect();
+                    let ret = die.reference(DW_AT_TYPE).or(decl_die.reference(DW_AT_TYPE));
+
+                    info.functions.push(
+                        DebugFunction {
+                            name: name,
+                            linkage_name: linkage_name,
+                            bound: bound,
+                            parameters: parameters,
+                            return_type: ret.map(|t| self.type_name(&index, t, 0)),
+                            declaration: decl(die).or(decl(decl_die)),
+                        }
+                    );
+                }
+                DW_TAG_VARIABLE if die.depth == 1 => {
+                    if let Some(var) = variable(die) {
+                        info.variables.push(var);
+                    }
+                }
+                DW_TAG_BASE_TYPE | DW_TAG_STRUCTURE_TYPE | DW_TAG_UNION_TYPE | DW_TAG_CLASS_TYPE | DW_TAG_ENUMERATION_TYPE | DW_TAG_TYPEDEF => {
+                    if let Some(name) = die.string(DW_AT_NAME) {
+                        let size = die.unsigned(DW_AT_BYTE_SIZE);
+                        let entry = types.entry(name).or_insert(size);
+
+                        if entry.is_none() {
+                            *entry = size;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Follows `DW_AT_specification` and `DW_AT_abstract_origin` from `die` to its declaration.
+    fn origin<'a>(&'a self, index: &HashMap<usize, usize>, die: &'a Die) -> &'a Die {
+        let mut die = die;
+
+        for _ in 0..8 {
+            match die.reference(DW_AT_SPECIFICATION).or(die.reference(DW_AT_ABSTRACT_ORIGIN)).and_then(|r| index.get(&r)) {
+                Some(&i) => die = &self.dies[i],
+                None => break,
+            }
+        }
+        die
+    }
+
+    /// Human-readable name of the type DIE at `offset`.
+    fn type_name(&self, index: &HashMap<usize, usize>, offset: usize, depth: usize) -> String {
+        let die = match index.get(&offset) {
+            Some(&i) if depth < 16 => &self.dies[i],
+            _ => return "?".to_string(),
+        };
+        let inner = || match die.reference(DW_AT_TYPE) {
+            Some(t) => self.type_name(index, t, depth + 1),
+            None => "void".to_string(),
+        };
+
+        match die.tag {
+            DW_TAG_POINTER_TYPE => format!("{}*", inner()),
+            DW_TAG_REFERENCE_TYPE => format!("{}&", inner()),
+            DW_TAG_CONST_TYPE => format!("const {}", inner()),
+            DW_TAG_VOLATILE_TYPE => format!("volatile {}", inner()),
+            DW_TAG_RESTRICT_TYPE => inner(),
+            DW_TAG_ARRAY_TYPE => format!("{}[]", inner()),
+            DW_TAG_SUBROUTINE_TYPE => format!("{}()", inner()),
+            _ => die.string(DW_AT_NAME).unwrap_or("?".to_string()),
+        }
+    }
+}
+
+/// Entry of the abbreviation table.
+struct Abbrev {
+    tag: u64,
+    children: bool,
+    attrs: Vec<(u64, u64)>,
+}
+
+/// Parses the abbreviation table at `offset`.
+fn parse_abbrevs(buf: &[u8], offset: usize) -> Result<HashMap<u64, Abbrev>> {
+    let mut rd = Reader::new(buf, offset);
+    let mut ret = HashMap::new();
+
+    loop {
+        let code = rd.uleb()?;
+
+        if code == 0 {
+            return Ok(ret);
+        }
+
+        let tag = rd.uleb()?;
+        let children = rd.u8()? != 0;
+        let mut attrs = Vec::new();
+
+        loop {
+            let at = rd.uleb()?;
+            let form = rd.uleb()?;
+
+            if at == 0 && form == 0 {
+                break;
+            }
+            attrs.push((at, form));
+        }
+
+        ret.insert(code, Abbrev { tag: tag, children: children, attrs: attrs });
+    }
+}
+
+/// Joins `dir` and `file` unless `file` is absolute.
+fn join_path(dir: &str, file: &str) -> String {
+    if file.starts_with('/') || dir.is_empty() {
+        file.to_string()
+    } else if dir.ends_with('/') {
+        format!("{}{}", dir, file)
+    } else {
+        format!("{}/{}", dir, file)
+    }
+}
+
+/// Runs the line number program at `offset` and adds its rows to `lines`. Returns the program's file
+/// table.
+fn parse_line_program(buf: &[u8], offset: usize, comp_dir: &str, bias: u64, lines: &mut BTreeMap<u64, Option<SourceLocation>>) -> Result<Vec<String>> {
+    let mut rd = Reader::new(buf, offset);
+    let (len, is_64) = rd.initial_length()?;
+    let end = rd.pos.checked_add(len).ok_or("DWARF line program length overflows")?;
+    let version = rd.u16()?;
+
+    if version < 2 || version > 4 || end > buf.len() {
+        debug!("Skipping DWARF {} line program at {:#x}", version, offset);
+        return Ok(Vec::new());
+    }
+
+    let header_len = rd.uint(if is_64 { 8 } else { 4 })? as usize;
+    let program = rd.pos.checked_add(header_len).ok_or("DWARF line program header length overflows")?;
+    let min_inst_len = rd.u8()? as u64;
+
+    if version >= 4 {
+        // maximum_operations_per_instruction, only used for VLIW
+        rd.u8()?;
+    }
+
+    let default_is_stmt = rd.u8()? != 0;
+    let line_base = rd.u8()? as i8 as i64;
+    let line_range = rd.u8()? as u64;
+    let opcode_base = rd.u8()?;
+    let opcode_lengths = rd.bytes(opcode_base.saturating_sub(1) as usize)?.to_vec();
+    let mut dirs = vec![comp_dir.to_string()];
+    let mut files = Vec::new();
+
+    if line_range == 0 {
+        return Err("Invalid DWARF line program: line_range is zero".into());
+    }
+
+    loop {
+        let dir = rd.cstr()?;
+        if dir.is_empty() {
+            break;
+        }
+        dirs.push(join_path(comp_dir, &dir));
+    }
+
+    let read_file = |rd: &mut Reader, name: String, dirs: &[String]| -> Result<String> {
+        let dir = rd.uleb()? as usize;
+        // modification time and length
+        rd.uleb()?;
+        rd.uleb()?;
+        Ok(join_path(dirs.get(dir).map(|d| d.as_str()).unwrap_or(""), &name))
+    };
+
+    loop {
+        let name = rd.cstr()?;
+        if name.is_empty() {
+            break;
+        }
+        let file = read_file(&mut rd, name, &dirs)?;
+        files.push(file);
+    }
+
+    rd.pos = program;
+    rd.buf = &buf[..end];
+
+    let mut address = 0u64;
+    let mut file = 1u64;
+    let mut line = 1i64;
+    let mut column = 0u64;
+    let mut is_stmt = default_is_stmt;
+    let emit = |lines: &mut BTreeMap<u64, Option<SourceLocation>>, files: &[String], address: u64, file: u64, line: i64, column: u64| {
+        let name = files.get((file as usize).wrapping_sub(1)).cloned().unwrap_or("?".to_string());
+        let loc = SourceLocation { file: name, line: if line > 0 { line as u64 } else { 0 }, column: column };
+        lines.insert(address.wrapping_add(bias), Some(loc));
+    };
+
+    while rd.pos < end {
+        let op = rd.u8()?;
+
+        if op >= opcode_base {
+            // special opcode
+            let adj = (op - opcode_base) as u64;
+            address = address.wrapping_add((adj / line_range).wrapping_mul(min_inst_len));
+            line = line.wrapping_add(line_base + (adj % line_range) as i64);
+            if is_stmt {
+                emit(lines, &files, address, file, line, column);
+            }
+            continue;
+        }
+
+        match op {
+            0 => {
+                let len = rd.uleb()? as usize;
+                let next = rd.pos.checked_add(len).ok_or("DWARF extended opcode length overflows")?;
+                if len == 0 {
+                    continue;
+                }
+
+                match rd.u8()? {
+                    // DW_LNE_end_sequence
+                    1 => {
+                        lines.entry(address.wrapping_add(bias)).or_insert(None);
+                        address = 0;
+                        file = 1;
+                        line = 1;
+                        column = 0;
+                        is_stmt = default_is_stmt;
+                    }
+                    // DW_LNE_set_address
+                    2 => address = rd.uint(len - 1)?,
+                    // DW_LNE_define_file
+                    3 => {
+                        let name = rd.cstr()?;
+                        let file = read_file(&mut rd, name, &dirs)?;
+                        files.push(file);
+                    }
+                    _ => {}
+                }
+                rd.pos = next;
+            }
+            // DW_LNS_copy
+            1 => {
+                if is_stmt {
+                    emit(lines, &files, address, file, line, column);
+                }
+            }
+            // DW_LNS_advance_pc
+            2 => address = address.wrapping_add(rd.uleb()?.wrapping_mul(min_inst_len)),
+            // DW_LNS_advance_line
+            3 => line = line.wrapping_add(rd.sleb()?),
+            // DW_LNS_set_file
+            4 => file = rd.uleb()?,
+            // DW_LNS_set_column
+            5 => column = rd.uleb()?,
+            // DW_LNS_negate_stmt
+            6 => is_stmt = !is_stmt,
+            // DW_LNS_const_add_pc
+            8 => address = address.wrapping_add(((255 - opcode_base) as u64 / line_range).wrapping_mul(min_inst_len)),
+            // DW_LNS_fixed_advance_pc
+            9 => address = address.wrapping_add(rd.u16()? as u64),
+            // DW_LNS_set_basic_block, DW_LNS_prologue_end, DW_LNS_epilogue_begin, DW_LNS_set_isa and unknown opcodes
+            op => {
+                for _ in 0..opcode_lengths.get(op as usize - 1).cloned().unwrap_or(0) {
+                    rd.uleb()?;
+                }
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leb128() {
+        let buf = [0xe5, 0x8e, 0x26, 0x7f, 0x80, 0x7f, 0x02];
+        let mut rd = Reader::new(&buf, 0);
+
+        assert_eq!(rd.uleb().unwrap(), 624485);
+        assert_eq!(rd.sleb().unwrap(), -1);
+        assert_eq!(rd.sleb().unwrap(), -128);
+        assert_eq!(rd.sleb().unwrap(), 2);
+        assert!(rd.uleb().is_err());
+    }
+
+    #[test]
+    fn line_lookup() {
+        let mut info = DebugInfo::default();
+        let loc = SourceLocation { file: "a.c".to_string(), line: 3, column: 0 };
+
+        info.lines.insert(0x10, Some(loc.clone()));
+        info.lines.insert(0x20, None);
+
+        assert_eq!(info.source_line(0xf), None);
+        assert_eq!(info.source_line(0x10), Some(&loc));
+        assert_eq!(info.source_line(0x1f), Some(&loc));
+        assert_eq!(info.source_line(0x20), None);
+        assert_eq!(format!("{}", loc), "a.c:3");
+    }
+
+    #[test]
+    fn malformed_units() {
+        // a compile unit and two kinds of subprograms: name, low_pc, high_pc as data8 and name,
+        // low_pc, type as ref8
+        let abbrev = [1, 0x2e, 0, 0x03, 0x08, 0x11, 0x01, 0x12, 0x07, 0, 0, 2, 0x2e, 0, 0x03, 0x08, 0x11, 0x01, 0x49, 0x14, 0, 0, 3, 0x11, 1, 0, 0, 0];
+        let mut info = vec![0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 8, 3];
+        let mut die = |info: &mut Vec<u8>, code: u8, name: u8, low: u64, last: u64| {
+            info.extend_from_slice(&[code, name, 0]);
+            for x in &[low, last] {
+                info.extend((0..8).map(|i| (x >> (i * 8)) as u8));
+            }
+        };
+
+        die(&mut info, 1, b'f', 0x1000, u64::max_value());
+        die(&mut info, 1, b'g', 0x2000, 0x10);
+        die(&mut info, 2, b'h', 0x3000, u64::max_value());
+        info.extend_from_slice(&[0, 0]);
+
+        let len = info.len() as u32 - 4;
+        info[0..4].copy_from_slice(&[len as u8, (len >> 8) as u8, 0, 0]);
+
+        // skipped DWARF 5 unit, moves the references of the second one past the end
+        let info = [2, 0, 0, 0, 5, 0].iter().chain(info.iter()).cloned().collect::<Vec<u8>>();
+
+        let sections = DwarfSections { debug_info: &info, debug_abbrev: &abbrev, ..DwarfSections::default() };
+        let dbg = DebugInfo::parse(&sections, 0).unwrap();
+        let names = dbg.functions.iter().map(|f| f.name.as_str()).collect::<Vec<_>>();
+
+        assert_eq!(names, vec!["g"]);
+        assert_eq!(dbg.functions[0].bound, Bound::new(0x2000, 0x2010));
+        assert!(DebugInfo::parse(&sections, u64::max_value()).is_ok());
+    }
+}
@@ -0,0 +1,761 @@
+/*
+ * Panopticon - A libre disassembler
+ * Copyright (C) 2017  Panopticon authors
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Concrete execution of RREIL code.
+//!
+//! The `Emulator` walks the control flow graph of a single `Function`, executing one mnemonic at
+//! a time. After the last mnemonic of a basic block the first outgoing edge whose `Guard` is true
+//! is followed. Jumps to unresolved targets continue at the basic block starting at the target
+//! address if there is one in the function.
+//!
+//! Variables are identified by name only, SSA subscripts are ignored. Reading a variable that was
+//! never written yields `Rvalue::Undefined`, as does any operation on undefined values.
+//!
+//! `Load` and `Store` operations go through `Memory`. Memory banks are backed by the `Region`
+//! `Project::bank` returns for them. Writes never modify the `Region`s, they end up in a
+//! scratch layer on top of them that can be inspected and discarded. A store through an undefined
+//! pointer stops execution.
+//!
+//! Emulation is intra-procedural. Calls are recorded but not followed, the callee's effects on
+//! variables and memory are not modeled. Use `set_break_on_calls` to stop after each call and
+//! apply them with `set_variable` and `memory_mut` before resuming.
+
+use {ControlFlowRef, ControlFlowTarget, Endianess, Function, Guard, Lvalue, Operation, Project, Region, Result, Rvalue, Statement, execute};
+use layer::Cell;
+use panopticon_graph_algos::{GraphTrait, IncidenceGraphTrait, VertexListGraphTrait};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Memory banks accessed by `Load` and `Store` operations.
+pub struct Memory<'a> {
+    regions: Vec<&'a Region>,
+    project: Option<&'a Project>,
+    scratch: HashMap<String, BTreeMap<u64, Cell>>,
+}
+
+impl<'a> Memory<'a> {
+    /// Creates memory without any backing `Region`s. All cells are undefined until written.
+    pub fn new() -> Memory<'a> {
+        Memory {
+            regions: vec![],
+            project: None,
+            scratch: HashMap::new(),
+        }
+    }
+
+    /// Creates memory backed by the `Region`s of `project`, see `Project::bank`.
+    pub fn from_project(project: &'a Project) -> Memory<'a> {
+        Memory {
+            regions: vec![],
+            project: Some(project),
+            scratch: HashMap::new(),
+        }
+    }
+
+    /// Backs the bank with the same name as `region` (ignoring case) with it.
+    pub fn add_region(&mut self, region: &'a Region) {
+        self.regions.push(region);
+    }
+
+    fn region(&self, bank: &str) -> Option<&'a Region> {
+        let bank = bank.to_lowercase();
+
+        self.regions.iter().find(|r| r.name().to_lowercase() == bank).cloned().or(self.project.map(|p| p.bank(&bank)))
+    }
+
+    /// Returns `len` cells of `bank` starting at `address`. Written cells shadow the backing
+    /// `Region`, cells outside of it are undefined.
+    pub fn read(&self, bank: &str, address: u64, len: usize) -> Vec<Cell> {
+        let mut ret = match self.region(bank) {
+            Some(reg) => reg.read(address, len as u64),
+            None => vec![],
+        };
+
+        ret.resize(len, None);
+
+        if let Some(scratch) = self.scratch.get(&bank.to_lowercase()) {
+            for (i, cell) in ret.iter_mut().enumerate() {
+                if let Some(c) = scratch.get(&address.wrapping_add(i as u64)) {
+                    *cell = *c;
+                }
+            }
+        }
+
+        ret
+    }
+
+    /// Writes `cells` to `bank` starting at `address`. The backing `Region` is not modified.
+    pub fn write(&mut self, bank: &str, address: u64, cells: &[Cell]) {
+        let scratch = self.scratch.entry(bank.to_lowercase()).or_insert_with(BTreeMap::new);
+
+        for (i, c) in cells.iter().enumerate() {
+            scratch.insert(address.wrapping_add(i as u64), *c);
+        }
+    }
+
+    /// Returns all cells of `bank` written so far, ordered by address.
+    pub fn written(&self, bank: &str) -> Vec<(u64, Cell)> {
+        match self.scratch.get(&bank.to_lowercase()) {
+            Some(scratch) => scratch.iter().map(|(&a, &c)| (a, c)).collect(),
+            None => vec![],
+        }
+    }
+
+    /// Discards all writes.
+    pub fn reset(&mut self) {
+        self.scratch.clear();
+    }
+}
+
+/// Reason the `Emulator` stopped.
+#[derive(Clone,PartialEq,Eq,Debug)]
+pub enum Stop {
+    /// A breakpoint was hit. The mnemonic at the address has not been executed yet.
+    Breakpoint(u64),
+    /// A `Call` to the target was executed while breaking on calls is enabled.
+    Call(Rvalue),
+    /// A basic block without outgoing edges was executed.
+    Returned,
+    /// Execution continued outside of the function or at an indirect jump with undefined target.
+    Jump(Rvalue),
+    /// The `Guard` of an edge leaving the basic block ending at the address is undefined.
+    UndefinedGuard(u64),
+    /// Execution reached code that failed to disassemble.
+    Failed(u64, Cow<'static, str>),
+    /// The limit on executed mnemonics was reached.
+    InstructionLimit,
+    /// The limit on executed RREIL statements was reached.
+    StepLimit,
+    /// The mnemonic at the address stores to an undefined pointer. It was executed only partially.
+    UndefinedAddress(u64),
+}
+
+impl Stop {
+    /// Returns true if execution can't be continued after this.
+    pub fn is_final(&self) -> bool {
+        match self {
+            &Stop::Breakpoint(_) | &Stop::Call(_) | &Stop::InstructionLimit | &Stop::StepLimit => false,
+            _ => true,
+        }
+    }
+}
+
+/// Concrete interpreter for the RREIL code of a `Function`.
+pub struct Emulator<'a> {
+    function: &'a Function,
+    memory: Memory<'a>,
+    variables: HashMap<Cow<'static, str>, Rvalue>,
+    position: Option<(ControlFlowRef, usize)>,
+    halted: Option<Stop>,
+    breakpoints: HashSet<u64>,
+    last_breakpoint: Option<u64>,
+    break_on_calls: bool,
+    calls: Vec<(u64, Rvalue)>,
+    instructions: usize,
+    steps: usize,
+    instruction_limit: Option<usize>,
+    step_limit: Option<usize>,
+}
+
+impl<'a> Emulator<'a> {
+    /// Creates a new emulator positioned at the entry point of `function`.
+    pub fn new(function: &'a Function, memory: Memory<'a>) -> Result<Emulator<'a>> {
+        let mut emu = Emulator {
+            function: function,
+            memory: memory,
+            variables: HashMap::new(),
+            position: None,
+            halted: None,
+            breakpoints: HashSet::new(),
+            last_breakpoint: None,
+            break_on_calls: false,
+            calls: vec![],
+            instructions: 0,
+            steps: 0,
+            instruction_limit: None,
+            step_limit: None,
+        };
+
+        emu.enter(function.entry_point_ref())?;
+        Ok(emu)
+    }
+
+    /// Address of the next mnemonic to execute. `None` if execution ended.
+    pub fn pc(&self) -> Option<u64> {
+        self.position.and_then(
+            |(vx, idx)| match self.function.cfg().vertex_label(vx) {
+                Some(&ControlFlowTarget::Resolved(ref bb)) => bb.mnemonics.get(idx).map(|m| m.area.start),
+                _ => None,
+            }
+        )
+    }
+
+    /// Returns the reason execution ended, if it did.
+    pub fn halted(&self) -> Option<&Stop> {
+        self.halted.as_ref()
+    }
+
+    /// Current value of the variable `name`.
+    pub fn variable(&self, name: &str) -> Rvalue {
+        self.variables.get(name).cloned().unwrap_or(Rvalue::Undefined)
+    }
+
+    /// Sets the variable `name` to `value`.
+    pub fn set_variable(&mut self, name: &str, value: Rvalue) {
+        self.variables.insert(Cow::Owned(name.to_string()), value);
+    }
+
+    /// All variables written so far, ordered by name.
+    pub fn variables(&self) -> Vec<(&str, &Rvalue)> {
+        let mut ret = self.variables.iter().map(|(k, v)| (&**k, v)).collect::<Vec<_>>();
+
+        ret.sort_by(|a, b| a.0.cmp(b.0));
+        ret
+    }
+
+    /// Memory used by `Load` and `Store` operations.
+    pub fn memory(&self) -> &Memory<'a> {
+        &self.memory
+    }
+
+    /// Mutable access to the memory used by `Load` and `Store` operations.
+    pub fn memory_mut(&mut self) -> &mut Memory<'a> {
+        &mut self.memory
+    }
+
+    /// Address of the mnemonic and target of all calls executed so far.
+    pub fn calls(&self) -> &[(u64, Rvalue)] {
+        &self.calls
+    }
+
+    /// Number of mnemonics executed so far.
+    pub fn instructions(&self) -> usize {
+        self.instructions
+    }
+
+    /// Number of RREIL statements executed so far.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Stops execution before the mnemonic at `address`.
+    pub fn add_breakpoint(&mut self, address: u64) {
+        self.breakpoints.insert(address);
+    }
+
+    /// Removes the breakpoint at `address`. Returns false if there was none.
+    pub fn remove_breakpoint(&mut self, address: u64) -> bool {
+        self.breakpoints.remove(&address)
+    }
+
+    /// Stops execution after each mnemonic executing a `Call` if `enable` is true.
+    pub fn set_break_on_calls(&mut self, enable: bool) {
+        self.break_on_calls = enable;
+    }
+
+    /// Stops execution once `limit` mnemonics were executed in total.
+    pub fn set_instruction_limit(&mut self, limit: Option<usize>) {
+        self.instruction_limit = limit;
+    }
+
+    /// Stops execution before the mnemonic that would exceed `limit` RREIL statements in total.
+    pub fn set_step_limit(&mut self, limit: Option<usize>) {
+        self.step_limit = limit;
+    }
+
+    /// Executes the next mnemonic, ignoring breakpoints. Returns the reason execution stopped
+    /// afterwards, if any.
+    pub fn step(&mut self) -> Result<Option<Stop>> {
+        let func = self.function;
+        let (vx, idx) = match self.position {
+            Some(p) => p,
+            None => return Ok(self.halted.clone()),
+        };
+        let bb = match func.cfg().vertex_label(vx) {
+            Some(&ControlFlowTarget::Resolved(ref bb)) => bb,
+            _ => return Err("Emulator is not positioned inside a basic block".into()),
+        };
+        let mne = &bb.mnemonics[idx];
+
+        if self.instruction_limit.map(|l| self.instructions >= l).unwrap_or(false) {
+            return Ok(Some(Stop::InstructionLimit));
+        }
+        if self.step_limit.map(|l| self.steps + mne.instructions.len() > l).unwrap_or(false) {
+            return Ok(Some(Stop::StepLimit));
+        }
+
+        let calls = self.calls.len();
+
+        for stmt in mne.instructions.iter() {
+            if let Some(stop) = self.execute_statement(mne.area.start, stmt)? {
+                return Ok(self.halt(stop));
+            }
+        }
+
+        self.instructions += 1;
+        self.steps += mne.instructions.len();
+        self.last_breakpoint = None;
+
+        let call = if self.break_on_calls && self.calls.len() > calls {
+            self.calls.last().map(|&(_, ref tgt)| Stop::Call(tgt.clone()))
+        } else {
+            None
+        };
+        let stop = if idx + 1 < bb.mnemonics.len() {
+            self.position = Some((vx, idx + 1));
+            None
+        } else {
+            self.leave(vx, mne.area.start)?
+        };
+
+        Ok(call.or(stop))
+    }
+
+    /// Executes mnemonics until a breakpoint or limit is hit or execution ends. A breakpoint at the
+    /// current position is ignored if it was the reason execution stopped last.
+    pub fn run(&mut self) -> Result<Stop> {
+        loop {
+            if let Some(pc) = self.pc() {
+                if self.breakpoints.contains(&pc) && self.last_breakpoint != Some(pc) {
+                    self.last_breakpoint = Some(pc);
+                    return Ok(Stop::Breakpoint(pc));
+                }
+            }
+
+            if let Some(stop) = self.step()? {
+                return Ok(stop);
+            }
+        }
+    }
+
+    fn halt(&mut self, stop: Stop) -> Option<Stop> {
+        self.position = None;
+        self.halted = Some(stop.clone());
+        Some(stop)
+    }
+
+    // Moves to the first mnemonic of `vx`, following unresolved targets and empty basic blocks.
+    fn enter(&mut self, mut vx: ControlFlowRef) -> Result<Option<Stop>> {
+        let func = self.function;
+
+        for _ in 0..func.cfg().num_vertices() + 1 {
+            match func.cfg().vertex_label(vx) {
+                Some(&ControlFlowTarget::Resolved(ref bb)) if !bb.mnemonics.is_empty() => {
+                    self.position = Some((vx, 0));
+                    return Ok(None);
+                }
+                Some(&ControlFlowTarget::Resolved(ref bb)) => {
+                    match self.successor(vx, bb.area.start)? {
+                        Ok(next) => vx = next,
+                        Err(stop) => return Ok(self.halt(stop)),
+                    }
+                }
+                Some(&ControlFlowTarget::Unresolved(ref rv)) => {
+                    let tgt = self.evaluate(rv);
+                    let next = match tgt {
+                        Rvalue::Constant { value, .. } => func.find_basic_block_by_start(value),
+                        _ => None,
+                    };
+
+                    match next {
+                        Some(next) => vx = next,
+                        None => return Ok(self.halt(Stop::Jump(tgt))),
+                    }
+                }
+                Some(&ControlFlowTarget::Failed(pos, ref msg)) => return Ok(self.halt(Stop::Failed(pos, msg.clone()))),
+                None => return Err("Unknown basic block".into()),
+            }
+        }
+
+        Err("Cycle of empty basic blocks".into())
+    }
+
+    // Leaves `vx` after its last mnemonic at `address` was executed.
+    fn leave(&mut self, vx: ControlFlowRef, address: u64) -> Result<Option<Stop>> {
+        match self.successor(vx, address)? {
+            Ok(next) => self.enter(next),
+            Err(stop) => Ok(self.halt(stop)),
+        }
+    }
+
+    // Picks the first outgoing edge of `vx` with a true guard.
+    fn successor(&self, vx: ControlFlowRef, address: u64) -> Result<::std::result::Result<ControlFlowRef, Stop>> {
+        let cfg = self.function.cfg();
+        let mut edges = 0;
+        let mut undefined = false;
+
+        for e in cfg.out_edges(vx) {
+            edges += 1;
+
+            match cfg.edge_label(e).map(|g| self.guard(g)) {
+                Some(Some(true)) => return Ok(Ok(cfg.target(e))),
+                Some(Some(false)) => {}
+                _ => undefined = true,
+            }
+        }
+
+        if edges == 0 {
+            Ok(Err(Stop::Returned))
+        } else if undefined {
+            Ok(Err(Stop::UndefinedGuard(address)))
+        } else {
+            Err(format!("No outgoing edge of the basic block at {:#x} is taken", address).into())
+        }
+    }
+
+    fn guard(&self, guard: &Guard) -> Option<bool> {
+        match guard {
+            &Guard::True => Some(true),
+            &Guard::False => Some(false),
+            &Guard::Predicate { ref flag, expected } => {
+                match self.evaluate(flag) {
+                    Rvalue::Constant { value, .. } => Some((value != 0) == expected),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    fn evaluate(&self, rv: &Rvalue) -> Rvalue {
+        match rv {
+            &Rvalue::Variable { ref name, size, offset, .. } => {
+                self.variables.get(name).and_then(|v| v.extract(size, offset).ok()).unwrap_or(Rvalue::Undefined)
+            }
+            _ => rv.clone(),
+        }
+    }
+
+    fn assign(&mut self, lv: &Lvalue, value: Rvalue) {
+        if let &Lvalue::Variable { ref name, size, .. } = lv {
+            let value = match value.limbs() {
+                Some(v) => Rvalue::new_wide(v, size),
+                None => Rvalue::Undefined,
+            };
+
+            self.variables.insert(name.clone(), value);
+        }
+    }
+
+    fn execute_statement(&mut self, address: u64, stmt: &Statement) -> Result<Option<Stop>> {
+        let mut op = stmt.op.clone();
+
+        for o in op.operands_mut() {
+            *o = self.evaluate(o);
+        }
+
+        let value = match op {
+            Operation::Load(ref bank, endianess, sz, ref ptr) => self.load(bank, endianess, sz, ptr),
+            Operation::Store(ref bank, endianess, sz, ref ptr, ref val) => {
+                if !self.store(bank, endianess, sz, ptr, val) {
+                    return Ok(Some(Stop::UndefinedAddress(address)));
+                }
+                Rvalue::Undefined
+            }
+            Operation::Call(ref tgt) => {
+                self.calls.push((address, tgt.clone()));
+                Rvalue::Undefined
+            }
+            Operation::Intrinsic(_, _, ref outputs) => {
+                for o in outputs.iter() {
+                    self.assign(o, Rvalue::Undefined);
+                }
+                Rvalue::Undefined
+            }
+            Operation::Phi(_) | Operation::Initialize(_, _) => return Ok(None),
+            op => execute(op),
+        };
+
+        self.assign(&stmt.assignee, value);
+        Ok(None)
+    }
+
+    fn load(&self, bank: &str, endianess: Endianess, sz: usize, ptr: &Rvalue) -> Rvalue {
+        let address = match ptr {
+            &Rvalue::Constant { value, .. } => value,
+            _ => return Rvalue::Undefined,
+        };
+
+        if sz == 0 || sz % 8 != 0 {
+            return Rvalue::Undefined;
+        }
+
+        let mut cells = self.memory.read(bank, address, sz / 8);
+        let mut value = vec![0u64; (sz + 63) / 64];
+
+        if endianess == Endianess::Big {
+            cells.reverse();
+        }
+
+        for (i, c) in cells.into_iter().enumerate() {
+            match c {
+                Some(b) => value[i / 8] |= (b as u64) << (8 * (i % 8)),
+                None => return Rvalue::Undefined,
+            }
+        }
+
+        Rvalue::new_wide(value, sz)
+    }
+
+    // Returns false if `ptr` is undefined.
+    fn store(&mut self, bank: &str, endianess: Endianess, sz: usize, ptr: &Rvalue, val: &Rvalue) -> bool {
+        let ptr = match ptr {
+            &Rvalue::Constant { value, .. } => value,
+            _ => return false,
+        };
+        let mut cells = match val.limbs() {
+            Some(v) => (0..sz / 8).map(|i| Some(v.get(i / 8).map(|l| (l >> (8 * (i % 8))) as u8).unwrap_or(0))).collect::<Vec<_>>(),
+            None => vec![None; sz / 8],
+        };
+
+        if endianess == Endianess::Big {
+            cells.reverse();
+        }
+
+        self.memory.write(bank, ptr, &cells);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {BasicBlock, Mnemonic, parse_statements};
+    use panopticon_graph_algos::MutableGraphTrait;
+
+    fn mnemonic(address: u64, code: &str) -> Mnemonic {
+        let stmts = parse_statements(code).unwrap();
+        Mnemonic::new(address..address + 1, "test".to_string(), "".to_string(), vec![].iter(), stmts.iter()).unwrap()
+    }
+
+    // Copies `len` bytes from ram[src] to ram[dst] xor'ing each with 0x20.
+    fn decoder() -> Function {
+        let reg = Region::undefined("ram".to_string(), 0x100);
+        let mut func = Function::undefined(0, None, &reg, Some("decode".to_string()));
+        let bb0 = BasicBlock::from_vec(vec![mnemonic(0, "mov i:32, 0:32")]);
+        let bb1 = BasicBlock::from_vec(
+            vec![
+                mnemonic(1, "add p:32, src:32, i:32; load/ram/le/8 b:8, p:32"),
+                mnemonic(2, "xor b:8, b:8, 0x20:8"),
+                mnemonic(3, "add p:32, dst:32, i:32; store/ram/le/8 ?, p:32, b:8"),
+                mnemonic(4, "add i:32, i:32, 1:32; cmplu f:1, i:32, len:32"),
+            ]
+        );
+        let bb2 = BasicBlock::from_vec(vec![mnemonic(5, "call ?, 0x40:32")]);
+        let f = Rvalue::Variable { name: Cow::Borrowed("f"), size: 1, offset: 0, subscript: None };
+        let g = Guard::from_flag(&f).unwrap();
+        let vx0 = func.cfg_mut().add_vertex(ControlFlowTarget::Resolved(bb0));
+        let vx1 = func.cfg_mut().add_vertex(ControlFlowTarget::Resolved(bb1));
+        let vx2 = func.cfg_mut().add_vertex(ControlFlowTarget::Resolved(bb2));
+
+        func.cfg_mut().add_edge(Guard::always(), vx0, vx1);
+        func.cfg_mut().add_edge(g.clone(), vx1, vx1);
+        func.cfg_mut().add_edge(g.negation(), vx1, vx2);
+        func.set_entry_point_ref(vx0);
+        func
+    }
+
+    fn setup<'a>(func: &'a Function, reg: &'a Region) -> Emulator<'a> {
+        let mut mem = Memory::new();
+
+        mem.add_region(reg);
+
+        let mut emu = Emulator::new(func, mem).unwrap();
+
+        emu.set_variable("src", Rvalue::new_u32(0x10));
+        emu.set_variable("dst", Rvalue::new_u32(0x20));
+        emu.set_variable("len", Rvalue::new_u32(3));
+        emu
+    }
+
+    fn ram() -> Region {
+        let mut data = vec![0u8; 0x30];
+
+        data[0x10] = 0x41;
+        data[0x11] = 0x42;
+        data[0x12] = 0x43;
+        Region::wrap("RAM".to_string(), data)
+    }
+
+    #[test]
+    fn run_to_end() {
+        let func = decoder();
+        let reg = ram();
+        let mut emu = setup(&func, &reg);
+
+        assert_eq!(emu.pc(), Some(0));
+        assert_eq!(emu.run().unwrap(), Stop::Returned);
+        assert_eq!(emu.pc(), None);
+        assert_eq!(emu.halted(), Some(&Stop::Returned));
+        assert_eq!(emu.step().unwrap(), Some(Stop::Returned));
+
+        assert_eq!(emu.variable("i"), Rvalue::new_u32(3));
+        assert_eq!(emu.variable("f"), Rvalue::new_bit(0));
+        assert_eq!(emu.variable("nope"), Rvalue::Undefined);
+        assert_eq!(emu.instructions(), 1 + 3 * 4 + 1);
+        assert_eq!(emu.steps(), 1 + 3 * 7 + 1);
+        assert_eq!(emu.calls(), &[(5, Rvalue::new_u32(0x40))]);
+
+        assert_eq!(emu.memory().read("ram", 0x20, 4), vec![Some(0x61), Some(0x62), Some(0x63), Some(0)]);
+        assert_eq!(emu.memory().written("Ram"), vec![(0x20, Some(0x61)), (0x21, Some(0x62)), (0x22, Some(0x63))]);
+        assert_eq!(reg.read(0x20, 1), vec![Some(0)]);
+        assert_eq!(emu.memory().read("ram", 0x2e, 4), vec![Some(0), Some(0), None, None]);
+
+        emu.memory_mut().reset();
+        assert!(emu.memory().written("ram").is_empty());
+    }
+
+    #[test]
+    fn breakpoints() {
+        let func = decoder();
+        let reg = ram();
+        let mut emu = setup(&func, &reg);
+
+        emu.add_breakpoint(2);
+
+        for i in 0..3 {
+            assert_eq!(emu.run().unwrap(), Stop::Breakpoint(2));
+            assert_eq!(emu.pc(), Some(2));
+            assert_eq!(emu.variable("i"), Rvalue::new_u32(i));
+            assert_eq!(emu.variable("b"), Rvalue::new_u8(0x41 + i as u8));
+        }
+
+        assert!(emu.remove_breakpoint(2));
+        assert!(!emu.remove_breakpoint(2));
+        emu.set_break_on_calls(true);
+        assert_eq!(emu.run().unwrap(), Stop::Call(Rvalue::new_u32(0x40)));
+        assert_eq!(emu.run().unwrap(), Stop::Returned);
+    }
+
+    #[test]
+    fn limits() {
+        let func = decoder();
+        let reg = ram();
+        let mut emu = setup(&func, &reg);
+
+        emu.set_instruction_limit(Some(3));
+        assert_eq!(emu.run().unwrap(), Stop::InstructionLimit);
+        assert_eq!(emu.pc(), Some(3));
+        assert_eq!(emu.step().unwrap(), Some(Stop::InstructionLimit));
+
+        emu.set_instruction_limit(None);
+        emu.set_step_limit(Some(6));
+        assert_eq!(emu.run().unwrap(), Stop::StepLimit);
+        assert_eq!(emu.steps(), 6);
+        assert_eq!(emu.pc(), Some(4));
+
+        emu.set_step_limit(None);
+        assert_eq!(emu.step().unwrap(), None);
+        assert_eq!(emu.pc(), Some(1));
+    }
+
+    #[test]
+    fn undefined_guard() {
+        let func = decoder();
+        let reg = ram();
+        let mut emu = setup(&func, &reg);
+
+        emu.set_variable("len", Rvalue::Undefined);
+        assert_eq!(emu.run().unwrap(), Stop::UndefinedGuard(4));
+        assert!(emu.halted().map(|s| s.is_final()).unwrap_or(false));
+    }
+
+    #[test]
+    fn undefined_address() {
+        let func = decoder();
+        let reg = ram();
+        let mut emu = setup(&func, &reg);
+
+        emu.set_variable("dst", Rvalue::Undefined);
+        assert_eq!(emu.run().unwrap(), Stop::UndefinedAddress(3));
+        assert_eq!(emu.pc(), None);
+        assert!(emu.halted().map(|s| s.is_final()).unwrap_or(false));
+        assert!(emu.memory().written("ram").is_empty());
+    }
+
+    #[test]
+    fn unresolved_jumps() {
+        let reg = ram();
+        let mut func = Function::undefined(0, None, &reg, None);
+        let bb0 = BasicBlock::from_vec(vec![mnemonic(0, "mov t:32, 2:32")]);
+        let bb1 = BasicBlock::from_vec(vec![mnemonic(2, "mov t:32, 0x50:32")]);
+        let t = Rvalue::Variable { name: Cow::Borrowed("t"), size: 32, offset: 0, subscript: None };
+        let vx0 = func.cfg_mut().add_vertex(ControlFlowTarget::Resolved(bb0));
+        let vx1 = func.cfg_mut().add_vertex(ControlFlowTarget::Resolved(bb1));
+        let vx2 = func.cfg_mut().add_vertex(ControlFlowTarget::Unresolved(t.clone()));
+        let vx3 = func.cfg_mut().add_vertex(ControlFlowTarget::Unresolved(t));
+
+        func.cfg_mut().add_edge(Guard::always(), vx0, vx2);
+        func.cfg_mut().add_edge(Guard::always(), vx1, vx3);
+        func.set_entry_point_ref(vx0);
+
+        let mut emu = Emulator::new(&func, Memory::new()).unwrap();
+
+        assert_eq!(emu.step().unwrap(), None);
+        assert_eq!(emu.pc(), Some(2));
+        assert_eq!(emu.run().unwrap(), Stop::Jump(Rvalue::new_u32(0x50)));
+        assert_eq!(emu.pc(), None);
+    }
+
+    #[test]
+    fn memory_endianess() {
+        let func = decoder();
+        let reg = ram();
+        let mut emu = setup(&func, &reg);
+
+        emu.set_variable("v", Rvalue::new_u32(0x11223344));
+        emu.execute_statement(0, &parse_statements("store/ram/be/32 ?, 0:32, v:32").unwrap()[0]).unwrap();
+        emu.execute_statement(0, &parse_statements("load/ram/le/16 w:16, 1:32").unwrap()[0]).unwrap();
+        emu.execute_statement(0, &parse_statements("load/ram/le/16 u:16, 0x2f:32").unwrap()[0]).unwrap();
+
+        assert_eq!(emu.memory().read("ram", 0, 4), vec![Some(0x11), Some(0x22), Some(0x33), Some(0x44)]);
+        assert_eq!(emu.variable("w"), Rvalue::new_u16(0x3322));
+        assert_eq!(emu.variable("u"), Rvalue::Undefined);
+        assert_eq!(
+            emu.execute_statement(7, &parse_statements("store/ram/le/8 ?, x:32, 1:8").unwrap()[0]).unwrap(),
+            Some(Stop::UndefinedAddress(7))
+        );
+    }
+
+    #[test]
+    fn wide_values() {
+        let func = decoder();
+        let reg = ram();
+        let mut emu = setup(&func, &reg);
+        let code = parse_statements(
+            "
+            zext/128 a:128, v:64
+            mul p:128, a:128, a:128
+            store/ram/le/128 ?, 0:32, p:128
+            load/ram/be/128 q:128, 0:32
+            mov hi:64, p:64/64
+            "
+        )
+        .unwrap();
+
+        emu.set_variable("v", Rvalue::new_u64(0xffffffffffffffff));
+        for stmt in code.iter() {
+            emu.execute_statement(0, stmt).unwrap();
+        }
+
+        assert_eq!(emu.variable("p"), Rvalue::Wide { value: vec![1, 0xfffffffffffffffe], size: 128 });
+        assert_eq!(emu.variable("hi"), Rvalue::new_u64(0xfffffffffffffffe));
+        assert_eq!(emu.variable("q"), Rvalue::Wide { value: vec![0xfeffffffffffffff, 0x0100000000000000], size: 128 });
+        assert_eq!(emu.memory().read("ram", 0, 2), vec![Some(1), Some(0)]);
+        assert_eq!(emu.memory().read("ram", 15, 1), vec![Some(0xff)]);
+    }
+}
@@ -28,8 +28,9 @@
 //! on the front-end.
 
 
-use {Architecture, BasicBlock, Guard, Mnemonic, Operation, Region, Result, Rvalue, Statement};
+use {Architecture, BasicBlock, Bound, Guard, Mnemonic, Operation, Region, Result, Rvalue, Statement};
 
+use demangle;
 use panopticon_graph_algos::{AdjacencyList, EdgeListGraphTrait, GraphTrait, MutableGraphTrait, VertexListGraphTrait};
 use panopticon_graph_algos::adjacency_list::{AdjacencyListEdgeDescriptor, AdjacencyListVertexDescriptor, VertexLabelIterator};
 use panopticon_graph_algos::search::{TraversalOrder, TreeIterator};
@@ -141,7 +142,7 @@ impl Function {
     }
     // this private method is where the meat of making a function is;
     // almost all perf gains for function disassembly will be in here, and related functions like, assemble_cflow_graph, etc.
-    fn disassemble<A: Architecture>(start: u64, cflow_graph: &mut ControlFlowGraph, size: &mut usize, name: &str, uuid: &Uuid, region: &Region, init: A::Configuration) -> Result<ControlFlowRef> {
+    fn disassemble<A: Architecture>(start: u64, extent: Option<&Bound>, cflow_graph: &mut ControlFlowGraph, size: &mut usize, name: &str, uuid: &Uuid, region: &Region, init: A::Configuration) -> Result<ControlFlowRef> {
         let (mut mnemonics, mut by_source, mut by_destination) = Self::index_cflow_graph(cflow_graph, start);
 
         let mut todo = cflow_graph.vertex_labels().filter_map(|lb| {
@@ -203,6 +204,10 @@ impl Function {
                     for (origin, tgt, gu) in match_st.jumps {
                         debug!("jump to {:?}", tgt);
                         match tgt {
+                            // jumps leaving the known extent of the function are tail calls
+                            Rvalue::Constant { value: ref c, .. } if extent.map_or(false, |b| *c < b.start || *c >= b.end) => {
+                                by_source.entry(origin).or_insert(Vec::new()).push((tgt.clone(), gu.clone()));
+                            }
                             Rvalue::Constant { value: ref c, .. } => {
                                 by_source.entry(origin).or_insert(Vec::new()).push((tgt.clone(), gu.clone()));
                                 by_destination.entry(*c).or_insert(Vec::new()).push((Rvalue::new_u64(origin), gu.clone()));
@@ -243,29 +248,13 @@ impl Function {
     }
     /// Continue disassembling from `start`, at `region`, with CPU `configuration`, using the functions current, internal control flow graph.
     pub fn cont<A: Architecture>(&mut self, start: u64, region: &Region, configuration: A::Configuration) -> Result<()> {
-        self.entry_point = Self::disassemble::<A>(start, &mut self.cflow_graph, &mut self.size, &self.name, &self.uuid, region, configuration)?;
+        self.entry_point = Self::disassemble::<A>(start, None, &mut self.cflow_graph, &mut self.size, &self.name, &self.uuid, region, configuration)?;
         Ok(())
     }
 
     /// Create and start disassembling a new function with `name`, inside memory `region`, starting at entry point `start`, with a random UUID.
     pub fn new<A: Architecture>(start: u64, region: &Region, name: Option<String>, init: A::Configuration) -> Result<Function> {
-        let mut cflow_graph = AdjacencyList::new();
-        let entry_point = ControlFlowTarget::Unresolved(Rvalue::new_u64(start));
-        cflow_graph.add_vertex(entry_point);
-        let mut size = 0;
-        let name = name.unwrap_or(format!("func_{:#x}", start));
-        let uuid = Uuid::new_v4();
-        let entry_point = Self::disassemble::<A>(start, &mut cflow_graph, &mut size, &name, &uuid, region, init)?;
-        Ok(Function {
-            name,
-            aliases: Vec::new(),
-            uuid,
-            cflow_graph,
-            entry_point,
-            region: region.name().clone(),
-            size,
-            kind: FunctionKind::Regular,
-        })
+        Self::with_uuid::<A>(start, &Uuid::new_v4(), None, region, name, init)
     }
 
     /// Returns the start address of the first basic block in this function
@@ -293,10 +282,29 @@ impl Function {
     }
 
     /// New function starting at `start`, with name `name`, inside memory region `region` and UUID `uuid`.
-    pub fn with_uuid<A: Architecture>(start: u64, uuid: &Uuid, region: &Region, name: Option<String>, init: A::Configuration) -> Result<Function> {
-        let mut f = Function::new::<A>(start, region, name, init)?;
-        f.uuid = uuid.clone();
-        Ok(f)
+    /// If `extent` is given, jumps leaving it are never followed and left unresolved.
+    pub fn with_uuid<A: Architecture>(start: u64, uuid: &Uuid, extent: Option<&Bound>, region: &Region, name: Option<String>, init: A::Configuration) -> Result<Function> {
+        let mut cflow_graph = AdjacencyList::new();
+        let entry_point = ControlFlowTarget::Unresolved(Rvalue::new_u64(start));
+        cflow_graph.add_vertex(entry_point);
+        let mut size = 0;
+        let name = name.unwrap_or(format!("func_{:#x}", start));
+        let entry_point = Self::disassemble::<A>(start, extent, &mut cflow_graph, &mut size, &name, uuid, region, init)?;
+        Ok(Function {
+            name,
+            aliases: Vec::new(),
+            uuid: uuid.clone(),
+            cflow_graph,
+            entry_point,
+            region: region.name().clone(),
+            size,
+            kind: FunctionKind::Regular,
+        })
+    }
+
+    /// Returns the name of the memory region the function is part of
+    pub fn region(&self) -> &String {
+        &self.region
     }
 
     /// Returns the UUID of this function
@@ -337,6 +345,22 @@ impl Function {
         self.aliases.as_slice()
     }
 
+    /// Returns the demangled form of this function's name or `None` if it isn't mangled
+    pub fn demangled_name(&self) -> Option<String> {
+        demangle::demangle(&self.name)
+    }
+
+    /// Returns this function's name as it should be shown to the user, demangled if possible
+    pub fn display_name(&self) -> String {
+        demangle::display_name(&self.name)
+    }
+
+    /// Returns true if `query` is this function's name or one of its aliases, either in mangled or
+    /// demangled form. Demangled names match with or without their parameter list.
+    pub fn is_named(&self, query: &str) -> bool {
+        demangle::matches(&self.name, query) || self.aliases.iter().any(|a| demangle::matches(a, query))
+    }
+
     /// Returns a mutable reference to this functions control flow graph; **WARNING** this can cause instability if the entry point is not correctly updated
     pub fn cfg_mut(&mut self) -> &mut ControlFlowGraph {
         &mut self.cflow_graph
@@ -1437,4 +1461,23 @@ mod tests {
         assert!(func.cflow_graph.edge(bb1_vx.unwrap(), bb2_vx.unwrap()).is_some());
         assert!(func.cflow_graph.edge(bb2_vx.unwrap(), bb01_vx.unwrap()).is_some());
     }
+
+    #[test]
+    fn demangled_names() {
+        let reg = Region::undefined("ram".to_owned(), 100);
+        let mut func = Function::undefined(0, None, &reg, Some("_ZN3foo3barEi".to_string()));
+
+        func.add_alias("_ZN3foo3bazEv".to_string());
+
+        assert_eq!(func.demangled_name(), Some("foo::bar(int)".to_string()));
+        assert_eq!(func.display_name(), "foo::bar(int)");
+        assert!(func.is_named("_ZN3foo3barEi"));
+        assert!(func.is_named("foo::bar"));
+        assert!(func.is_named("foo::baz()"));
+        assert!(!func.is_named("foo"));
+
+        func.name = "main".to_string();
+        assert_eq!(func.demangled_name(), None);
+        assert_eq!(func.display_name(), "main");
+    }
 }
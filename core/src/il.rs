@@ -54,7 +54,17 @@
 //! Each RREIL program is a sequence of instructions. The first argument of each instructions is
 //! assigned its result. The remaining arguments are only read. Arguments can be constants, variables
 //! of a special undefined value `?`. Except for the undefined value all arguments are integers with
-//! a fixed size.
+//! a fixed size. Sizes aren't limited to 64 bits, constants that don't fit into a `u64` are
+//! `Rvalue::Wide` and evaluated like any other constant.
+//!
+//! Floating point operations (`fadd`, `fsqrt`, `itof`, ...) interpret their arguments as the bit
+//! pattern of IEEE 754 values. Each of them takes an explicit rounding mode (`rne`, `rtz`, `rtn`
+//! or `rtp`). Semantics that depend on a rounding control register should default to `rne`.
+//!
+//! Instructions that can't be modeled precisely are translated into an `intrinsic`. It names the
+//! instruction, reads a list of arguments and sets a list of outputs to unknown values:
+//! `intrinsic/cpuid ?, eax:32, ecx:32 -> eax:32, ebx:32, ecx:32, edx:32`. Analyses treat the
+//! outputs like any other assignee.
 //!
 //! Memory in RREIL programs is modeled as an array of memory cells. The are accessed by the `load`
 //! and `store` instructions.
@@ -105,10 +115,29 @@
 //! # inner();
 //! # }
 //! ```
+//!
+//! Parsing Text
+//! ------------
+//!
+//! RREIL code that is only available at runtime can be read with `parse_statements`. It accepts
+//! the same syntax `Statement`, `Lvalue` and `Rvalue` are displayed in. Each of them (as well as
+//! `Guard`) implements `FromStr`, so printing and parsing IL round-trips.
+//!
+//! ```
+//! # use panopticon_core::{parse_statements,Statement};
+//! let stmts = parse_statements("
+//!     convert_8 carry:8, C:1
+//!     add res_1:8, R0:8, 0x1:8  // SSA subscripts are written as `_1`
+//!     load/ram/le/16 ptr:16, res:8/4; phi res_2:8, res:8, res_1:8
+//! ").unwrap();
+//!
+//! assert_eq!(stmts.len(), 4);
+//! assert_eq!(format!("{}", stmts[1]).parse::<Statement>().unwrap(), stmts[1]);
+//! ```
 
 use Result;
 use quickcheck::{Arbitrary, Gen};
-use serde::{Serialize,Deserialize};
+use serde::{Serialize,Deserialize,Deserializer};
 
 use std::borrow::Cow;
 use std::cmp;
@@ -116,11 +145,12 @@ use std::convert::From;
 use std::fmt::{Display, Error, Formatter, Debug};
 use std::num::Wrapping;
 use std::result;
-use std::str::{FromStr, SplitWhitespace};
+use std::str::FromStr;
 use std::u64;
+use std::usize;
 
 /// A readable RREIL value.
-#[derive(Clone,PartialEq,Eq,Debug,Serialize,Deserialize,Hash,PartialOrd,Ord)]
+#[derive(Clone,PartialEq,Eq,Debug,Serialize,Hash,PartialOrd,Ord)]
 pub enum Rvalue {
     /// Undefined value of unknown length
     Undefined,
@@ -142,6 +172,37 @@ pub enum Rvalue {
         /// Size in bits
         size: usize,
     },
+    /// Constant that doesn't fit into 64 bits. Constants of any size that do are always
+    /// represented by `Constant`, use `Rvalue::new_wide` to get the right one.
+    Wide {
+        /// Value as 64 bit limbs, least significant first. Bits above `size` are zero.
+        value: Vec<u64>,
+        /// Size in bits
+        size: usize,
+    },
+}
+
+/// Serialized form of a `Rvalue`. `Wide` constants are normalized after deserialization.
+#[derive(Deserialize)]
+#[serde(rename = "Rvalue")]
+enum RvalueData {
+    Undefined,
+    Variable { name: Cow<'static, str>, subscript: Option<usize>, offset: usize, size: usize },
+    Constant { value: u64, size: usize },
+    Wide { value: Vec<u64>, size: usize },
+}
+
+impl<'de> Deserialize<'de> for Rvalue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> result::Result<Rvalue, D::Error> {
+        Ok(
+            match RvalueData::deserialize(deserializer)? {
+                RvalueData::Undefined => Rvalue::Undefined,
+                RvalueData::Variable { name, subscript, offset, size } => Rvalue::Variable { name: name, subscript: subscript, offset: offset, size: size },
+                RvalueData::Constant { value, size } => Rvalue::Constant { value: value, size: size },
+                RvalueData::Wide { value, size } => Rvalue::new_wide(value, size),
+            }
+        )
+    }
 }
 
 impl Rvalue {
@@ -170,10 +231,37 @@ impl Rvalue {
         Rvalue::Constant { value: v, size: 64 }
     }
 
+    /// Returns a new constant of size `size` from 64 bit limbs `v`, least significant first. Bits
+    /// above `size` are cleared. The result is a `Constant` if the value fits into 64 bits and a
+    /// `Wide` constant otherwise.
+    pub fn new_wide(mut v: Vec<u64>, size: usize) -> Rvalue {
+        truncate_limbs(&mut v, size);
+
+        if v.iter().skip(1).all(|&x| x == 0) {
+            Rvalue::Constant { value: v[0], size: size }
+        } else {
+            Rvalue::Wide { value: v, size: size }
+        }
+    }
+
+    /// Returns the value of a constant as 64 bit limbs, least significant first. Bits above the
+    /// size of the constant are cleared. Returns None if `self` is not a constant.
+    pub fn limbs(&self) -> Option<Vec<u64>> {
+        let (mut v, size) = match self {
+            &Rvalue::Constant { value, size } => (vec![value], size),
+            &Rvalue::Wide { ref value, size } => (value.clone(), size),
+            _ => return None,
+        };
+
+        truncate_limbs(&mut v, size);
+        Some(v)
+    }
+
     /// Returns the size of the value in bits or None if its undefined.
     pub fn size(&self) -> Option<usize> {
         match self {
             &Rvalue::Constant { ref size, .. } => Some(*size),
+            &Rvalue::Wide { ref size, .. } => Some(*size),
             &Rvalue::Variable { ref size, .. } => Some(*size),
             &Rvalue::Undefined => None,
         }
@@ -186,9 +274,10 @@ impl Rvalue {
         }
 
         match self {
-            &Rvalue::Constant { ref size, ref value } => {
+            &Rvalue::Constant { ref size, .. } | &Rvalue::Wide { ref size, .. } => {
                 if *size >= s + o {
-                    Ok(Rvalue::Constant { size: s, value: (*value >> o) % (1 << s) })
+                    let v = self.limbs().unwrap_or(vec![]);
+                    Ok(Rvalue::new_wide(shift_right_limbs(&v, o), s))
                 } else {
                     Err("Rvalue::extract: invalid argument".into())
                 }
@@ -222,28 +311,53 @@ impl From<Lvalue> for Rvalue {
 }
 
 impl FromStr for Rvalue {
-    type Err = ();
+    type Err = ::Error;
+
+    fn from_str(s: &str) -> Result<Rvalue> {
+        let s = s.trim();
 
-    fn from_str<'a>(s: &'a str) -> result::Result<Rvalue, ()> {
         if s == "?" {
-            Ok(Rvalue::Undefined)
-        } else if let Ok(n) = u64::from_str(s) {
-            Ok(Rvalue::Constant { value: n, size: 0 })
+            return Ok(Rvalue::Undefined);
+        }
+
+        let (head, tail) = match s.find(':') {
+            Some(p) => (&s[..p], &s[p + 1..]),
+            None => return Err(format!("'{}' has no size", s).into()),
+        };
+        let (size, offset) = match tail.find('/') {
+            Some(p) => (parse_number(&tail[..p])?, parse_number(&tail[p + 1..])?),
+            None => (parse_number(tail)?, 0),
+        };
+        let constant = if head.starts_with("0x") {
+            parse_hex_limbs(&head[2..])
+        } else if !head.is_empty() && head.chars().all(|c| c.is_digit(10)) {
+            Some(vec![u64::from_str(head).map_err(|_| format!("'{}' is out of range", head))?])
         } else {
-            let mut ws: SplitWhitespace<'a> = s.split_whitespace();
-            let maybe_chr = ws.next();
-            match maybe_chr {
-                Some(s) => {
-                    Ok(
-                        Rvalue::Variable {
-                            name: Cow::Owned(s.to_string()),
-                            subscript: None,
-                            offset: 0,
-                            size: 0,
-                        }
-                    )
+            None
+        };
+
+        match constant {
+            Some(_) if offset > 0 => Err(format!("Constant '{}' can't have an offset", s).into()),
+            Some(ref value) if value.len() == 1 => Ok(Rvalue::Constant { value: value[0], size: size }),
+            Some(value) => {
+                let fits = value.iter().enumerate().all(|(i, &x)| x == 0 || 64 * i + 64 - x.leading_zeros() as usize <= size);
+
+                if fits {
+                    Ok(Rvalue::new_wide(value, size))
+                } else {
+                    Err(format!("Constant '{}' doesn't fit into {} bits", head, size).into())
                 }
-                None => Err(()),
+            }
+            None => {
+                let (name, subscript) = parse_name(head)?;
+                Ok(
+                    Rvalue::Variable {
+                        name: name,
+                        subscript: subscript,
+                        offset: offset,
+                        size: size,
+                    }
+                )
             }
         }
     }
@@ -254,6 +368,15 @@ impl Display for Rvalue {
         match self {
             &Rvalue::Undefined => f.write_str("?"),
             &Rvalue::Constant { value: v, size: s } => f.write_fmt(format_args!("0x{:x}:{}", v, s)),
+            &Rvalue::Wide { ref value, size } => {
+                let mut limbs = value.iter().rev().skip_while(|&&x| x == 0);
+
+                f.write_fmt(format_args!("0x{:x}", limbs.next().cloned().unwrap_or(0)))?;
+                for l in limbs {
+                    f.write_fmt(format_args!("{:016x}", l))?;
+                }
+                f.write_fmt(format_args!(":{}", size))
+            }
             &Rvalue::Variable { ref name, ref subscript, ref offset, ref size } => {
                 f.write_str(name)?;
                 if let &Some(ss) = subscript {
@@ -336,6 +459,14 @@ impl Display for Lvalue {
     }
 }
 
+impl FromStr for Lvalue {
+    type Err = ::Error;
+
+    fn from_str(s: &str) -> Result<Lvalue> {
+        Lvalue::from_rvalue(s.parse::<Rvalue>()?).ok_or_else(|| format!("'{}' is not writeable", s.trim()).into())
+    }
+}
+
 /// Branch condition
 #[derive(Clone,PartialEq,Eq,Debug,Serialize,Deserialize)]
 pub enum Guard {
@@ -390,13 +521,62 @@ impl Display for Guard {
         match self {
             &Guard::True => f.write_str("true"),
             &Guard::False => f.write_str("false"),
-            &Guard::Predicate { flag: Rvalue::Variable { ref name, .. }, expected: true } => f.write_fmt(format_args!("{}", name)),
-            &Guard::Predicate { flag: Rvalue::Variable { ref name, .. }, expected: false } => f.write_fmt(format_args!("¬{}", name)),
+            &Guard::Predicate { flag: Rvalue::Variable { ref name, subscript, offset: 0, size: 1 }, expected } => {
+                if !expected {
+                    f.write_str("¬")?;
+                }
+                f.write_str(name)?;
+                if let Some(ss) = subscript {
+                    f.write_fmt(format_args!("_{}", ss))?;
+                }
+                Ok(())
+            }
             &Guard::Predicate { ref flag, ref expected } => f.write_fmt(format_args!("({} == {})", flag, expected)),
         }
     }
 }
 
+impl FromStr for Guard {
+    type Err = ::Error;
+
+    fn from_str(s: &str) -> Result<Guard> {
+        let s = s.trim();
+
+        if s == "true" {
+            Ok(Guard::True)
+        } else if s == "false" {
+            Ok(Guard::False)
+        } else if s.starts_with('(') && s.ends_with(')') {
+            let inner = &s[1..s.len() - 1];
+            let p = inner.find("==").ok_or_else(|| format!("'{}' is not a guard", s))?;
+            let expected = match inner[p + 2..].trim() {
+                "true" => true,
+                "false" => false,
+                _ => return Err(format!("'{}' is not a guard", s).into()),
+            };
+
+            Ok(Guard::Predicate { flag: inner[..p].parse::<Rvalue>()?, expected: expected })
+        } else {
+            let (flag, expected) = if s.starts_with("¬") {
+                (&s["¬".len()..], false)
+            } else if s.starts_with('!') {
+                (&s[1..], false)
+            } else {
+                (s, true)
+            };
+            let (name, subscript) = parse_name(flag.trim())?;
+            let flag = Rvalue::Variable {
+                name: name,
+                subscript: subscript,
+                offset: 0,
+                size: 1,
+            };
+
+            Ok(Guard::Predicate { flag: flag, expected: expected })
+        }
+    }
+}
+
 /// Endianess of a memory operation.
 #[derive(Debug,Clone,Copy,PartialEq,Eq,Serialize,Deserialize)]
 pub enum Endianess {
@@ -415,6 +595,56 @@ impl Display for Endianess {
     }
 }
 
+impl FromStr for Endianess {
+    type Err = ::Error;
+
+    fn from_str(s: &str) -> Result<Endianess> {
+        match s.trim() {
+            "le" => Ok(Endianess::Little),
+            "be" => Ok(Endianess::Big),
+            _ => Err(format!("'{}' is not an endianess", s).into()),
+        }
+    }
+}
+
+/// Rounding direction of a floating point operation.
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Serialize,Deserialize)]
+pub enum Rounding {
+    /// Round to the nearest value, ties to even. Default of IEEE 754.
+    NearestEven,
+    /// Round toward zero (truncate)
+    TowardZero,
+    /// Round toward negative infinity
+    TowardNegative,
+    /// Round toward positive infinity
+    TowardPositive,
+}
+
+impl Display for Rounding {
+    fn fmt(&self, f: &mut Formatter) -> result::Result<(), Error> {
+        match self {
+            &Rounding::NearestEven => f.write_str("rne"),
+            &Rounding::TowardZero => f.write_str("rtz"),
+            &Rounding::TowardNegative => f.write_str("rtn"),
+            &Rounding::TowardPositive => f.write_str("rtp"),
+        }
+    }
+}
+
+impl FromStr for Rounding {
+    type Err = ::Error;
+
+    fn from_str(s: &str) -> Result<Rounding> {
+        match s.trim() {
+            "rne" => Ok(Rounding::NearestEven),
+            "rtz" => Ok(Rounding::TowardZero),
+            "rtn" => Ok(Rounding::TowardNegative),
+            "rtp" => Ok(Rounding::TowardPositive),
+            _ => Err(format!("'{}' is not a rounding mode", s).into()),
+        }
+    }
+}
+
 /// A RREIL operation.
 #[derive(Clone,PartialEq,Eq,Debug,Serialize,Deserialize)]
 #[serde(bound(deserialize = "V: Serialize + for<'a> Deserialize<'a> + Clone + PartialEq + Eq + Debug"))]
@@ -479,6 +709,41 @@ pub enum Operation<V>
     /// Writes a memory cell pointed by 1st V w/ 2nd V, returns Undef
     Store(Cow<'static,str>,Endianess,usize,V,V),
 
+    /// IEEE 754 addition. Floating point operands are 32 or 64 bits large, x87 extended precision
+    /// values are modeled with intrinsics.
+    FloatAdd(Rounding, V, V),
+    /// IEEE 754 subtraction
+    FloatSubtract(Rounding, V, V),
+    /// IEEE 754 multiplication
+    FloatMultiply(Rounding, V, V),
+    /// IEEE 754 division
+    FloatDivide(Rounding, V, V),
+    /// IEEE 754 square root
+    FloatSquareRoot(Rounding, V),
+    /// Rounds the operand to an integral value of the same format.
+    FloatRound(Rounding, V),
+    /// Returns `1` if both operands are equal and neither is NaN, `0` otherwise.
+    FloatEqual(V, V),
+    /// Returns `1` if the first operand is less than the second and neither is NaN, `0` otherwise.
+    FloatLess(V, V),
+    /// Returns `1` if the first operand is less than or equal to the second and neither is NaN,
+    /// `0` otherwise.
+    FloatLessOrEqual(V, V),
+    /// Returns `1` if at least one operand is NaN, `0` otherwise.
+    FloatUnordered(V, V),
+    /// Converts a signed integer into a floating point value of the given size.
+    IntToFloat(Rounding, usize, V),
+    /// Converts a floating point value into a signed integer of the given size. NaN and values
+    /// out of range are undefined.
+    FloatToInt(Rounding, usize, V),
+    /// Converts a floating point value into another floating point format.
+    FloatToFloat(Rounding, usize, V),
+
+    /// Opaque operation that reads its inputs and sets all listed outputs to unknown values.
+    /// Used for instructions that can't be modeled precisely like `cpuid`. The assignee of the
+    /// statement is always Undefined, the outputs are defined by the operation instead.
+    Intrinsic(Cow<'static,str>,Vec<V>,Vec<Lvalue>),
+
     /// SSA Phi function
     Phi(Vec<V>),
 }
@@ -493,6 +758,22 @@ pub struct Statement {
 }
 
 impl Statement {
+    /// Returns all values written by the statement: the assignee and the outputs of an intrinsic.
+    pub fn assignees(&self) -> Vec<&Lvalue> {
+        match self.op {
+            Operation::Intrinsic(_, _, ref outputs) => Some(&self.assignee).into_iter().chain(outputs.iter()).collect(),
+            _ => vec![&self.assignee],
+        }
+    }
+
+    /// Returns all values written by the statement: the assignee and the outputs of an intrinsic.
+    pub fn assignees_mut(&mut self) -> Vec<&mut Lvalue> {
+        match self {
+            &mut Statement { op: Operation::Intrinsic(_, _, ref mut outputs), ref mut assignee } => Some(assignee).into_iter().chain(outputs.iter_mut()).collect(),
+            &mut Statement { ref mut assignee, .. } => vec![assignee],
+        }
+    }
+
     /// Does a simple sanity check. The functions returns Err if
     /// - The argument size are not equal
     /// - The result has not the same size as `assignee`
@@ -534,6 +815,33 @@ impl Statement {
             }
             Ok(())
         };
+        // floating point values are IEEE 754 binary32 or binary64. These are the only formats execute() supports.
+        let check_float_size = |v: Option<usize>| -> Result<()> {
+            match v {
+                None | Some(32) | Some(64) => Ok(()),
+                Some(sz) => Err(format!("{} bits is not a floating point format", sz).into()),
+            }
+        };
+        let typecheck_float_binop = |a: &Rvalue, b: &Rvalue, assignee: &Lvalue| -> Result<()> {
+            check_float_size(a.size())?;
+            check_float_size(b.size())?;
+            check_float_size(assignee.size())?;
+            typecheck_binop(a, b, assignee)
+        };
+        let typecheck_float_cmpop = |a: &Rvalue, b: &Rvalue, assignee: &Lvalue| -> Result<()> {
+            check_float_size(a.size())?;
+            check_float_size(b.size())?;
+            typecheck_cmpop(a, b, assignee)
+        };
+        let typecheck_float_conv = |to: usize, assignee: &Lvalue| -> Result<()> {
+            if to == 0 {
+                return Err("Conversion to a value of size 0".into());
+            }
+            if !(assignee.size() == None || assignee.size() == Some(to)) {
+                return Err("Operation result and assingnee sizes mismatch".into());
+            }
+            Ok(())
+        };
 
         match self {
             &Statement { op: Operation::Add(ref a, ref b), ref assignee } => typecheck_binop(a, b, assignee),
@@ -614,6 +922,45 @@ impl Statement {
                 }
             }
 
+            &Statement { op: Operation::FloatAdd(_, ref a, ref b), ref assignee } => typecheck_float_binop(a, b, assignee),
+            &Statement { op: Operation::FloatSubtract(_, ref a, ref b), ref assignee } => typecheck_float_binop(a, b, assignee),
+            &Statement { op: Operation::FloatMultiply(_, ref a, ref b), ref assignee } => typecheck_float_binop(a, b, assignee),
+            &Statement { op: Operation::FloatDivide(_, ref a, ref b), ref assignee } => typecheck_float_binop(a, b, assignee),
+            &Statement { op: Operation::FloatSquareRoot(_, ref a), ref assignee } => typecheck_float_binop(a, a, assignee),
+            &Statement { op: Operation::FloatRound(_, ref a), ref assignee } => typecheck_float_binop(a, a, assignee),
+            &Statement { op: Operation::FloatEqual(ref a, ref b), ref assignee } => typecheck_float_cmpop(a, b, assignee),
+            &Statement { op: Operation::FloatLess(ref a, ref b), ref assignee } => typecheck_float_cmpop(a, b, assignee),
+            &Statement { op: Operation::FloatLessOrEqual(ref a, ref b), ref assignee } => typecheck_float_cmpop(a, b, assignee),
+            &Statement { op: Operation::FloatUnordered(ref a, ref b), ref assignee } => typecheck_float_cmpop(a, b, assignee),
+            &Statement { op: Operation::IntToFloat(_, sz, ref a), ref assignee } => {
+                match a.size() {
+                    Some(s) if s > 64 => return Err(format!("{} bit integers can't be converted to floating point", s).into()),
+                    _ => {}
+                }
+                check_float_size(Some(sz))?;
+                typecheck_float_conv(sz, assignee)
+            }
+            &Statement { op: Operation::FloatToInt(_, sz, ref a), ref assignee } => {
+                check_float_size(a.size())?;
+                typecheck_float_conv(sz, assignee)
+            }
+            &Statement { op: Operation::FloatToFloat(_, sz, ref a), ref assignee } => {
+                check_float_size(a.size())?;
+                check_float_size(Some(sz))?;
+                typecheck_float_conv(sz, assignee)
+            }
+
+            &Statement { op: Operation::Intrinsic(ref name, _, ref outputs), ref assignee } => {
+                if name.is_empty() {
+                    return Err("Intrinsic without name".into());
+                } else if assignee != &Lvalue::Undefined {
+                    return Err("Intrinsic operation can only be assigned to Undefined".into());
+                } else if outputs.iter().any(|o| o == &Lvalue::Undefined) {
+                    return Err("Intrinsic outputs can't be Undefined".into());
+                } else {
+                    Ok(())
+                }
+            }
 
             &Statement { op: Operation::Phi(ref vec), ref assignee } => {
                 if !(vec.iter().all(|rv| rv.size() == assignee.size()) && assignee.size() != None) {
@@ -624,7 +971,7 @@ impl Statement {
             }
         }?;
 
-        if !(self.op.operands().iter().all(|rv| rv.size() != Some(0)) && self.assignee.size() != Some(0)) {
+        if !(self.op.operands().iter().all(|rv| rv.size() != Some(0)) && self.assignees().iter().all(|lv| lv.size() != Some(0))) {
             return Err("Operation argument and/or assignee has size 0".into());
         }
 
@@ -632,8 +979,23 @@ impl Statement {
     }
 }
 
-/// Executes a RREIL operation returning the result.
-pub fn execute(op: Operation<Rvalue>) -> Rvalue {
+/// Executes a RREIL operation returning the result. Integer operations support constants of any
+/// size.
+pub fn execute(mut op: Operation<Rvalue>) -> Rvalue {
+    // `Wide` values built by hand may fit into a `Constant`
+    for o in op.operands_mut() {
+        let normalized = match o {
+            &mut Rvalue::Wide { ref value, size } => Rvalue::new_wide(value.clone(), size),
+            _ => continue,
+        };
+
+        *o = normalized;
+    }
+
+    if let Some(rv) = execute_wide(&op) {
+        return rv;
+    }
+
     match op {
         Operation::Add(Rvalue::Constant { value: _a, size: s }, Rvalue::Constant { value: _b, size: _s }) => {
             debug_assert!(s == _s);
@@ -988,7 +1350,7 @@ pub fn execute(op: Operation<Rvalue>) -> Rvalue {
                 size: s,
             }
         }
-        Operation::ZeroExtend(_, Rvalue::Undefined) => Rvalue::Undefined,
+        Operation::ZeroExtend(_, _) => Rvalue::Undefined,
 
         Operation::SignExtend(t, Rvalue::Constant { value: v, size: s, .. }) => {
             let mask0 = if s < 64 { (1u64 << s) - 1 } else { u64::MAX };
@@ -1010,7 +1372,7 @@ pub fn execute(op: Operation<Rvalue>) -> Rvalue {
                 size: s,
             }
         }
-        Operation::SignExtend(_, Rvalue::Undefined) => Rvalue::Undefined,
+        Operation::SignExtend(_, _) => Rvalue::Undefined,
 
         Operation::Move(Rvalue::Constant { ref value, ref size }) => {
             if *size < 64 {
@@ -1025,23 +1387,144 @@ pub fn execute(op: Operation<Rvalue>) -> Rvalue {
 
         Operation::Call(_) => Rvalue::Undefined,
 
-        Operation::Select(off, Rvalue::Constant { value: _a, size: s }, Rvalue::Constant { value: _b, size: _s }) => {
-            debug_assert!(off + _s <= s);
-
-            if off + _s < 64 {
-                let hi = _a >> (off + _s);
-                let lo = _a % (1 << off);
-                Rvalue::Constant { value: lo | (_b << off) | (hi << (off + _s)), size: s }
-            } else {
-                Rvalue::Undefined
-            }
-        }
         Operation::Select(_, _, _) => Rvalue::Undefined,
 
         Operation::Load(_, _, _, _) => Rvalue::Undefined,
 
         Operation::Store(_, _, _, _, _) => Rvalue::Undefined,
 
+        // floating point operations are computed in double precision alongside an error term
+        // that is used to implement directed rounding.
+        Operation::FloatAdd(m, Rvalue::Constant { value: a, size: s }, Rvalue::Constant { value: b, size: _s }) => {
+            debug_assert!(s == _s);
+            execute_float_binop(
+                a, b, s, m, &|a, b| {
+                    let x = a + b;
+                    let bb = x - a;
+                    (x, (a - (x - bb)) + (b - bb))
+                }
+            )
+        }
+        Operation::FloatAdd(_, _, _) => Rvalue::Undefined,
+        Operation::FloatSubtract(m, Rvalue::Constant { value: a, size: s }, Rvalue::Constant { value: b, size: _s }) => {
+            debug_assert!(s == _s);
+            execute_float_binop(
+                a, b, s, m, &|a, b| {
+                    let x = a - b;
+                    let bb = x - a;
+                    (x, (a - (x - bb)) - (b + bb))
+                }
+            )
+        }
+        Operation::FloatSubtract(_, _, _) => Rvalue::Undefined,
+        Operation::FloatMultiply(m, Rvalue::Constant { value: a, size: s }, Rvalue::Constant { value: b, size: _s }) => {
+            debug_assert!(s == _s);
+            execute_float_binop(
+                a, b, s, m, &|a, b| {
+                    let x = a * b;
+                    (x, a.mul_add(b, -x))
+                }
+            )
+        }
+        Operation::FloatMultiply(_, _, _) => Rvalue::Undefined,
+        Operation::FloatDivide(m, Rvalue::Constant { value: a, size: s }, Rvalue::Constant { value: b, size: _s }) => {
+            debug_assert!(s == _s);
+            execute_float_binop(
+                a, b, s, m, &|a, b| {
+                    let x = a / b;
+                    let rem = (-x).mul_add(b, a);
+                    (x, if b < 0.0 { -rem } else { rem })
+                }
+            )
+        }
+        Operation::FloatDivide(_, _, _) => Rvalue::Undefined,
+        Operation::FloatSquareRoot(m, Rvalue::Constant { value: a, size: s }) => {
+            execute_float_binop(
+                a, a, s, m, &|a, _| {
+                    let x = a.sqrt();
+                    (x, (-x).mul_add(x, a))
+                }
+            )
+        }
+        Operation::FloatSquareRoot(_, _) => Rvalue::Undefined,
+        Operation::FloatRound(m, Rvalue::Constant { value: a, size: s }) => {
+            match float_value(a, s) {
+                Some(a) => Rvalue::Constant { value: float_bits(round_integral(a, m), s), size: s },
+                None => Rvalue::Undefined,
+            }
+        }
+        Operation::FloatRound(_, _) => Rvalue::Undefined,
+
+        Operation::FloatEqual(Rvalue::Constant { value: a, size: s }, Rvalue::Constant { value: b, size: _s }) => {
+            debug_assert!(s == _s);
+            execute_float_cmpop(a, b, s, &|a, b| a == b)
+        }
+        Operation::FloatEqual(_, _) => Rvalue::Undefined,
+        Operation::FloatLess(Rvalue::Constant { value: a, size: s }, Rvalue::Constant { value: b, size: _s }) => {
+            debug_assert!(s == _s);
+            execute_float_cmpop(a, b, s, &|a, b| a < b)
+        }
+        Operation::FloatLess(_, _) => Rvalue::Undefined,
+        Operation::FloatLessOrEqual(Rvalue::Constant { value: a, size: s }, Rvalue::Constant { value: b, size: _s }) => {
+            debug_assert!(s == _s);
+            execute_float_cmpop(a, b, s, &|a, b| a <= b)
+        }
+        Operation::FloatLessOrEqual(_, _) => Rvalue::Undefined,
+        Operation::FloatUnordered(Rvalue::Constant { value: a, size: s }, Rvalue::Constant { value: b, size: _s }) => {
+            debug_assert!(s == _s);
+            execute_float_cmpop(a, b, s, &|a, b| a.is_nan() || b.is_nan())
+        }
+        Operation::FloatUnordered(_, _) => Rvalue::Undefined,
+
+        Operation::IntToFloat(m, t, Rvalue::Constant { value: v, size: s }) => {
+            if s == 0 || s > 64 {
+                return Rvalue::Undefined;
+            }
+
+            let i = ((v << (64 - s)) as i64) >> (64 - s);
+            // 2^63 is the only result not representable as i64
+            let (x, err) = match t {
+                32 => {
+                    let x = i as f32;
+                    (x as f64, if x as f64 >= 9223372036854775808.0 { -1 } else { i - x as i64 })
+                }
+                64 => {
+                    let x = i as f64;
+                    (x, if x >= 9223372036854775808.0 { -1 } else { i - x as i64 })
+                }
+                _ => return Rvalue::Undefined,
+            };
+
+            round_float(x, err as f64, t, m)
+        }
+        Operation::IntToFloat(_, _, _) => Rvalue::Undefined,
+        Operation::FloatToInt(m, t, Rvalue::Constant { value: v, size: s }) => {
+            match float_value(v, s) {
+                Some(x) if !x.is_nan() && t > 0 && t <= 64 => {
+                    let r = round_integral(x, m);
+                    let lim = 2f64.powi(t as i32 - 1);
+
+                    if r < -lim || r >= lim {
+                        Rvalue::Undefined
+                    } else {
+                        let mask = if t < 64 { (1u64 << t) - 1 } else { u64::MAX };
+                        Rvalue::Constant { value: (r as i64) as u64 & mask, size: t }
+                    }
+                }
+                _ => Rvalue::Undefined,
+            }
+        }
+        Operation::FloatToInt(_, _, _) => Rvalue::Undefined,
+        Operation::FloatToFloat(m, t, Rvalue::Constant { value: v, size: s }) => {
+            match float_value(v, s) {
+                Some(x) if t == 32 || t == 64 => round_float(x, 0.0, t, m),
+                _ => Rvalue::Undefined,
+            }
+        }
+        Operation::FloatToFloat(_, _, _) => Rvalue::Undefined,
+
+        Operation::Intrinsic(_, _, _) => Rvalue::Undefined,
+
         Operation::Phi(ref vec) => {
             match vec.len() {
                 0 => Rvalue::Undefined,
@@ -1058,6 +1541,385 @@ pub fn execute(op: Operation<Rvalue>) -> Rvalue {
     }
 }
 
+// Evaluates integer operations on constants if one of them is wider than 64 bits, as well as
+// extensions to more than 64 bits and all selects. Returns None for everything else.
+fn execute_wide(op: &Operation<Rvalue>) -> Option<Rvalue> {
+    let mut args = vec![];
+    let mut wide = false;
+
+    for o in op.operands() {
+        match (o.limbs(), o.size()) {
+            (Some(v), Some(sz)) => {
+                wide |= sz > 64;
+                args.push((v, sz));
+            }
+            _ => return None,
+        }
+    }
+
+    let res = match op {
+        &Operation::ZeroExtend(sz, _) if wide || sz > 64 => Rvalue::new_wide(args[0].0.clone(), sz),
+        &Operation::SignExtend(sz, _) if wide || sz > 64 => {
+            let (ref v, s) = args[0];
+            let mut v = v.clone();
+
+            if s > 0 && s < sz && limb_bit(&v, s - 1) {
+                v.resize(limb_count(sz), 0);
+                for i in s..sz {
+                    set_limb_bit(&mut v, i, true);
+                }
+            }
+
+            Rvalue::new_wide(v, sz)
+        }
+        &Operation::Select(off, _, _) => {
+            let (mut v, s) = args[0].clone();
+            let (ref b, bs) = args[1];
+
+            if off + bs > s {
+                return Some(Rvalue::Undefined);
+            }
+            for i in 0..bs {
+                set_limb_bit(&mut v, off + i, limb_bit(b, i));
+            }
+
+            Rvalue::new_wide(v, s)
+        }
+        _ if !wide => return None,
+        &Operation::Move(_) => Rvalue::new_wide(args[0].0.clone(), args[0].1),
+        _ if args.len() != 2 || args[0].1 != args[1].1 || args[0].1 == 0 => return None,
+        _ => {
+            let (ref a, s) = args[0];
+            let b = &args[1].0;
+            let bool_value = |x: bool| Rvalue::new_bit(if x { 1 } else { 0 });
+
+            match op {
+                &Operation::Add(_, _) => Rvalue::new_wide(add_limbs(a, b), s),
+                &Operation::Subtract(_, _) => Rvalue::new_wide(sub_limbs(a, b), s),
+                &Operation::Multiply(_, _) => Rvalue::new_wide(mul_limbs(a, b), s),
+                &Operation::DivideUnsigned(_, _) | &Operation::Modulo(_, _) if b.iter().all(|&x| x == 0) => Rvalue::Undefined,
+                &Operation::DivideUnsigned(_, _) => Rvalue::new_wide(div_limbs(a, b, s).0, s),
+                &Operation::Modulo(_, _) => Rvalue::new_wide(div_limbs(a, b, s).1, s),
+                &Operation::DivideSigned(_, _) => {
+                    if b.iter().all(|&x| x == 0) {
+                        Rvalue::Undefined
+                    } else {
+                        let (na, nb) = (limb_bit(a, s - 1), limb_bit(b, s - 1));
+                        let abs_a = if na { neg_limbs(a, s) } else { a.clone() };
+                        let abs_b = if nb { neg_limbs(b, s) } else { b.clone() };
+                        let q = div_limbs(&abs_a, &abs_b, s).0;
+
+                        Rvalue::new_wide(if na != nb { neg_limbs(&q, s) } else { q }, s)
+                    }
+                }
+                &Operation::ShiftLeft(_, _) => Rvalue::new_wide(shift_left_limbs(a, shift_amount(b)), s),
+                &Operation::ShiftRightUnsigned(_, _) => Rvalue::new_wide(shift_right_limbs(a, shift_amount(b)), s),
+                &Operation::ShiftRightSigned(_, _) => {
+                    let n = shift_amount(b);
+                    let mut v = shift_right_limbs(a, n);
+
+                    if limb_bit(a, s - 1) {
+                        for i in s.saturating_sub(n)..s {
+                            set_limb_bit(&mut v, i, true);
+                        }
+                    }
+
+                    Rvalue::new_wide(v, s)
+                }
+                &Operation::And(_, _) => Rvalue::new_wide(a.iter().zip(b.iter()).map(|(x, y)| x & y).collect(), s),
+                &Operation::InclusiveOr(_, _) => Rvalue::new_wide(a.iter().zip(b.iter()).map(|(x, y)| x | y).collect(), s),
+                &Operation::ExclusiveOr(_, _) => Rvalue::new_wide(a.iter().zip(b.iter()).map(|(x, y)| x ^ y).collect(), s),
+                &Operation::Equal(_, _) => bool_value(a == b),
+                &Operation::LessOrEqualUnsigned(_, _) => bool_value(cmp_limbs(a, b) != cmp::Ordering::Greater),
+                &Operation::LessUnsigned(_, _) => bool_value(cmp_limbs(a, b) == cmp::Ordering::Less),
+                &Operation::LessOrEqualSigned(_, _) | &Operation::LessSigned(_, _) => {
+                    // flipping the sign bits maps signed to unsigned order
+                    let (mut a, mut b) = (a.clone(), b.clone());
+                    let (sa, sb) = (limb_bit(&a, s - 1), limb_bit(&b, s - 1));
+
+                    set_limb_bit(&mut a, s - 1, !sa);
+                    set_limb_bit(&mut b, s - 1, !sb);
+
+                    match op {
+                        &Operation::LessSigned(_, _) => bool_value(cmp_limbs(&a, &b) == cmp::Ordering::Less),
+                        _ => bool_value(cmp_limbs(&a, &b) != cmp::Ordering::Greater),
+                    }
+                }
+                _ => return None,
+            }
+        }
+    };
+
+    Some(res)
+}
+
+/// Number of 64 bit limbs needed to store `size` bits.
+fn limb_count(size: usize) -> usize {
+    cmp::max(1, (size + 63) / 64)
+}
+
+/// Resizes `v` to `limb_count(size)` limbs and clears all bits above `size`.
+fn truncate_limbs(v: &mut Vec<u64>, size: usize) {
+    let n = limb_count(size);
+
+    v.resize(n, 0);
+    if size < n * 64 {
+        v[n - 1] &= (1u64 << (size % 64)) - 1;
+    }
+}
+
+fn limb_bit(v: &[u64], i: usize) -> bool {
+    v.get(i / 64).map(|x| (x >> (i % 64)) & 1 == 1).unwrap_or(false)
+}
+
+fn set_limb_bit(v: &mut Vec<u64>, i: usize, bit: bool) {
+    if let Some(x) = v.get_mut(i / 64) {
+        if bit {
+            *x |= 1 << (i % 64);
+        } else {
+            *x &= !(1 << (i % 64));
+        }
+    }
+}
+
+fn cmp_limbs(a: &[u64], b: &[u64]) -> cmp::Ordering {
+    a.iter().rev().cmp(b.iter().rev())
+}
+
+fn shift_amount(v: &[u64]) -> usize {
+    if v.iter().skip(1).any(|&x| x != 0) || v[0] > usize::MAX as u64 {
+        usize::MAX
+    } else {
+        v[0] as usize
+    }
+}
+
+fn add_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut carry = false;
+
+    a.iter()
+        .zip(b.iter())
+        .map(
+            |(&x, &y)| {
+                let (r, c1) = x.overflowing_add(y);
+                let (r, c2) = r.overflowing_add(carry as u64);
+
+                carry = c1 || c2;
+                r
+            }
+        )
+        .collect()
+}
+
+fn sub_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut borrow = false;
+
+    a.iter()
+        .zip(b.iter())
+        .map(
+            |(&x, &y)| {
+                let (r, b1) = x.overflowing_sub(y);
+                let (r, b2) = r.overflowing_sub(borrow as u64);
+
+                borrow = b1 || b2;
+                r
+            }
+        )
+        .collect()
+}
+
+/// Two's complement of the `size` bit value `v`.
+fn neg_limbs(v: &[u64], size: usize) -> Vec<u64> {
+    let mut ret = sub_limbs(&vec![0; v.len()], v);
+
+    truncate_limbs(&mut ret, size);
+    ret
+}
+
+/// Product of `a` and `b` truncated to their length. Computed in 32 bit digits to keep
+/// intermediate results inside 64 bits.
+fn mul_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let digits = |v: &[u64]| v.iter().flat_map(|&x| vec![x as u32, (x >> 32) as u32]).collect::<Vec<u32>>();
+    let (x, y) = (digits(a), digits(b));
+    let n = x.len();
+    let mut r = vec![0u32; n];
+
+    for i in 0..n {
+        let mut carry = 0u64;
+
+        for j in 0..(n - i) {
+            let t = x[i] as u64 * y[j] as u64 + r[i + j] as u64 + carry;
+
+            r[i + j] = t as u32;
+            carry = t >> 32;
+        }
+    }
+
+    (0..a.len()).map(|i| r[2 * i] as u64 | (r[2 * i + 1] as u64) << 32).collect()
+}
+
+/// Unsigned quotient and remainder of the `size` bit values `a` and `b`. `b` must not be zero.
+fn div_limbs(a: &[u64], b: &[u64], size: usize) -> (Vec<u64>, Vec<u64>) {
+    // one extra limb keeps the shifted remainder from overflowing
+    let mut b = b.to_vec();
+    let mut q = vec![0; a.len()];
+    let mut r = vec![0; a.len() + 1];
+
+    b.push(0);
+    for i in (0..size).rev() {
+        r = shift_left_limbs(&r, 1);
+        set_limb_bit(&mut r, 0, limb_bit(a, i));
+
+        if cmp_limbs(&r, &b) != cmp::Ordering::Less {
+            r = sub_limbs(&r, &b);
+            set_limb_bit(&mut q, i, true);
+        }
+    }
+
+    r.pop();
+    (q, r)
+}
+
+fn shift_left_limbs(v: &[u64], n: usize) -> Vec<u64> {
+    let (q, r) = (n / 64, n % 64);
+
+    (0..v.len())
+        .map(
+            |i| if i < q {
+                0
+            } else {
+                let hi = if r > 0 && i > q { v[i - q - 1] >> (64 - r) } else { 0 };
+                (v[i - q] << r) | hi
+            }
+        )
+        .collect()
+}
+
+fn shift_right_limbs(v: &[u64], n: usize) -> Vec<u64> {
+    let (q, r) = (n / 64, n % 64);
+
+    (0..v.len())
+        .map(
+            |i| if i + q >= v.len() {
+                0
+            } else {
+                let lo = if r > 0 && i + q + 1 < v.len() { v[i + q + 1] << (64 - r) } else { 0 };
+                (v[i + q] >> r) | lo
+            }
+        )
+        .collect()
+}
+
+/// Parses a hexadecimal number of any length into 64 bit limbs, least significant first.
+fn parse_hex_limbs(s: &str) -> Option<Vec<u64>> {
+    if s.is_empty() || !s.chars().all(|c| c.is_digit(16)) {
+        return None;
+    }
+
+    let mut ret = vec![];
+    let mut end = s.len();
+
+    while end > 0 {
+        let start = end.saturating_sub(16);
+
+        ret.push(u64::from_str_radix(&s[start..end], 16).unwrap_or(0));
+        end = start;
+    }
+
+    Some(ret)
+}
+
+/// Decodes a IEEE 754 binary32 or binary64 value. Other formats are not supported.
+fn float_value(v: u64, sz: usize) -> Option<f64> {
+    match sz {
+        32 => Some(f32::from_bits(v as u32) as f64),
+        64 => Some(f64::from_bits(v)),
+        _ => None,
+    }
+}
+
+/// Encodes `v` as a `sz` bit IEEE 754 value, rounding to nearest.
+fn float_bits(v: f64, sz: usize) -> u64 {
+    if sz == 32 {
+        (v as f32).to_bits() as u64
+    } else {
+        v.to_bits()
+    }
+}
+
+/// Returns the neighbour of the `sz` bit float `bits` toward positive (`up`) or negative infinity.
+fn next_float(bits: u64, sz: usize, up: bool) -> u64 {
+    let sign = 1u64 << (sz - 1);
+    let negative = bits & sign != 0;
+
+    if bits & (sign - 1) == 0 {
+        if up { 1 } else { sign | 1 }
+    } else if negative == up {
+        bits - 1
+    } else {
+        bits + 1
+    }
+}
+
+/// Rounds `x` to a `sz` bit float. `err` has the sign of the difference between the exact
+/// result and `x`. Results that can't be rounded exactly are undefined.
+fn round_float(x: f64, err: f64, sz: usize, mode: Rounding) -> Rvalue {
+    if x.is_nan() || mode == Rounding::NearestEven {
+        return Rvalue::Constant { value: float_bits(x, sz), size: sz };
+    }
+    if x.is_infinite() || err.is_nan() {
+        return Rvalue::Undefined;
+    }
+
+    let bits = float_bits(x, sz);
+    let r = float_value(bits, sz).unwrap();
+    let err = if r != x { x - r } else { err };
+    let value = match mode {
+        Rounding::TowardPositive if err > 0.0 => next_float(bits, sz, true),
+        Rounding::TowardNegative if err < 0.0 => next_float(bits, sz, false),
+        Rounding::TowardZero if err > 0.0 && r < 0.0 => next_float(bits, sz, true),
+        Rounding::TowardZero if err < 0.0 && r > 0.0 => next_float(bits, sz, false),
+        _ => bits,
+    };
+
+    Rvalue::Constant { value: value, size: sz }
+}
+
+/// Rounds `x` to an integral value.
+fn round_integral(x: f64, mode: Rounding) -> f64 {
+    let r = match mode {
+        Rounding::TowardZero => x.trunc(),
+        Rounding::TowardNegative => x.floor(),
+        Rounding::TowardPositive => x.ceil(),
+        Rounding::NearestEven => {
+            let f = x.floor();
+            let d = x - f;
+
+            if d < 0.5 || (d == 0.5 && f % 2.0 == 0.0) { f } else { f + 1.0 }
+        }
+    };
+
+    // keep the sign of zero
+    if r == 0.0 && x.is_sign_negative() { -0.0 } else { r }
+}
+
+fn execute_float_binop(a: u64, b: u64, sz: usize, mode: Rounding, f: &Fn(f64, f64) -> (f64, f64)) -> Rvalue {
+    match (float_value(a, sz), float_value(b, sz)) {
+        (Some(a), Some(b)) => {
+            let (x, err) = f(a, b);
+            round_float(x, err, sz, mode)
+        }
+        _ => Rvalue::Undefined,
+    }
+}
+
+fn execute_float_cmpop(a: u64, b: u64, sz: usize, f: &Fn(f64, f64) -> bool) -> Rvalue {
+    match (float_value(a, sz), float_value(b, sz)) {
+        (Some(a), Some(b)) => Rvalue::Constant { value: if f(a, b) { 1 } else { 0 }, size: 1 },
+        _ => Rvalue::Undefined,
+    }
+}
+
 /// Maps the function `m` over all operands of `op`.
 pub fn lift<A, B, F>(op: &Operation<B>, m: &F) -> Operation<A>
     where A: Serialize + for<'a> Deserialize<'a> + Clone + PartialEq + Eq + Debug,
@@ -1092,6 +1954,20 @@ pub fn lift<A, B, F>(op: &Operation<B>, m: &F) -> Operation<A>
         &Operation::Select(ref off, _, _) => Operation::Select(*off, args[0].clone(), args[1].clone()),
         &Operation::ZeroExtend(ref sz, _) => Operation::ZeroExtend(*sz, args[0].clone()),
         &Operation::SignExtend(ref sz, _) => Operation::SignExtend(*sz, args[0].clone()),
+        &Operation::FloatAdd(m, _, _) => Operation::FloatAdd(m, args[0].clone(), args[1].clone()),
+        &Operation::FloatSubtract(m, _, _) => Operation::FloatSubtract(m, args[0].clone(), args[1].clone()),
+        &Operation::FloatMultiply(m, _, _) => Operation::FloatMultiply(m, args[0].clone(), args[1].clone()),
+        &Operation::FloatDivide(m, _, _) => Operation::FloatDivide(m, args[0].clone(), args[1].clone()),
+        &Operation::FloatSquareRoot(m, _) => Operation::FloatSquareRoot(m, args[0].clone()),
+        &Operation::FloatRound(m, _) => Operation::FloatRound(m, args[0].clone()),
+        &Operation::FloatEqual(_, _) => Operation::FloatEqual(args[0].clone(), args[1].clone()),
+        &Operation::FloatLess(_, _) => Operation::FloatLess(args[0].clone(), args[1].clone()),
+        &Operation::FloatLessOrEqual(_, _) => Operation::FloatLessOrEqual(args[0].clone(), args[1].clone()),
+        &Operation::FloatUnordered(_, _) => Operation::FloatUnordered(args[0].clone(), args[1].clone()),
+        &Operation::IntToFloat(m, sz, _) => Operation::IntToFloat(m, sz, args[0].clone()),
+        &Operation::FloatToInt(m, sz, _) => Operation::FloatToInt(m, sz, args[0].clone()),
+        &Operation::FloatToFloat(m, sz, _) => Operation::FloatToFloat(m, sz, args[0].clone()),
+        &Operation::Intrinsic(ref name, _, ref outputs) => Operation::Intrinsic(name.clone(), args, outputs.clone()),
     }
 }
 
@@ -1132,6 +2008,22 @@ impl<V> Operation<V>
             Operation::Load(_, _, _, ref b) => return vec![b],
             Operation::Store(_, _, _, ref a, ref b) => return vec![a,b],
 
+            Operation::FloatAdd(_, ref a, ref b) => return vec![a, b],
+            Operation::FloatSubtract(_, ref a, ref b) => return vec![a, b],
+            Operation::FloatMultiply(_, ref a, ref b) => return vec![a, b],
+            Operation::FloatDivide(_, ref a, ref b) => return vec![a, b],
+            Operation::FloatSquareRoot(_, ref a) => return vec![a],
+            Operation::FloatRound(_, ref a) => return vec![a],
+            Operation::FloatEqual(ref a, ref b) => return vec![a, b],
+            Operation::FloatLess(ref a, ref b) => return vec![a, b],
+            Operation::FloatLessOrEqual(ref a, ref b) => return vec![a, b],
+            Operation::FloatUnordered(ref a, ref b) => return vec![a, b],
+            Operation::IntToFloat(_, _, ref a) => return vec![a],
+            Operation::FloatToInt(_, _, ref a) => return vec![a],
+            Operation::FloatToFloat(_, _, ref a) => return vec![a],
+
+            Operation::Intrinsic(_, ref vec, _) => return vec.iter().collect(),
+
             Operation::Phi(ref vec) => return vec.iter().collect(),
         }
     }
@@ -1168,6 +2060,22 @@ impl<V> Operation<V>
             &mut Operation::Load(_, _, _, ref mut b) => return vec![b],
             &mut Operation::Store(_, _, _, ref mut a, ref mut b) => return vec![a, b],
 
+            &mut Operation::FloatAdd(_, ref mut a, ref mut b) => return vec![a, b],
+            &mut Operation::FloatSubtract(_, ref mut a, ref mut b) => return vec![a, b],
+            &mut Operation::FloatMultiply(_, ref mut a, ref mut b) => return vec![a, b],
+            &mut Operation::FloatDivide(_, ref mut a, ref mut b) => return vec![a, b],
+            &mut Operation::FloatSquareRoot(_, ref mut a) => return vec![a],
+            &mut Operation::FloatRound(_, ref mut a) => return vec![a],
+            &mut Operation::FloatEqual(ref mut a, ref mut b) => return vec![a, b],
+            &mut Operation::FloatLess(ref mut a, ref mut b) => return vec![a, b],
+            &mut Operation::FloatLessOrEqual(ref mut a, ref mut b) => return vec![a, b],
+            &mut Operation::FloatUnordered(ref mut a, ref mut b) => return vec![a, b],
+            &mut Operation::IntToFloat(_, _, ref mut a) => return vec![a],
+            &mut Operation::FloatToInt(_, _, ref mut a) => return vec![a],
+            &mut Operation::FloatToFloat(_, _, ref mut a) => return vec![a],
+
+            &mut Operation::Intrinsic(_, ref mut vec, _) => return vec.iter_mut().collect(),
+
             &mut Operation::Phi(ref mut vec) => return vec.iter_mut().collect(),
         }
     }
@@ -1203,18 +2111,38 @@ impl Display for Statement {
 
             Operation::Initialize(ref name,ref size) => f.write_fmt(format_args!("init {}, {}:{}",self.assignee,name,size)),
 
-            Operation::Load(ref r,Endianess::Little,ref sz,ref b) => f.write_fmt(format_args!("load_{}/le/{} {}, {}",r,sz,self.assignee,b)),
-            Operation::Load(ref r,Endianess::Big,ref sz,ref b) => f.write_fmt(format_args!("load_{}/be/{} {}, {}",r,sz,self.assignee,b)),
-            Operation::Store(ref r,Endianess::Little,ref sz,ref a, ref b) => f.write_fmt(format_args!("store_{}/le/{} {}, {}, {}",r,sz,self.assignee,a,b)),
-            Operation::Store(ref r,Endianess::Big,ref sz,ref a, ref b) => f.write_fmt(format_args!("store_{}/be/{} {}, {}, {}",r,sz,self.assignee,a,b)),
+            Operation::Load(ref r,ref e,ref sz,ref b) => f.write_fmt(format_args!("load/{}/{}/{} {}, {}",r,e,sz,self.assignee,b)),
+            Operation::Store(ref r,ref e,ref sz,ref a, ref b) => f.write_fmt(format_args!("store/{}/{}/{} {}, {}, {}",r,e,sz,self.assignee,a,b)),
+
+            Operation::FloatAdd(m, ref a, ref b) => f.write_fmt(format_args!("fadd/{} {}, {}, {}", m, self.assignee, a, b)),
+            Operation::FloatSubtract(m, ref a, ref b) => f.write_fmt(format_args!("fsub/{} {}, {}, {}", m, self.assignee, a, b)),
+            Operation::FloatMultiply(m, ref a, ref b) => f.write_fmt(format_args!("fmul/{} {}, {}, {}", m, self.assignee, a, b)),
+            Operation::FloatDivide(m, ref a, ref b) => f.write_fmt(format_args!("fdiv/{} {}, {}, {}", m, self.assignee, a, b)),
+            Operation::FloatSquareRoot(m, ref a) => f.write_fmt(format_args!("fsqrt/{} {}, {}", m, self.assignee, a)),
+            Operation::FloatRound(m, ref a) => f.write_fmt(format_args!("fround/{} {}, {}", m, self.assignee, a)),
+            Operation::FloatEqual(ref a, ref b) => f.write_fmt(format_args!("fcmpeq {}, {}, {}", self.assignee, a, b)),
+            Operation::FloatLess(ref a, ref b) => f.write_fmt(format_args!("fcmplt {}, {}, {}", self.assignee, a, b)),
+            Operation::FloatLessOrEqual(ref a, ref b) => f.write_fmt(format_args!("fcmple {}, {}, {}", self.assignee, a, b)),
+            Operation::FloatUnordered(ref a, ref b) => f.write_fmt(format_args!("fcmpuo {}, {}, {}", self.assignee, a, b)),
+            Operation::IntToFloat(m, sz, ref a) => f.write_fmt(format_args!("itof/{}/{} {}, {}", m, sz, self.assignee, a)),
+            Operation::FloatToInt(m, sz, ref a) => f.write_fmt(format_args!("ftoi/{}/{} {}, {}", m, sz, self.assignee, a)),
+            Operation::FloatToFloat(m, sz, ref a) => f.write_fmt(format_args!("ftof/{}/{} {}, {}", m, sz, self.assignee, a)),
+
+            Operation::Intrinsic(ref name, ref inputs, ref outputs) => {
+                f.write_fmt(format_args!("intrinsic/{} {}", name, self.assignee))?;
+                for x in inputs.iter() {
+                    f.write_fmt(format_args!(", {}", x))?;
+                }
+                for (i, x) in outputs.iter().enumerate() {
+                    f.write_fmt(format_args!("{} {}", if i == 0 { " ->" } else { "," }, x))?;
+                }
+                Ok(())
+            }
 
             Operation::Phi(ref vec) => {
                 f.write_fmt(format_args!("phi {}", self.assignee))?;
-                for (i, x) in vec.iter().enumerate() {
-                    f.write_fmt(format_args!("{}", x))?;
-                    if i < vec.len() - 1 {
-                        f.write_str(", ")?;
-                    }
+                for x in vec.iter() {
+                    f.write_fmt(format_args!(", {}", x))?;
                 }
                 Ok(())
             }
@@ -1222,9 +2150,240 @@ impl Display for Statement {
     }
 }
 
+impl FromStr for Statement {
+    type Err = ::Error;
+
+    /// Parses a single statement in the format it is displayed in. The mnemonics and size
+    /// suffixes of the `rreil!` macro (`zext/8`, `sel/4`, `cmpltu`, ...) are accepted too.
+    fn from_str(s: &str) -> Result<Statement> {
+        let s = s.trim().trim_right_matches(';').trim();
+        let (mnemonic, rest) = match s.find(char::is_whitespace) {
+            Some(p) => (&s[..p], s[p..].trim()),
+            None => (s, ""),
+        };
+
+        if rest.is_empty() {
+            return Err(format!("'{}' has no assignee", s).into());
+        }
+
+        let (rest, outputs) = match rest.find("->") {
+            Some(p) => (rest[..p].trim(), Some(rest[p + 2..].trim())),
+            None => (rest, None),
+        };
+        let args = rest.split(',').map(|a| a.trim()).collect::<Vec<_>>();
+        let assignee = args[0].parse::<Lvalue>()?;
+        let mut op = parse_operation(mnemonic, &args[1..])?;
+
+        // outputs of intrinsics follow the inputs after an arrow
+        match (&mut op, outputs) {
+            (&mut Operation::Intrinsic(_, _, ref mut outs), Some(outputs)) => {
+                for o in outputs.split(',') {
+                    outs.push(o.trim().parse::<Lvalue>()?);
+                }
+            }
+            (_, Some(_)) => return Err(format!("'{}' has no outputs", mnemonic).into()),
+            (_, None) => {}
+        }
+
+        Ok(Statement { assignee: assignee, op: op })
+    }
+}
+
+/// Parses RREIL code in the format `Statement` is displayed in. Statements are separated by
+/// newlines or `;`. Empty lines and comments starting with `//` are skipped.
+pub fn parse_statements(s: &str) -> Result<Vec<Statement>> {
+    let mut ret = vec![];
+
+    for (i, line) in s.lines().enumerate() {
+        let line = match line.find("//") {
+            Some(p) => &line[..p],
+            None => line,
+        };
+
+        for stmt in line.split(';').filter(|x| !x.trim().is_empty()) {
+            match stmt.parse::<Statement>() {
+                Ok(stmt) => ret.push(stmt),
+                Err(e) => return Err(format!("line {}: {}", i + 1, e).into()),
+            }
+        }
+    }
+
+    Ok(ret)
+}
+
+fn parse_operation(mnemonic: &str, args: &[&str]) -> Result<Operation<Rvalue>> {
+    let (base, param) = match mnemonic.find(|c: char| c == '_' || c == '/') {
+        Some(p) => (&mnemonic[..p], Some(&mnemonic[p + 1..])),
+        None => (mnemonic, None),
+    };
+
+    match (base, param) {
+        ("add", None) => parse_binop(mnemonic, args, Operation::Add),
+        ("sub", None) => parse_binop(mnemonic, args, Operation::Subtract),
+        ("mul", None) => parse_binop(mnemonic, args, Operation::Multiply),
+        ("divu", None) | ("div", None) => parse_binop(mnemonic, args, Operation::DivideUnsigned),
+        ("divs", None) => parse_binop(mnemonic, args, Operation::DivideSigned),
+        ("shl", None) => parse_binop(mnemonic, args, Operation::ShiftLeft),
+        ("shru", None) | ("shr", None) => parse_binop(mnemonic, args, Operation::ShiftRightUnsigned),
+        ("shrs", None) => parse_binop(mnemonic, args, Operation::ShiftRightSigned),
+        ("mod", None) => parse_binop(mnemonic, args, Operation::Modulo),
+        ("and", None) => parse_binop(mnemonic, args, Operation::And),
+        ("or", None) => parse_binop(mnemonic, args, Operation::InclusiveOr),
+        ("xor", None) => parse_binop(mnemonic, args, Operation::ExclusiveOr),
+
+        ("cmpeq", None) => parse_binop(mnemonic, args, Operation::Equal),
+        ("cmpleu", None) => parse_binop(mnemonic, args, Operation::LessOrEqualUnsigned),
+        ("cmples", None) => parse_binop(mnemonic, args, Operation::LessOrEqualSigned),
+        ("cmplu", None) | ("cmpltu", None) => parse_binop(mnemonic, args, Operation::LessUnsigned),
+        ("cmpls", None) | ("cmplts", None) => parse_binop(mnemonic, args, Operation::LessSigned),
+
+        ("convert", Some(sz)) | ("zext", Some(sz)) => {
+            let sz = parse_number(sz)?;
+            parse_unop(mnemonic, args, |a| Operation::ZeroExtend(sz, a))
+        }
+        ("sign-extend", Some(sz)) | ("sext", Some(sz)) => {
+            let sz = parse_number(sz)?;
+            parse_unop(mnemonic, args, |a| Operation::SignExtend(sz, a))
+        }
+        ("select", Some(off)) | ("sel", Some(off)) => {
+            let off = parse_number(off)?;
+            parse_binop(mnemonic, args, |a, b| Operation::Select(off, a, b))
+        }
+        ("mov", None) => parse_unop(mnemonic, args, Operation::Move),
+        ("call", None) => parse_unop(mnemonic, args, Operation::Call),
+
+        ("init", None) => {
+            match (args.len(), args.get(0).and_then(|a| a.rfind(':'))) {
+                (1, Some(p)) if p > 0 => Ok(Operation::Initialize(Cow::Owned(args[0][..p].to_string()), parse_number(&args[0][p + 1..])?)),
+                _ => Err(format!("'{}' expects a single name:size operand", mnemonic).into()),
+            }
+        }
+
+        ("load", Some(access)) => {
+            let (bank, endianess, sz) = parse_access(access)?;
+            parse_unop(mnemonic, args, |a| Operation::Load(bank, endianess, sz, a))
+        }
+        ("store", Some(access)) => {
+            let (bank, endianess, sz) = parse_access(access)?;
+            parse_binop(mnemonic, args, |a, b| Operation::Store(bank, endianess, sz, a, b))
+        }
+
+        ("fadd", Some(m)) => {
+            let m = m.parse::<Rounding>()?;
+            parse_binop(mnemonic, args, |a, b| Operation::FloatAdd(m, a, b))
+        }
+        ("fsub", Some(m)) => {
+            let m = m.parse::<Rounding>()?;
+            parse_binop(mnemonic, args, |a, b| Operation::FloatSubtract(m, a, b))
+        }
+        ("fmul", Some(m)) => {
+            let m = m.parse::<Rounding>()?;
+            parse_binop(mnemonic, args, |a, b| Operation::FloatMultiply(m, a, b))
+        }
+        ("fdiv", Some(m)) => {
+            let m = m.parse::<Rounding>()?;
+            parse_binop(mnemonic, args, |a, b| Operation::FloatDivide(m, a, b))
+        }
+        ("fsqrt", Some(m)) => {
+            let m = m.parse::<Rounding>()?;
+            parse_unop(mnemonic, args, |a| Operation::FloatSquareRoot(m, a))
+        }
+        ("fround", Some(m)) => {
+            let m = m.parse::<Rounding>()?;
+            parse_unop(mnemonic, args, |a| Operation::FloatRound(m, a))
+        }
+        ("fcmpeq", None) => parse_binop(mnemonic, args, Operation::FloatEqual),
+        ("fcmplt", None) => parse_binop(mnemonic, args, Operation::FloatLess),
+        ("fcmple", None) => parse_binop(mnemonic, args, Operation::FloatLessOrEqual),
+        ("fcmpuo", None) => parse_binop(mnemonic, args, Operation::FloatUnordered),
+        ("itof", Some(conv)) => {
+            let (m, sz) = parse_conversion(conv)?;
+            parse_unop(mnemonic, args, |a| Operation::IntToFloat(m, sz, a))
+        }
+        ("ftoi", Some(conv)) => {
+            let (m, sz) = parse_conversion(conv)?;
+            parse_unop(mnemonic, args, |a| Operation::FloatToInt(m, sz, a))
+        }
+        ("ftof", Some(conv)) => {
+            let (m, sz) = parse_conversion(conv)?;
+            parse_unop(mnemonic, args, |a| Operation::FloatToFloat(m, sz, a))
+        }
+
+        ("intrinsic", Some(name)) if !name.is_empty() => {
+            let inputs = args.iter().map(|a| a.parse::<Rvalue>()).collect::<Result<Vec<_>>>()?;
+            Ok(Operation::Intrinsic(Cow::Owned(name.to_string()), inputs, vec![]))
+        }
+
+        ("phi", None) => Ok(Operation::Phi(args.iter().map(|a| a.parse::<Rvalue>()).collect::<Result<Vec<_>>>()?)),
+
+        _ => Err(format!("Unknown RREIL operation '{}'", mnemonic).into()),
+    }
+}
+
+fn parse_unop<F: FnOnce(Rvalue) -> Operation<Rvalue>>(mnemonic: &str, args: &[&str], f: F) -> Result<Operation<Rvalue>> {
+    if args.len() != 1 {
+        return Err(format!("'{}' expects one operand, found {}", mnemonic, args.len()).into());
+    }
+
+    Ok(f(args[0].parse::<Rvalue>()?))
+}
+
+fn parse_binop<F: FnOnce(Rvalue, Rvalue) -> Operation<Rvalue>>(mnemonic: &str, args: &[&str], f: F) -> Result<Operation<Rvalue>> {
+    if args.len() != 2 {
+        return Err(format!("'{}' expects two operands, found {}", mnemonic, args.len()).into());
+    }
+
+    Ok(f(args[0].parse::<Rvalue>()?, args[1].parse::<Rvalue>()?))
+}
+
+/// Splits the `bank/endianess/size` suffix of memory operations.
+fn parse_access(s: &str) -> Result<(Cow<'static, str>, Endianess, usize)> {
+    let mut parts = s.rsplitn(3, '/');
+
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(sz), Some(e), Some(bank)) if !bank.is_empty() => Ok((Cow::Owned(bank.to_string()), e.parse::<Endianess>()?, parse_number(sz)?)),
+        _ => Err(format!("'{}' is not a bank/endianess/size triple", s).into()),
+    }
+}
+
+/// Splits the `rounding/size` suffix of floating point conversions.
+fn parse_conversion(s: &str) -> Result<(Rounding, usize)> {
+    match s.find('/') {
+        Some(p) => Ok((s[..p].parse::<Rounding>()?, parse_number(&s[p + 1..])?)),
+        None => Err(format!("'{}' is not a rounding/size pair", s).into()),
+    }
+}
+
+/// Splits a variable name into name and SSA subscript (`eax_2`).
+fn parse_name(s: &str) -> Result<(Cow<'static, str>, Option<usize>)> {
+    if s.is_empty() || s.chars().any(|c| c.is_whitespace() || c == ',' || c == ':' || c == '/') {
+        return Err(format!("'{}' is not a variable name", s).into());
+    }
+
+    match s.rfind('_') {
+        Some(p) if p > 0 && p + 1 < s.len() && s[p + 1..].chars().all(|c| c.is_digit(10)) => {
+            let subscript = usize::from_str(&s[p + 1..]).map_err(|_| format!("'{}' has an invalid subscript", s))?;
+            Ok((Cow::Owned(s[..p].to_string()), Some(subscript)))
+        }
+        _ => Ok((Cow::Owned(s.to_string()), None)),
+    }
+}
+
+/// Parses a decimal or `0x` prefixed hexadecimal number.
+fn parse_number(s: &str) -> Result<usize> {
+    let s = s.trim();
+    let n = if s.starts_with("0x") {
+        usize::from_str_radix(&s[2..], 16)
+    } else {
+        usize::from_str(s)
+    };
+
+    n.map_err(|_| format!("'{}' is not a number", s).into())
+}
+
 impl Arbitrary for Rvalue {
     fn arbitrary<G: Gen>(g: &mut G) -> Self {
-        match g.gen_range(0, 3) {
+        match g.gen_range(0, 4) {
             0 => Rvalue::Undefined,
             1 => {
                 Rvalue::Variable {
@@ -1235,6 +2394,10 @@ impl Arbitrary for Rvalue {
                 }
             }
             2 => Rvalue::Constant { value: g.gen(), size: g.gen_range(1, 513) },
+            3 => {
+                let size = g.gen_range(65, 513);
+                Rvalue::new_wide((0..limb_count(size)).map(|_| g.gen()).collect(), size)
+            }
             _ => unreachable!(),
         }
     }
@@ -1258,7 +2421,7 @@ impl Arbitrary for Lvalue {
 
 impl Arbitrary for Operation<Rvalue> {
     fn arbitrary<G: Gen>(g: &mut G) -> Self {
-        let mut op = match g.gen_range(0, 25) {
+        let mut op = match g.gen_range(0, 39) {
             0 => Operation::Add(Rvalue::arbitrary(g), Rvalue::arbitrary(g)),
             1 => Operation::Subtract(Rvalue::arbitrary(g), Rvalue::arbitrary(g)),
             2 => Operation::Multiply(Rvalue::arbitrary(g), Rvalue::arbitrary(g)),
@@ -1296,6 +2459,28 @@ impl Arbitrary for Operation<Rvalue> {
             }
             24 => Operation::Call(Rvalue::arbitrary(g)),
 
+            25 => Operation::FloatAdd(Rounding::arbitrary(g), Rvalue::arbitrary(g), Rvalue::arbitrary(g)),
+            26 => Operation::FloatSubtract(Rounding::arbitrary(g), Rvalue::arbitrary(g), Rvalue::arbitrary(g)),
+            27 => Operation::FloatMultiply(Rounding::arbitrary(g), Rvalue::arbitrary(g), Rvalue::arbitrary(g)),
+            28 => Operation::FloatDivide(Rounding::arbitrary(g), Rvalue::arbitrary(g), Rvalue::arbitrary(g)),
+            29 => Operation::FloatSquareRoot(Rounding::arbitrary(g), Rvalue::arbitrary(g)),
+            30 => Operation::FloatRound(Rounding::arbitrary(g), Rvalue::arbitrary(g)),
+            31 => Operation::FloatEqual(Rvalue::arbitrary(g), Rvalue::arbitrary(g)),
+            32 => Operation::FloatLess(Rvalue::arbitrary(g), Rvalue::arbitrary(g)),
+            33 => Operation::FloatLessOrEqual(Rvalue::arbitrary(g), Rvalue::arbitrary(g)),
+            34 => Operation::FloatUnordered(Rvalue::arbitrary(g), Rvalue::arbitrary(g)),
+            35 => Operation::IntToFloat(Rounding::arbitrary(g), *g.choose(&[32, 64]).unwrap(), Rvalue::arbitrary(g)),
+            36 => Operation::FloatToInt(Rounding::arbitrary(g), g.gen_range(1, 65), Rvalue::arbitrary(g)),
+            37 => Operation::FloatToFloat(Rounding::arbitrary(g), *g.choose(&[32, 64]).unwrap(), Rvalue::arbitrary(g)),
+
+            38 => {
+                let ins = g.gen_range(0, 4);
+                let outs = g.gen_range(0, 4);
+                let i = (0..ins).into_iter().map(|_| Rvalue::arbitrary(g)).collect();
+                let o = (0..outs).into_iter().map(|_| Lvalue::arbitrary(g)).collect();
+                Operation::Intrinsic(g.gen_ascii_chars().take(4).collect(), i, o)
+            }
+
             _ => unreachable!(),
         };
 
@@ -1316,16 +2501,35 @@ impl Arbitrary for Operation<Rvalue> {
             Operation::LessOrEqualUnsigned(_, _) |
             Operation::LessOrEqualSigned(_, _) |
             Operation::LessUnsigned(_, _) |
-            Operation::LessSigned(_, _) => {
+            Operation::LessSigned(_, _) |
+            Operation::FloatAdd(_, _, _) |
+            Operation::FloatSubtract(_, _, _) |
+            Operation::FloatMultiply(_, _, _) |
+            Operation::FloatDivide(_, _, _) |
+            Operation::FloatEqual(_, _) |
+            Operation::FloatLess(_, _) |
+            Operation::FloatLessOrEqual(_, _) |
+            Operation::FloatUnordered(_, _) => {
                 let mut sz = None;
                 for o in op.operands_mut() {
                     if sz.is_none() {
                         sz = o.size();
                     } else {
-                        match o {
-                            &mut Rvalue::Undefined => {}
-                            &mut Rvalue::Constant { ref mut size, .. } => *size = sz.unwrap(),
-                            &mut Rvalue::Variable { ref mut size, .. } => *size = sz.unwrap(),
+                        let wide = match o {
+                            &mut Rvalue::Undefined => None,
+                            &mut Rvalue::Constant { ref mut size, .. } => {
+                                *size = sz.unwrap();
+                                None
+                            }
+                            &mut Rvalue::Wide { ref value, .. } => Some(value.clone()),
+                            &mut Rvalue::Variable { ref mut size, .. } => {
+                                *size = sz.unwrap();
+                                None
+                            }
+                        };
+
+                        if let Some(v) = wide {
+                            *o = Rvalue::new_wide(v, sz.unwrap());
                         }
                     }
                 }
@@ -1349,6 +2553,18 @@ impl Arbitrary for Operation<Rvalue> {
     }
 }
 
+impl Arbitrary for Rounding {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        match g.gen_range(0, 4) {
+            0 => Rounding::NearestEven,
+            1 => Rounding::TowardZero,
+            2 => Rounding::TowardNegative,
+            3 => Rounding::TowardPositive,
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl Arbitrary for Endianess {
     fn arbitrary<G: Gen>(g: &mut G) -> Self {
         match g.gen_range(0, 1) {
@@ -1626,6 +2842,27 @@ mod tests {
                 op: Operation::Phi(vec![Rvalue::Undefined, Rvalue::Undefined]),
                 assignee: Lvalue::Undefined,
             },
+
+            Statement {
+                op: Operation::FloatAdd(Rounding::NearestEven, Rvalue::Undefined, Rvalue::Undefined),
+                assignee: Lvalue::Undefined,
+            },
+            Statement {
+                op: Operation::FloatSquareRoot(Rounding::TowardZero, Rvalue::Undefined),
+                assignee: Lvalue::Undefined,
+            },
+            Statement {
+                op: Operation::FloatLess(Rvalue::Undefined, Rvalue::Undefined),
+                assignee: Lvalue::Undefined,
+            },
+            Statement {
+                op: Operation::FloatToInt(Rounding::TowardNegative, 32, Rvalue::Undefined),
+                assignee: Lvalue::Undefined,
+            },
+            Statement {
+                op: Operation::FloatToFloat(Rounding::TowardPositive, 80, Rvalue::Undefined),
+                assignee: Lvalue::Undefined,
+            },
         ]
     }
 
@@ -1654,6 +2891,287 @@ mod tests {
         assert!(g1 != g2);
     }
 
+    #[test]
+    fn parse_round_trip() {
+        let mut stmts = setup();
+
+        stmts.extend(
+            rreil!{
+                zext/16 a:16, b:8;
+                sel/4 c:32, d:8;
+                mov e:32, f:32/8;
+                load/ram/be/16 g:16, h:32;
+                store/io/le/8 i:8, [0x100]:16;
+            }.unwrap()
+        );
+        stmts.push(Statement { op: Operation::Initialize(Cow::Borrowed("eax"), 32), assignee: Lvalue::Undefined });
+        stmts.push(Statement { op: Operation::Phi(vec![]), assignee: Lvalue::Undefined });
+
+        for x in stmts {
+            assert_eq!(format!("{}", x).parse::<Statement>().ok(), Some(x));
+        }
+
+        fn prop(assignee: Lvalue, op: Operation<Rvalue>) -> bool {
+            let stmt = Statement { assignee: assignee, op: op };
+            format!("{}", stmt).parse::<Statement>().ok() == Some(stmt)
+        }
+        ::quickcheck::quickcheck(prop as fn(Lvalue, Operation<Rvalue>) -> bool);
+    }
+
+    #[test]
+    fn parse_text() {
+        let stmts = parse_statements(
+            "
+            // adc R0, R1
+            zext/8 carry:8, C:1;
+            add res:8, R0:8, R1:8
+            add res:8, res:8, carry:8; cmpeq Z:1, res:8, 0:8
+            ",
+        )
+                .unwrap();
+
+        assert_eq!(
+            stmts,
+            rreil!{
+                zext/8 carry:8, C:1;
+                add res:8, R0:8, R1:8;
+                add res:8, res:8, carry:8;
+                cmpeq Z:1, res:8, [0]:8;
+            }.unwrap()
+        );
+        assert_eq!(
+            "eax_12:32/8".parse::<Rvalue>().ok(),
+            Some(Rvalue::Variable { name: Cow::Borrowed("eax"), subscript: Some(12), offset: 8, size: 32 })
+        );
+        assert_eq!("0xff:8".parse::<Rvalue>().ok(), Some(Rvalue::new_u8(255)));
+
+        assert!("eax".parse::<Rvalue>().is_err());
+        assert!("0x1:8".parse::<Lvalue>().is_err());
+        assert!("a:8/1".parse::<Lvalue>().is_err());
+        assert!("add a:8, b:8".parse::<Statement>().is_err());
+        assert!("frob a:8, b:8".parse::<Statement>().is_err());
+        assert!("load/ram/xe/8 a:8, b:8".parse::<Statement>().is_err());
+        assert!(parse_statements("mov a:8, b:8\nmov a:8").is_err());
+    }
+
+    #[test]
+    fn parse_guard() {
+        let flag = Rvalue::Variable { name: Cow::Borrowed("ZF"), subscript: Some(2), offset: 0, size: 1 };
+        let guards = vec![
+            Guard::True,
+            Guard::False,
+            Guard::from_flag(&flag).unwrap(),
+            Guard::from_flag(&flag).unwrap().negation(),
+            Guard::from_flag(&Rvalue::Undefined).unwrap(),
+            Guard::Predicate { flag: Rvalue::new_bit(1), expected: false },
+        ];
+
+        for g in guards {
+            assert_eq!(format!("{}", g).parse::<Guard>().ok(), Some(g));
+        }
+
+        assert_eq!("!CF".parse::<Guard>().ok(), "¬CF".parse::<Guard>().ok());
+        assert!("(CF:1 = true)".parse::<Guard>().is_err());
+    }
+
+    #[test]
+    fn float_operations() {
+        fn f64c(v: f64) -> Rvalue {
+            Rvalue::Constant { value: v.to_bits(), size: 64 }
+        }
+        fn f32c(v: f32) -> Rvalue {
+            Rvalue::Constant { value: v.to_bits() as u64, size: 32 }
+        }
+        let rne = Rounding::NearestEven;
+
+        assert_eq!(execute(Operation::FloatAdd(rne, f64c(1.5), f64c(2.25))), f64c(3.75));
+        assert_eq!(execute(Operation::FloatSubtract(rne, f32c(1.0), f32c(0.25))), f32c(0.75));
+        assert_eq!(execute(Operation::FloatMultiply(rne, f64c(-3.0), f64c(0.5))), f64c(-1.5));
+        assert_eq!(execute(Operation::FloatDivide(rne, f32c(1.0), f32c(3.0))), f32c(1.0 / 3.0));
+        assert_eq!(execute(Operation::FloatSquareRoot(rne, f64c(2.0))), f64c(2f64.sqrt()));
+        assert_eq!(execute(Operation::FloatAdd(rne, f64c(1.0), Rvalue::Undefined)), Rvalue::Undefined);
+        assert_eq!(execute(Operation::FloatAdd(rne, Rvalue::Constant { value: 1, size: 80 }, Rvalue::Constant { value: 1, size: 80 })), Rvalue::Undefined);
+
+        // directed rounding
+        let third = execute(Operation::FloatDivide(Rounding::TowardPositive, f64c(1.0), f64c(3.0)));
+        assert_eq!(third, Rvalue::Constant { value: (1.0f64 / 3.0).to_bits() + 1, size: 64 });
+        let third = execute(Operation::FloatDivide(Rounding::TowardZero, f64c(-1.0), f64c(3.0)));
+        assert_eq!(third, f64c(-1.0 / 3.0));
+        let third = execute(Operation::FloatDivide(Rounding::TowardNegative, f64c(-1.0), f64c(3.0)));
+        assert_eq!(third, Rvalue::Constant { value: (-1.0f64 / 3.0).to_bits() + 1, size: 64 });
+        assert_eq!(execute(Operation::FloatAdd(Rounding::TowardPositive, f64c(1.0), f64c(1e-30))), f64c(1.0 + 2.220446049250313e-16));
+        assert_eq!(execute(Operation::FloatAdd(Rounding::TowardNegative, f64c(1.0), f64c(1e-30))), f64c(1.0));
+        assert_eq!(execute(Operation::FloatAdd(Rounding::TowardZero, f32c(3.0e38), f32c(3.0e38))), f32c(::std::f32::MAX));
+        assert_eq!(execute(Operation::FloatMultiply(Rounding::TowardPositive, f64c(0.5), f64c(6.0))), f64c(3.0));
+
+        assert_eq!(execute(Operation::FloatRound(rne, f64c(2.5))), f64c(2.0));
+        assert_eq!(execute(Operation::FloatRound(rne, f64c(3.5))), f64c(4.0));
+        assert_eq!(execute(Operation::FloatRound(rne, f64c(-0.25))), f64c(-0.0));
+        assert_eq!(execute(Operation::FloatRound(Rounding::TowardNegative, f32c(-1.5))), f32c(-2.0));
+        assert_eq!(execute(Operation::FloatRound(Rounding::TowardPositive, f32c(-1.5))), f32c(-1.0));
+
+        // comparisons
+        let nan = f64c(::std::f64::NAN);
+        assert_eq!(execute(Operation::FloatLess(f64c(1.0), f64c(2.0))), Rvalue::new_bit(1));
+        assert_eq!(execute(Operation::FloatLessOrEqual(f64c(2.0), f64c(1.0))), Rvalue::new_bit(0));
+        assert_eq!(execute(Operation::FloatEqual(f64c(0.0), f64c(-0.0))), Rvalue::new_bit(1));
+        assert_eq!(execute(Operation::FloatEqual(nan.clone(), nan.clone())), Rvalue::new_bit(0));
+        assert_eq!(execute(Operation::FloatUnordered(f64c(1.0), nan.clone())), Rvalue::new_bit(1));
+
+        // conversions
+        assert_eq!(execute(Operation::IntToFloat(rne, 64, Rvalue::new_u32(0xffff_fffe))), f64c(-2.0));
+        assert_eq!(execute(Operation::IntToFloat(rne, 32, Rvalue::new_u64(16777217))), f32c(16777216.0));
+        assert_eq!(execute(Operation::IntToFloat(Rounding::TowardPositive, 32, Rvalue::new_u64(16777217))), f32c(16777218.0));
+        assert_eq!(execute(Operation::IntToFloat(Rounding::TowardPositive, 64, Rvalue::new_u64(u64::MAX))), f64c(-1.0));
+        assert_eq!(execute(Operation::FloatToInt(Rounding::TowardZero, 32, f64c(-2.75))), Rvalue::new_u32(0xffff_fffe));
+        assert_eq!(execute(Operation::FloatToInt(rne, 8, f32c(2.5))), Rvalue::new_u8(2));
+        assert_eq!(execute(Operation::FloatToInt(rne, 8, f32c(128.0))), Rvalue::Undefined);
+        assert_eq!(execute(Operation::FloatToInt(rne, 32, nan.clone())), Rvalue::Undefined);
+        assert_eq!(execute(Operation::FloatToFloat(rne, 32, f64c(0.1))), f32c(0.1));
+        assert_eq!(execute(Operation::FloatToFloat(Rounding::TowardPositive, 32, f64c(0.1))), f32c(0.1));
+        assert_eq!(execute(Operation::FloatToFloat(Rounding::TowardZero, 32, f64c(0.1))), Rvalue::Constant { value: 0.1f32.to_bits() as u64 - 1, size: 32 });
+        assert_eq!(execute(Operation::FloatToFloat(rne, 64, f32c(0.1))), f64c(0.1f32 as f64));
+    }
+
+    #[test]
+    fn float_sanity_check() {
+        let ok = rreil!{
+            mov a:64, b:64;
+        }.unwrap();
+        let a = ok[0].assignee.clone();
+        let b: Rvalue = a.clone().into();
+        let flag = Lvalue::Variable { name: Cow::Borrowed("f"), subscript: None, size: 1 };
+        let stmt = |assignee: &Lvalue, op| Statement { assignee: assignee.clone(), op: op };
+        let rne = Rounding::NearestEven;
+
+        assert!(stmt(&a, Operation::FloatAdd(rne, b.clone(), b.clone())).sanity_check().is_ok());
+        assert!(stmt(&flag, Operation::FloatUnordered(b.clone(), b.clone())).sanity_check().is_ok());
+        assert!(stmt(&a, Operation::FloatToInt(rne, 64, b.clone())).sanity_check().is_ok());
+        assert!(stmt(&a, Operation::IntToFloat(rne, 64, Rvalue::new_u16(1))).sanity_check().is_ok());
+
+        assert!(stmt(&a, Operation::FloatAdd(rne, b.clone(), Rvalue::new_u32(0))).sanity_check().is_err());
+        assert!(stmt(&a, Operation::FloatEqual(b.clone(), b.clone())).sanity_check().is_err());
+        assert!(stmt(&a, Operation::FloatSquareRoot(rne, Rvalue::new_u16(0))).sanity_check().is_err());
+        assert!(stmt(&a, Operation::IntToFloat(rne, 16, Rvalue::new_u16(1))).sanity_check().is_err());
+        assert!(stmt(&a, Operation::IntToFloat(rne, 64, Rvalue::new_wide(vec![1, 0], 128))).sanity_check().is_err());
+        assert!(stmt(&a, Operation::FloatToFloat(rne, 80, b.clone())).sanity_check().is_err());
+        assert!(stmt(&a, Operation::FloatToFloat(rne, 32, b.clone())).sanity_check().is_err());
+    }
+
+    #[test]
+    fn intrinsic() {
+        let eax = Lvalue::Variable { name: Cow::Borrowed("eax"), subscript: None, size: 32 };
+        let ebx = Lvalue::Variable { name: Cow::Borrowed("ebx"), subscript: Some(2), size: 32 };
+        let mut stmt = Statement {
+            op: Operation::Intrinsic(Cow::Borrowed("cpuid"), vec![eax.clone().into(), Rvalue::new_u32(1)], vec![eax.clone(), ebx.clone()]),
+            assignee: Lvalue::Undefined,
+        };
+
+        assert!(stmt.sanity_check().is_ok());
+        assert_eq!(stmt.op.operands(), vec![&Rvalue::from(eax.clone()), &Rvalue::new_u32(1)]);
+        assert_eq!(stmt.assignees(), vec![&Lvalue::Undefined, &eax, &ebx]);
+        assert_eq!(execute(stmt.op.clone()), Rvalue::Undefined);
+
+        let txt = format!("{}", stmt);
+        assert_eq!(txt, "intrinsic/cpuid ?, eax:32, 0x1:32 -> eax:32, ebx_2:32");
+        assert_eq!(txt.parse::<Statement>().ok(), Some(stmt.clone()));
+        assert_eq!("intrinsic/rdtsc ? -> edx:32".parse::<Statement>().ok().map(|s| s.op.operands().len()), Some(0));
+        assert!("add a:32, b:32, c:32 -> d:32".parse::<Statement>().is_err());
+        assert!("intrinsic ?, a:32".parse::<Statement>().is_err());
+
+        for lv in stmt.assignees_mut() {
+            if let &mut Lvalue::Variable { ref mut subscript, .. } = lv {
+                *subscript = Some(7);
+            }
+        }
+        assert_eq!(format!("{}", stmt), "intrinsic/cpuid ?, eax:32, 0x1:32 -> eax_7:32, ebx_7:32");
+
+        stmt.assignee = eax.clone();
+        assert!(stmt.sanity_check().is_err());
+        stmt.assignee = Lvalue::Undefined;
+        if let Operation::Intrinsic(_, _, ref mut outputs) = stmt.op {
+            outputs.push(Lvalue::Undefined);
+        }
+        assert!(stmt.sanity_check().is_err());
+    }
+
+    #[test]
+    fn wide_constants() {
+        let c = |v: u64, s: usize| Rvalue::Constant { value: v, size: s };
+        let w = |v: &[u64], s: usize| Rvalue::new_wide(v.to_vec(), s);
+        let max = u64::MAX;
+
+        assert_eq!(w(&[5, 0], 128), c(5, 128));
+        assert_eq!(w(&[max, 3], 65), Rvalue::Wide { value: vec![max, 1], size: 65 });
+        assert_eq!(w(&[0, 1], 128).limbs(), Some(vec![0, 1]));
+        assert_eq!(c(0x1ff, 8).limbs(), Some(vec![0xff]));
+        assert_eq!(w(&[0, 1], 128).size(), Some(128));
+
+        // text
+        assert_eq!("0x1ffffffffffffffff:128".parse::<Rvalue>().ok(), Some(w(&[max, 1], 128)));
+        assert_eq!("0x00000000000000000001:128".parse::<Rvalue>().ok(), Some(c(1, 128)));
+        assert!("0x10000000000000000:64".parse::<Rvalue>().is_err());
+        assert_eq!(format!("{}", w(&[2, 1, 0, 0], 256)), "0x10000000000000002:256");
+
+        // serialization
+        let bytes = ::serde_cbor::to_vec(&Rvalue::Wide { value: vec![5, 0], size: 128 }).unwrap();
+        assert_eq!(::serde_cbor::from_slice::<Rvalue>(&bytes).unwrap(), c(5, 128));
+        let bytes = ::serde_cbor::to_vec(&w(&[max, 1], 128)).unwrap();
+        assert_eq!(::serde_cbor::from_slice::<Rvalue>(&bytes).unwrap(), w(&[max, 1], 128));
+
+        // arithmetic
+        assert_eq!(execute(Operation::Add(c(max, 128), c(1, 128))), w(&[0, 1], 128));
+        assert_eq!(execute(Operation::Add(w(&[max, max], 128), c(1, 128))), c(0, 128));
+        assert_eq!(execute(Operation::Add(Rvalue::Wide { value: vec![1], size: 32 }, c(1, 32))), c(2, 32));
+        assert_eq!(execute(Operation::Subtract(c(0, 128), c(1, 128))), w(&[max, max], 128));
+        assert_eq!(execute(Operation::Multiply(c(max, 128), c(max, 128))), w(&[1, max - 1], 128));
+        assert_eq!(execute(Operation::Multiply(w(&[1, 0, 1, 0], 256), w(&[1, 0, 1, 0], 256))), w(&[1, 0, 2, 0], 256));
+        assert_eq!(execute(Operation::DivideUnsigned(w(&[max, max], 128), w(&[1, 1], 128))), c(max, 128));
+        assert_eq!(execute(Operation::Modulo(w(&[max, max], 128), w(&[1, 1], 128))), c(0, 128));
+        assert_eq!(execute(Operation::Modulo(w(&[7, 1], 128), c(0x10, 128))), c(7, 128));
+        assert_eq!(execute(Operation::DivideUnsigned(w(&[0, 1], 128), c(0, 128))), Rvalue::Undefined);
+        assert_eq!(execute(Operation::DivideSigned(w(&[max - 5, max], 128), c(3, 128))), w(&[max - 1, max], 128));
+        assert_eq!(execute(Operation::DivideSigned(w(&[max - 5, max], 128), w(&[max - 2, max], 128))), c(2, 128));
+
+        // bit operations
+        assert_eq!(execute(Operation::ShiftLeft(c(1, 128), c(100, 128))), w(&[0, 1 << 36], 128));
+        assert_eq!(execute(Operation::ShiftLeft(c(1, 128), c(128, 128))), c(0, 128));
+        assert_eq!(execute(Operation::ShiftRightUnsigned(w(&[0, 1 << 36], 128), c(100, 128))), c(1, 128));
+        assert_eq!(execute(Operation::ShiftRightSigned(w(&[0, 1 << 63], 128), c(127, 128))), w(&[max, max], 128));
+        assert_eq!(execute(Operation::ShiftRightSigned(w(&[0, 1 << 62], 128), c(126, 128))), c(1, 128));
+        assert_eq!(execute(Operation::And(w(&[3, 3], 128), w(&[1, 2], 128))), w(&[1, 2], 128));
+        assert_eq!(execute(Operation::InclusiveOr(w(&[1, 0], 128), w(&[0, 1], 128))), w(&[1, 1], 128));
+        assert_eq!(execute(Operation::ExclusiveOr(w(&[1, 1], 128), w(&[0, 1], 128))), c(1, 128));
+
+        // comparisons
+        assert_eq!(execute(Operation::Equal(w(&[1, 1], 128), w(&[1, 1], 128))), Rvalue::new_bit(1));
+        assert_eq!(execute(Operation::LessUnsigned(c(max, 128), w(&[0, 1], 128))), Rvalue::new_bit(1));
+        assert_eq!(execute(Operation::LessUnsigned(w(&[max, max], 128), c(1, 128))), Rvalue::new_bit(0));
+        assert_eq!(execute(Operation::LessSigned(w(&[max, max], 128), c(1, 128))), Rvalue::new_bit(1));
+        assert_eq!(execute(Operation::LessOrEqualSigned(c(1, 128), c(1, 128))), Rvalue::new_bit(1));
+        assert_eq!(execute(Operation::LessOrEqualUnsigned(w(&[0, 1], 128), c(1, 128))), Rvalue::new_bit(0));
+
+        // extension, select and extract
+        assert_eq!(execute(Operation::ZeroExtend(128, c(max, 64))), c(max, 128));
+        assert_eq!(execute(Operation::ZeroExtend(64, w(&[3, 1], 128))), c(3, 64));
+        assert_eq!(execute(Operation::SignExtend(128, c(1 << 63, 64))), w(&[1 << 63, max], 128));
+        assert_eq!(execute(Operation::SignExtend(128, c(1, 64))), c(1, 128));
+        assert_eq!(execute(Operation::Move(w(&[1, 1], 128))), w(&[1, 1], 128));
+        assert_eq!(execute(Operation::Select(64, c(0, 128), c(1, 64))), w(&[0, 1], 128));
+        assert_eq!(execute(Operation::Select(32, c(0, 64), c(0xffffffff, 32))), c(0xffffffff00000000, 64));
+        assert_eq!(execute(Operation::Select(100, c(0, 128), c(1, 64))), Rvalue::Undefined);
+        assert_eq!(w(&[0, 1], 128).extract(64, 64).ok(), Some(c(1, 64)));
+        assert_eq!(w(&[1 << 63, 1], 128).extract(2, 63).ok(), Some(c(3, 2)));
+        assert_eq!(c(max, 64).extract(64, 0).ok(), Some(c(max, 64)));
+        assert!(w(&[0, 1], 128).extract(64, 65).is_err());
+
+        // symbolic operands are left alone
+        let x = Rvalue::Variable { name: Cow::Borrowed("x"), subscript: None, offset: 0, size: 128 };
+
+        assert_eq!(execute(Operation::Add(x.clone(), c(0, 128))), x);
+        assert_eq!(execute(Operation::Add(x.clone(), w(&[0, 1], 128))), Rvalue::Undefined);
+    }
+
     #[test]
     fn guard_negation() {
         let g = Guard::from_flag(&Rvalue::Undefined).ok().unwrap();
@@ -38,14 +38,28 @@
 //! reg.cover(Bound::new(0x100,0x100 + mapping.len()),Layer::Opaque(mapping));
 //! ```
 //! Loading a Windows COM file.
+//!
+//! ```
+//! use panopticon_core::{Region,Bound,Layer,Transform};
+//!
+//! let mut reg = Region::wrap("ram".to_string(),vec![0x32, 0x67, 0x30, 0x30, 0x31]);
+//!
+//! // Decrypt the first four bytes by XOR'ing them with 0x5a
+//! reg.cover(Bound::new(0,4),Layer::Transform(Transform::Xor(vec![0x5a])));
+//!
+//! assert_eq!(reg.iter().collect::<Vec<_>>(), vec![Some(0x68), Some(0x3d), Some(0x6a), Some(0x6a), Some(0x31)]);
+//! ```
+//! Decrypting data in place.
 
 
-use Result;
+use {Error, MappedFile, Result};
 use std::collections::HashMap;
+use std::fmt;
 use std::fs::File;
 use std::io::Read;
 use std::ops::Range;
 use std::path::Path;
+use std::str::FromStr;
 use std::sync::Arc;
 
 /// A cell represents a single, possible undefined, byte.
@@ -58,6 +72,8 @@ pub enum OpaqueLayer {
     Undefined(u64),
     /// Layer consisting of fixed byte values.
     Defined(Arc<Vec<u8>>),
+    /// Layer consisting of the contents of a memory mapped file.
+    Mapped(MappedFile),
 }
 
 /// Iterator over a range of `Cell`s.
@@ -83,6 +99,264 @@ pub enum LayerIter<'a> {
         /// Second layer
         cdr: Box<LayerIter<'a>>,
     },
+    /// Layer computing new cells from the ones below.
+    Transform {
+        /// Function applied to the cells
+        transform: &'a Transform,
+        /// All cells covered by the layer
+        source: Box<LayerIter<'a>>,
+        /// Covered cells not yet transformed
+        mapped: Box<LayerIter<'a>>,
+        /// Position of the next cell inside the layer
+        pos: u64,
+        /// Cipher state or transformed cells, computed on first use
+        state: Option<TransformState>,
+    },
+}
+
+/// Function transforming the `Cell`s covered by a `Layer`.
+///
+/// Decoders produce fewer `Cell`s than they read. The decoded contents start at the beginning of
+/// the `Layer`, the remaining `Cell`s are undefined.
+#[derive(Clone,Debug,PartialEq,Eq,Serialize,Deserialize)]
+pub enum Transform {
+    /// XOR with the repeated key.
+    Xor(Vec<u8>),
+    /// Add a constant to each byte, modulo 256.
+    Add(u8),
+    /// Subtract a constant from each byte, modulo 256.
+    Sub(u8),
+    /// Rotate each byte to the left.
+    RotateLeft(u32),
+    /// Rotate each byte to the right.
+    RotateRight(u32),
+    /// RC4 stream cipher. The key stream starts at the beginning of the `Layer`.
+    Rc4(Vec<u8>),
+    /// Base64 decoding. Whitespace is ignored.
+    Base64,
+    /// Hexadecimal decoding. Whitespace is ignored.
+    Hex,
+}
+
+/// Intermediate state of an iterator over a `Transform` layer.
+#[derive(Clone,Debug)]
+pub struct TransformState {
+    rc4: Option<Rc4>,
+    /// All cells of the layer. Used instead of the cipher state if present.
+    decoded: Option<Arc<Vec<Cell>>>,
+}
+
+/// RC4 key stream generator.
+#[derive(Clone,Debug)]
+struct Rc4 {
+    s: Vec<u8>,
+    i: u8,
+    j: u8,
+}
+
+impl Rc4 {
+    /// Key scheduling. Discards the first `skip` bytes of the key stream.
+    fn new(key: &[u8], skip: u64) -> Rc4 {
+        let mut s = (0..256).map(|x| x as u8).collect::<Vec<u8>>();
+        let mut j = 0u8;
+
+        for i in 0..256 {
+            j = j.wrapping_add(s[i]).wrapping_add(key[i % key.len()]);
+            s.swap(i, j as usize);
+        }
+
+        let mut ret = Rc4 { s: s, i: 0, j: 0 };
+
+        for _ in 0..skip {
+            ret.next();
+        }
+        ret
+    }
+
+    fn next(&mut self) -> u8 {
+        self.i = self.i.wrapping_add(1);
+        self.j = self.j.wrapping_add(self.s[self.i as usize]);
+        self.s.swap(self.i as usize, self.j as usize);
+
+        let k = self.s[self.i as usize].wrapping_add(self.s[self.j as usize]);
+        self.s[k as usize]
+    }
+}
+
+impl Transform {
+    /// Computes the cell at `pos` inside the layer from the `covered` one below it. `source`
+    /// iterates over all cells below the layer.
+    fn apply(&self, source: &LayerIter, pos: u64, covered: Cell, state: &mut Option<TransformState>) -> Cell {
+        if let Some(cells) = state.as_ref().and_then(|st| st.decoded.as_ref()) {
+            return cells.get(pos as usize).cloned().unwrap_or(None);
+        }
+
+        match *self {
+            Transform::Xor(ref key) if !key.is_empty() => covered.map(|b| b ^ key[(pos % key.len() as u64) as usize]),
+            Transform::Add(n) => covered.map(|b| b.wrapping_add(n)),
+            Transform::Sub(n) => covered.map(|b| b.wrapping_sub(n)),
+            Transform::RotateLeft(n) => covered.map(|b| b.rotate_left(n % 8)),
+            Transform::RotateRight(n) => covered.map(|b| b.rotate_right(n % 8)),
+            Transform::Rc4(ref key) if !key.is_empty() => {
+                if state.is_none() {
+                    *state = Some(TransformState { rc4: Some(Rc4::new(key, pos)), decoded: None });
+                }
+
+                // the key stream advances for undefined cells too
+                let k = state.as_mut().and_then(|st| st.rc4.as_mut()).map(|rc4| rc4.next()).unwrap_or(0);
+                covered.map(|b| b ^ k)
+            }
+            Transform::Base64 | Transform::Hex => {
+                let decoded = Arc::new(self.decode(source.clone()));
+                let ret = decoded.get(pos as usize).cloned().unwrap_or(None);
+
+                *state = Some(TransformState { rc4: None, decoded: Some(decoded) });
+                ret
+            }
+            Transform::Xor(_) | Transform::Rc4(_) => covered,
+        }
+    }
+
+    /// Decodes all cells in `source`. Groups with undefined cells decode to undefined ones, the
+    /// first invalid character ends the decoding.
+    fn decode(&self, source: LayerIter) -> Vec<Cell> {
+        let (bits, group) = match *self {
+            Transform::Base64 => (6, 4),
+            _ => (4, 2),
+        };
+        let mut ret = Vec::new();
+        let mut digits: Vec<Option<u32>> = Vec::with_capacity(group);
+
+        for cell in source {
+            let digit = match cell {
+                Some(b' ') | Some(b'\t') | Some(b'\r') | Some(b'\n') => continue,
+                Some(b) => {
+                    match decode_digit(*self == Transform::Base64, b) {
+                        Some(d) => Some(d),
+                        None => break,
+                    }
+                }
+                None => None,
+            };
+
+            digits.push(digit);
+
+            if digits.len() == group {
+                flush_digits(&mut ret, &digits, bits);
+                digits.clear();
+            }
+        }
+
+        // trailing, unpadded base64 group
+        if digits.len() > 1 {
+            flush_digits(&mut ret, &digits, bits);
+        }
+
+        ret
+    }
+}
+
+fn decode_digit(base64: bool, b: u8) -> Option<u32> {
+    if base64 {
+        match b {
+            b'A'..=b'Z' => Some((b - b'A') as u32),
+            b'a'..=b'z' => Some((b - b'a') as u32 + 26),
+            b'0'..=b'9' => Some((b - b'0') as u32 + 52),
+            b'+' | b'-' => Some(62),
+            b'/' | b'_' => Some(63),
+            _ => None,
+        }
+    } else {
+        match b {
+            b'0'..=b'9' => Some((b - b'0') as u32),
+            b'a'..=b'f' => Some((b - b'a') as u32 + 10),
+            b'A'..=b'F' => Some((b - b'A') as u32 + 10),
+            _ => None,
+        }
+    }
+}
+
+/// Appends the bytes encoded in `digits` of `bits` bits each to `out`.
+fn flush_digits(out: &mut Vec<Cell>, digits: &[Option<u32>], bits: usize) {
+    let len = digits.len() * bits / 8;
+
+    if digits.iter().any(|d| d.is_none()) {
+        out.extend((0..len).map(|_| None));
+    } else {
+        let value = digits.iter().fold(0u32, |acc, d| (acc << bits) | d.unwrap());
+        let value = value << (32 - digits.len() * bits);
+
+        out.extend((0..len).map(|i| Some((value >> (24 - i * 8)) as u8)));
+    }
+}
+
+fn parse_bytes(s: &str) -> Result<Vec<u8>> {
+    let s = s.trim_left_matches("0x");
+
+    if s.is_empty() || s.len() % 2 != 0 {
+        return Err(format!("'{}' is not a hexadecimal byte string", s).into());
+    }
+
+    (0..s.len() / 2)
+        .map(|i| u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).map_err(|_| format!("'{}' is not a hexadecimal byte string", s).into()))
+        .collect()
+}
+
+fn parse_number(s: &str) -> Result<u32> {
+    let ret = if s.starts_with("0x") {
+        u32::from_str_radix(&s[2..], 16)
+    } else {
+        u32::from_str(s)
+    };
+
+    ret.map_err(|_| format!("'{}' is not a number", s).into())
+}
+
+impl FromStr for Transform {
+    type Err = Error;
+
+    /// Parses transforms written like `xor:5a17`, `add:3`, `sub:0x10`, `rol:1`, `ror:2`,
+    /// `rc4:6b6579`, `base64` or `hex`. Keys are hexadecimal byte strings.
+    fn from_str(s: &str) -> Result<Transform> {
+        let mut parts = s.splitn(2, ':');
+        let kind = parts.next().unwrap_or("").to_lowercase();
+        let arg = parts.next();
+        let byte = |arg: &str| -> Result<u8> {
+            match parse_number(arg)? {
+                n if n < 256 => Ok(n as u8),
+                n => Err(format!("{} is larger than a byte", n).into()),
+            }
+        };
+
+        match (kind.as_str(), arg) {
+            ("xor", Some(key)) => Ok(Transform::Xor(parse_bytes(key)?)),
+            ("add", Some(n)) => Ok(Transform::Add(byte(n)?)),
+            ("sub", Some(n)) => Ok(Transform::Sub(byte(n)?)),
+            ("rol", Some(n)) => Ok(Transform::RotateLeft(parse_number(n)?)),
+            ("ror", Some(n)) => Ok(Transform::RotateRight(parse_number(n)?)),
+            ("rc4", Some(key)) => Ok(Transform::Rc4(parse_bytes(key)?)),
+            ("base64", None) => Ok(Transform::Base64),
+            ("hex", None) => Ok(Transform::Hex),
+            _ => Err(format!("Unknown transform '{}', expected one of xor:KEY, add:N, sub:N, rol:N, ror:N, rc4:KEY, base64 or hex", s).into()),
+        }
+    }
+}
+
+impl fmt::Display for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let hex = |key: &[u8]| key.iter().map(|b| format!("{:02x}", b)).collect::<String>();
+
+        match *self {
+            Transform::Xor(ref key) => write!(f, "xor:{}", hex(key)),
+            Transform::Add(n) => write!(f, "add:{}", n),
+            Transform::Sub(n) => write!(f, "sub:{}", n),
+            Transform::RotateLeft(n) => write!(f, "rol:{}", n),
+            Transform::RotateRight(n) => write!(f, "ror:{}", n),
+            Transform::Rc4(ref key) => write!(f, "rc4:{}", hex(key)),
+            Transform::Base64 => write!(f, "base64"),
+            Transform::Hex => write!(f, "hex"),
+        }
+    }
 }
 
 impl<'a> Iterator for LayerIter<'a> {
@@ -122,6 +396,14 @@ impl<'a> Iterator for LayerIter<'a> {
                     b.next()
                 }
             }
+            LayerIter::Transform { transform: t, source: ref s, mapped: ref mut m, pos: ref mut p, state: ref mut st } => {
+                if let Some(covered) = m.next() {
+                    *p += 1;
+                    Some(t.apply(s, *p - 1, covered, st))
+                } else {
+                    None
+                }
+            }
         }
     }
 }
@@ -158,6 +440,12 @@ impl<'a> LayerIter<'a> {
             LayerIter::Defined(None) => LayerIter::Defined(None),
             LayerIter::Defined(Some(ref buf)) => LayerIter::Defined(Some(&buf[r.start as usize..real_end as usize])),
             LayerIter::Sparse { map: ref m, mapped: ref i, pos: ref p, .. } => LayerIter::Sparse { map: m, mapped: Box::new(i.cut(r)), pos: p + r.start },
+            LayerIter::Transform { transform: t, source: ref s, mapped: ref i, pos: p, state: ref st } => {
+                // decoded cells don't depend on the position, cipher states do
+                let state = st.as_ref().and_then(|st| st.decoded.as_ref()).map(|d| TransformState { rc4: None, decoded: Some(d.clone()) });
+
+                LayerIter::Transform { transform: t, source: s.clone(), mapped: Box::new(i.cut(r)), pos: p + r.start, state: state }
+            }
             LayerIter::Concat { car: ref a, cdr: ref b } => {
                 if r.start < a.len() && real_end <= a.len() {
                     a.cut(r)
@@ -194,6 +482,7 @@ impl<'a> LayerIter<'a> {
             LayerIter::Defined(None) => 0,
             LayerIter::Defined(Some(ref r)) => r.len() as u64,
             LayerIter::Sparse { mapped: ref m, .. } => m.len(),
+            LayerIter::Transform { mapped: ref m, .. } => m.len(),
             LayerIter::Concat { car: ref a, cdr: ref b } => a.len() + b.len(),
         }
     }
@@ -209,6 +498,8 @@ pub enum Layer {
     Opaque(OpaqueLayer),
     /// Layer overwriting single cells with new values.
     Sparse(HashMap<u64, Cell>),
+    /// Layer computing new cells from the ones below, e.g. to decrypt them.
+    Transform(Transform),
 }
 
 impl OpaqueLayer {
@@ -217,6 +508,7 @@ impl OpaqueLayer {
         match *self {
             OpaqueLayer::Undefined(ref len) => LayerIter::Undefined(*len),
             OpaqueLayer::Defined(ref v) => LayerIter::Defined(Some(v)),
+            OpaqueLayer::Mapped(ref f) => LayerIter::Defined(Some(f.bytes())),
         }
     }
 
@@ -225,6 +517,7 @@ impl OpaqueLayer {
         match *self {
             OpaqueLayer::Undefined(ref len) => *len,
             OpaqueLayer::Defined(ref v) => v.len() as u64,
+            OpaqueLayer::Mapped(ref f) => f.len(),
         }
     }
 
@@ -237,6 +530,20 @@ impl OpaqueLayer {
         Ok(Self::wrap(buf))
     }
 
+    /// Create a new `Layer` that replaces overlapped `Cell`s with the contents of the file at
+    /// `path` without reading it into memory. The `Layer` will have the size of the file.
+    pub fn map(p: &Path) -> Result<OpaqueLayer> {
+        MappedFile::open(p).map(|f| OpaqueLayer::Mapped(f))
+    }
+
+    /// Returns a copy of this `Layer` that holds the contents of memory mapped files in memory.
+    pub fn embed(&self) -> OpaqueLayer {
+        match *self {
+            OpaqueLayer::Mapped(ref f) => OpaqueLayer::wrap(f.bytes().to_vec()),
+            ref o => o.clone(),
+        }
+    }
+
     /// Create a new `Layer` that replaces overlapped `Cell`s with the contents of `data`.
     /// The `Layer` will have the size of the vector.
     pub fn wrap(data: Vec<u8>) -> OpaqueLayer {
@@ -255,6 +562,19 @@ impl Layer {
         match *self {
             Layer::Opaque(ref o) => o.iter(),
             Layer::Sparse(ref m) => LayerIter::Sparse { map: m, mapped: Box::new(i), pos: 0 },
+            Layer::Transform(ref t) => LayerIter::Transform { transform: t, source: Box::new(i.clone()), mapped: Box::new(i), pos: 0, state: None },
+        }
+    }
+
+    /// Like `filter`, but a `Transform` layer yields `cells`, its output computed before, instead
+    /// of transforming `i` again.
+    pub fn filter_cached<'a>(&'a self, i: LayerIter<'a>, cells: Arc<Vec<Cell>>) -> LayerIter<'a> {
+        match *self {
+            Layer::Transform(ref t) => {
+                let state = TransformState { rc4: None, decoded: Some(cells) };
+                LayerIter::Transform { transform: t, source: Box::new(i.clone()), mapped: Box::new(i), pos: 0, state: Some(state) }
+            }
+            _ => self.filter(i),
         }
     }
 
@@ -319,6 +639,18 @@ impl Layer {
     }
 }
 
+impl fmt::Display for Layer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Layer::Opaque(OpaqueLayer::Undefined(len)) => write!(f, "undefined ({} cells)", len),
+            Layer::Opaque(OpaqueLayer::Defined(ref v)) => write!(f, "data ({} bytes)", v.len()),
+            Layer::Opaque(OpaqueLayer::Mapped(ref m)) => write!(f, "file {} ({:#x}+{:#x})", m.path().display(), m.offset(), m.len()),
+            Layer::Sparse(ref m) => write!(f, "sparse ({} cells)", m.len()),
+            Layer::Transform(ref t) => write!(f, "transform {}", t),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -408,6 +740,98 @@ mod tests {
         assert_eq!(s.collect::<Vec<Cell>>(), e);
     }
 
+    #[test]
+    fn transform_bytes() {
+        let l1 = OpaqueLayer::wrap(vec![0x00, 0x11, 0x22, 0x33, 0x81]);
+        let xor = Layer::Transform(Transform::Xor(vec![0xff, 0x0f]));
+        let add = Layer::Transform(Transform::Add(0x80));
+        let sub = Layer::Transform(Transform::Sub(1));
+        let rol = Layer::Transform(Transform::RotateLeft(1));
+        let ror = Layer::Transform(Transform::RotateRight(9));
+
+        assert_eq!(xor.filter(l1.iter()).collect::<Vec<Cell>>(), vec![Some(0xff), Some(0x1e), Some(0xdd), Some(0x3c), Some(0x7e)]);
+        assert_eq!(add.filter(l1.iter()).collect::<Vec<Cell>>(), vec![Some(0x80), Some(0x91), Some(0xa2), Some(0xb3), Some(0x01)]);
+        assert_eq!(sub.filter(l1.iter()).collect::<Vec<Cell>>(), vec![Some(0xff), Some(0x10), Some(0x21), Some(0x32), Some(0x80)]);
+        assert_eq!(rol.filter(l1.iter()).collect::<Vec<Cell>>(), vec![Some(0x00), Some(0x22), Some(0x44), Some(0x66), Some(0x03)]);
+        assert_eq!(ror.filter(l1.iter()).collect::<Vec<Cell>>(), vec![Some(0x00), Some(0x88), Some(0x11), Some(0x99), Some(0xc0)]);
+
+        // key stream stays aligned to the start of the layer
+        assert_eq!(xor.filter(l1.iter()).cut(&(1..3)).collect::<Vec<Cell>>(), vec![Some(0x1e), Some(0xdd)]);
+
+        let l2 = OpaqueLayer::undefined(2);
+        assert_eq!(xor.filter(l2.iter()).collect::<Vec<Cell>>(), vec![None, None]);
+    }
+
+    #[test]
+    fn transform_rc4() {
+        let l1 = OpaqueLayer::wrap(b"Plaintext".to_vec());
+        let rc4 = Layer::Transform(Transform::Rc4(b"Key".to_vec()));
+        let e = vec![0xbb, 0xf3, 0x16, 0xe8, 0xd9, 0x40, 0xaf, 0x0a, 0xd3].into_iter().map(Some).collect::<Vec<Cell>>();
+
+        assert_eq!(rc4.filter(l1.iter()).collect::<Vec<Cell>>(), e);
+        assert_eq!(rc4.filter(l1.iter()).cut(&(4..9)).collect::<Vec<Cell>>(), e[4..].to_vec());
+
+        let mut i = rc4.filter(l1.iter());
+        i.next();
+        assert_eq!(i.cut(&(1..3)).collect::<Vec<Cell>>(), e[2..4].to_vec());
+    }
+
+    #[test]
+    fn transform_cached() {
+        let l1 = OpaqueLayer::wrap(b"Plaintext".to_vec());
+        let rc4 = Layer::Transform(Transform::Rc4(b"Key".to_vec()));
+        let e = rc4.filter(l1.iter()).collect::<Vec<Cell>>();
+        let i = rc4.filter_cached(l1.iter(), Arc::new(e.clone()));
+
+        assert_eq!(i.clone().collect::<Vec<Cell>>(), e);
+        assert_eq!(i.cut(&(4..9)).collect::<Vec<Cell>>(), e[4..].to_vec());
+
+        let xor = Layer::Transform(Transform::Xor(vec![0xff]));
+        assert_eq!(xor.filter_cached(l1.iter(), Arc::new(vec![None; 9])).collect::<Vec<Cell>>(), vec![None; 9]);
+    }
+
+    #[test]
+    fn transform_decode() {
+        let l1 = OpaqueLayer::wrap(b"SGVs\nbG8=".to_vec());
+        let l2 = OpaqueLayer::wrap(b"48 65 6c 6cxx".to_vec());
+        let l3 = OpaqueLayer::wrap(b"SGV".to_vec());
+        let b64 = Layer::Transform(Transform::Base64);
+        let hex = Layer::Transform(Transform::Hex);
+
+        assert_eq!(
+            b64.filter(l1.iter()).collect::<Vec<Cell>>(),
+            vec![Some(b'H'), Some(b'e'), Some(b'l'), Some(b'l'), Some(b'o'), None, None, None, None]
+        );
+        assert_eq!(b64.filter(l1.iter()).cut(&(3..6)).collect::<Vec<Cell>>(), vec![Some(b'l'), Some(b'o'), None]);
+        assert_eq!(
+            hex.filter(l2.iter()).collect::<Vec<Cell>>()[0..5].to_vec(),
+            vec![Some(b'H'), Some(b'e'), Some(b'l'), Some(b'l'), None]
+        );
+        assert_eq!(b64.filter(l3.iter()).collect::<Vec<Cell>>(), vec![Some(b'H'), Some(b'e'), None]);
+
+        let l4 = OpaqueLayer::undefined(4);
+        let l5 = OpaqueLayer::wrap(b"SGVs".to_vec());
+        assert_eq!(
+            b64.filter(l4.iter().append(l5.iter())).collect::<Vec<Cell>>(),
+            vec![None, None, None, Some(b'H'), Some(b'e'), Some(b'l'), None, None]
+        );
+    }
+
+    #[test]
+    fn parse_transform() {
+        for s in &["xor:5a17", "add:3", "sub:16", "rol:1", "ror:2", "rc4:6b6579", "base64", "hex"] {
+            let t = s.parse::<Transform>().unwrap();
+            assert_eq!(format!("{}", t), *s);
+        }
+
+        assert_eq!("sub:0x10".parse::<Transform>().unwrap(), Transform::Sub(16));
+        assert_eq!("XOR:0xFF".parse::<Transform>().unwrap(), Transform::Xor(vec![0xff]));
+        assert!("xor:5".parse::<Transform>().is_err());
+        assert!("add:256".parse::<Transform>().is_err());
+        assert!("hex:1".parse::<Transform>().is_err());
+        assert!("aes:00".parse::<Transform>().is_err());
+    }
+
     #[test]
     fn random_access_iter() {
         let l1 = OpaqueLayer::undefined(0xffffffff);
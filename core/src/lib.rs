@@ -80,6 +80,8 @@ extern crate log;
 
 extern crate num;
 extern crate flate2;
+extern crate memmap;
+extern crate sha2;
 extern crate panopticon_graph_algos;
 extern crate uuid;
 extern crate byteorder;
@@ -98,7 +100,7 @@ pub use disassembler::{Architecture, Disassembler, Match, State};
 
 #[macro_use]
 pub mod il;
-pub use il::{Guard, Lvalue, Operation, Rvalue, Statement, execute, Endianess};
+pub use il::{Guard, Lvalue, Operation, Rvalue, Statement, execute, parse_statements, Endianess, Rounding};
 
 pub mod mnemonic;
 pub use mnemonic::{Bound, Mnemonic, MnemonicFormatToken};
@@ -118,11 +120,38 @@ pub mod region;
 pub use region::{Region, World};
 
 pub mod layer;
-pub use layer::{Layer, LayerIter, OpaqueLayer};
+pub use layer::{Layer, LayerIter, OpaqueLayer, Transform};
+
+pub mod codec;
+pub use codec::Codec;
+
+pub mod mapped;
+pub use mapped::MappedFile;
 
 pub mod result;
 pub use result::{Error, Result};
 
+pub mod segment;
+pub use segment::{Segment, SegmentKind};
+
+pub mod search;
+pub use search::{Hit, Pattern, StringKind};
+
+pub mod emulator;
+pub use emulator::{Emulator, Memory, Stop};
+
+pub mod dwarf;
+pub use dwarf::{DebugFunction, DebugInfo, DebugType, DebugVariable, SourceLocation};
+
+pub mod unwind;
+
+mod reader;
+
+pub mod demangle;
+
+pub mod coredump;
+pub use coredump::{CoreDump, FileMapping, Thread};
+
 // file formats
 pub mod loader;
-pub use loader::{Machine, load};
+pub use loader::{MachSlice, Machine, load, load_at, load_raw};
@@ -16,21 +16,26 @@
  * along with this program.  If not, see <http://www.gnu.org/licenses/>.
  */
 
-//! Loader for 32 and 64-bit ELF, PE, and Mach-o files.
+//! Loader for 32 and 64-bit ELF, PE, and Mach-o files, ELF relocatable objects, static libraries, DOS MZ executables and
+//! `.com` files as well as Intel HEX and Motorola S-record firmware images.
 
 
-use {Bound, CallTarget, Layer, Program, Project, Region, Result, Rvalue};
+use {Architecture, Bound, CallTarget, CoreDump, DebugInfo, Error, Layer, MappedFile, OpaqueLayer, Program, Project, Region, Result, Rvalue, Segment, SegmentKind};
+use dwarf::DwarfSections;
+use unwind;
 use goblin::{self, Hint, archive, elf, mach, pe};
 use goblin::elf::program_header;
 
 use panopticon_graph_algos::MutableGraphTrait;
+use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
 use std::io::{Cursor, Read, Seek, SeekFrom};
 use std::path::Path;
+use std::str::FromStr;
 use uuid::Uuid;
 
 /// CPU the binary file is intended for.
-#[derive(Clone,Copy,Debug)]
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
 pub enum Machine {
     /// 8-bit AVR
     Avr,
@@ -38,6 +43,97 @@ pub enum Machine {
     Amd64,
     /// Intel x86
     Ia32,
+    /// Intel x86 in 16-bit real mode
+    Ia16,
+}
+
+impl FromStr for Machine {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Machine> {
+        match s.to_lowercase().as_str() {
+            "avr" => Ok(Machine::Avr),
+            "amd64" | "x86_64" | "x86-64" => Ok(Machine::Amd64),
+            "ia32" | "x86" | "i386" => Ok(Machine::Ia32),
+            "ia16" | "8086" | "i8086" | "real" => Ok(Machine::Ia16),
+            _ => Err(format!("Unknown machine '{}', expected one of avr, amd64, ia32 or ia16", s).into()),
+        }
+    }
+}
+
+/// A single architecture slice of a fat (universal) Mach-o binary.
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+pub struct MachSlice {
+    /// Mach-o CPU type of the slice.
+    pub cputype: u32,
+    /// Start of the slice, relative to the start of the fat binary.
+    pub offset: u64,
+    /// Size of the slice in bytes.
+    pub size: u64,
+}
+
+impl MachSlice {
+    /// CPU the slice is intended for, `None` if Panopticon does not support it.
+    pub fn machine(&self) -> Option<Machine> {
+        match self.cputype {
+            mach::cputype::CPU_TYPE_X86 => Some(Machine::Ia32),
+            mach::cputype::CPU_TYPE_X86_64 => Some(Machine::Amd64),
+            _ => None,
+        }
+    }
+
+    /// Human-readable name of the slice's CPU type.
+    pub fn cpu_name(&self) -> &'static str {
+        mach::cputype::cpu_type_to_str(self.cputype)
+    }
+}
+
+/// Returns all architecture slices inside the fat Mach-o binary `bytes`.
+pub fn mach_slices(bytes: &[u8]) -> Result<Vec<MachSlice>> {
+    let multi = mach::MultiArch::new(&bytes)?;
+    let slices = multi.arches()?
+        .into_iter()
+        .map(|arch| MachSlice { cputype: arch.cputype as u32, offset: arch.offset as u64, size: arch.size as u64 })
+        .collect::<Vec<_>>();
+
+    debug!("fat mach slices: {:?}", &slices);
+    Ok(slices)
+}
+
+/// Parses the fat Mach-o binary in `bytes` and creates a `Project` from slice number `slice`. If
+/// `slice` is `None` the first slice with a supported CPU type is loaded. Returns the `Project`
+/// instance and the CPU its intended for.
+pub fn load_fat_mach(bytes: &[u8], slice: Option<usize>, name: String) -> Result<(Project, Machine)> {
+    let slices = mach_slices(bytes)?;
+    let slice = match slice {
+        Some(idx) => {
+            match slices.get(idx) {
+                Some(s) => *s,
+                None => return Err(format!("Fat mach-o binary has no slice #{} (only {} slices)", idx, slices.len()).into()),
+            }
+        }
+        None => {
+            match slices.iter().find(|s| s.machine().is_some()) {
+                Some(s) => *s,
+                None => return Err("Fat mach-o binary has no slice for a supported CPU".into()),
+            }
+        }
+    };
+    let start = slice.offset as usize;
+    let end = match start.checked_add(slice.size as usize) {
+        Some(end) if end <= bytes.len() => end,
+        _ => return Err(format!("Failed to read slice: range {:#x}+{:#x} greater than len {}", start, slice.size, bytes.len()).into()),
+    };
+
+    debug!("loading {} slice at {:?}", slice.cpu_name(), start..end);
+    let (mut proj, machine) = load_mach(&bytes[start..end], 0, name)?;
+
+    // file offsets are relative to the slice, make them relative to the fat binary
+    for seg in proj.segments.iter_mut() {
+        seg.file_offset = seg.file_offset.map(|off| off + start as u64);
+    }
+
+    Ok((proj, machine))
 }
 
 /// Parses a non-fat Mach-o binary from `bytes` at `offset` and creates a `Project` from it. Returns the `Project` instance and
@@ -68,6 +164,8 @@ pub fn load_mach(bytes: &[u8], offset: usize, name: String) -> Result<(Project,
         }
     };
 
+    let mut segments = Vec::new();
+
     for segment in &*binary.segments {
         let offset = segment.fileoff as usize;
         let filesize = segment.filesize as usize;
@@ -92,6 +190,11 @@ pub fn load_mach(bytes: &[u8], offset: usize, name: String) -> Result<(Project,
             start
         );
         reg.cover(Bound::new(start, end), Layer::wrap(Vec::from(section)));
+        if segment.vmsize > 0 {
+            let flags = (segment.initprot & 1 != 0, segment.initprot & 2 != 0, segment.initprot & 4 != 0);
+            let file_offset = if segment.filesize > 0 { Some(segment.fileoff) } else { None };
+            segments.push(Segment::new(name.to_string(), SegmentKind::Segment, Bound::new(start, end), file_offset, segment.filesize, flags));
+        }
         if name == "__TEXT" {
             base = segment.vmaddr;
             debug!("Setting vm address base to {:#x}", base);
@@ -107,6 +210,8 @@ pub fn load_mach(bytes: &[u8], offset: usize, name: String) -> Result<(Project,
     let mut prog = Program::new("prog0");
     let mut proj = Project::new(name.clone(), reg);
 
+    proj.segments = segments;
+
     let entry = binary.entry;
 
     if entry != 0 {
@@ -140,31 +245,237 @@ pub fn load_mach(bytes: &[u8], offset: usize, name: String) -> Result<(Project,
     Ok((proj, machine))
 }
 
-/// Parses an ELF 32/64-bit binary from `bytes` and creates a `Project` from it. Returns the `Project` instance and
-/// the CPU its intended for.
-fn load_elf(bytes: &[u8], name: String) -> Result<(Project, Machine)> {
+/// Returns an empty `Region` spanning the address space of `machine`.
+fn machine_region(machine: Machine) -> Region {
+    match machine {
+        Machine::Amd64 => Region::undefined("RAM".to_string(), 0xFFFF_FFFF_FFFF_FFFF),
+        Machine::Ia32 => Region::undefined("RAM".to_string(), 0x1_0000_0000),
+        Machine::Ia16 => Region::undefined("RAM".to_string(), 0x10_0000),
+        Machine::Avr => Region::undefined("Flash".to_string(), 0x2_0000),
+    }
+}
+
+/// Returns empty `Region`s for the address spaces of `machine` other than the one code is
+/// fetched from. AVR has separate data memory (SRAM), I/O registers and EEPROM. The `Region`s are
+/// named after the memory banks used in the IL.
+fn machine_banks(machine: Machine) -> Vec<Region> {
+    match machine {
+        Machine::Avr => {
+            vec![
+                Region::undefined("SRAM".to_string(), 0x1_0000),
+                Region::undefined("IO".to_string(), 0x40),
+                Region::undefined("EEPROM".to_string(), 0x1_0000),
+            ]
+        }
+        _ => vec![],
+    }
+}
+
+/// Returns the bank and the address inside of it of the ELF address `addr`. avr-gcc places data
+/// memory at 0x800000 and EEPROM at 0x810000.
+fn elf_bank(machine: Machine, addr: u64) -> Option<(&'static str, u64)> {
+    match machine {
+        Machine::Avr if addr >= 0x80_0000 && addr < 0x81_0000 => Some(("SRAM", addr - 0x80_0000)),
+        Machine::Avr if addr >= 0x81_0000 && addr < 0x82_0000 => Some(("EEPROM", addr - 0x81_0000)),
+        _ => None,
+    }
+}
+
+/// Returns the CPU the ELF file `binary` is intended for and an empty `Region` spanning its address space.
+fn elf_machine(binary: &elf::Elf) -> Result<(Machine, Region)> {
+    let machine = match binary.header.e_machine {
+        elf::header::EM_X86_64 => Machine::Amd64,
+        elf::header::EM_386 => Machine::Ia32,
+        elf::header::EM_AVR => Machine::Avr,
+        machine => return Err(format!("Unsupported machine: {}", machine).into()),
+    };
+
+    Ok((machine, machine_region(machine)))
+}
+
+/// Returns the loadable segments and allocated sections of `binary`. Sections are placed at the addresses in
+/// `mapped` if given (relocatable objects), otherwise at their `sh_addr`. All addresses are moved by `bias`.
+fn elf_segments(binary: &elf::Elf, mapped: Option<&HashMap<usize, u64>>, bias: u64) -> Vec<Segment> {
+    use goblin::elf::section_header::{SHF_ALLOC, SHF_EXECINSTR, SHF_WRITE, SHT_NOBITS};
+
+    let mut ret = Vec::new();
+
+    for (idx, ph) in binary.program_headers.iter().enumerate() {
+        if ph.p_type == program_header::PT_LOAD {
+            let flags = (
+                ph.p_flags & program_header::PF_R != 0,
+                ph.p_flags & program_header::PF_W != 0,
+                ph.p_flags & program_header::PF_X != 0,
+            );
+            let bound = Bound::new(ph.p_vaddr.wrapping_add(bias), ph.p_vaddr.wrapping_add(bias).wrapping_add(ph.p_memsz));
+
+            ret.push(Segment::new(format!("LOAD{}", idx), SegmentKind::Segment, bound, Some(ph.p_offset), ph.p_filesz, flags));
+        }
+    }
+
+    for (idx, sh) in binary.section_headers.iter().enumerate() {
+        if (sh.sh_flags & SHF_ALLOC as u64) == 0 || sh.sh_size == 0 {
+            continue;
+        }
+
+        let start = match mapped {
+            Some(mapped) => {
+                match mapped.get(&idx) {
+                    Some(&addr) => addr,
+                    None => continue,
+                }
+            }
+            None => sh.sh_addr,
+        }.wrapping_add(bias);
+        let flags = (true, sh.sh_flags & SHF_WRITE as u64 != 0, sh.sh_flags & SHF_EXECINSTR as u64 != 0);
+        let offset = if sh.sh_type == SHT_NOBITS { None } else { Some(sh.sh_offset) };
+        let name = binary.shdr_strtab[sh.sh_name].to_string();
+        let end = match start.checked_add(sh.sh_size) {
+            Some(end) => end,
+            None => {
+                debug!("Ignoring section {} at {:#x}, it wraps around the address space", name, start);
+                continue;
+            }
+        };
+
+        ret.push(Segment::new(name, SegmentKind::Section, Bound::new(start, end), offset, sh.sh_size, flags));
+    }
+
+    ret
+}
+
+/// Returns the file offset of virtual address `addr` of the ELF file `binary`.
+fn elf_file_offset(binary: &elf::Elf, addr: u64) -> Option<u64> {
+    binary
+        .program_headers
+        .iter()
+        .find(|ph| ph.p_type == program_header::PT_LOAD && ph.p_vaddr <= addr && ph.p_vaddr.checked_add(ph.p_filesz).map_or(false, |e| addr < e))
+        .and_then(|ph| ph.p_offset.checked_add(addr - ph.p_vaddr))
+}
+
+/// Computes the dynamic relocations of `binary` after moving it by `bias` bytes. Returns the address, new
+/// value and size in bytes of each relocated field. Relocations against undefined symbols are left to the
+/// import resolution.
+fn elf_dynamic_relocs(bytes: &[u8], binary: &elf::Elf, machine: Machine, bias: u64) -> Result<Vec<(u64, u64, usize)>> {
+    use byteorder::{ByteOrder, LittleEndian};
+
+    const R_X86_64_64: u32 = 1;
+    const R_X86_64_GLOB_DAT: u32 = 6;
+    const R_X86_64_JUMP_SLOT: u32 = 7;
+    const R_X86_64_RELATIVE: u32 = 8;
+    const R_386_32: u32 = 1;
+    const R_386_GLOB_DAT: u32 = 6;
+    const R_386_JMP_SLOT: u32 = 7;
+    const R_386_RELATIVE: u32 = 8;
+
+    let mut ret = Vec::new();
+
+    // x86_64 only uses RELA, i386 only REL relocations
+    for reloc in binary.dynrelas.iter().chain(binary.dynrels.iter()).chain(binary.pltrelocs.iter()) {
+        let addr = reloc.r_offset as u64;
+        let sym = binary
+            .dynsyms
+            .get(reloc.r_sym)
+            .and_then(|sym| if sym.st_shndx != 0 { Some(sym.st_value.wrapping_add(bias)) } else { None });
+        let addend = if machine == Machine::Amd64 {
+            reloc.r_addend as i64 as u64
+        } else {
+            match elf_file_offset(binary, addr) {
+                Some(off) if (off as usize) + 4 <= bytes.len() => LittleEndian::read_i32(&bytes[off as usize..off as usize + 4]) as i64 as u64,
+                _ => {
+                    debug!("Relocation at {:#x} outside of the file", addr);
+                    continue;
+                }
+            }
+        };
+        let value = match (machine, reloc.r_type) {
+            (Machine::Amd64, R_X86_64_RELATIVE) => Some((bias.wrapping_add(addend), 8)),
+            (Machine::Amd64, R_X86_64_64) => sym.map(|s| (s.wrapping_add(addend), 8)),
+            (Machine::Amd64, R_X86_64_GLOB_DAT) |
+            (Machine::Amd64, R_X86_64_JUMP_SLOT) => sym.map(|s| (s, 8)),
+            (Machine::Ia32, R_386_RELATIVE) => Some((bias.wrapping_add(addend), 4)),
+            (Machine::Ia32, R_386_32) => sym.map(|s| (s.wrapping_add(addend), 4)),
+            (Machine::Ia32, R_386_GLOB_DAT) |
+            (Machine::Ia32, R_386_JMP_SLOT) => sym.map(|s| (s, 4)),
+            (_, typ) => {
+                debug!("Unsupported dynamic relocation type {} at {:#x}", typ, addr);
+                None
+            }
+        };
+
+        if let Some((value, len)) = value {
+            ret.push((addr.wrapping_add(bias), value, len));
+        }
+    }
+
+    Ok(ret)
+}
+
+/// Writes the relocated fields `fixups` as (address, value, size in bytes) into a new `Layer::Sparse` on
+/// top of `reg`. Returns the position of the new layer in the stack of `reg`, `None` if there was nothing
+/// to relocate.
+fn cover_relocations(reg: &mut Region, fixups: &[(u64, u64, usize)]) -> Result<Option<usize>> {
+    let start = match fixups.iter().map(|&(addr, _, _)| addr).min() {
+        Some(start) => start,
+        None => return Ok(None),
+    };
+    let end = fixups.iter().map(|&(addr, _, len)| addr + len as u64).max().unwrap_or(start);
+    let mut layer = Layer::writable();
+
+    for &(addr, value, len) in fixups {
+        debug!("Relocate {:#x} to {:#x}", addr, value);
+
+        for i in 0..len {
+            layer.write(addr - start + i as u64, Some((value >> (i * 8)) as u8));
+        }
+    }
+
+    if !reg.cover(Bound::new(start, end), layer) {
+        return Err(format!("Cannot cover bound: {:?}", Bound::new(start, end)).into());
+    }
+
+    Ok(Some(reg.stack().len() - 1))
+}
+
+/// Parses the ELF 32/64-bit binary in `file` and creates a `Project` from it. Position independent
+/// files are mapped to `base` if given. Returns the `Project` instance and the CPU its intended for.
+fn load_elf(file: &MappedFile, name: String, base: Option<u64>) -> Result<(Project, Machine)> {
     use std::collections::HashSet;
 
+    let bytes = file.bytes();
     let mut cursor = Cursor::new(&bytes);
     let binary = elf::Elf::parse(&bytes)?;
     debug!("elf: {:#?}", &binary);
 
-    let entry = binary.entry;
-    let (machine, mut reg) = match binary.header.e_machine {
-        elf::header::EM_X86_64 => {
-            let reg = Region::undefined("RAM".to_string(), 0xFFFF_FFFF_FFFF_FFFF);
-            (Machine::Amd64, reg)
+    if binary.header.e_type == elf::header::ET_REL {
+        if base.is_some() {
+            return Err("Relocatable objects cannot be rebased".into());
         }
-        elf::header::EM_386 => {
-            let reg = Region::undefined("RAM".to_string(), 0x1_0000_0000);
-            (Machine::Ia32, reg)
-        }
-        elf::header::EM_AVR => {
-            let reg = Region::undefined("Flash".to_string(), 0x2_0000);
-            (Machine::Avr, reg)
+        return load_elf_object(bytes, &binary, name);
+    }
+    if binary.header.e_type == elf::header::ET_CORE {
+        if base.is_some() {
+            return Err("Core dumps cannot be rebased".into());
         }
-        machine => return Err(format!("Unsupported machine: {}", machine).into()),
+        return load_elf_core(file, &binary, name);
+    }
+
+    let (machine, mut reg) = elf_machine(&binary)?;
+    let mut banks = machine_banks(machine);
+    let preferred = binary
+        .program_headers
+        .iter()
+        .filter(|ph| ph.p_type == program_header::PT_LOAD)
+        .map(|ph| ph.p_vaddr & !0xfff)
+        .min()
+        .unwrap_or(0);
+    let bias = match base {
+        None => 0,
+        Some(base) if base == preferred => 0,
+        Some(base) if binary.header.e_type == elf::header::ET_DYN => base.wrapping_sub(preferred),
+        Some(_) => return Err(format!("{} is not position independent and cannot be rebased", name).into()),
     };
+    let entry = (binary.entry as u64).wrapping_add(bias);
 
     for ph in &binary.program_headers {
         if ph.p_type == program_header::PT_LOAD {
@@ -173,18 +484,45 @@ fn load_elf(bytes: &[u8], name: String) -> Result<(Project, Machine)> {
             debug!(
                 "Load ELF {} bytes segment to {:#x}",
                 ph.p_filesz,
-                ph.p_vaddr
+                ph.p_vaddr.wrapping_add(bias)
             );
 
             if cursor.seek(SeekFrom::Start(ph.p_offset)).ok() == Some(ph.p_offset) {
                 cursor.read_exact(&mut buf)?;
-                reg.cover(
-                    Bound::new(ph.p_vaddr, ph.p_vaddr + ph.p_filesz),
-                    Layer::wrap(buf),
-                );
             } else {
                 return Err("Failed to read segment".into());
             }
+
+            match elf_bank(machine, ph.p_vaddr) {
+                Some((bank, start)) => {
+                    // initialized data is copied from flash at startup
+                    if ph.p_paddr != ph.p_vaddr && ph.p_filesz > 0 {
+                        reg.cover(Bound::new(ph.p_paddr, ph.p_paddr.wrapping_add(ph.p_filesz)), Layer::wrap(buf.clone()));
+                    }
+
+                    if let Some(bank) = banks.iter_mut().find(|r| r.name() == bank) {
+                        if ph.p_memsz > 0 {
+                            let end = match start.checked_add(ph.p_memsz) {
+                                Some(end) if end <= bank.size() => end,
+                                _ => return Err(format!("Segment of {:#x} bytes at {:#x} does not fit into {}", ph.p_memsz, start, bank.name()).into()),
+                            };
+
+                            // .bss is cleared at startup
+                            buf.resize(ph.p_memsz as usize, 0);
+
+                            if !bank.cover(Bound::new(start, end), Layer::wrap(buf)) {
+                                return Err(format!("Cannot cover {:#x}-{:#x} of {}", start, end, bank.name()).into());
+                            }
+                        }
+                    }
+                }
+                None => {
+                    reg.cover(
+                        Bound::new(ph.p_vaddr.wrapping_add(bias), ph.p_vaddr.wrapping_add(bias).wrapping_add(ph.p_filesz)),
+                        Layer::wrap(buf),
+                    );
+                }
+            }
         }
     }
 
@@ -196,14 +534,22 @@ fn load_elf(bytes: &[u8], name: String) -> Result<(Project, Machine)> {
 
     debug!("interpreter: {:?}", &binary.interpreter);
 
+    let fixups = elf_dynamic_relocs(bytes, &binary, machine, bias)?;
+    let relocations = cover_relocations(&mut reg, &fixups)?;
     let mut prog = Program::new("prog0");
     let mut proj = Project::new(name.clone(), reg);
 
-    prog.call_graph.add_vertex(CallTarget::Todo(Rvalue::new_u64(entry as u64), Some(name), Uuid::new_v4()));
+    for bank in banks {
+        proj.data.add_region(bank)?;
+    }
+
+    proj.segments = elf_segments(&binary, None, bias);
+    proj.relocations = relocations;
+    prog.call_graph.add_vertex(CallTarget::Todo(Rvalue::new_u64(entry), Some(name), Uuid::new_v4()));
 
     let add_sym = |prog: &mut Program, sym: &elf::Sym, name: &str| {
         let name = name.to_string();
-        let addr = sym.st_value;
+        let addr = sym.st_value.wrapping_add(bias);
         debug!("Symbol: {} @ 0x{:x}: {:?}", name, addr, sym);
         if sym.is_function() {
             if sym.is_import() {
@@ -219,8 +565,8 @@ fn load_elf(bytes: &[u8], name: String) -> Result<(Project, Machine)> {
             let pltsym = &binary.dynsyms[reloc.r_sym];
             let pltname = &binary.dynstrtab[pltsym.st_name];
             if pltname == name {
-                debug!("Import match {}: {:#x} {:?}", name, reloc.r_offset, pltsym);
-                proj.imports.insert(reloc.r_offset as u64, name.to_string());
+                debug!("Import match {}: {:#x} {:?}", name, (reloc.r_offset as u64).wrapping_add(bias), pltsym);
+                proj.imports.insert((reloc.r_offset as u64).wrapping_add(bias), name.to_string());
                 return true;
             }
         }
@@ -229,6 +575,8 @@ fn load_elf(bytes: &[u8], name: String) -> Result<(Project, Machine)> {
 
     let mut seen_syms = HashSet::<u64>::new();
 
+    seen_syms.insert(binary.entry as u64);
+
     // add dynamic symbol information (non-strippable)
     for sym in &binary.dynsyms {
         let name = &binary.dynstrtab[sym.st_name];
@@ -255,6 +603,38 @@ fn load_elf(bytes: &[u8], name: String) -> Result<(Project, Machine)> {
         }
         seen_syms.insert(sym.st_value);
     }
+
+    // seed and name functions using the debug information
+    proj.debug_info = elf_debug_info(&binary, bytes, bias);
+
+    for func in proj.debug_info.functions.iter() {
+        if seen_syms.insert(func.bound.start.wrapping_sub(bias)) {
+            debug!("Debug info function: {} @ {:#x}", func.name, func.bound.start);
+            prog.call_graph.add_vertex(CallTarget::Todo(Rvalue::new_u64(func.bound.start), Some(func.symbol_name().to_string()), Uuid::new_v4()));
+        }
+    }
+
+    // unwind tables survive stripping
+    for bound in elf_unwind_functions(&binary, bytes) {
+        let start = bound.start.wrapping_add(bias);
+
+        if seen_syms.insert(bound.start) {
+            debug!("Unwind info function @ {:#x}", start);
+            prog.call_graph.add_vertex(CallTarget::Todo(Rvalue::new_u64(start), None, Uuid::new_v4()));
+        }
+        if bound.end > bound.start {
+            prog.extents.insert(start, Bound::new(start, bound.end.wrapping_add(bias)));
+        }
+    }
+
+    for ct in prog.call_graph.vertex_labels_mut() {
+        if let &mut CallTarget::Todo(Rvalue::Constant { value, .. }, ref mut name, _) = ct {
+            if let Some(func) = proj.debug_info.functions.iter().find(|f| f.bound.start == value) {
+                *name = Some(func.symbol_name().to_string());
+            }
+        }
+    }
+
     prog.imports = proj.imports.clone();
     proj.comments.insert(("base".to_string(), entry), "main".to_string());
     proj.code.push(prog);
@@ -262,108 +642,1296 @@ fn load_elf(bytes: &[u8], name: String) -> Result<(Project, Machine)> {
     Ok((proj, machine))
 }
 
-/// Parses a PE32/PE32+ file from `bytes` and create a project from it.
-fn load_pe(bytes: &[u8], name: String) -> Result<(Project, Machine)> {
-    let pe = pe::PE::parse(&bytes)?;
-    debug!("pe: {:#?}", &pe);
-    let image_base = pe.image_base as u64;
-    let mut ram = Region::undefined("RAM".to_string(), 0x100000000);
-    for section in &pe.sections {
-        let name = String::from_utf8_lossy(&section.name);
-        debug!("section: {}", name);
-        let virtual_address = section.virtual_address as u64;
-        let offset = section.pointer_to_raw_data as usize;
-        let (layer, size) = {
-            let vsize = section.virtual_size as u64;
-            let size = section.size_of_raw_data as usize;
-            if size > 0 {
-                if offset + size >= bytes.len() {
-                    debug!(
-                        "bad section pointer: {:#x} + {:#x} >= {:#x}",
-                        offset,
-                        size,
-                        bytes.len()
-                    );
-                    (Layer::undefined(0), 0)
-                } else {
-                    debug!("mapped '{}': {:?}", name, offset..offset + size);
-                    (Layer::wrap(bytes[offset..offset + size].to_vec()), size as u64)
+/// Returns the address ranges of all functions in the `.eh_frame` section of `binary`. The section is
+/// found using the `PT_GNU_EH_FRAME` segment if the section headers are missing. Functions only
+/// listed in `.eh_frame_hdr` have an empty range.
+fn elf_unwind_functions(binary: &elf::Elf, bytes: &[u8]) -> Vec<Bound> {
+    use goblin::elf::section_header::SHT_NOBITS;
+
+    const PT_GNU_EH_FRAME: u32 = 0x6474_e550;
+
+    let address_size = if binary.is_64 { 8 } else { 4 };
+    let eh_frame = binary
+        .section_headers
+        .iter()
+        .find(|sh| &binary.shdr_strtab[sh.sh_name] == ".eh_frame" && sh.sh_type != SHT_NOBITS)
+        .map(|sh| sh.sh_addr);
+    let hdr = binary.program_headers.iter().find(|ph| ph.p_type == PT_GNU_EH_FRAME);
+    let (hdr_frame, mut ret) = match hdr {
+        Some(ph) => {
+            let start = ph.p_offset as usize;
+
+            match start.checked_add(ph.p_filesz as usize) {
+                Some(end) if end <= bytes.len() => {
+                    match unwind::eh_frame_hdr(&bytes[start..end], ph.p_vaddr, address_size) {
+                        Ok((frame, starts)) => (frame, starts.into_iter().map(|a| Bound::new(a, a)).collect()),
+                        Err(e) => {
+                            debug!("Failed to read .eh_frame_hdr: {}", e);
+                            (None, vec![])
+                        }
+                    }
                 }
-            } else {
-                debug!("bss '{}'", name);
-                (Layer::undefined(vsize), vsize)
+                _ => (None, vec![]),
+            }
+        }
+        None => (None, vec![]),
+    };
+
+    // the .eh_frame contents extend to the end of the segment containing it
+    let frame = eh_frame.or(hdr_frame).and_then(
+        |addr| {
+            binary
+                .program_headers
+                .iter()
+                .find(|ph| ph.p_type == program_header::PT_LOAD && ph.p_vaddr <= addr && ph.p_vaddr.checked_add(ph.p_filesz).map_or(false, |e| addr < e))
+                .and_then(|ph| ph.p_offset.checked_add(ph.p_filesz).map(|end| (addr, (ph.p_offset + addr - ph.p_vaddr) as usize, end as usize)))
+        }
+    );
+
+    if let Some((addr, start, end)) = frame {
+        if end <= bytes.len() {
+            match unwind::eh_frame_functions(&bytes[start..end], addr, address_size) {
+                Ok(fdes) => ret.extend(fdes),
+                Err(e) => debug!("Failed to read .eh_frame: {}", e),
             }
-        };
-        let begin = image_base + virtual_address;
-        let end = image_base + virtual_address + size as u64;
-        let bound = Bound::new(begin, end);
-        debug!("bound: {:?}", &bound);
-        if !ram.cover(bound, layer) {
-            debug!("bad cover");
-            return Err(format!("Cannot cover bound: {:?}", Bound::new(begin, end)).into());
         }
     }
-    let entry = (pe.image_base + pe.entry) as u64;
-    debug!("entry: {:#x}", entry);
-    let mut prog = Program::new("prog0");
-    let mut proj = Project::new(name.to_string(), ram);
 
-    prog.call_graph
-        .add_vertex(
-            CallTarget::Todo(
-                Rvalue::new_u64(entry),
-                Some(name.to_string()),
-                Uuid::new_v4(),
-            )
-        );
+    // prefer FDE ranges over the bare start addresses of the header
+    ret.sort_by_key(|b| (b.start, ::std::u64::MAX - b.end));
+    ret.dedup_by_key(|b| b.start);
+    ret
+}
 
-    for export in pe.exports {
-        debug!("adding export: {:?}", &export);
-        prog.call_graph
-            .add_vertex(
-                CallTarget::Todo(
-                    Rvalue::new_u64(export.rva as u64 + image_base),
-                    Some(export.name.to_string()),
-                    Uuid::new_v4(),
-                )
-            );
+/// Returns the contents of the section `name` of `binary`. Returns an empty slice if there is no such
+/// section or it has no contents in the file.
+fn elf_section<'a>(binary: &elf::Elf, bytes: &'a [u8], name: &str) -> &'a [u8] {
+    use goblin::elf::section_header::SHT_NOBITS;
+
+    for sh in &binary.section_headers {
+        if &binary.shdr_strtab[sh.sh_name] == name && sh.sh_type != SHT_NOBITS {
+            let start = sh.sh_offset as usize;
+            let end = start + sh.sh_size as usize;
+
+            if end <= bytes.len() {
+                return &bytes[start..end];
+            }
+        }
     }
+    &[]
+}
 
-    for import in pe.imports {
-        debug!(
-            "adding import: {:?} @ {:#x}",
-            &import,
-            import.rva + pe.image_base
+/// Reads the DWARF debug information of `binary`, moving all addresses by `bias`. Returns an empty
+/// `DebugInfo` if the file has none or it cannot be parsed.
+fn elf_debug_info(binary: &elf::Elf, bytes: &[u8], bias: u64) -> DebugInfo {
+    let sections = DwarfSections {
+        debug_info: elf_section(binary, bytes, ".debug_info"),
+        debug_abbrev: elf_section(binary, bytes, ".debug_abbrev"),
+        debug_str: elf_section(binary, bytes, ".debug_str"),
+        debug_line: elf_section(binary, bytes, ".debug_line"),
+    };
+
+    if sections.debug_info.is_empty() {
+        return DebugInfo::default();
+    }
+    if !binary.little_endian {
+        debug!("Ignoring big endian DWARF information");
+        return DebugInfo::default();
+    }
+
+    match DebugInfo::parse(&sections, bias) {
+        Ok(info) => info,
+        Err(e) => {
+            debug!("Failed to read DWARF information: {}", e);
+            DebugInfo::default()
+        }
+    }
+}
+
+/// Creates a `Project` from the ELF core dump `binary`. All memory segments saved in the dump are
+/// mapped at their addresses in the crashed process. A function is started at the instruction
+/// pointer of each thread.
+fn load_elf_core(file: &MappedFile, binary: &elf::Elf, name: String) -> Result<(Project, Machine)> {
+    let bytes = file.bytes();
+    let (machine, mut reg) = elf_machine(binary)?;
+    let mut core = CoreDump::default();
+
+    for ph in &binary.program_headers {
+        if ph.p_type == program_header::PT_NOTE {
+            let start = ph.p_offset as usize;
+            let end = match start.checked_add(ph.p_filesz as usize) {
+                Some(end) if end <= bytes.len() => end,
+                _ => return Err("Failed to read note segment".into()),
+            };
+
+            core.parse_notes(&bytes[start..end], machine)?;
+        }
+    }
+
+    let mut segments = Vec::new();
+
+    for (idx, ph) in binary.program_headers.iter().enumerate() {
+        if ph.p_type != program_header::PT_LOAD {
+            continue;
+        }
+
+        // e.g. the vsyscall page at the very end of the address space
+        let end = match ph.p_vaddr.checked_add(ph.p_memsz) {
+            Some(end) if end <= reg.size() => end,
+            _ => {
+                debug!("Ignoring segment at {:#x} outside the address space", ph.p_vaddr);
+                continue;
+            }
+        };
+        // the file contents never extend past the segment
+        let filesz = ::std::cmp::min(ph.p_filesz, ph.p_memsz);
+
+        match (ph.p_offset as usize).checked_add(filesz as usize) {
+            Some(file_end) if file_end <= bytes.len() => {}
+            _ => return Err("Failed to read segment".into()),
+        }
+
+        // segments not included in the dump have no file contents. Core dumps can be huge, the
+        // segments reference the file instead of copying it.
+        if filesz > 0 {
+            let contents = file.slice(ph.p_offset, filesz)?;
+            reg.cover(Bound::new(ph.p_vaddr, ph.p_vaddr + filesz), Layer::Opaque(OpaqueLayer::Mapped(contents)));
+        }
+
+        let name = match core.file_at(ph.p_vaddr) {
+            Some(file) => file.path.clone(),
+            None => format!("LOAD{}", idx),
+        };
+        let flags = (
+            ph.p_flags & program_header::PF_R != 0,
+            ph.p_flags & program_header::PF_W != 0,
+            ph.p_flags & program_header::PF_X != 0,
         );
-        prog.call_graph.add_vertex(CallTarget::Symbolic(import.name.into_owned(), Uuid::new_v4()));
+        let offset = if filesz > 0 { Some(ph.p_offset) } else { None };
+
+        segments.push(Segment::new(name, SegmentKind::Segment, Bound::new(ph.p_vaddr, end), offset, filesz, flags));
     }
 
-    proj.comments.insert(("base".to_string(), entry), "main".to_string());
+    let region_name = reg.name().clone();
+    let mut prog = Program::new("prog0");
+    let mut proj = Project::new(name, reg);
+
+    for thread in core.threads.iter() {
+        let comment = if thread.signal != 0 {
+            format!("Thread {} (signal {})", thread.pid, thread.signal)
+        } else {
+            format!("Thread {}", thread.pid)
+        };
+
+        debug!("{} at {:#x}", comment, thread.ip);
+        prog.call_graph.add_vertex(CallTarget::Todo(Rvalue::new_u64(thread.ip), Some(format!("thread_{}", thread.pid)), Uuid::new_v4()));
+        proj.comments.insert((region_name.clone(), thread.ip), comment);
+    }
+
+    proj.segments = segments;
+    proj.core_dump = Some(core);
     proj.code.push(prog);
-    Ok((proj, Machine::Ia32))
+
+    Ok((proj, machine))
 }
 
-/// Load an ELF or PE file from disk and creates a `Project` from it. Returns the `Project` instance and
-/// the CPU its intended for.
-pub fn load(path: &Path) -> Result<(Project, Machine)> {
-    let name = path.file_name().map(|x| x.to_string_lossy().to_string()).unwrap_or("(encoding error)".to_string());
-    let mut fd = File::open(path)?;
-    let peek = goblin::peek(&mut fd)?;
-    if let Hint::Unknown(magic) = peek {
-        Err(format!("Tried to load an unknown file. Magic: {}", magic).into())
-    } else {
-        let mut bytes = Vec::new();
-        fd.read_to_end(&mut bytes)?;
-        match peek {
-            Hint::Elf(_) => load_elf(&bytes, name),
-            Hint::PE => load_pe(&bytes, name),
-            Hint::Mach(_) => load_mach(&bytes, 0, name),
-            Hint::MachFat(_) => Err("Cannot directly load a fat mach-o binary (e.g., which one do I load?)".into()),
-            Hint::Archive => {
-                let archive = archive::Archive::parse(&bytes)?;
-                debug!("archive: {:#?}", &archive);
-                Err("Tried to load an archive, unsupported format".into())
-            }
+/// Address the first section of a relocatable ELF object is mapped to.
+pub const ELF_OBJECT_BASE: u64 = 0x1000;
+
+/// Relocation entry of an ELF relocatable object.
+struct ObjectReloc {
+    /// Index of the section the relocation is applied to.
+    section: usize,
+    /// Offset of the relocated field inside `section`.
+    offset: u64,
+    /// Machine specific relocation type.
+    typ: u32,
+    /// Index of the symbol inside the symbol table.
+    sym: usize,
+    /// Explicit addend, `None` for `SHT_REL` relocations.
+    addend: Option<i64>,
+}
+
+/// Reads all relocations applied to sections in `mapped` from the `SHT_REL`/`SHT_RELA` sections
+/// of `binary`.
+fn elf_object_relocs(bytes: &[u8], binary: &elf::Elf, mapped: &HashMap<usize, u64>) -> Result<Vec<ObjectReloc>> {
+    use byteorder::{ByteOrder, LittleEndian};
+    use goblin::elf::section_header::{SHT_REL, SHT_RELA};
+
+    let mut ret = Vec::new();
+
+    for sh in &binary.section_headers {
+        if sh.sh_type != SHT_REL && sh.sh_type != SHT_RELA {
+            continue;
+        }
+
+        let section = sh.sh_info as usize;
+
+        // skip relocations of debug information and other non-allocated sections
+        if !mapped.contains_key(&section) {
+            continue;
+        }
+
+        let is_rela = sh.sh_type == SHT_RELA;
+        let entsize = match (binary.is_64, is_rela) {
+            (true, true) => 24,
+            (true, false) => 16,
+            (false, true) => 12,
+            (false, false) => 8,
+        };
+        let start = sh.sh_offset as usize;
+        let end = start + sh.sh_size as usize;
+
+        if end > bytes.len() {
+            return Err(format!("Failed to read relocations: range {:?} greater than len {}", start..end, bytes.len()).into());
+        }
+
+        for ent in bytes[start..end].chunks(entsize).filter(|x| x.len() == entsize) {
+            let reloc = if binary.is_64 {
+                let info = LittleEndian::read_u64(&ent[8..16]);
+                ObjectReloc {
+                    section: section,
+                    offset: LittleEndian::read_u64(&ent[0..8]),
+                    typ: (info & 0xffff_ffff) as u32,
+                    sym: (info >> 32) as usize,
+                    addend: if is_rela { Some(LittleEndian::read_i64(&ent[16..24])) } else { None },
+                }
+            } else {
+                let info = LittleEndian::read_u32(&ent[4..8]);
+                ObjectReloc {
+                    section: section,
+                    offset: LittleEndian::read_u32(&ent[0..4]) as u64,
+                    typ: info & 0xff,
+                    sym: (info >> 8) as usize,
+                    addend: if is_rela { Some(LittleEndian::read_i32(&ent[8..12]) as i64) } else { None },
+                }
+            };
+
+            ret.push(reloc);
+        }
+    }
+
+    Ok(ret)
+}
+
+/// Addresses an ELF relocatable object was mapped to by `map_elf_object`.
+struct ObjectLayout {
+    /// Start address of each allocated section, by section index.
+    sections: HashMap<usize, u64>,
+    /// Synthetic addresses of the undefined symbols, by name.
+    externs: HashMap<String, u64>,
+    /// First address behind the object.
+    end: u64,
+}
+
+impl ObjectLayout {
+    /// Address of `sym` or `None` if it's undefined, common or outside the address space.
+    fn symbol_address(&self, sym: &elf::Sym) -> Option<u64> {
+        const SHN_UNDEF: usize = 0;
+        const SHN_ABS: usize = 0xfff1;
+        const SHN_COMMON: usize = 0xfff2;
+
+        match sym.st_shndx {
+            SHN_UNDEF | SHN_COMMON => None,
+            SHN_ABS => Some(sym.st_value),
+            shndx => self.sections.get(&shndx).and_then(|a| a.checked_add(sym.st_value)),
+        }
+    }
+}
+
+/// Maps the allocated sections of the ELF relocatable object `binary` one after another into `reg`,
+/// starting at `base`. Relocations are applied in a `Layer::Sparse` on top of the section
+/// contents. Undefined symbols are assigned a synthetic address behind the last section.
+fn map_elf_object(bytes: &[u8], binary: &elf::Elf, machine: Machine, reg: &mut Region, base: u64) -> Result<ObjectLayout> {
+    use byteorder::{ByteOrder, LittleEndian};
+    use goblin::elf::section_header::{SHF_ALLOC, SHT_NOBITS};
+
+    const R_X86_64_64: u32 = 1;
+    const R_X86_64_PC32: u32 = 2;
+    const R_X86_64_PLT32: u32 = 4;
+    const R_X86_64_GOTPCREL: u32 = 9;
+    const R_X86_64_32: u32 = 10;
+    const R_X86_64_32S: u32 = 11;
+    const R_X86_64_PC64: u32 = 24;
+    const R_X86_64_GOTPCRELX: u32 = 41;
+    const R_X86_64_REX_GOTPCRELX: u32 = 42;
+    const R_386_32: u32 = 1;
+    const R_386_PC32: u32 = 2;
+    const R_386_PLT32: u32 = 4;
+
+    let mut mapped = HashMap::<usize, u64>::new();
+    let mut next = base;
+
+    for (idx, sh) in binary.section_headers.iter().enumerate() {
+        if (sh.sh_flags & SHF_ALLOC as u64) == 0 || sh.sh_size == 0 {
+            continue;
+        }
+
+        let align = if sh.sh_addralign > 1 { sh.sh_addralign } else { 1 };
+        let (start, end) = match next.checked_add(align - 1).map(|x| x / align * align).and_then(|start| start.checked_add(sh.sh_size).map(|end| (start, end))) {
+            Some(x) => x,
+            None => return Err(format!("Section {} does not fit into the address space", &binary.shdr_strtab[sh.sh_name]).into()),
+        };
+        let layer = if sh.sh_type == SHT_NOBITS {
+            Layer::undefined(sh.sh_size)
+        } else {
+            let offset = sh.sh_offset as usize;
+            let size = sh.sh_size as usize;
+
+            if offset + size > bytes.len() {
+                return Err(format!("Failed to read section: range {:?} greater than len {}", offset..offset + size, bytes.len()).into());
+            }
+            Layer::wrap(bytes[offset..offset + size].to_vec())
+        };
+
+        debug!(
+            "Load ELF section {} ({} bytes) to {:#x}",
+            &binary.shdr_strtab[sh.sh_name],
+            sh.sh_size,
+            start
+        );
+
+        if !reg.cover(Bound::new(start, end), layer) {
+            return Err(format!("Cannot cover bound: {:?}", Bound::new(start, end)).into());
+        }
+        mapped.insert(idx, start);
+        next = end;
+    }
+
+    let mut layout = ObjectLayout { sections: mapped, externs: HashMap::new(), end: 0 };
+    let relocs = elf_object_relocs(bytes, binary, &layout.sections)?;
+    let is_got = |typ: u32| machine == Machine::Amd64 && (typ == R_X86_64_GOTPCREL || typ == R_X86_64_GOTPCRELX || typ == R_X86_64_REX_GOTPCRELX);
+
+    // each relocation needs at most one GOT entry and one import slot behind the sections
+    if next.checked_add(relocs.len() as u64 * 24 + 32).is_none() {
+        return Err(format!("Object mapped at {:#x} does not fit into the address space", base).into());
+    }
+
+    // synthetic GOT entries for GOT relative relocations
+    let mut got = HashMap::<usize, u64>::new();
+    let got_start = (next + 7) & !7;
+
+    for reloc in relocs.iter().filter(|r| is_got(r.typ)) {
+        let slot = got_start + got.len() as u64 * 8;
+        got.entry(reloc.sym).or_insert(slot);
+    }
+
+    // undefined symbols are given 16 bytes of undefined cells each
+    let extern_start = (got_start + got.len() as u64 * 8 + 15) & !15;
+
+    for reloc in relocs.iter() {
+        if let Some(sym) = binary.syms.get(reloc.sym) {
+            let name = &binary.strtab[sym.st_name];
+
+            if layout.symbol_address(sym).is_none() && !name.is_empty() && !layout.externs.contains_key(name) {
+                let slot = extern_start + layout.externs.len() as u64 * 16;
+                layout.externs.insert(name.to_string(), slot);
+            }
+        }
+    }
+
+    layout.end = extern_start + layout.externs.len() as u64 * 16;
+
+    let mut layer = Layer::writable();
+    let write = |layer: &mut Layer, addr: u64, value: u64, len: usize| for i in 0..len {
+        layer.write(addr - base + i as u64, Some((value >> (i * 8)) as u8));
+    };
+    let symbol_value = |idx: usize| -> Option<u64> {
+        binary.syms.get(idx).and_then(|sym| layout.symbol_address(sym).or_else(|| layout.externs.get(&binary.strtab[sym.st_name]).cloned()))
+    };
+
+    for (&sym, &slot) in got.iter() {
+        if let Some(value) = symbol_value(sym) {
+            write(&mut layer, slot, value, 8);
+        }
+    }
+
+    for reloc in relocs.iter() {
+        let size = binary.section_headers[reloc.section].sh_size;
+        let p = layout.sections[&reloc.section].wrapping_add(reloc.offset);
+
+        if reloc.offset >= size {
+            debug!("Relocation at {:#x} outside of section", p);
+            continue;
+        }
+
+        let s = match symbol_value(reloc.sym) {
+            Some(s) => s,
+            None => {
+                debug!("Relocation at {:#x} against unknown symbol #{}", p, reloc.sym);
+                continue;
+            }
+        };
+        let a = match reloc.addend {
+            Some(a) => a as u64,
+            None => {
+                // implicit addend is stored in the relocated field
+                let off = binary.section_headers[reloc.section].sh_offset as usize + reloc.offset as usize;
+                if off + 4 > bytes.len() {
+                    return Err(format!("Relocation at {:#x} outside of section", p).into());
+                }
+                LittleEndian::read_i32(&bytes[off..off + 4]) as i64 as u64
+            }
+        };
+        let (value, len) = match (machine, reloc.typ) {
+            (Machine::Amd64, R_X86_64_64) => (s.wrapping_add(a), 8),
+            (Machine::Amd64, R_X86_64_PC32) |
+            (Machine::Amd64, R_X86_64_PLT32) => (s.wrapping_add(a).wrapping_sub(p), 4),
+            (Machine::Amd64, R_X86_64_32) |
+            (Machine::Amd64, R_X86_64_32S) => (s.wrapping_add(a), 4),
+            (Machine::Amd64, R_X86_64_PC64) => (s.wrapping_add(a).wrapping_sub(p), 8),
+            (Machine::Amd64, typ) if is_got(typ) => (got[&reloc.sym].wrapping_add(a).wrapping_sub(p), 4),
+            (Machine::Ia32, R_386_32) => (s.wrapping_add(a), 4),
+            (Machine::Ia32, R_386_PC32) |
+            (Machine::Ia32, R_386_PLT32) => (s.wrapping_add(a).wrapping_sub(p), 4),
+            (_, typ) => {
+                debug!("Unsupported relocation type {} at {:#x}", typ, p);
+                continue;
+            }
+        };
+
+        if reloc.offset + len as u64 > size {
+            debug!("Relocation at {:#x} outside of section", p);
+            continue;
+        }
+
+        debug!("Relocate {:#x} to {:#x}", p, value);
+        write(&mut layer, p, value, len);
+    }
+
+    if !reg.cover(Bound::new(base, layout.end), layer) {
+        return Err(format!("Cannot cover bound: {:?}", Bound::new(base, layout.end)).into());
+    }
+
+    Ok(layout)
+}
+
+/// Parses an ELF relocatable object (`.o`, `.ko`). The allocated sections are mapped one after another
+/// starting at `ELF_OBJECT_BASE` and relocated. Undefined symbols become imports. Returns the
+/// `Project` instance and the CPU its intended for.
+fn load_elf_object(bytes: &[u8], binary: &elf::Elf, name: String) -> Result<(Project, Machine)> {
+    let (machine, mut reg) = elf_machine(binary)?;
+    let layout = map_elf_object(bytes, binary, machine, &mut reg, ELF_OBJECT_BASE)?;
+
+    let mut prog = Program::new("prog0");
+    let mut proj = Project::new(name, reg);
+
+    proj.segments = elf_segments(binary, Some(&layout.sections), 0);
+
+    for sym in &binary.syms {
+        if sym.is_function() {
+            if let Some(addr) = layout.symbol_address(sym) {
+                let name = binary.strtab[sym.st_name].to_string();
+                debug!("Symbol: {} @ 0x{:x}: {:?}", name, addr, sym);
+                prog.call_graph.add_vertex(CallTarget::Todo(Rvalue::new_u64(addr), Some(name), Uuid::new_v4()));
+            }
+        }
+    }
+
+    for (name, &addr) in layout.externs.iter() {
+        debug!("Import {}: {:#x}", name, addr);
+        prog.call_graph.add_vertex(CallTarget::Symbolic(name.clone(), Uuid::new_v4()));
+        proj.imports.insert(addr, name.clone());
+    }
+
+    prog.imports = proj.imports.clone();
+    proj.code.push(prog);
+
+    Ok((proj, machine))
+}
+
+/// Returns the file offset of the relative virtual address `rva` of the PE image `pe`.
+fn pe_file_offset(pe: &pe::PE, rva: u64) -> Option<u64> {
+    pe.sections
+        .iter()
+        .find(
+            |s| {
+                s.virtual_address as u64 <= rva && rva < s.virtual_address as u64 + s.size_of_raw_data as u64
+            }
+        )
+        .map(|s| s.pointer_to_raw_data as u64 + rva - s.virtual_address as u64)
+}
+
+/// Returns the contents of data directory number `idx` of the PE image in `bytes`, `None` if the image
+/// has no such directory.
+fn pe_data_directory<'a>(bytes: &'a [u8], pe: &pe::PE, idx: usize) -> Result<Option<&'a [u8]>> {
+    use byteorder::{ByteOrder, LittleEndian};
+
+    // locate the data directory in the optional header
+    if bytes.len() < 0x40 {
+        return Err("PE file too short".into());
+    }
+    let pe_hdr = LittleEndian::read_u32(&bytes[0x3c..0x40]) as usize;
+    let opt_hdr = pe_hdr + 24;
+    let dirs = opt_hdr + if pe_is_64(pe) { 112 } else { 96 };
+    let dir = dirs + idx * 8;
+
+    if dir + 8 > bytes.len() {
+        return Err("PE optional header too short".into());
+    }
+
+    let rva = LittleEndian::read_u32(&bytes[dir..dir + 4]) as u64;
+    let size = LittleEndian::read_u32(&bytes[dir + 4..dir + 8]) as usize;
+
+    if rva == 0 || size == 0 {
+        return Ok(None);
+    }
+
+    match pe_file_offset(pe, rva) {
+        Some(off) if off as usize + size <= bytes.len() => Ok(Some(&bytes[off as usize..off as usize + size])),
+        _ => Err(format!("Data directory {} outside of the file", idx).into()),
+    }
+}
+
+/// Computes the base relocations of the PE image in `bytes` after moving it to `image_base`. Returns the
+/// address, new value and size in bytes of each relocated field. Returns nothing if the image is loaded
+/// at its preferred base.
+fn pe_base_relocs(bytes: &[u8], pe: &pe::PE, image_base: u64) -> Result<Vec<(u64, u64, usize)>> {
+    use byteorder::{ByteOrder, LittleEndian};
+
+    const IMAGE_REL_BASED_ABSOLUTE: u16 = 0;
+    const IMAGE_REL_BASED_HIGHLOW: u16 = 3;
+    const IMAGE_REL_BASED_DIR64: u16 = 10;
+    const IMAGE_DIRECTORY_ENTRY_BASERELOC: usize = 5;
+
+    let delta = image_base.wrapping_sub(pe.image_base as u64);
+    let mut ret = Vec::new();
+
+    if delta == 0 {
+        return Ok(ret);
+    }
+
+    let table = match pe_data_directory(bytes, pe, IMAGE_DIRECTORY_ENTRY_BASERELOC)? {
+        Some(table) => table,
+        None => return Err(format!("Image has no base relocations and cannot be moved to {:#x}", image_base).into()),
+    };
+    let mut pos = 0;
+
+    while pos + 8 <= table.len() {
+        let page = LittleEndian::read_u32(&table[pos..pos + 4]) as u64;
+        let block = LittleEndian::read_u32(&table[pos + 4..pos + 8]) as usize;
+
+        if block < 8 || pos + block > table.len() {
+            break;
+        }
+
+        for ent in table[pos + 8..pos + block].chunks(2).filter(|x| x.len() == 2) {
+            let ent = LittleEndian::read_u16(ent);
+            let rva = page + (ent & 0xfff) as u64;
+            let len = match ent >> 12 {
+                IMAGE_REL_BASED_ABSOLUTE => continue,
+                IMAGE_REL_BASED_HIGHLOW => 4,
+                IMAGE_REL_BASED_DIR64 => 8,
+                typ => {
+                    debug!("Unsupported base relocation type {} at {:#x}", typ, rva);
+                    continue;
+                }
+            };
+            let off = match pe_file_offset(pe, rva) {
+                Some(off) if off as usize + len <= bytes.len() => off as usize,
+                _ => {
+                    debug!("Base relocation at {:#x} outside of the file", rva);
+                    continue;
+                }
+            };
+            let value = if len == 4 {
+                (LittleEndian::read_u32(&bytes[off..off + 4]) as u64).wrapping_add(delta) & 0xffff_ffff
+            } else {
+                LittleEndian::read_u64(&bytes[off..off + 8]).wrapping_add(delta)
+            };
+
+            ret.push((image_base + rva, value, len));
+        }
+
+        pos += block;
+    }
+
+    Ok(ret)
+}
+
+/// Returns true if `pe` has a PE32+ optional header (64-bit image).
+fn pe_is_64(pe: &pe::PE) -> bool {
+    match pe.header.optional_header {
+        Some(ref opt) => opt.standard_fields.magic == pe::optional_header::MAGIC_64,
+        None => false,
+    }
+}
+
+/// Parses a PE32/PE32+ file from `bytes` and create a project from it.
+fn load_pe(bytes: &[u8], name: String, base: Option<u64>) -> Result<(Project, Machine)> {
+    const IMAGE_SCN_MEM_EXECUTE: u32 = 0x2000_0000;
+    const IMAGE_SCN_MEM_READ: u32 = 0x4000_0000;
+    const IMAGE_SCN_MEM_WRITE: u32 = 0x8000_0000;
+
+    let pe = pe::PE::parse(&bytes)?;
+    debug!("pe: {:#?}", &pe);
+    let image_base = base.unwrap_or(pe.image_base as u64);
+    let (machine, mut ram) = if pe_is_64(&pe) {
+        (Machine::Amd64, Region::undefined("RAM".to_string(), 0xFFFF_FFFF_FFFF_FFFF))
+    } else {
+        (Machine::Ia32, Region::undefined("RAM".to_string(), 0x1_0000_0000))
+    };
+    let mut segments = Vec::new();
+
+    for section in &pe.sections {
+        let name = String::from_utf8_lossy(&section.name);
+        debug!("section: {}", name);
+        let virtual_address = section.virtual_address as u64;
+        let offset = section.pointer_to_raw_data as usize;
+        let (layer, size) = {
+            let vsize = section.virtual_size as u64;
+            let size = section.size_of_raw_data as usize;
+            if size > 0 {
+                if offset + size >= bytes.len() {
+                    debug!(
+                        "bad section pointer: {:#x} + {:#x} >= {:#x}",
+                        offset,
+                        size,
+                        bytes.len()
+                    );
+                    (Layer::undefined(0), 0)
+                } else {
+                    debug!("mapped '{}': {:?}", name, offset..offset + size);
+                    (Layer::wrap(bytes[offset..offset + size].to_vec()), size as u64)
+                }
+            } else {
+                debug!("bss '{}'", name);
+                (Layer::undefined(vsize), vsize)
+            }
+        };
+        let begin = image_base + virtual_address;
+        let end = image_base + virtual_address + size as u64;
+        let bound = Bound::new(begin, end);
+        debug!("bound: {:?}", &bound);
+        if !ram.cover(bound, layer) {
+            debug!("bad cover");
+            return Err(format!("Cannot cover bound: {:?}", Bound::new(begin, end)).into());
+        }
+
+        let flags = (
+            section.characteristics & IMAGE_SCN_MEM_READ != 0,
+            section.characteristics & IMAGE_SCN_MEM_WRITE != 0,
+            section.characteristics & IMAGE_SCN_MEM_EXECUTE != 0,
+        );
+        let file_offset = if section.size_of_raw_data > 0 { Some(offset as u64) } else { None };
+        let vsize = ::std::cmp::max(section.virtual_size as u64, size);
+        let name = name.trim_right_matches('\0').to_string();
+
+        segments.push(Segment::new(name, SegmentKind::Section, Bound::new(begin, begin + vsize), file_offset, size, flags));
+    }
+    let entry = image_base + pe.entry as u64;
+    debug!("entry: {:#x}", entry);
+
+    let fixups = pe_base_relocs(bytes, &pe, image_base)?;
+    let relocations = cover_relocations(&mut ram, &fixups)?;
+    let mut prog = Program::new("prog0");
+    let mut proj = Project::new(name.to_string(), ram);
+
+    proj.segments = segments;
+    proj.relocations = relocations;
+
+    prog.call_graph
+        .add_vertex(
+            CallTarget::Todo(
+                Rvalue::new_u64(entry),
+                Some(name.to_string()),
+                Uuid::new_v4(),
+            )
+        );
+
+    // PE32+ images list every non-leaf function in the exception directory
+    if pe_is_64(&pe) {
+        const IMAGE_DIRECTORY_ENTRY_EXCEPTION: usize = 3;
+
+        let pdata = pe_data_directory(bytes, &pe, IMAGE_DIRECTORY_ENTRY_EXCEPTION)?.unwrap_or(&[]);
+
+        for bound in unwind::pdata_functions(pdata, image_base) {
+            if bound.start != entry && !pe.exports.iter().any(|e| e.rva as u64 + image_base == bound.start) {
+                debug!("Unwind info function @ {:#x}", bound.start);
+                prog.call_graph.add_vertex(CallTarget::Todo(Rvalue::new_u64(bound.start), None, Uuid::new_v4()));
+            }
+            prog.extents.insert(bound.start, bound);
+        }
+    }
+
+    for export in pe.exports {
+        debug!("adding export: {:?}", &export);
+        prog.call_graph
+            .add_vertex(
+                CallTarget::Todo(
+                    Rvalue::new_u64(export.rva as u64 + image_base),
+                    Some(export.name.to_string()),
+                    Uuid::new_v4(),
+                )
+            );
+    }
+
+    for import in pe.imports {
+        // `offset` is the RVA of the import address table slot the loader patches
+        let iat = import.offset as u64 + image_base;
+        let name = format!("{}!{}", import.dll, import.name);
+        debug!("adding import: {:?} @ {:#x}", &import, iat);
+        prog.call_graph.add_vertex(CallTarget::Symbolic(name.clone(), Uuid::new_v4()));
+        proj.imports.insert(iat, name);
+    }
+
+    debug!("Imports: {:?}", &proj.imports);
+    prog.imports = proj.imports.clone();
+    proj.comments.insert(("base".to_string(), entry), "main".to_string());
+    proj.code.push(prog);
+    Ok((proj, machine))
+}
+
+/// Segment DOS programs are loaded at. The 256 byte program segment prefix in front of the image
+/// starts at address 0, which puts both `.com` files and MZ executables at 0x100.
+const DOS_LOAD_SEGMENT: u64 = 0x10;
+
+/// Returns true if the MZ header in `bytes` is the stub of a PE image.
+fn is_pe(bytes: &[u8]) -> bool {
+    use byteorder::{ByteOrder, LittleEndian};
+
+    if bytes.len() < 0x40 {
+        return false;
+    }
+
+    let off = LittleEndian::read_u32(&bytes[0x3c..]) as usize;
+
+    off.checked_add(4).map(|end| end <= bytes.len() && &bytes[off..end] == b"PE\0\0").unwrap_or(false)
+}
+
+/// Parses a DOS MZ executable from `bytes` and creates a `Project` from it. The image is mapped at
+/// segment `DOS_LOAD_SEGMENT`, its segment relocations are applied in a separate `Layer`. The entry
+/// point is the linear address of the initial CS:IP.
+fn load_mz(bytes: &[u8], name: String) -> Result<(Project, Machine)> {
+    use byteorder::{ByteOrder, LittleEndian};
+
+    if bytes.len() < 0x1c {
+        return Err("MZ header is truncated".into());
+    }
+
+    let word = |off: usize| LittleEndian::read_u16(&bytes[off..]) as u64;
+    let (last_page, pages, num_relocs, header) = (word(0x02), word(0x04), word(0x06) as usize, word(0x08) as usize * 16);
+    let (ss, sp, ip, cs, relocs) = (word(0x0e), word(0x10), word(0x14), word(0x16), word(0x18) as usize);
+    let end = match (pages, last_page) {
+        (0, _) => return Err("MZ executable has no pages".into()),
+        (p, 0) => p * 512,
+        (p, l) => (p - 1) * 512 + l,
+    };
+    let end = ::std::cmp::min(end as usize, bytes.len());
+
+    if header > end {
+        return Err("MZ header is larger than the file".into());
+    }
+    if relocs + num_relocs * 4 > bytes.len() {
+        return Err("MZ relocation table extends past the end of the file".into());
+    }
+
+    let machine = Machine::Ia16;
+    let mut reg = machine_region(machine);
+    let image = &bytes[header..end];
+    let base = DOS_LOAD_SEGMENT * 16;
+    let bound = Bound::new(base, base + image.len() as u64);
+
+    debug!("Load MZ image ({} bytes) to {:#x}", image.len(), base);
+
+    if bound.end > reg.size() || !reg.cover(bound.clone(), Layer::wrap(image.to_vec())) {
+        return Err(format!("Cannot cover bound: {:?}", bound).into());
+    }
+
+    // relocation entries point to segment words in the image that are relative to its start
+    let mut fixups = Vec::with_capacity(num_relocs);
+
+    for i in 0..num_relocs {
+        let off = (word(relocs + i * 4 + 2) * 16 + word(relocs + i * 4)) as usize;
+
+        if off + 2 > image.len() {
+            return Err(format!("MZ relocation {} points outside of the image", i).into());
+        }
+
+        let seg = (LittleEndian::read_u16(&image[off..]) as u64 + DOS_LOAD_SEGMENT) & 0xffff;
+        fixups.push((base + off as u64, seg, 2));
+    }
+
+    let relocations = cover_relocations(&mut reg, &fixups)?;
+    let entry = (cs + DOS_LOAD_SEGMENT) * 16 + ip;
+
+    if entry < bound.start || entry >= bound.end {
+        return Err(format!("Entry point {:04x}:{:04x} is outside of the image", cs + DOS_LOAD_SEGMENT, ip).into());
+    }
+
+    let mut prog = Program::new("prog0");
+    let mut proj = Project::new(name.clone(), reg);
+
+    let len = bound.len();
+
+    proj.segments.push(Segment::new(name.clone(), SegmentKind::Segment, bound, Some(header as u64), len, (true, true, true)));
+    proj.relocations = relocations;
+    proj.comments.insert(("RAM".to_string(), entry), format!("Stack at {:04x}:{:04x}", ss + DOS_LOAD_SEGMENT, sp));
+    prog.call_graph.add_vertex(CallTarget::Todo(Rvalue::new_u64(entry), Some(name), Uuid::new_v4()));
+    proj.code.push(prog);
+
+    Ok((proj, machine))
+}
+
+/// Creates a `Project` from the DOS `.com` file `bytes`. The file is mapped to 0x100 and executed
+/// from its first byte.
+fn load_com(bytes: &[u8], name: String) -> Result<(Project, Machine)> {
+    let machine = Machine::Ia16;
+    let mut reg = machine_region(machine);
+    let base = DOS_LOAD_SEGMENT * 16;
+    let bound = Bound::new(base, base + bytes.len() as u64);
+
+    // .com files must fit into a single 64KB segment together with the PSP
+    if bound.end > 0x1_0000 {
+        return Err(format!("{} is too large for a .com file", name).into());
+    }
+
+    debug!("Load {} ({} bytes) to {:#x}", name, bytes.len(), base);
+
+    if !reg.cover(bound.clone(), Layer::wrap(bytes.to_vec())) {
+        return Err(format!("Cannot cover bound: {:?}", bound).into());
+    }
+
+    let mut prog = Program::new("prog0");
+    let mut proj = Project::new(name.clone(), reg);
+
+    proj.segments.push(Segment::new(name.clone(), SegmentKind::Segment, bound, Some(0), bytes.len() as u64, (true, true, true)));
+    prog.call_graph.add_vertex(CallTarget::Todo(Rvalue::new_u64(base), Some(name), Uuid::new_v4()));
+    proj.code.push(prog);
+
+    Ok((proj, machine))
+}
+
+/// Parses a static library (`.a`/`.lib`) from `bytes` and creates a `Project` with one `Program` per
+/// member object. Members are mapped and relocated like `load_elf_object` does, one after another
+/// at page aligned, synthetic addresses. Symbols listed in the archive index become
+/// `CallTarget::Todo`s of the `Program` of the member defining them, undefined symbols of a member
+/// become its imports. Members that aren't ELF objects are skipped. Returns the `Project` instance
+/// and the CPU its intended for.
+fn load_archive(bytes: &[u8], name: String) -> Result<(Project, Machine)> {
+    let archive = archive::Archive::parse(&bytes)?;
+    debug!("archive: {:#?}", &archive);
+
+    let mut machine_and_region: Option<(Machine, Region)> = None;
+    let mut programs = Vec::new();
+    let mut segments = Vec::new();
+    let mut imports = HashMap::new();
+    let mut next_base = ELF_OBJECT_BASE;
+
+    let mut summary = archive.summarize();
+
+    // keep the layout stable between runs
+    summary.sort_by(|a, b| a.0.cmp(b.0));
+
+    for (member, _, symbols) in summary {
+        let data = archive.extract(member, &bytes)?;
+        let binary = match elf::Elf::parse(data) {
+            Ok(binary) => binary,
+            Err(e) => {
+                // e.g. COFF objects of .lib files
+                warn!("skipping archive member {}, it's not an ELF object: {}", member, e);
+                continue;
+            }
+        };
+        let (machine, reg) = elf_machine(&binary)?;
+
+        let expected = machine_and_region.as_ref().map(|&(m, _)| m);
+
+        match expected {
+            Some(m) if m != machine => {
+                return Err(format!("Archive member {} is for {:?}, expected {:?}", member, machine, m).into());
+            }
+            _ => {}
+        }
+
+        let layout = {
+            let &mut (_, ref mut reg) = machine_and_region.get_or_insert((machine, reg));
+            map_elf_object(data, &binary, machine, reg, next_base)?
+        };
+        let mut prog = Program::new(member);
+
+        debug!("archive member {} ({} bytes) mapped to {:#x}", member, data.len(), next_base);
+
+        for symbol in symbols {
+            let maybe_sym = binary.syms.iter().find(|sym| &binary.strtab[sym.st_name] == symbol && sym.st_shndx != 0);
+
+            match maybe_sym.map(|sym| (sym, layout.symbol_address(sym))) {
+                Some((sym, Some(addr))) if sym.is_function() => {
+                    debug!("archive symbol {} of {} @ {:#x}", symbol, member, addr);
+                    prog.call_graph.add_vertex(CallTarget::Todo(Rvalue::new_u64(addr), Some(symbol.to_string()), Uuid::new_v4()));
+                }
+                Some((_, None)) => debug!("archive symbol {} of {} has no address", symbol, member),
+                Some(_) => debug!("archive symbol {} of {} is not a function", symbol, member),
+                None => debug!("archive symbol {} not defined in {}", symbol, member),
+            }
+        }
+
+        for (name, &addr) in layout.externs.iter() {
+            debug!("Import {} of {}: {:#x}", name, member, addr);
+            prog.call_graph.add_vertex(CallTarget::Symbolic(name.clone(), Uuid::new_v4()));
+            prog.imports.insert(addr, name.clone());
+        }
+
+        imports.extend(prog.imports.iter().map(|(&a, n)| (a, n.clone())));
+        segments.extend(elf_segments(&binary, Some(&layout.sections), 0));
+        programs.push(prog);
+
+        next_base = match layout.end.checked_add(0xfff) {
+            Some(end) => end & !0xfff,
+            None => return Err(format!("Archive member {} does not fit into the address space", member).into()),
+        };
+    }
+
+    let (machine, reg) = match machine_and_region {
+        Some(x) => x,
+        None => return Err("Archive contains no object file of a supported machine".into()),
+    };
+
+    let mut proj = Project::new(name, reg);
+    proj.code = programs;
+    proj.segments = segments;
+    proj.imports = imports;
+
+    Ok((proj, machine))
+}
+
+/// Decodes a string of hexadecimal digit pairs.
+fn decode_hex(s: &str) -> Result<Vec<u8>> {
+    if s.len() % 2 != 0 || !s.chars().all(|c| c.is_digit(16)) {
+        return Err(format!("Invalid hex string '{}'", s).into());
+    }
+
+    (0..s.len() / 2)
+        .map(|i| u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).map_err(|_| format!("Invalid hex string '{}'", s).into()))
+        .collect()
+}
+
+/// Returns true if `bytes` look like an Intel HEX file.
+fn is_ihex(bytes: &[u8]) -> bool {
+    bytes.first() == Some(&b':')
+}
+
+/// Returns true if `bytes` look like a Motorola S-record file.
+fn is_srec(bytes: &[u8]) -> bool {
+    bytes.len() >= 2 && bytes[0] == b'S' && (bytes[1] as char).is_digit(10)
+}
+
+/// Parses an Intel HEX file. Returns the data bytes with their addresses and the start address, if
+/// the file has one.
+fn parse_ihex(bytes: &[u8]) -> Result<(BTreeMap<u64, u8>, Option<u64>)> {
+    let text = String::from_utf8_lossy(bytes);
+    let mut data = BTreeMap::new();
+    let mut base = 0u64;
+    let mut entry = None;
+
+    for (no, line) in text.lines().map(|l| l.trim()).enumerate().filter(|&(_, l)| !l.is_empty()) {
+        if !line.starts_with(':') {
+            return Err(format!("Line {}: Intel HEX record does not start with ':'", no + 1).into());
+        }
+
+        let rec = decode_hex(&line[1..])?;
+
+        if rec.len() < 5 || rec.len() != rec[0] as usize + 5 {
+            return Err(format!("Line {}: Intel HEX record has invalid length", no + 1).into());
+        }
+        if rec.iter().fold(0u8, |acc, &b| acc.wrapping_add(b)) != 0 {
+            return Err(format!("Line {}: Intel HEX record has invalid checksum", no + 1).into());
+        }
+
+        let addr = ((rec[1] as u64) << 8) | rec[2] as u64;
+        let payload = &rec[4..rec.len() - 1];
+        let be = |p: &[u8]| p.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64);
+
+        match (rec[3], payload.len()) {
+            // Data
+            (0x00, _) => {
+                for (i, &b) in payload.iter().enumerate() {
+                    data.insert(base + ((addr + i as u64) & 0xffff), b);
+                }
+            }
+            // End Of File
+            (0x01, _) => break,
+            // Extended Segment Address
+            (0x02, 2) => base = be(payload) << 4,
+            // Start Segment Address (CS:IP)
+            (0x03, 4) => entry = Some((be(&payload[0..2]) << 4) + be(&payload[2..4])),
+            // Extended Linear Address
+            (0x04, 2) => base = be(payload) << 16,
+            // Start Linear Address
+            (0x05, 4) => entry = Some(be(payload)),
+            (typ, _) => return Err(format!("Line {}: Invalid Intel HEX record of type {}", no + 1, typ).into()),
+        }
+    }
+
+    Ok((data, entry))
+}
+
+/// Parses a Motorola S-record file. Returns the data bytes with their addresses and the start
+/// address, if the file has one.
+fn parse_srec(bytes: &[u8]) -> Result<(BTreeMap<u64, u8>, Option<u64>)> {
+    let text = String::from_utf8_lossy(bytes);
+    let mut data = BTreeMap::new();
+    let mut entry = None;
+
+    for (no, line) in text.lines().map(|l| l.trim()).enumerate().filter(|&(_, l)| !l.is_empty()) {
+        if line.len() < 4 || !line.starts_with('S') {
+            return Err(format!("Line {}: S-record does not start with 'S'", no + 1).into());
+        }
+
+        let typ = line.as_bytes()[1];
+        let addr_len = match typ {
+            b'0' | b'1' | b'5' | b'9' => 2,
+            b'2' | b'6' | b'8' => 3,
+            b'3' | b'7' => 4,
+            _ => return Err(format!("Line {}: Invalid S-record type", no + 1).into()),
+        };
+        let rec = decode_hex(&line[2..])?;
+
+        if rec.is_empty() || rec.len() != rec[0] as usize + 1 {
+            return Err(format!("Line {}: S-record has invalid length", no + 1).into());
+        }
+        if rec.iter().fold(0u8, |acc, &b| acc.wrapping_add(b)) != 0xff {
+            return Err(format!("Line {}: S-record has invalid checksum", no + 1).into());
+        }
+
+        if rec.len() < addr_len + 2 {
+            return Err(format!("Line {}: S-record has invalid length", no + 1).into());
+        }
+
+        let addr = rec[1..1 + addr_len].iter().fold(0u64, |acc, &b| (acc << 8) | b as u64);
+        let payload = &rec[1 + addr_len..rec.len() - 1];
+
+        match typ {
+            // Data
+            b'1' | b'2' | b'3' => {
+                for (i, &b) in payload.iter().enumerate() {
+                    data.insert(addr + i as u64, b);
+                }
+            }
+            // Start address
+            b'7' | b'8' | b'9' => entry = Some(addr),
+            // Header and record counts
+            _ => {}
+        }
+    }
+
+    Ok((data, entry))
+}
+
+/// Creates a `Project` for the firmware image `data` (Intel HEX or S-record). Each run of
+/// consecutive bytes becomes a cover on the `Flash` region, gaps stay undefined. The start
+/// address is added as entry point if present.
+fn load_firmware(data: BTreeMap<u64, u8>, entry: Option<u64>, name: String) -> Result<(Project, Machine)> {
+    let machine = Machine::Avr;
+    let mut reg = machine_region(machine);
+    let mut runs: Vec<(u64, Vec<u8>)> = Vec::new();
+
+    for (addr, b) in data {
+        let extend = match runs.last() {
+            Some(&(start, ref bytes)) => start + bytes.len() as u64 == addr,
+            None => false,
+        };
+
+        if extend {
+            runs.last_mut().unwrap().1.push(b);
+        } else {
+            runs.push((addr, vec![b]));
+        }
+    }
+
+    for (start, bytes) in runs {
+        let bound = Bound::new(start, start + bytes.len() as u64);
+
+        debug!("Load firmware data ({} bytes) to {:#x}", bytes.len(), start);
+
+        if bound.end > reg.size() || !reg.cover(bound.clone(), Layer::wrap(bytes)) {
+            return Err(format!("Cannot cover bound: {:?}", bound).into());
+        }
+    }
+
+    let mut prog = Program::new("prog0");
+    let mut proj = Project::new(name, reg);
+
+    for bank in machine_banks(machine) {
+        proj.data.add_region(bank)?;
+    }
+
+    if let Some(entry) = entry {
+        prog.call_graph.add_vertex(CallTarget::Todo(Rvalue::new_u64(entry), Some("main".to_string()), Uuid::new_v4()));
+        proj.comments.insert(("Flash".to_string(), entry), "main".to_string());
+    }
+
+    proj.code.push(prog);
+
+    Ok((proj, machine))
+}
+
+/// Loads the headerless binary blob at `path` (firmware dumps, boot sectors, ...) and creates a
+/// `Project` from it. The file contents are mapped to `base` inside a `Region` spanning the
+/// address space of the machine `A::machine` returns for `cfg`. Each address in `entries` is
+/// added as an entry point to the `Project`'s only `Program`, followed by the ones `A::prepare`
+/// returns for `cfg`.
+pub fn load_raw<A: Architecture>(path: &Path, base: u64, entries: &[u64], cfg: &A::Configuration) -> Result<(Project, Machine)> {
+    let name = path.file_name().map(|x| x.to_string_lossy().to_string()).unwrap_or("(encoding error)".to_string());
+    let machine = match A::machine(cfg) {
+        Some(m) => m,
+        None => return Err("The architecture has no machine to load raw binaries for".into()),
+    };
+    let file = MappedFile::open(path)?;
+    let mut reg = machine_region(machine);
+    let bound = match base.checked_add(file.len()) {
+        Some(end) if end <= reg.size() => Bound::new(base, end),
+        _ => return Err(format!("{} does not fit into the address space of {:?} when loaded at {:#x}", name, machine, base).into()),
+    };
+
+    debug!("Load {} ({} bytes) to {:#x}", name, file.len(), base);
+
+    if !reg.cover(bound.clone(), Layer::Opaque(OpaqueLayer::Mapped(file))) {
+        return Err(format!("Cannot cover bound: {:?}", bound).into());
+    }
+
+    let mut prog = Program::new("prog0");
+    let mut proj = Project::new(name.clone(), reg);
+
+    for bank in machine_banks(machine) {
+        proj.data.add_region(bank)?;
+    }
+
+    proj.segments.push(Segment::new(name, SegmentKind::Segment, bound.clone(), Some(0), bound.len(), (true, true, true)));
+
+    for &entry in entries {
+        if entry < bound.start || entry >= bound.end {
+            return Err(format!("Entry point {:#x} is outside of the loaded file ({:#x}-{:#x})", entry, bound.start, bound.end).into());
+        }
+
+        prog.call_graph.add_vertex(CallTarget::Todo(Rvalue::new_u64(entry), None, Uuid::new_v4()));
+    }
+
+    proj.code.push(prog);
+    prepare::<A>(&mut proj, cfg)?;
+
+    Ok((proj, machine))
+}
+
+/// Adds the architecture specific entry points returned by `Architecture::prepare` (e.g. AVR
+/// interrupt vectors) to the first `Program` of `proj`. The comment of each entry point is
+/// attached to its address.
+pub fn prepare<A: Architecture>(proj: &mut Project, cfg: &A::Configuration) -> Result<()> {
+    let entries = A::prepare(proj.region(), cfg)?;
+    let region = proj.region().name().clone();
+
+    if proj.code.is_empty() {
+        proj.code.push(Program::new("prog0"));
+    }
+
+    for (name, addr, comment) in entries {
+        debug!("Architecture entry point {} @ {:#x}", name, addr);
+        proj.code[0].call_graph.add_vertex(CallTarget::Todo(Rvalue::new_u64(addr), Some(name.to_string()), Uuid::new_v4()));
+
+        if !comment.is_empty() {
+            proj.comments.insert((region.clone(), addr), comment.to_string());
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the architecture slices of the fat Mach-o binary at `path`. Returns an empty vector if
+/// the file is not a fat binary.
+pub fn slices(path: &Path) -> Result<Vec<MachSlice>> {
+    let mut fd = File::open(path)?;
+
+    if let Hint::MachFat(_) = goblin::peek(&mut fd)? {
+        let mut bytes = Vec::new();
+        fd.read_to_end(&mut bytes)?;
+        mach_slices(&bytes)
+    } else {
+        Ok(vec![])
+    }
+}
+
+/// Load slice number `slice` of the fat Mach-o binary at `path` and creates a `Project` from it.
+/// Returns the `Project` instance and the CPU its intended for.
+pub fn load_slice(path: &Path, slice: usize) -> Result<(Project, Machine)> {
+    let name = path.file_name().map(|x| x.to_string_lossy().to_string()).unwrap_or("(encoding error)".to_string());
+    let mut fd = File::open(path)?;
+
+    if let Hint::MachFat(_) = goblin::peek(&mut fd)? {
+        let mut bytes = Vec::new();
+        fd.read_to_end(&mut bytes)?;
+        load_fat_mach(&bytes, Some(slice), name)
+    } else {
+        Err(format!("{} is not a fat mach-o binary", name).into())
+    }
+}
+
+/// Load an ELF, PE, Mach-o file, static library, DOS executable or Intel HEX/Motorola S-record firmware image from disk
+/// and creates a `Project` from it. Returns the `Project` instance and the CPU its intended for. Firmware images are
+/// assumed to be AVR flash contents. DOS MZ executables and `.com` files are loaded for real mode x86. Fat Mach-o
+/// binaries are loaded using the first slice with a supported CPU type, use `load_slice` to load a specific one.
+pub fn load(path: &Path) -> Result<(Project, Machine)> {
+    load_file(path, None)
+}
+
+/// Load an ELF or PE file from disk like `load`, but map it to `base` instead of its preferred load
+/// address. Dynamic relocations are applied for the new base. Only position independent ELF files and
+/// PE images with base relocations can be rebased.
+pub fn load_at(path: &Path, base: u64) -> Result<(Project, Machine)> {
+    load_file(path, Some(base))
+}
+
+fn load_file(path: &Path, base: Option<u64>) -> Result<(Project, Machine)> {
+    let name = path.file_name().map(|x| x.to_string_lossy().to_string()).unwrap_or("(encoding error)".to_string());
+    let mut fd = File::open(path)?;
+    let is_com = path.extension().map(|e| e.to_string_lossy().to_lowercase() == "com").unwrap_or(false);
+
+    // .com files have no header. DOS runs them as MZ executables if they start with the MZ magic.
+    if is_com {
+        let mut bytes = Vec::new();
+        fd.read_to_end(&mut bytes)?;
+
+        if !bytes.starts_with(b"MZ") {
+            if base.is_some() {
+                return Err(format!("{} cannot be rebased, only ELF and PE files can", name).into());
+            }
+            return load_com(&bytes, name);
+        }
+        fd.seek(SeekFrom::Start(0))?;
+    }
+
+    let peek = goblin::peek(&mut fd)?;
+
+    match (&peek, base) {
+        (&Hint::Elf(_), _) | (&Hint::PE, _) | (_, None) => {}
+        (_, Some(_)) => return Err(format!("{} cannot be rebased, only ELF and PE files can", name).into()),
+    }
+
+    if let Hint::Unknown(magic) = peek {
+        let mut bytes = Vec::new();
+        fd.seek(SeekFrom::Start(0))?;
+        fd.read_to_end(&mut bytes)?;
+
+        if is_ihex(&bytes) {
+            let (data, entry) = parse_ihex(&bytes)?;
+            load_firmware(data, entry, name)
+        } else if is_srec(&bytes) {
+            let (data, entry) = parse_srec(&bytes)?;
+            load_firmware(data, entry, name)
+        } else {
+            Err(format!("Tried to load an unknown file. Magic: {}", magic).into())
+        }
+    } else if let Hint::Elf(_) = peek {
+        load_elf(&MappedFile::open(path)?, name, base)
+    } else {
+        let mut bytes = Vec::new();
+        fd.read_to_end(&mut bytes)?;
+        match peek {
+            Hint::PE if is_pe(&bytes) => load_pe(&bytes, name, base),
+            Hint::PE if base.is_some() => Err(format!("{} cannot be rebased, only ELF and PE files can", name).into()),
+            Hint::PE => load_mz(&bytes, name),
+            Hint::Mach(_) => load_mach(&bytes, 0, name),
+            Hint::MachFat(_) => load_fat_mach(&bytes, None, name),
+            Hint::Archive => load_archive(&bytes, name),
             _ => {
                 println!(
                     "Loader branch hit wildcard, should be unreachable (a new variant must have been added but code was not updated)",
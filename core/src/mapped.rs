@@ -0,0 +1,238 @@
+/*
+ * Panopticon - A libre disassembler
+ * Copyright (C) 2017  Panopticon authors
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Memory mapped files.
+//!
+//! Firmware images and core dumps can be several gigabytes large. Instead of reading them into
+//! memory, `OpaqueLayer::Mapped` references a window of a `MappedFile`. A `MappedFile` is
+//! identified by its path and the SHA-256 hash of its contents. Snapshots written with
+//! `Project::snapshot_by_reference` only store these two. `Project::open` maps the file again and
+//! verifies the hash.
+//!
+//! Mapped files are not copied nor locked. Changes made to the file by other processes are visible
+//! through the mapping and truncating a mapped file makes reading the lost pages raise `SIGBUS`.
+//! Files must not be truncated while a `MappedFile` references them.
+
+use Result;
+use memmap::{Mmap, Protection};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error as DeError;
+use std::fmt;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// A window into a read-only, memory mapped file.
+#[derive(Clone)]
+pub struct MappedFile {
+    path: PathBuf,
+    // computed on first use and shared by all windows into the file
+    hash: Arc<Mutex<Option<String>>>,
+    // hash stored in the project this reference was deserialized from
+    saved_hash: Option<String>,
+    offset: u64,
+    len: u64,
+    // empty files cannot be mapped
+    map: Option<Arc<Mmap>>,
+}
+
+/// Serialized form of a `MappedFile`.
+#[derive(Serialize,Deserialize)]
+struct MappedFileRef {
+    path: PathBuf,
+    hash: String,
+    offset: u64,
+    len: u64,
+}
+
+impl MappedFile {
+    /// Maps the whole file at `path` into memory. The hash is computed when first needed.
+    pub fn open(path: &Path) -> Result<MappedFile> {
+        let path = path.canonicalize()?;
+        let map = Self::map(&path)?;
+        let len = map.as_ref().map(|m| m.len() as u64).unwrap_or(0);
+
+        Ok(MappedFile { path: path, hash: Arc::new(Mutex::new(None)), saved_hash: None, offset: 0, len: len, map: map })
+    }
+
+    fn map(path: &Path) -> Result<Option<Arc<Mmap>>> {
+        if path.metadata()?.len() == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(Arc::new(Mmap::open_path(path, Protection::Read)?)))
+        }
+    }
+
+    /// Returns a window of `len` bytes starting at `offset` into this one.
+    pub fn slice(&self, offset: u64, len: u64) -> Result<MappedFile> {
+        if offset.checked_add(len).map(|end| end > self.len).unwrap_or(true) {
+            return Err(format!("{:#x}+{:#x} is outside of {}", offset, len, self.path.display()).into());
+        }
+
+        let mut ret = self.clone();
+
+        ret.offset += offset;
+        ret.len = len;
+        Ok(ret)
+    }
+
+    /// Canonical path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Hex encoded SHA-256 hash of the whole file. Hashes the file on the first call.
+    pub fn hash(&self) -> String {
+        let mut hash = self.hash.lock().unwrap();
+
+        if hash.is_none() {
+            *hash = Some(sha256(self.file_contents()));
+        }
+
+        hash.clone().unwrap()
+    }
+
+    /// Offset of the window inside the file.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Number of bytes in the window.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Contents of the window.
+    pub fn bytes(&self) -> &[u8] {
+        &self.file_contents()[self.offset as usize..(self.offset + self.len) as usize]
+    }
+
+    fn file_contents(&self) -> &[u8] {
+        match self.map {
+            // The mapping lives as long as self holds the Arc. It's neither copied nor locked, so
+            // the slice sees changes made to the file by other processes and raises SIGBUS if the
+            // file is truncated. See the module documentation.
+            Some(ref m) => unsafe { m.as_slice() },
+            None => &[],
+        }
+    }
+
+    /// Checks that the file still has the hash stored in the project this reference was
+    /// deserialized from. Files mapped with `open` have no stored hash and always pass.
+    pub fn verify(&self) -> Result<()> {
+        match self.saved_hash {
+            Some(ref h) if sha256(self.file_contents()) != *h => {
+                Err(format!("The contents of {} changed since the project was saved", self.path.display()).into())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Debug for MappedFile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MappedFile({}, {:#x}+{:#x})", self.path.display(), self.offset, self.len)
+    }
+}
+
+impl Serialize for MappedFile {
+    fn serialize<S: Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+        let r = MappedFileRef { path: self.path.clone(), hash: self.hash(), offset: self.offset, len: self.len };
+
+        r.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MappedFile {
+    /// Maps the referenced file. The hash is checked by `Project::open`.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> ::std::result::Result<MappedFile, D::Error> {
+        let r = MappedFileRef::deserialize(deserializer)?;
+        let map = MappedFile::map(&r.path).map_err(|e| D::Error::custom(format!("Cannot map {}: {}", r.path.display(), e)))?;
+        let file_len = map.as_ref().map(|m| m.len() as u64).unwrap_or(0);
+
+        if r.offset.checked_add(r.len).map(|end| end > file_len).unwrap_or(true) {
+            return Err(D::Error::custom(format!("{} is shorter than when the project was saved", r.path.display())));
+        }
+
+        Ok(
+            MappedFile {
+                path: r.path,
+                hash: Arc::new(Mutex::new(Some(r.hash.clone()))),
+                saved_hash: Some(r.hash),
+                offset: r.offset,
+                len: r.len,
+                map: map,
+            }
+        )
+    }
+}
+
+/// Hex encoded SHA-256 hash of `data`.
+fn sha256(data: &[u8]) -> String {
+    let mut hasher = Sha256::default();
+
+    hasher.input(data);
+    hasher.result().iter().map(|x| format!("{:02x}", x)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_cbor;
+    use std::fs::{File, OpenOptions};
+    use std::io::Write;
+    use tempdir::TempDir;
+
+    #[test]
+    fn hash() {
+        assert_eq!(sha256(b""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+        assert_eq!(sha256(b"abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    }
+
+    #[test]
+    fn map_file() {
+        let tmp = TempDir::new("panop-mapped").unwrap();
+        let path = tmp.path().join("file");
+
+        File::create(&path).unwrap().write_all(b"Hello, World").unwrap();
+
+        let file = MappedFile::open(&path).unwrap();
+        let slice = file.slice(7, 5).unwrap();
+
+        assert_eq!(file.bytes(), b"Hello, World");
+        assert_eq!(slice.bytes(), b"World");
+        assert!(file.slice(7, 6).is_err());
+        assert_eq!(slice.hash(), file.hash());
+
+        let saved = serde_cbor::to_vec(&slice).unwrap();
+        let restored: MappedFile = serde_cbor::from_slice(&saved).unwrap();
+        assert_eq!(restored.bytes(), b"World");
+        assert!(restored.verify().is_ok());
+
+        // overwritten in place without truncating, the mapping sees the new contents
+        OpenOptions::new().write(true).open(&path).unwrap().write_all(b"Hello, Panop").unwrap();
+        assert!(restored.verify().is_err());
+        assert!(serde_cbor::from_slice::<MappedFile>(&saved).unwrap().verify().is_err());
+        assert!(file.verify().is_ok());
+        assert!(MappedFile::open(&path).unwrap().hash() != file.hash());
+
+        let empty = tmp.path().join("empty");
+        File::create(&empty).unwrap();
+        assert_eq!(MappedFile::open(&empty).unwrap().bytes(), b"");
+    }
+}
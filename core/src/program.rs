@@ -29,7 +29,7 @@
 //! error node.
 
 
-use {Function, Statement, Operation, Rvalue};
+use {Bound, Function, Statement, Operation, Rvalue};
 use panopticon_graph_algos::{AdjacencyList, AdjacencyMatrixGraphTrait, GraphTrait, MutableGraphTrait, VertexListGraphTrait};
 use panopticon_graph_algos::adjacency_list::{AdjacencyListVertexDescriptor, VertexLabelIterator, VertexLabelMutIterator};
 use uuid::Uuid;
@@ -126,6 +126,12 @@ pub struct Program {
     pub call_graph: CallGraph,
     /// Symbolic References (Imports)
     pub imports: ::std::collections::HashMap<u64, String>,
+    /// Known address ranges of functions, keyed by entry point (e.g. from unwind tables)
+    #[serde(default)]
+    pub extents: ::std::collections::HashMap<u64, Bound>,
+    /// Name of the `Region` the code is read from, `None` for the root `Region` of the `Project`
+    #[serde(default)]
+    pub region: Option<String>,
 }
 
 impl<'a> IntoIterator for &'a Program {
@@ -144,6 +150,8 @@ impl Program {
             name: n.to_string(),
             call_graph: CallGraph::new(),
             imports: ::std::collections::HashMap::new(),
+            extents: ::std::collections::HashMap::new(),
+            region: None,
         }
     }
 
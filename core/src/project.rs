@@ -18,11 +18,14 @@
 
 //! The root of a Panopticon session.
 //!
-//! Projects are a set of `Program`s, associated memory `Region`s and comments.
+//! Projects are a set of `Program`s, associated memory `Region`s, the segment map and debug information of the loaded
+//! binary and comments.
 
 
-use {CallGraphRef, Function, Program, Region, Result, World};
-use panopticon_graph_algos::GraphTrait;
+use {Bound, CallGraphRef, CallTarget, Codec, CoreDump, DebugInfo, Function, Layer, Program, Region, Result, Rvalue, Segment, SourceLocation, World};
+use region::RegionRef;
+use segment;
+use panopticon_graph_algos::{GraphTrait, MutableGraphTrait, VertexListGraphTrait};
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 use flate2::Compression;
 use flate2::read::ZlibDecoder;
@@ -30,6 +33,7 @@ use flate2::write::ZlibEncoder;
 use serde_cbor::de::Deserializer;
 use serde_cbor::ser::Serializer;
 use serde::{Deserialize, Serialize};
+use std::cell::Cell;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{Read, Write};
@@ -45,11 +49,42 @@ pub struct Project {
     /// Recognized code
     pub code: Vec<Program>,
     /// Memory regions
+    #[serde(serialize_with = "serialize_world")]
     pub data: World,
     /// Comments
     pub comments: HashMap<(String, u64), String>,
     /// Symbolic References (Imports)
     pub imports: HashMap<u64, String>,
+    /// Sections and segments of the loaded binary
+    #[serde(default)]
+    pub segments: Vec<Segment>,
+    /// Position of the `Layer` holding the applied dynamic relocations in the root `Region`'s stack
+    #[serde(default)]
+    pub relocations: Option<usize>,
+    /// DWARF debug information of the loaded binary
+    #[serde(default)]
+    pub debug_info: DebugInfo,
+    /// Threads and mapped files of the process if the project was loaded from a core dump
+    #[serde(default)]
+    pub core_dump: Option<CoreDump>,
+    /// Positions of the `Layer`s holding byte patches in the root `Region`'s stack, oldest first
+    #[serde(default)]
+    pub patches: Vec<usize>,
+}
+
+thread_local! {
+    // set while `Project::snapshot` runs
+    static EMBED_MAPPED_FILES: Cell<bool> = Cell::new(false);
+}
+
+/// Serializes `world`, reading the contents of memory mapped files into the snapshot if written by
+/// `Project::snapshot`.
+fn serialize_world<S: ::serde::Serializer>(world: &World, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+    if EMBED_MAPPED_FILES.with(|e| e.get()) {
+        world.embed().serialize(serializer)
+    } else {
+        world.serialize(serializer)
+    }
 }
 
 impl Project {
@@ -61,6 +96,11 @@ impl Project {
             data: World::new(r),
             comments: HashMap::new(),
             imports: HashMap::new(),
+            segments: Vec::new(),
+            relocations: None,
+            debug_info: DebugInfo::default(),
+            core_dump: None,
+            patches: Vec::new(),
         }
     }
 
@@ -70,7 +110,226 @@ impl Project {
         self.data.dependencies.vertex_label(self.data.root).unwrap()
     }
 
-    /// Reads a serialized project from disk.
+    /// Returns this project's root Region for modification.
+    pub fn region_mut(&mut self) -> &mut Region {
+        let root = self.data.root;
+        self.data.dependencies.vertex_label_mut(root).unwrap()
+    }
+
+    /// Applies `layer` to the `area` of this project's root Region. Returns the index of the new
+    /// `Layer` in the Region's stack.
+    pub fn cover(&mut self, area: Bound, layer: Layer) -> Result<usize> {
+        let reg = self.region_mut();
+
+        if reg.cover(area.clone(), layer) {
+            Ok(reg.stack().len() - 1)
+        } else {
+            Err(format!("Cannot cover {:?} of region {}", area, reg.name()).into())
+        }
+    }
+
+    /// Overwrites the root Region with `bytes`, starting at `address`. The patch is recorded as a new
+    /// writable `Layer`. Returns the index of the new `Layer` in the Region's stack. All patched
+    /// bytes must be backed by the file the project was loaded from, see `file_offset`.
+    pub fn patch(&mut self, address: u64, bytes: &[u8]) -> Result<usize> {
+        for i in 0..bytes.len() as u64 {
+            let addr = address.checked_add(i).ok_or_else(|| format!("Patch at {:#x} overflows the address space", address))?;
+
+            if self.file_offset(addr).is_none() {
+                return Err(format!("Cannot patch {:#x}, it is not backed by the file", addr).into());
+            }
+        }
+
+        let idx = {
+            let reg = self.region_mut();
+
+            if !reg.patch(address, bytes) {
+                return Err(format!("Cannot patch {} bytes at {:#x} of region {}", bytes.len(), address, reg.name()).into());
+            }
+            reg.stack().len() - 1
+        };
+
+        self.patches.push(idx);
+        Ok(idx)
+    }
+
+    /// Removes the patch at position `layer` of the root Region's stack. The positions of all
+    /// `Layer`s above it are updated.
+    pub fn revert_patch(&mut self, layer: usize) -> Result<()> {
+        if !self.patches.contains(&layer) {
+            return Err(format!("Layer {} is not a patch", layer).into());
+        }
+
+        let reg = self.region().without_layer(layer);
+
+        *self.region_mut() = reg;
+        self.patches.retain(|&p| p != layer);
+        for p in self.patches.iter_mut() {
+            if *p > layer {
+                *p -= 1;
+            }
+        }
+        if let Some(ref mut r) = self.relocations {
+            if *r > layer {
+                *r -= 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Patched bytes as (address, value) pairs, in the order they were patched.
+    pub fn patched_bytes(&self) -> Vec<(u64, u8)> {
+        let mut ret = Vec::new();
+
+        for &idx in self.patches.iter() {
+            if let Some(&(ref area, Layer::Sparse(ref m))) = self.region().stack().get(idx) {
+                let mut cells = m.iter().filter_map(|(&k, &c)| c.map(|b| (area.start + k, b))).collect::<Vec<_>>();
+
+                cells.sort();
+                ret.extend(cells);
+            }
+        }
+
+        ret
+    }
+
+    /// Writes a copy of `original`, the file this project was loaded from, with all patches applied
+    /// to `output`. Virtual addresses are mapped to file offsets using the segments of the project.
+    pub fn write_patched(&self, original: &Path, output: &Path) -> Result<()> {
+        let mut bytes = Vec::new();
+
+        File::open(original)?.read_to_end(&mut bytes)?;
+
+        for (addr, b) in self.patched_bytes() {
+            match self.file_offset(addr) {
+                Some(off) if off < bytes.len() as u64 => bytes[off as usize] = b,
+                _ => return Err(format!("Patched address {:#x} is not backed by {}", addr, original.display()).into()),
+            }
+        }
+
+        File::create(output)?.write_all(&bytes)?;
+        Ok(())
+    }
+
+    /// Returns the offset of `addr` in the file the project was loaded from, `None` if `addr` is
+    /// not backed by the file.
+    pub fn file_offset(&self, addr: u64) -> Option<u64> {
+        self.segment(addr).and_then(|s| s.file_offset_of(addr))
+    }
+
+    /// Returns the Region the code of `prog` is read from.
+    pub fn region_of(&self, prog: &Program) -> Option<&Region> {
+        match prog.region {
+            Some(ref name) => self.data.find_region(name).and_then(|r| self.data.region(r)),
+            None => Some(self.region()),
+        }
+    }
+
+    /// Decompresses `area` of the root Region with `codec` into a new Region overlapping it. If
+    /// `entries` is not empty, a new Program disassembling the decompressed Region starting at
+    /// the given offsets is added too.
+    pub fn decompress(&mut self, area: Bound, codec: Codec, entries: &[u64]) -> Result<RegionRef> {
+        let name = format!("{}@{:x}-{:x}", codec, area.start, area.end);
+        let root = self.data.root;
+
+        if self.data.find_region(&name).is_some() {
+            return Err(format!("A region called '{}' already exists", name).into());
+        }
+
+        let unpacked = self.data.unpack(root, area, codec)?;
+        let size = unpacked.len() as u64;
+
+        for &entry in entries {
+            if entry >= size {
+                return Err(format!("Entry point {:#x} is outside of the {} decompressed bytes", entry, size).into());
+            }
+        }
+
+        let reg = self.data.overlay(root, area, Region::wrap(name.clone(), unpacked))?;
+
+        if !entries.is_empty() {
+            let mut prog = Program::new(&name);
+
+            prog.region = Some(name.clone());
+
+            for &entry in entries {
+                prog.call_graph.add_vertex(CallTarget::Todo(Rvalue::new_u64(entry), None, Uuid::new_v4()));
+            }
+
+            self.code.push(prog);
+        }
+
+        Ok(reg)
+    }
+
+    /// Returns this project's root Region as it was read from the file, without dynamic relocations
+    /// applied.
+    pub fn raw_region(&self) -> Region {
+        match self.relocations {
+            Some(idx) => self.region().without_layer(idx),
+            None => self.region().clone(),
+        }
+    }
+
+    /// Returns the `Region` backing the memory bank `bank` used by `Load`/`Store` operations and
+    /// pointer operands. Banks are matched against the names of the `Region`s ignoring case. Banks
+    /// without a `Region` of their own are part of the root `Region`.
+    pub fn bank(&self, bank: &str) -> &Region {
+        let bank = bank.to_lowercase();
+
+        self.data.regions().into_iter().find(|r| r.name().to_lowercase() == bank).unwrap_or(self.region())
+    }
+
+    /// Returns the innermost section or segment containing `addr`.
+    pub fn segment(&self, addr: u64) -> Option<&Segment> {
+        segment::segment_at(&self.segments, addr)
+    }
+
+    /// Returns the source file and line of the code at `addr`, if the binary has debug information.
+    pub fn source_line(&self, addr: u64) -> Option<&SourceLocation> {
+        self.debug_info.source_line(addr)
+    }
+
+    /// Returns true if `addr` is executable. Addresses outside of all known segments are assumed to be
+    /// executable.
+    pub fn is_executable(&self, addr: u64) -> bool {
+        self.segment(addr).map(|s| s.execute).unwrap_or(true)
+    }
+
+    /// Removes all `CallTarget::Todo`s pointing to addresses that aren't executable, so analyses
+    /// don't disassemble data. Returns the removed addresses.
+    pub fn remove_non_executable_entries(&mut self) -> Vec<u64> {
+        let todos = self.code
+            .iter()
+            .enumerate()
+            // the segment map only describes the main region
+            .filter(|&(_, prog)| prog.region.is_none())
+            .flat_map(
+                |(idx, prog)| {
+                    prog.call_graph
+                        .vertices()
+                        .filter_map(
+                            move |vx| match prog.call_graph.vertex_label(vx) {
+                                Some(&CallTarget::Todo(Rvalue::Constant { value, .. }, _, _)) => Some((idx, vx, value)),
+                                _ => None,
+                            }
+                        )
+                }
+            )
+            .filter(|&(_, _, addr)| !self.is_executable(addr))
+            .collect::<Vec<_>>();
+
+        for &(idx, vx, addr) in todos.iter() {
+            debug!("entry point {:#x} is not executable", addr);
+            self.code[idx].call_graph.remove_vertex(vx);
+        }
+
+        todos.into_iter().map(|(_, _, addr)| addr).collect()
+    }
+
+    /// Reads a serialized project from disk. Fails if a memory mapped file referenced by the
+    /// project changed since it was saved.
     pub fn open(p: &Path) -> Result<Project> {
         let mut fd = match File::open(p) {
             Ok(fd) => fd,
@@ -84,7 +343,9 @@ impl Project {
             if version == 0 {
                 let mut z = ZlibDecoder::new(fd);
                 let mut cbor = Deserializer::new(&mut z);
-                let proj = Deserialize::deserialize(&mut cbor)?;
+                let proj: Project = Deserialize::deserialize(&mut cbor)?;
+
+                proj.data.verify_mapped_files()?;
                 Ok(proj)
             } else {
                 Err("wrong version".into())
@@ -152,7 +413,23 @@ impl Project {
     /// [u8;10] magic = "PANOPTICON"
     /// u32     version = 0
     /// zlib compressed MsgPack
+    ///
+    /// The contents of memory mapped files are embedded into the snapshot.
     pub fn snapshot(&self, p: &Path) -> Result<()> {
+        EMBED_MAPPED_FILES.with(|e| e.set(true));
+        let ret = self.write_snapshot(p);
+        EMBED_MAPPED_FILES.with(|e| e.set(false));
+
+        ret
+    }
+
+    /// Like `snapshot`, but memory mapped files are saved as path and hash only. Opening the
+    /// snapshot fails if the files were moved or changed.
+    pub fn snapshot_by_reference(&self, p: &Path) -> Result<()> {
+        self.write_snapshot(p)
+    }
+
+    fn write_snapshot(&self, p: &Path) -> Result<()> {
         let mut fd = File::create(p)?;
 
         fd.write(b"PANOPTICON")?;
@@ -0,0 +1,121 @@
+/*
+ * Panopticon - A libre disassembler
+ * Copyright (C) 2017  Panopticon authors
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Cursor over little endian DWARF encoded data, shared by the debug info and unwind table parsers.
+
+use Result;
+
+/// Cursor over little endian DWARF data.
+pub struct Reader<'a> {
+    pub buf: &'a [u8],
+    pub pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(buf: &'a [u8], pos: usize) -> Reader<'a> {
+        Reader { buf: buf, pos: pos }
+    }
+
+    pub fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.pos.checked_add(len).map(|e| e <= self.buf.len()) == Some(true) {
+            let ret = &self.buf[self.pos..self.pos + len];
+            self.pos += len;
+            Ok(ret)
+        } else {
+            Err("Truncated DWARF data".into())
+        }
+    }
+
+    pub fn uint(&mut self, len: usize) -> Result<u64> {
+        Ok(self.bytes(len)?.iter().rev().fold(0u64, |acc, &b| (acc << 8) | b as u64))
+    }
+
+    pub fn sint(&mut self, len: usize) -> Result<i64> {
+        let v = self.uint(len)?;
+        let shift = 64 - len * 8;
+        Ok(((v << shift) as i64) >> shift)
+    }
+
+    pub fn u8(&mut self) -> Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    pub fn u16(&mut self) -> Result<u16> {
+        Ok(self.uint(2)? as u16)
+    }
+
+    pub fn uleb(&mut self) -> Result<u64> {
+        let mut ret = 0u64;
+        let mut shift = 0;
+
+        loop {
+            let b = self.u8()?;
+
+            if shift < 64 {
+                ret |= ((b & 0x7f) as u64) << shift;
+            }
+            shift += 7;
+
+            if b & 0x80 == 0 {
+                return Ok(ret);
+            }
+        }
+    }
+
+    pub fn sleb(&mut self) -> Result<i64> {
+        let mut ret = 0i64;
+        let mut shift = 0;
+
+        loop {
+            let b = self.u8()?;
+
+            if shift < 64 {
+                ret |= ((b & 0x7f) as i64) << shift;
+            }
+            shift += 7;
+
+            if b & 0x80 == 0 {
+                if shift < 64 && b & 0x40 != 0 {
+                    ret |= -1i64 << shift;
+                }
+                return Ok(ret);
+            }
+        }
+    }
+
+    pub fn cstr(&mut self) -> Result<String> {
+        let rest = &self.buf[::std::cmp::min(self.pos, self.buf.len())..];
+
+        match rest.iter().position(|&b| b == 0) {
+            Some(len) => {
+                let ret = String::from_utf8_lossy(&rest[..len]).to_string();
+                self.pos += len + 1;
+                Ok(ret)
+            }
+            None => Err("Unterminated string in DWARF data".into()),
+        }
+    }
+
+    /// Reads an initial length field. Returns the length and whenever the 64-bit format is used.
+    pub fn initial_length(&mut self) -> Result<(usize, bool)> {
+        match self.uint(4)? {
+            0xffff_ffff => Ok((self.uint(8)? as usize, true)),
+            len => Ok((len as usize, false)),
+        }
+    }
+}
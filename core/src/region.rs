@@ -48,12 +48,29 @@
 //! let undefined_region = Region::undefined("undef".to_string(),4096);
 //! ```
 //! This region is named "undef" and is just 4k of undefined cells
+//!
+//! ```
+//! use panopticon_core::{Bound, Codec, Region, World};
+//!
+//! // "Hello" compressed with zlib, starting at offset 2
+//! let buf = vec![0, 0, 0x78, 0x9c, 0xf3, 0x48, 0xcd, 0xc9, 0xc9, 0x07, 0x00, 0x05, 0x8c, 0x01, 0xf5];
+//! let mut world = World::new(Region::wrap("file".to_string(),buf));
+//! let root = world.root;
+//! let zlib = world.decompress(root,Bound::new(2,15),Codec::Zlib,"zlib".to_string()).unwrap();
+//!
+//! assert_eq!(world.region(zlib).unwrap().size(), 5);
+//! ```
+//! The region "zlib" holds the 5 decompressed bytes and overlaps bytes 2 to 15 of "file".
 
 
-use {Bound, Layer, LayerIter, OpaqueLayer, Result};
+use {Bound, Codec, Layer, LayerIter, MappedFile, OpaqueLayer, Result};
+use layer::Cell;
 use panopticon_graph_algos::{AdjacencyList, GraphTrait, IncidenceGraphTrait, MutableGraphTrait, VertexListGraphTrait};
 use panopticon_graph_algos::adjacency_list::{AdjacencyListEdgeDescriptor, AdjacencyListVertexDescriptor};
+use serde::{Deserialize, Deserializer};
+use std::cmp::{self, Ordering};
 use std::collections::HashSet;
+use std::mem;
 use std::path::Path;
 use std::sync::Arc;
 
@@ -62,11 +79,50 @@ use std::sync::Arc;
 /// `Region`s are a stack of [`Layer`](../layer/index.html) inside a single address space. The
 /// `Region` is the primary way panopticon handles data. They can be created from files or
 /// in-memory buffers.
-#[derive(Clone,Debug,Serialize,Deserialize)]
+///
+/// Besides the stack, a `Region` keeps a flattened view of it: a sorted list of non-overlapping
+/// areas, each pointing to where its `Cell`s come from. `read` uses it to access any part of the
+/// `Region` without walking the whole stack.
+#[derive(Clone,Debug,Serialize)]
 pub struct Region {
     stack: Vec<(Bound, Layer)>,
     name: String,
     size: u64,
+    #[serde(skip_serializing)]
+    index: Vec<(Bound, Source)>,
+    /// Output of each `Transform` layer of the stack, `None` for all other layers.
+    #[serde(skip_serializing)]
+    transformed: Vec<Option<Arc<Vec<Cell>>>>,
+}
+
+/// Serialized form of a `Region`. The flattened view is recomputed after deserialization.
+#[derive(Deserialize)]
+struct RegionData {
+    stack: Vec<(Bound, Layer)>,
+    name: String,
+    size: u64,
+}
+
+/// Origin of the `Cell`s in an area of the flattened view of a `Region`.
+#[derive(Clone,Debug)]
+enum Source {
+    /// `Cell`s of the opaque `Layer` at this position in the stack, starting at `offset`.
+    Layer { layer: usize, offset: u64 },
+    /// A single `Cell` written by a sparse `Layer`.
+    Cell(Cell),
+    /// Precomputed output of a transform `Layer`, starting at `offset`.
+    Cells { cells: Arc<Vec<Cell>>, offset: u64 },
+}
+
+impl Source {
+    /// Returns the source of the area starting `n` `Cell`s later.
+    fn shift(&self, n: u64) -> Source {
+        match *self {
+            Source::Layer { layer, offset } => Source::Layer { layer: layer, offset: offset + n },
+            Source::Cell(c) => Source::Cell(c),
+            Source::Cells { ref cells, offset } => Source::Cells { cells: cells.clone(), offset: offset + n },
+        }
+    }
 }
 
 /// Graph that models overlapping regions.
@@ -84,7 +140,8 @@ pub type RegionRef = AdjacencyListVertexDescriptor;
 /// inside the overlapped `Region` would still see only the compressed version.
 #[derive(Clone,Serialize,Deserialize,Debug)]
 pub struct World {
-    ///< Graph of all `Region`s with edges pointing from the overlapping to the overlapped `Region`.
+    ///< Graph of all `Region`s with edges pointing from the overlapped to the overlapping `Region`,
+    ///< labeled with the overlapped area.
     pub dependencies: RegionGraph,
     /// Lowest `Region` in the stack.
     pub root: RegionRef,
@@ -97,6 +154,12 @@ impl Region {
         Ok(Region::new(s.clone(), layer))
     }
 
+    /// Creates a new `Region` called `name` that is backed by the memory mapped file at `path`.
+    pub fn map(name: String, p: &Path) -> Result<Region> {
+        let layer = OpaqueLayer::map(p)?;
+        Ok(Region::new(name, layer))
+    }
+
     /// Creates a new `Region` called `name`, filled with `data`.
     pub fn wrap(name: String, data: Vec<u8>) -> Region {
         Region::new(name, OpaqueLayer::Defined(Arc::new(data)))
@@ -111,7 +174,10 @@ impl Region {
     pub fn new(name: String, root: OpaqueLayer) -> Region {
         let l = root.len();
         let b = Layer::Opaque(root);
-        Region { stack: vec![(Bound::new(0, l), b)], name: name, size: l }
+        let mut ret = Region { stack: vec![(Bound::new(0, l), b)], name: name, size: l, index: vec![], transformed: vec![] };
+
+        ret.reindex();
+        ret
     }
 
     /// Applies `layer` to the cells inside `area`.
@@ -128,23 +194,250 @@ impl Region {
             }
 
             self.stack.push((b, l));
+
+            let idx = self.stack.len() - 1;
+            self.index_layer(idx);
             true
         } else {
             false
         }
     }
 
+    /// Overwrites the `Cell`s starting at `address` with `bytes` using a new writable `Layer`.
+    ///
+    /// # Returns
+    /// `false` if the patched area is outside of `0..self.size()` or `bytes` is empty, `true`
+    /// otherwise.
+    pub fn patch(&mut self, address: u64, bytes: &[u8]) -> bool {
+        if bytes.is_empty() {
+            return false;
+        }
+
+        let mut layer = Layer::writable();
+
+        for (i, &b) in bytes.iter().enumerate() {
+            layer.write(i as u64, Some(b));
+        }
+
+        match address.checked_add(bytes.len() as u64) {
+            Some(end) => self.cover(Bound::new(address, end), layer),
+            None => false,
+        }
+    }
+
+    /// Returns a copy of this `Region` without the `idx`-th `Layer` of its stack. The lowest layer
+    /// (index 0) cannot be removed.
+    pub fn without_layer(&self, idx: usize) -> Region {
+        let mut ret = self.clone();
+
+        if idx > 0 && idx < ret.stack.len() {
+            ret.stack.remove(idx);
+            ret.reindex();
+        }
+
+        ret
+    }
+
+    /// Returns a copy of this `Region` with the contents of all memory mapped files read into
+    /// memory.
+    pub fn embed(&self) -> Region {
+        let mut ret = self.clone();
+
+        for &mut (_, ref mut layer) in ret.stack.iter_mut() {
+            let embedded = match *layer {
+                Layer::Opaque(ref o @ OpaqueLayer::Mapped(_)) => Some(Layer::Opaque(o.embed())),
+                _ => None,
+            };
+
+            if let Some(embedded) = embedded {
+                *layer = embedded;
+            }
+        }
+
+        ret
+    }
+
+    /// Memory mapped files referenced by the `Layer`s of this `Region`.
+    pub fn mapped_files(&self) -> Vec<&MappedFile> {
+        self.stack
+            .iter()
+            .filter_map(
+                |&(_, ref layer)| match *layer {
+                    Layer::Opaque(OpaqueLayer::Mapped(ref f)) => Some(f),
+                    _ => None,
+                }
+            )
+            .collect()
+    }
+
+    /// Returns up to `len` `Cell`s starting at `addr`. Fewer `Cell`s are returned if the end of
+    /// the `Region` is reached.
+    pub fn read(&self, addr: u64, len: u64) -> Vec<Cell> {
+        let end = cmp::min(addr.saturating_add(len), self.size);
+        let mut ret = Vec::with_capacity(end.saturating_sub(addr) as usize);
+
+        if addr >= end {
+            return ret;
+        }
+
+        let first = self.index.binary_search_by(
+            |&(ref b, _)| if b.end <= addr {
+                Ordering::Less
+            } else if b.start > addr {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        );
+        let first = match first {
+            Ok(i) => i,
+            Err(i) => i,
+        };
+
+        for &(ref b, ref src) in self.index[first..].iter() {
+            if b.start >= end {
+                break;
+            }
+
+            let from = cmp::max(b.start, addr);
+            let to = cmp::min(b.end, end);
+
+            match src.shift(from - b.start) {
+                Source::Layer { layer, offset } => {
+                    let o = self.stack[layer].1.as_opaque().unwrap();
+                    ret.extend(o.iter().cut(&(offset..offset + to - from)));
+                }
+                Source::Cell(c) => ret.push(c),
+                Source::Cells { ref cells, offset } => ret.extend_from_slice(&cells[offset as usize..(offset + to - from) as usize]),
+            }
+        }
+
+        ret
+    }
+
+    /// Parts of the `Region` not covered by undefined `Layer`s, in ascending order.
+    pub fn defined_areas(&self) -> Vec<Bound> {
+        let mut ret: Vec<Bound> = vec![];
+
+        for &(ref b, ref src) in self.index.iter() {
+            let undefined = match *src {
+                Source::Layer { layer, .. } => self.stack[layer].1.is_undefined(),
+                Source::Cell(c) => c.is_none(),
+                Source::Cells { .. } => false,
+            };
+
+            if undefined {
+                continue;
+            }
+
+            if ret.last().map(|l| l.end == b.start).unwrap_or(false) {
+                ret.last_mut().unwrap().end = b.end;
+            } else {
+                ret.push(b.clone());
+            }
+        }
+
+        ret
+    }
+
+    /// Recomputes the flattened view from the whole stack.
+    fn reindex(&mut self) {
+        self.index = vec![];
+        self.transformed = vec![];
+
+        for idx in 0..self.stack.len() {
+            self.index_layer(idx);
+        }
+    }
+
+    /// Adds the `idx`-th `Layer` of the stack to the flattened view. All `Layer`s below it must
+    /// have been added before.
+    fn index_layer(&mut self, idx: usize) {
+        let mut output = None;
+        let pieces = {
+            let &(ref area, ref layer) = &self.stack[idx];
+
+            match *layer {
+                Layer::Opaque(_) => vec![(area.clone(), Source::Layer { layer: idx, offset: 0 })],
+                Layer::Sparse(ref m) => {
+                    let mut cells = m.iter().filter(|&(&k, _)| k < area.end - area.start).map(|(&k, &c)| (k, c)).collect::<Vec<_>>();
+
+                    cells.sort_by_key(|&(k, _)| k);
+                    cells
+                        .into_iter()
+                        .map(|(k, c)| (Bound::new(area.start + k, area.start + k + 1), Source::Cell(c)))
+                        .collect()
+                }
+                Layer::Transform(_) => {
+                    // transforms may depend on all cells they cover, compute them once
+                    let src = Self::iter_stack(&self.stack[..idx], &self.transformed[..idx]).cut(&(area.start..area.end));
+                    let cells = Arc::new(layer.filter(src).collect::<Vec<_>>());
+
+                    output = Some(cells.clone());
+                    vec![(area.clone(), Source::Cells { cells: cells, offset: 0 })]
+                }
+            }
+        };
+
+        self.transformed.truncate(idx);
+        self.transformed.push(output);
+        self.overlay(pieces);
+    }
+
+    /// Replaces the areas of the flattened view covered by `pieces`. The pieces must be sorted
+    /// and must not overlap.
+    fn overlay(&mut self, pieces: Vec<(Bound, Source)>) {
+        let old = mem::replace(&mut self.index, vec![]);
+        let mut old = old.into_iter();
+        let mut cur = old.next();
+
+        for (b, src) in pieces {
+            while let Some((ob, osrc)) = cur.take() {
+                if ob.end <= b.start {
+                    self.index.push((ob, osrc));
+                    cur = old.next();
+                } else if ob.start >= b.end {
+                    cur = Some((ob, osrc));
+                    break;
+                } else {
+                    if ob.start < b.start {
+                        self.index.push((Bound::new(ob.start, b.start), osrc.clone()));
+                    }
+                    if ob.end > b.end {
+                        cur = Some((Bound::new(b.end, ob.end), osrc.shift(b.end - ob.start)));
+                        break;
+                    }
+                    cur = old.next();
+                }
+            }
+
+            self.index.push((b, src));
+        }
+
+        self.index.extend(cur);
+        self.index.extend(old);
+    }
+
     /// Iterator over all `Cell`s, starting at 0.
     pub fn iter(&self) -> LayerIter {
-        let mut ret = self.stack[0].1.as_opaque().unwrap().iter();
+        Self::iter_stack(&self.stack, &self.transformed)
+    }
 
-        for s in self.stack.iter().skip(1) {
+    /// Iterator over `stack`. `Transform` layers yield their cached output from `transformed`
+    /// instead of being applied again.
+    fn iter_stack<'a>(stack: &'a [(Bound, Layer)], transformed: &[Option<Arc<Vec<Cell>>>]) -> LayerIter<'a> {
+        let mut ret = stack[0].1.as_opaque().unwrap().iter();
+
+        for (s, out) in stack.iter().zip(transformed.iter()).skip(1) {
             let &(ref area, ref layer) = s;
 
             let src = ret.cut(&(area.start..area.end));
             assert_eq!(src.len(), area.end - area.start);
 
-            let mut tmp = layer.filter(src);
+            let mut tmp = match *out {
+                Some(ref cells) => layer.filter_cached(src, cells.clone()),
+                None => layer.filter(src),
+            };
 
             if area.start != 0 {
                 tmp = ret.cut(&(0..area.start)).append(tmp);
@@ -232,6 +525,16 @@ impl Region {
     }
 }
 
+impl<'de> Deserialize<'de> for Region {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Region, D::Error> {
+        let r = RegionData::deserialize(deserializer)?;
+        let mut ret = Region { stack: r.stack, name: r.name, size: r.size, index: vec![], transformed: vec![] };
+
+        ret.reindex();
+        Ok(ret)
+    }
+}
+
 impl World {
     /// Creates a new `World` with a single `Region` `reg`
     pub fn new(reg: Region) -> World {
@@ -241,6 +544,115 @@ impl World {
         World { dependencies: g, root: b }
     }
 
+    /// Returns a copy of this `World` with the contents of all memory mapped files read into
+    /// memory.
+    pub fn embed(&self) -> World {
+        let mut ret = self.clone();
+
+        for reg in ret.dependencies.vertex_labels_mut() {
+            *reg = reg.embed();
+        }
+
+        ret
+    }
+
+    /// Checks that no memory mapped file referenced by a deserialized `Region` changed since the
+    /// project was saved.
+    pub fn verify_mapped_files(&self) -> Result<()> {
+        let mut seen = HashSet::new();
+
+        for reg in self.dependencies.vertex_labels() {
+            for file in reg.mapped_files() {
+                if seen.insert((file.path().to_path_buf(), file.hash())) {
+                    file.verify()?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the `Region` `r`.
+    pub fn region(&self, r: RegionRef) -> Option<&Region> {
+        self.dependencies.vertex_label(r)
+    }
+
+    /// Returns all `Region`s, starting with the root.
+    pub fn regions(&self) -> Vec<&Region> {
+        let mut ret = self.region(self.root).into_iter().collect::<Vec<_>>();
+
+        ret.extend(self.dependencies.vertices().filter(|&v| v != self.root).filter_map(|v| self.dependencies.vertex_label(v)));
+        ret
+    }
+
+    /// Adds `reg` as a separate address space that does not overlap any other `Region`.
+    pub fn add_region(&mut self, reg: Region) -> Result<RegionRef> {
+        if self.find_region(reg.name()).is_some() {
+            return Err(format!("A region called '{}' already exists", reg.name()).into());
+        }
+
+        Ok(self.dependencies.add_vertex(reg))
+    }
+
+    /// Returns the `Region` called `name`.
+    pub fn find_region(&self, name: &str) -> Option<RegionRef> {
+        self.dependencies.vertices().find(|&v| self.dependencies.vertex_label(v).map(|r| r.name() == name).unwrap_or(false))
+    }
+
+    /// Decompresses the `Cell`s inside `area` of `reg` using `codec` and adds the result as a new
+    /// `Region` called `name` that overlaps `area`. All `Cell`s inside `area` must be defined.
+    pub fn decompress(&mut self, reg: RegionRef, area: Bound, codec: Codec, name: String) -> Result<RegionRef> {
+        if self.find_region(&name).is_some() {
+            return Err(format!("A region called '{}' already exists", name).into());
+        }
+
+        let unpacked = self.unpack(reg, area.clone(), codec)?;
+        self.overlay(reg, area, Region::wrap(name, unpacked))
+    }
+
+    /// Decompresses the `Cell`s inside `area` of `reg` using `codec` without changing `self`. All
+    /// `Cell`s inside `area` must be defined.
+    pub fn unpack(&self, reg: RegionRef, area: Bound, codec: Codec) -> Result<Vec<u8>> {
+        let src = match self.region(reg) {
+            Some(src) => src,
+            None => return Err("Unknown region".into()),
+        };
+
+        if area.start >= area.end || area.end > src.size() {
+            return Err(format!("{:?} is not inside region '{}'", area, src.name()).into());
+        }
+
+        let cells = src.iter().cut(&(area.start..area.end));
+        let mut packed = Vec::with_capacity((area.end - area.start) as usize);
+
+        for (i, c) in cells.enumerate() {
+            match c {
+                Some(b) => packed.push(b),
+                None => return Err(format!("Cell {:#x} of region '{}' is undefined", area.start + i as u64, src.name()).into()),
+            }
+        }
+
+        let unpacked = codec.decompress(&packed)?;
+
+        debug!("decompressed {} bytes of {:?} into {} bytes ({})", packed.len(), area, unpacked.len(), codec);
+        Ok(unpacked)
+    }
+
+    /// Adds `new` as a `Region` that overlaps `area` of `reg`.
+    pub fn overlay(&mut self, reg: RegionRef, area: Bound, new: Region) -> Result<RegionRef> {
+        if self.find_region(new.name()).is_some() {
+            return Err(format!("A region called '{}' already exists", new.name()).into());
+        }
+        if self.region(reg).is_none() {
+            return Err("Unknown region".into());
+        }
+
+        let new = self.dependencies.add_vertex(new);
+        self.dependencies.add_edge(area, reg, new);
+
+        Ok(new)
+    }
+
     /// Vector of all `Region` in `self` and their uncovered area
     pub fn projection(&self) -> Vec<(Bound, RegionRef)> {
         let mut ret = Vec::<(Bound, RegionRef)>::new();
@@ -284,6 +696,7 @@ impl World {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use codec::Codec;
     use layer::Layer;
     use mnemonic::Bound;
     use panopticon_graph_algos::MutableGraphTrait;
@@ -368,4 +781,61 @@ mod tests {
         assert_eq!(proj[5].0, Bound::new(134, 140));
         assert_eq!(proj[5].1.as_opaque().unwrap().iter().len(), 140);
     }
+
+    #[test]
+    fn read() {
+        use layer::Transform;
+
+        let mut st = Region::wrap("".to_string(), (0..64).collect());
+        let mut sparse = Layer::writable();
+
+        sparse.write(0, Some(0xaa));
+        sparse.write(3, None);
+        sparse.write(100, Some(1));
+
+        assert!(st.cover(Bound::new(8, 24), Layer::undefined(16)));
+        assert!(st.cover(Bound::new(20, 40), Layer::Transform(Transform::Xor(vec![0x55, 0x66]))));
+        assert!(st.cover(Bound::new(10, 30), sparse));
+        assert!(st.cover(Bound::new(36, 48), Layer::wrap(vec![1; 12])));
+        assert!(st.cover(Bound::new(44, 60), Layer::Transform(Transform::Rc4(b"Key".to_vec()))));
+
+        let cells = st.iter().collect::<Vec<_>>();
+
+        for start in 0..64 {
+            for len in 0..(70 - start) {
+                let end = cmp::min(start + len, 64);
+                assert_eq!(st.read(start, len), &cells[start as usize..end as usize]);
+            }
+        }
+        assert_eq!(st.read(10, 1), vec![Some(0xaa)]);
+        assert!(st.read(64, 1).is_empty());
+
+        let st = st.without_layer(2);
+        assert_eq!(st.read(0, 64), st.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn decompress() {
+        let mut buf = vec![0xff; 8];
+        buf.extend_from_slice(&[0x78, 0x9c, 0xf3, 0x48, 0xcd, 0xc9, 0xc9, 0x07, 0x00, 0x05, 0x8c, 0x01, 0xf5]);
+        buf.extend_from_slice(&[0xff; 4]);
+
+        let mut world = World::new(Region::wrap("file".to_string(), buf));
+        let root = world.root;
+        let zlib = world.decompress(root, Bound::new(8, 21), Codec::Zlib, "zlib".to_string()).unwrap();
+
+        assert_eq!(world.find_region("zlib"), Some(zlib));
+        assert_eq!(world.region(zlib).unwrap().iter().collect::<Vec<_>>(), b"Hello".iter().map(|&b| Some(b)).collect::<Vec<_>>());
+        assert_eq!(world.projection(), vec![(Bound::new(0, 8), root), (Bound::new(0, 5), zlib), (Bound::new(21, 25), root)]);
+
+        // name clash, broken stream, outside of the region
+        assert!(world.decompress(root, Bound::new(8, 21), Codec::Zlib, "zlib".to_string()).is_err());
+        assert!(world.decompress(root, Bound::new(0, 8), Codec::Zlib, "a".to_string()).is_err());
+        assert!(world.decompress(root, Bound::new(8, 26), Codec::Zlib, "b".to_string()).is_err());
+
+        let mut world = World::new(Region::undefined("undef".to_string(), 32));
+        let root = world.root;
+        assert!(world.decompress(root, Bound::new(0, 13), Codec::Zlib, "zlib".to_string()).is_err());
+    }
+
 }
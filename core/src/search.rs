@@ -0,0 +1,417 @@
+/*
+ * Panopticon - A libre disassembler
+ * Copyright (C) 2017  Panopticon authors
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Searching `Region`s for byte patterns and strings.
+//!
+//! Byte patterns are written as hexadecimal bytes, optionally separated by whitespace. A `?` in
+//! place of a nibble matches any value, `??` matches any byte.
+//!
+//! ```
+//! use panopticon_core::{Region, Pattern};
+//!
+//! let reg = Region::wrap("ram".to_string(), vec![0x55, 0x48, 0x89, 0xe5, 0x48, 0x83, 0xec, 0x10]);
+//! let pat = "48 ?? e? ".parse::<Pattern>().unwrap();
+//! let hits = pat.find(&reg);
+//!
+//! assert_eq!(hits.len(), 1);
+//! assert_eq!(hits[0].address, 1);
+//! ```
+//!
+//! Undefined `Cell`s never match and end strings. Hits carry only their location inside the
+//! `Region`, `locate` adds the section and function containing them.
+
+use {Function, Region, Result, Segment};
+use layer::Cell;
+use segment;
+use std::cmp::{self, Ordering};
+use std::collections::VecDeque;
+use std::fmt;
+use std::str::FromStr;
+
+/// Sequence of bytes with wildcards.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct Pattern {
+    /// Expected value and mask of the bits that must match.
+    bytes: Vec<(u8, u8)>,
+}
+
+/// Encoding of the strings to extract.
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+pub enum StringKind {
+    /// Printable 7-bit ASCII.
+    Ascii,
+    /// Printable 7-bit ASCII characters encoded as little endian UTF-16.
+    Utf16Le,
+}
+
+/// Search result.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct Hit {
+    /// Name of the `Region` searched.
+    pub region: String,
+    /// Address of the first `Cell`.
+    pub address: u64,
+    /// Bytes matched. For UTF-16 strings these are the encoded characters.
+    pub bytes: Vec<u8>,
+    /// The string found, `None` for byte patterns.
+    pub text: Option<String>,
+    /// Name of the innermost section containing `address`.
+    pub section: Option<String>,
+    /// Name of the function containing `address`.
+    pub function: Option<String>,
+}
+
+impl Hit {
+    fn new(region: &Region, address: u64, bytes: Vec<u8>, text: Option<String>) -> Hit {
+        Hit {
+            region: region.name().clone(),
+            address: address,
+            bytes: bytes,
+            text: text,
+            section: None,
+            function: None,
+        }
+    }
+}
+
+impl Pattern {
+    /// Number of bytes matched.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns true if `cells` start with this pattern.
+    pub fn matches<'a, I: IntoIterator<Item = &'a Cell>>(&self, cells: I) -> bool {
+        let mut cells = cells.into_iter();
+
+        self.bytes
+            .iter()
+            .all(
+                |&(value, mask)| match cells.next() {
+                    Some(&Some(b)) => b & mask == value,
+                    _ => false,
+                }
+            )
+    }
+
+    /// Returns all, possibly overlapping, occurrences of this pattern in `region`.
+    pub fn find(&self, region: &Region) -> Vec<Hit> {
+        let mut ret = vec![];
+        let iter = region.iter();
+
+        if self.bytes.is_empty() {
+            return ret;
+        }
+
+        for area in region.defined_areas() {
+            let mut window = VecDeque::with_capacity(self.bytes.len());
+
+            for (addr, cell) in (area.start..area.end).zip(iter.cut(&(area.start..area.end))) {
+                if window.len() == self.bytes.len() {
+                    window.pop_front();
+                }
+                window.push_back(cell);
+
+                if window.len() == self.bytes.len() && self.matches(window.iter()) {
+                    let start = addr + 1 - self.bytes.len() as u64;
+                    let bytes = window.iter().map(|c| c.unwrap_or(0)).collect();
+
+                    ret.push(Hit::new(region, start, bytes, None));
+                }
+            }
+        }
+
+        ret
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = ::Error;
+
+    /// Parses patterns like `48 8b ?5 ??`.
+    fn from_str(s: &str) -> Result<Pattern> {
+        let nibbles = s.chars().filter(|c| !c.is_whitespace()).collect::<Vec<_>>();
+
+        if nibbles.is_empty() || nibbles.len() % 2 != 0 {
+            return Err(format!("'{}' is not a sequence of hexadecimal bytes", s).into());
+        }
+
+        let mut bytes = vec![];
+
+        for pair in nibbles.chunks(2) {
+            let mut value = 0;
+            let mut mask = 0;
+
+            for &c in pair {
+                value <<= 4;
+                mask <<= 4;
+
+                if c != '?' {
+                    match c.to_digit(16) {
+                        Some(d) => {
+                            value |= d as u8;
+                            mask |= 0xf;
+                        }
+                        None => return Err(format!("'{}' is not a hexadecimal digit", c).into()),
+                    }
+                }
+            }
+
+            bytes.push((value, mask));
+        }
+
+        Ok(Pattern { bytes: bytes })
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, &(value, mask)) in self.bytes.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            for &shift in [4, 0].iter() {
+                if (mask >> shift) & 0xf == 0 {
+                    write!(f, "?")?;
+                } else {
+                    write!(f, "{:x}", (value >> shift) & 0xf)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for StringKind {
+    type Err = ::Error;
+
+    fn from_str(s: &str) -> Result<StringKind> {
+        match s {
+            "ascii" => Ok(StringKind::Ascii),
+            "utf16" | "utf16le" | "utf-16le" => Ok(StringKind::Utf16Le),
+            _ => Err(format!("Unknown string encoding '{}'", s).into()),
+        }
+    }
+}
+
+fn is_printable(b: u8) -> bool {
+    (b >= 0x20 && b < 0x7f) || b == b'\t'
+}
+
+/// Run of printable characters.
+struct Run {
+    start: u64,
+    bytes: Vec<u8>,
+    text: String,
+}
+
+impl Run {
+    /// Adds the character `c` encoded as `bytes` at `start`, returns the finished run if `c` does
+    /// not continue it.
+    fn extend(run: &mut Option<Run>, start: u64, bytes: &[u8], c: char) -> Option<Run> {
+        let continues = run.as_ref().map(|r| r.start + r.bytes.len() as u64 == start).unwrap_or(false);
+
+        if continues {
+            let r = run.as_mut().unwrap();
+
+            r.bytes.extend_from_slice(bytes);
+            r.text.push(c);
+            None
+        } else {
+            let mut text = String::new();
+
+            text.push(c);
+            ::std::mem::replace(run, Some(Run { start: start, bytes: bytes.to_vec(), text: text }))
+        }
+    }
+
+    fn hit(self, region: &Region, min_len: usize) -> Option<Hit> {
+        if self.text.len() >= min_len {
+            Some(Hit::new(region, self.start, self.bytes, Some(self.text)))
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses a sequence of hexadecimal bytes like `48 8b 05`. Whitespace between the digits is
+/// ignored. Unlike `Pattern`, wildcards are not allowed.
+pub fn parse_hex(s: &str) -> Result<Vec<u8>> {
+    let pat = s.parse::<Pattern>()?;
+
+    if pat.bytes.iter().any(|&(_, mask)| mask != 0xff) {
+        return Err(format!("'{}' is not a sequence of hexadecimal bytes", s).into());
+    }
+
+    Ok(pat.bytes.iter().map(|&(value, _)| value).collect())
+}
+
+/// Extracts all strings of at least `min_len` printable characters from `region`.
+pub fn strings(region: &Region, kind: StringKind, min_len: usize) -> Vec<Hit> {
+    let mut ret = vec![];
+    let iter = region.iter();
+
+    for area in region.defined_areas() {
+        // UTF-16 strings can start at even and odd addresses
+        let mut runs: [Option<Run>; 2] = [None, None];
+        let mut prev: Cell = None;
+
+        for (addr, cell) in (area.start..area.end).zip(iter.cut(&(area.start..area.end))) {
+            let finished = match (kind, prev, cell) {
+                (StringKind::Ascii, _, Some(b)) if is_printable(b) => Run::extend(&mut runs[0], addr, &[b], b as char),
+                (StringKind::Ascii, _, _) => runs[0].take(),
+                (StringKind::Utf16Le, Some(b), Some(0)) if addr > area.start && is_printable(b) => {
+                    Run::extend(&mut runs[(addr % 2) as usize], addr - 1, &[b, 0], b as char)
+                }
+                (StringKind::Utf16Le, _, _) if addr > area.start => runs[(addr % 2) as usize].take(),
+                (StringKind::Utf16Le, _, _) => None,
+            };
+
+            ret.extend(finished.and_then(|r| r.hit(region, min_len)));
+            prev = cell;
+        }
+
+        for run in runs.iter_mut() {
+            ret.extend(run.take().and_then(|r| r.hit(region, min_len)));
+        }
+    }
+
+    ret.sort_by_key(|h| h.address);
+    ret
+}
+
+/// Fills in the section and function containing each hit. `segments` and `functions` must
+/// describe the `Region` the hits were found in.
+pub fn locate(hits: &mut [Hit], segments: &[Segment], functions: &[&Function]) {
+    let mut blocks = vec![];
+
+    for func in functions.iter() {
+        for bb in func.basic_blocks() {
+            blocks.push((bb.area.clone(), &func.name));
+        }
+    }
+
+    blocks.sort_by_key(|&(ref b, _)| b.start);
+
+    // reach[i] is the largest end address of the first i + 1 basic blocks
+    let reach = blocks
+        .iter()
+        .scan(0, |max, &(ref b, _)| {
+            *max = cmp::max(*max, b.end);
+            Some(*max)
+        })
+        .collect::<Vec<_>>();
+
+    for hit in hits.iter_mut() {
+        let addr = hit.address;
+        let after = match blocks.binary_search_by(|&(ref b, _)| if b.start <= addr { Ordering::Less } else { Ordering::Greater }) {
+            Ok(i) | Err(i) => i,
+        };
+
+        hit.section = segment::segment_at(segments, addr).map(|s| s.name.clone());
+        hit.function = (0..after)
+            .rev()
+            .take_while(|&i| reach[i] > addr)
+            .find(|&i| addr < blocks[i].0.end)
+            .map(|i| blocks[i].1.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Bound;
+    use layer::Layer;
+
+    #[test]
+    fn parse_pattern() {
+        let pat = "48 8b ?5 ??".parse::<Pattern>().unwrap();
+
+        assert_eq!(pat.len(), 4);
+        assert_eq!(pat.bytes, vec![(0x48, 0xff), (0x8b, 0xff), (0x05, 0x0f), (0, 0)]);
+        assert_eq!(format!("{}", pat), "48 8b ?5 ??");
+        assert_eq!("c3".parse::<Pattern>().ok(), Some(Pattern { bytes: vec![(0xc3, 0xff)] }));
+        assert!("".parse::<Pattern>().is_err());
+        assert!("4".parse::<Pattern>().is_err());
+        assert!("4g".parse::<Pattern>().is_err());
+    }
+
+    #[test]
+    fn parse_hex_bytes() {
+        assert_eq!(parse_hex("48 8b05 ").unwrap(), vec![0x48, 0x8b, 0x05]);
+        assert!(parse_hex("").is_err());
+        assert!(parse_hex("48 8").is_err());
+        assert!(parse_hex("48 ?5").is_err());
+        assert!(parse_hex("4g").is_err());
+        assert!(parse_hex("\u{e9}\u{e9}").is_err());
+        assert!(parse_hex("4\u{e9}").is_err());
+    }
+
+    #[test]
+    fn find_pattern() {
+        let mut reg = Region::undefined("ram".to_string(), 0x100);
+
+        assert!(reg.cover(Bound::new(0x10, 0x18), Layer::wrap(vec![0x90, 0x90, 0x90, 0xc3, 0x90, 0x90, 0x48, 0x90])));
+        assert!(reg.cover(Bound::new(0x20, 0x22), Layer::wrap(vec![0x90, 0x90])));
+
+        let hits = "90 9?".parse::<Pattern>().unwrap().find(&reg);
+        assert_eq!(hits.iter().map(|h| h.address).collect::<Vec<_>>(), vec![0x10, 0x11, 0x14, 0x20]);
+        assert_eq!(hits[0].bytes, vec![0x90, 0x90]);
+        assert_eq!(hits[0].region, "ram");
+
+        let hits = "?3 ?0".parse::<Pattern>().unwrap().find(&reg);
+        assert_eq!(hits.iter().map(|h| h.address).collect::<Vec<_>>(), vec![0x13]);
+        assert_eq!(hits[0].bytes, vec![0xc3, 0x90]);
+
+        // undefined cells between the two areas don't match
+        assert!("90 ??".parse::<Pattern>().unwrap().find(&reg).iter().all(|h| h.address != 0x17));
+    }
+
+    #[test]
+    fn find_strings() {
+        let mut data = b"\x00\x01Hello\x00Hi\x01".to_vec();
+        data.extend_from_slice(b"W\x00o\x00r\x00l\x00d\x00\xff");
+        data.extend_from_slice(b"\x00x\x00y\x00z\x00");
+
+        let reg = Region::wrap("ram".to_string(), data);
+        let ascii = strings(&reg, StringKind::Ascii, 3);
+
+        assert_eq!(ascii.len(), 1);
+        assert_eq!(ascii[0].address, 2);
+        assert_eq!(ascii[0].bytes, b"Hello".to_vec());
+        assert_eq!(ascii[0].text, Some("Hello".to_string()));
+
+        let utf16 = strings(&reg, StringKind::Utf16Le, 3);
+        assert_eq!(utf16.iter().map(|h| (h.address, h.text.clone().unwrap())).collect::<Vec<_>>(), vec![(11, "World".to_string()), (23, "xyz".to_string())]);
+        assert_eq!(utf16[1].bytes, b"x\x00y\x00z\x00".to_vec());
+    }
+
+    #[test]
+    fn locate_hits() {
+        use SegmentKind;
+
+        let segs = vec![Segment::new(".text".to_string(), SegmentKind::Section, Bound::new(0x10, 0x20), Some(0), 0x10, (true, false, true))];
+        let reg = Region::wrap("ram".to_string(), vec![0; 0x30]);
+        let mut hits = vec![Hit::new(&reg, 0x18, vec![0], None), Hit::new(&reg, 0x28, vec![0], None)];
+
+        locate(&mut hits, &segs, &[]);
+        assert_eq!(hits[0].section, Some(".text".to_string()));
+        assert_eq!(hits[1].section, None);
+        assert_eq!(hits[0].function, None);
+    }
+}
@@ -0,0 +1,143 @@
+/*
+ * Panopticon - A libre disassembler
+ * Copyright (C) 2017  Panopticon authors
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Sections and segments of a binary.
+//!
+//! Loaders record the segment (e.g. ELF program headers, Mach-o segments) and section tables of the
+//! files they read as `Segment`s. Each one has a name, the address range it occupies after loading,
+//! the offset of its contents in the file and its access permissions.
+//!
+//! The segment map of a `Project` can be queried by address with `Project::segment`.
+
+use Bound;
+use std::fmt;
+
+/// Whenever a `Segment` is a loadable segment or a section inside one.
+#[derive(Clone,Copy,Debug,PartialEq,Eq,Serialize,Deserialize)]
+pub enum SegmentKind {
+    /// Loadable segment (ELF program header, Mach-o segment, ...).
+    Segment,
+    /// Section (ELF section header, PE section, ...).
+    Section,
+}
+
+/// A named, contiguous address range with access permissions.
+#[derive(Clone,Debug,PartialEq,Eq,Serialize,Deserialize)]
+pub struct Segment {
+    /// Human-readable name, e.g. `.text`.
+    pub name: String,
+    /// Segment or section.
+    pub kind: SegmentKind,
+    /// Addresses occupied after loading.
+    pub bound: Bound,
+    /// Offset of the contents in the file. `None` if not backed by the file (e.g. `.bss`).
+    pub file_offset: Option<u64>,
+    /// Number of bytes at the start of `bound` that are backed by the file. The rest is zero
+    /// filled when loading.
+    pub file_size: u64,
+    /// Contents are readable.
+    pub read: bool,
+    /// Contents are writable.
+    pub write: bool,
+    /// Contents are executable.
+    pub execute: bool,
+}
+
+impl Segment {
+    /// Returns a new `Segment` named `name` spanning `bound`. The first `file_size` bytes are read
+    /// from `file_offset`. The flags are given as (read, write, execute).
+    pub fn new(name: String, kind: SegmentKind, bound: Bound, file_offset: Option<u64>, file_size: u64, flags: (bool, bool, bool)) -> Segment {
+        Segment {
+            name: name,
+            kind: kind,
+            bound: bound,
+            file_offset: file_offset,
+            file_size: if file_offset.is_some() { file_size } else { 0 },
+            read: flags.0,
+            write: flags.1,
+            execute: flags.2,
+        }
+    }
+
+    /// Returns true if `addr` is inside this segment.
+    pub fn contains(&self, addr: u64) -> bool {
+        self.bound.start <= addr && addr < self.bound.end
+    }
+
+    /// Returns the file offset of `addr` or `None` if `addr` is not backed by the file.
+    pub fn file_offset_of(&self, addr: u64) -> Option<u64> {
+        if self.contains(addr) && addr - self.bound.start < self.file_size {
+            self.file_offset.and_then(|off| off.checked_add(addr - self.bound.start))
+        } else {
+            None
+        }
+    }
+
+    /// Access permissions in `ls -l` notation, e.g. `r-x`.
+    pub fn permissions(&self) -> String {
+        format!(
+            "{}{}{}",
+            if self.read { "r" } else { "-" },
+            if self.write { "w" } else { "-" },
+            if self.execute { "x" } else { "-" }
+        )
+    }
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {:#x}-{:#x} {}", self.name, self.bound.start, self.bound.end, self.permissions())
+    }
+}
+
+/// Returns the innermost `Segment` in `segments` that contains `addr`. Sections are preferred over
+/// segments, smaller ranges over larger ones.
+pub fn segment_at(segments: &[Segment], addr: u64) -> Option<&Segment> {
+    segments.iter().filter(|s| s.contains(addr)).min_by_key(
+        |s| {
+            let kind = match s.kind {
+                SegmentKind::Section => 0,
+                SegmentKind::Segment => 1,
+            };
+            (kind, s.bound.len())
+        }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn innermost() {
+        let segs = vec![
+            Segment::new("LOAD".to_string(), SegmentKind::Segment, Bound::new(0x1000, 0x3000), Some(0), 0x1800, (true, false, true)),
+            Segment::new(".init".to_string(), SegmentKind::Section, Bound::new(0x1000, 0x1100), Some(0), 0x100, (true, false, true)),
+            Segment::new(".text".to_string(), SegmentKind::Section, Bound::new(0x1100, 0x2000), Some(0x100), 0xf00, (true, false, true)),
+        ];
+
+        assert_eq!(segment_at(&segs, 0x1000).map(|s| s.name.as_str()), Some(".init"));
+        assert_eq!(segment_at(&segs, 0x1200).map(|s| s.name.as_str()), Some(".text"));
+        assert_eq!(segment_at(&segs, 0x2800).map(|s| s.name.as_str()), Some("LOAD"));
+        assert_eq!(segment_at(&segs, 0x3000), None);
+        assert_eq!(segs[2].file_offset_of(0x1200), Some(0x200));
+        assert_eq!(segs[0].file_offset_of(0x27ff), Some(0x17ff));
+        assert_eq!(segs[0].file_offset_of(0x2800), None);
+        assert_eq!(segs[0].permissions(), "r-x");
+    }
+}
@@ -0,0 +1,240 @@
+/*
+ * Panopticon - A libre disassembler
+ * Copyright (C) 2017  Panopticon authors
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Function discovery using unwind tables.
+//!
+//! Unwind information survives stripping. Every ELF `.eh_frame` FDE and every PE32+ `.pdata`
+//! `RUNTIME_FUNCTION` entry describes the start and extent of a function. Loaders use them to seed
+//! the call graph of stripped binaries.
+
+use {Bound, Result};
+use reader::Reader;
+
+/// Reads a pointer encoded as `enc` (`DW_EH_PE_*`) from `rd`. `base` is the address of `rd.buf[0]`
+/// after loading, `data_base` is used for `DW_EH_PE_datarel`. Returns `None` for `DW_EH_PE_omit`
+/// and indirect pointers.
+fn pointer(rd: &mut Reader, base: u64, enc: u8, address_size: usize, data_base: u64) -> Result<Option<u64>> {
+    const DW_EH_PE_OMIT: u8 = 0xff;
+    const DW_EH_PE_PCREL: u8 = 0x10;
+    const DW_EH_PE_DATAREL: u8 = 0x30;
+    const DW_EH_PE_INDIRECT: u8 = 0x80;
+
+    if enc == DW_EH_PE_OMIT {
+        return Ok(None);
+    }
+
+    let field = base.wrapping_add(rd.pos as u64);
+    let value = match enc & 0x0f {
+        0x00 => rd.uint(address_size)?,
+        0x01 => rd.uleb()?,
+        0x02 => rd.uint(2)?,
+        0x03 => rd.uint(4)?,
+        0x04 => rd.uint(8)?,
+        0x09 => rd.sleb()? as u64,
+        0x0a => rd.sint(2)? as u64,
+        0x0b => rd.sint(4)? as u64,
+        0x0c => rd.sint(8)? as u64,
+        f => return Err(format!("Unknown pointer format {:#x} in unwind table", f).into()),
+    };
+    let value = match enc & 0x70 {
+        0 => value,
+        DW_EH_PE_PCREL => field.wrapping_add(value),
+        DW_EH_PE_DATAREL => data_base.wrapping_add(value),
+        app => return Err(format!("Unsupported pointer application {:#x} in unwind table", app).into()),
+    };
+
+    if enc & DW_EH_PE_INDIRECT != 0 { Ok(None) } else { Ok(Some(value)) }
+}
+
+/// Returns the address range of every function described by an FDE in the `.eh_frame` data `buf`
+/// loaded at `addr`. Parsing stops at the zero terminator or the end of `buf`.
+pub fn eh_frame_functions(buf: &[u8], addr: u64, address_size: usize) -> Result<Vec<Bound>> {
+    use std::collections::HashMap;
+
+    // FDE pointer encoding of each CIE, keyed by its offset
+    let mut cies = HashMap::<usize, u8>::new();
+    let mut ret = Vec::new();
+    let mut rd = Reader::new(buf, 0);
+
+    while rd.pos + 4 <= buf.len() {
+        let start = rd.pos;
+        let (len, id_size) = match rd.uint(4)? {
+            0 => break,
+            0xffff_ffff => (rd.uint(8)? as usize, 8),
+            len => (len as usize, 4),
+        };
+        let end = rd.pos.checked_add(len).ok_or("Truncated .eh_frame entry")?;
+        let id_pos = rd.pos;
+        let id = rd.uint(id_size)? as usize;
+
+        if end > buf.len() {
+            return Err("Truncated .eh_frame entry".into());
+        }
+
+        if id == 0 {
+            // CIE
+            let version = rd.uint(1)?;
+            let augmentation = rd.cstr()?.into_bytes();
+
+            if augmentation.starts_with(b"eh") {
+                rd.bytes(address_size)?;
+            }
+
+            // code alignment, data alignment and return address register
+            rd.uleb()?;
+            rd.sleb()?;
+            if version == 1 {
+                rd.uint(1)?;
+            } else {
+                rd.uleb()?;
+            }
+
+            let mut fde_enc = 0u8;
+
+            if augmentation.first() == Some(&b'z') {
+                rd.uleb()?;
+
+                for &c in augmentation[1..].iter() {
+                    match c {
+                        b'R' => fde_enc = rd.uint(1)? as u8,
+                        b'L' => {
+                            rd.uint(1)?;
+                        }
+                        b'P' => {
+                            let enc = rd.uint(1)? as u8;
+                            pointer(&mut rd, addr, enc & 0x7f, address_size, 0)?;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            cies.insert(start, fde_enc);
+        } else {
+            // FDE, `id` is the distance to its CIE
+            let cie = id_pos.wrapping_sub(id);
+            let enc = match cies.get(&cie) {
+                Some(&enc) => enc,
+                None => {
+                    debug!("FDE at {:#x} references unknown CIE at {:#x}", start, cie);
+                    rd.pos = end;
+                    continue;
+                }
+            };
+
+            if let Some(begin) = pointer(&mut rd, addr, enc, address_size, 0)? {
+                // the range is never relative
+                if let Some(len) = pointer(&mut rd, addr, enc & 0x0f, address_size, 0)? {
+                    if len > 0 {
+                        ret.push(Bound::new(begin, begin.wrapping_add(len)));
+                    }
+                }
+            }
+        }
+
+        rd.pos = end;
+    }
+
+    Ok(ret)
+}
+
+/// Parses the `.eh_frame_hdr` data `buf` loaded at `addr`. Returns the address of the `.eh_frame`
+/// section and the start addresses of the functions in the binary search table.
+pub fn eh_frame_hdr(buf: &[u8], addr: u64, address_size: usize) -> Result<(Option<u64>, Vec<u64>)> {
+    let mut rd = Reader::new(buf, 0);
+
+    if rd.uint(1)? != 1 {
+        return Err("Unsupported .eh_frame_hdr version".into());
+    }
+
+    let frame_enc = rd.uint(1)? as u8;
+    let count_enc = rd.uint(1)? as u8;
+    let table_enc = rd.uint(1)? as u8;
+    let eh_frame = pointer(&mut rd, addr, frame_enc, address_size, addr)?;
+    let count = pointer(&mut rd, addr, count_enc, address_size, addr)?.unwrap_or(0);
+    let mut starts = Vec::new();
+
+    if table_enc != 0xff {
+        for _ in 0..count {
+            if let Some(start) = pointer(&mut rd, addr, table_enc, address_size, addr)? {
+                starts.push(start);
+            }
+            // address of the FDE
+            pointer(&mut rd, addr, table_enc, address_size, addr)?;
+        }
+    }
+
+    Ok((eh_frame, starts))
+}
+
+/// Returns the address range of every function in the PE32+ exception directory (`.pdata`) `buf`
+/// of an image loaded at `image_base`.
+pub fn pdata_functions(buf: &[u8], image_base: u64) -> Vec<Bound> {
+    let mut rd = Reader::new(buf, 0);
+    let mut ret = Vec::new();
+
+    while rd.pos + 12 <= buf.len() {
+        // RUNTIME_FUNCTION: BeginAddress, EndAddress, UnwindInfoAddress
+        let begin = rd.uint(4).unwrap_or(0);
+        let end = rd.uint(4).unwrap_or(0);
+        rd.uint(4).unwrap_or(0);
+
+        if begin == 0 && end == 0 {
+            break;
+        }
+        if begin < end {
+            ret.push(Bound::new(image_base.wrapping_add(begin), image_base.wrapping_add(end)));
+        }
+    }
+
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pdata() {
+        let buf = [
+            0x00, 0x10, 0x00, 0x00, 0x20, 0x10, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00,
+            0x20, 0x10, 0x00, 0x00, 0x64, 0x10, 0x00, 0x00, 0x08, 0x30, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        assert_eq!(
+            pdata_functions(&buf, 0x1_4000_0000),
+            vec![Bound::new(0x1_4000_1000, 0x1_4000_1020), Bound::new(0x1_4000_1020, 0x1_4000_1064)]
+        );
+    }
+
+    #[test]
+    fn eh_frame() {
+        // CIE with "zR" augmentation and pcrel|sdata4 FDE encoding, followed by one FDE
+        let buf = [
+            0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x7a, 0x52, 0x00, 0x01, 0x78, 0x10, 0x01,
+            0x1b, 0x0c, 0x07, 0x08, 0x90, 0x01, 0x00, 0x00,
+            0x14, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0xe0, 0xff, 0xff, 0xff, 0x10, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ];
+
+        // pc_begin is stored relative to its own address 0x2020
+        assert_eq!(eh_frame_functions(&buf, 0x2000, 8).unwrap(), vec![Bound::new(0x2000, 0x2010)]);
+    }
+}
@@ -17,9 +17,15 @@
  */
 
 extern crate panopticon_core;
+extern crate panopticon_amd64;
+extern crate panopticon_avr;
+extern crate panopticon_graph_algos;
+extern crate tempdir;
+extern crate uuid;
 
-use panopticon_core::loader;
+use panopticon_core::{Machine, loader};
 use std::path::Path;
+use tempdir::TempDir;
 
 #[test]
 fn elf_load_static() {
@@ -101,15 +107,547 @@ fn mach_load_bytes() {
     }
 }
 
-// TODO: add imports into the PE loader
+#[test]
+fn mach_load_fat() {
+    use std::fs::File;
+    use std::io::{Read, Write};
+
+    let thin = {
+        let mut v = Vec::new();
+        let mut fd = File::open(Path::new("../test-data/deadbeef.mach")).unwrap();
+        fd.read_to_end(&mut v).unwrap();
+        v
+    };
+    let tmpdir = TempDir::new("test-panop").unwrap();
+    let path = tmpdir.path().join("deadbeef.fat");
+
+    {
+        // fat header and two slices: a PowerPC one we can't disassemble and the x86_64 binary
+        let be = |x: u32| vec![(x >> 24) as u8, (x >> 16) as u8, (x >> 8) as u8, x as u8];
+        let mut fat = Vec::new();
+
+        fat.extend(be(0xcafebabe));
+        fat.extend(be(2));
+        for &(cputype, offset, size) in &[(18, 0x1000, 0x10), (0x0100_0007, 0x2000, thin.len() as u32)] {
+            fat.extend(be(cputype));
+            fat.extend(be(3));
+            fat.extend(be(offset));
+            fat.extend(be(size));
+            fat.extend(be(12));
+        }
+        fat.resize(0x2000, 0);
+        fat.extend(thin.iter().cloned());
+
+        File::create(&path).unwrap().write_all(&fat).unwrap();
+    }
+
+    let slices = loader::slices(&path).unwrap();
+    assert_eq!(slices.len(), 2);
+    assert_eq!(slices[0].machine(), None);
+    assert_eq!(slices[1].machine(), Some(Machine::Amd64));
+    assert_eq!(slices[1].offset, 0x2000);
+    assert_eq!(slices[1].size, thin.len() as u64);
+
+    let (proj, machine) = loader::load(&path).unwrap();
+    assert_eq!(machine, Machine::Amd64);
+    assert_eq!(proj.imports.len(), 2);
+
+    // file offsets point into the fat binary, not into the slice
+    let (thin_proj, _) = loader::load(Path::new("../test-data/deadbeef.mach")).unwrap();
+    assert!(!proj.segments.is_empty());
+    for (fat_seg, thin_seg) in proj.segments.iter().zip(thin_proj.segments.iter()) {
+        assert_eq!(fat_seg.file_offset, thin_seg.file_offset.map(|off| off + 0x2000));
+    }
+
+    let (proj, machine) = loader::load_slice(&path, 1).unwrap();
+    assert_eq!(machine, Machine::Amd64);
+    assert_eq!(proj.imports.len(), 2);
+
+    assert!(loader::load_slice(&path, 0).is_err());
+    assert!(loader::load_slice(&path, 2).is_err());
+    assert!(loader::slices(Path::new("../test-data/deadbeef.mach")).unwrap().is_empty());
+}
+
+#[test]
+fn load_archive() {
+    use panopticon_core::{CallTarget, Rvalue};
+    use panopticon_graph_algos::VertexListGraphTrait;
+    use std::fs::File;
+    use std::io::{Read, Write};
+
+    let (proj, machine) = loader::load(Path::new("../test-data/libbeef.a")).unwrap();
+    let names = proj.code.iter().map(|p| p.name.clone()).collect::<Vec<_>>();
+    let todos = |idx: usize| {
+        let mut ret = proj.code[idx]
+            .call_graph
+            .vertex_labels()
+            .filter_map(
+                |ct| match ct {
+                    &CallTarget::Todo(_, Some(ref name), _) => Some(name.clone()),
+                    _ => None,
+                }
+            )
+            .collect::<Vec<_>>();
+        ret.sort();
+        ret
+    };
+
+    assert_eq!(machine, Machine::Amd64);
+    assert_eq!(names, vec!["beef.o".to_string(), "foo.o".to_string()]);
+    assert_eq!(todos(0), vec!["beef".to_string(), "dead".to_string()]);
+    assert_eq!(todos(1), vec!["foo".to_string()]);
+
+    // members are mapped to page aligned addresses and relocated
+    let address = |name: &str| {
+        proj.code
+            .iter()
+            .flat_map(|p| p.call_graph.vertex_labels())
+            .filter_map(
+                |ct| match ct {
+                    &CallTarget::Todo(ref rv, Some(ref n), _) if n == name => Some(rv.clone()),
+                    _ => None,
+                }
+            )
+            .next()
+    };
+
+    assert_eq!(address("beef"), Some(Rvalue::new_u64(0x1000)));
+    assert_eq!(address("dead"), Some(Rvalue::new_u64(0x1007)));
+    assert_eq!(address("foo"), Some(Rvalue::new_u64(0x2000)));
+    assert!(proj.code[0].imports.is_empty());
+    assert_eq!(proj.code[1].imports.len(), 1);
+
+    let (&beef, name) = proj.code[1].imports.iter().next().unwrap();
+    let rel = proj.region().read(0x2002, 4).iter().enumerate().fold(0u32, |acc, (i, b)| acc | (b.unwrap() as u32) << (i * 8)) as i32;
+
+    assert_eq!(name, "beef");
+    assert_eq!(proj.imports.get(&beef), Some(&"beef".to_string()));
+    // call beef@PLT in foo
+    assert_eq!((0x2006 + rel as i64) as u64, beef);
+
+    // members that aren't ELF objects don't become programs
+    let tmpdir = TempDir::new("test-panop").unwrap();
+    let path = tmpdir.path().join("libbeef.a");
+
+    {
+        let mut ar = Vec::new();
+        File::open("../test-data/libbeef.a").unwrap().read_to_end(&mut ar).unwrap();
+        let body = b"not an object\n";
+
+        if ar.len() % 2 != 0 {
+            ar.push(b'\n');
+        }
+        ar.extend(format!("{:<16}{:<12}{:<6}{:<6}{:<8}{:<10}`\n", "notes.txt/", 0, 0, 0, 644, body.len()).into_bytes());
+        ar.extend(body.iter().cloned());
+
+        File::create(&path).unwrap().write_all(&ar).unwrap();
+    }
+
+    let (proj, machine) = loader::load(&path).unwrap();
+    let names = proj.code.iter().map(|p| p.name.clone()).collect::<Vec<_>>();
+
+    assert_eq!(machine, Machine::Amd64);
+    assert_eq!(names, vec!["beef.o".to_string(), "foo.o".to_string()]);
+}
+
+#[test]
+fn load_elf_object() {
+    use panopticon_core::CallTarget;
+    use panopticon_graph_algos::VertexListGraphTrait;
+
+    let (proj, machine) = loader::load(Path::new("../test-data/relocs.o")).unwrap();
+    let mut todos = proj.code[0]
+        .call_graph
+        .vertex_labels()
+        .filter_map(
+            |ct| match ct {
+                &CallTarget::Todo(_, Some(ref name), _) => Some(name.clone()),
+                _ => None,
+            }
+        )
+        .collect::<Vec<_>>();
+    todos.sort();
+
+    assert_eq!(machine, Machine::Amd64);
+    assert_eq!(todos, vec!["foo".to_string(), "local".to_string()]);
+    assert_eq!(proj.imports.len(), 1);
+
+    let (&beef, name) = proj.imports.iter().next().unwrap();
+    assert_eq!(name, "beef");
+
+    // .text at 0x1000, .data (table) at 0x1030
+    let cells = proj.region().iter().cut(&(0x1000..0x1040)).collect::<Vec<_>>();
+    let read_u64 = |off: usize| (0..8).fold(0u64, |acc, i| acc | (cells[off + i].unwrap() as u64) << (i * 8));
+    let read_i32 = |off: usize| (0..4).fold(0u32, |acc, i| acc | (cells[off + i].unwrap() as u32) << (i * 8)) as i32;
+
+    assert_eq!(read_u64(0x30), beef);
+    assert_eq!(read_u64(0x38), 0x1000);
+    // call beef@PLT in foo
+    assert_eq!((0x100d + 4 + read_i32(0xd) as i64) as u64, beef);
+}
+
+#[test]
+fn load_raw_blob() {
+    use panopticon_core::CallTarget;
+    use panopticon_graph_algos::VertexListGraphTrait;
+    use std::fs;
+
+    let path = Path::new("../test-data/ia32.com");
+    let len = fs::metadata(path).unwrap().len();
+    let ia32 = |base: u64, entries: &[u64]| loader::load_raw::<panopticon_amd64::Amd64>(path, base, entries, &panopticon_amd64::Mode::Protected);
+    let (proj, machine) = ia32(0x100, &[0x100]).unwrap();
+    let entries = proj.code[0]
+        .call_graph
+        .vertex_labels()
+        .filter_map(
+            |ct| match ct {
+                &CallTarget::Todo(ref rv, _, _) => Some(rv.clone()),
+                _ => None,
+            }
+        )
+        .collect::<Vec<_>>();
+
+    assert_eq!(proj.name, "ia32.com");
+    assert_eq!(machine, Machine::Ia32);
+    assert_eq!(entries.len(), 1);
+    assert!(proj.region().iter().cut(&(0..0x100)).all(|c| c.is_none()));
+    assert!(proj.region().iter().cut(&(0x100..0x100 + len)).all(|c| c.is_some()));
+    assert!(ia32(0x100, &[0x10]).is_err());
+    assert!(ia32(u64::max_value() - 1, &[]).is_err());
+
+    // interrupt vectors are entry points too
+    let mcu = panopticon_avr::Mcu::atmega103();
+    let (avr, machine) = loader::load_raw::<panopticon_avr::Avr>(path, 0, &[0x100], &mcu).unwrap();
+    assert_eq!(machine, Machine::Avr);
+    assert_eq!(avr.code[0].call_graph.num_vertices(), 1 + mcu.int_vec.len());
+    assert!(loader::load_raw::<panopticon_avr::Avr>(path, 0x1_ffff, &[], &mcu).is_err());
+    assert_eq!("x86_64".parse::<Machine>().unwrap(), Machine::Amd64);
+    assert!("sparc".parse::<Machine>().is_err());
+
+    let (_, machine) = loader::load_raw::<panopticon_amd64::Amd64>(path, 0, &[], &panopticon_amd64::Mode::Real).unwrap();
+    assert_eq!(machine, Machine::Ia16);
+}
+
+#[test]
+fn avr_elf_banks() {
+    let (elf, machine) = loader::load(Path::new("../test-data/hello-world")).unwrap();
+    let (srec, _) = loader::load(Path::new("../test-data/hello-world.srec")).unwrap();
+    let data = elf.bank("sram").iter().cut(&(0x60..0x74)).collect::<Vec<_>>();
+
+    assert_eq!(machine, Machine::Avr);
+    assert_eq!(elf.bank("flash").name(), "Flash");
+    assert_eq!(elf.bank("sram").name(), "SRAM");
+    assert_eq!(elf.bank("io").size(), 0x40);
+    assert_eq!(elf.bank("eeprom").name(), "EEPROM");
+    assert_eq!(elf.bank("sram").iter().cut(&(0..0x60)).filter(|c| c.is_some()).count(), 0);
+
+    // .data is copied from the end of .text, .bss is zeroed
+    assert_eq!(&data[..14], &elf.region().iter().cut(&(0xbc..0xca)).collect::<Vec<_>>()[..]);
+    assert!(data[..14].iter().all(|c| c.is_some()));
+    assert!(data[14..].iter().all(|c| *c == Some(0)));
+
+    // the flash contents match the firmware image
+    assert_eq!(elf.region().iter().cut(&(0..0xca)).collect::<Vec<_>>(), srec.region().iter().cut(&(0..0xca)).collect::<Vec<_>>());
+    assert_eq!(srec.bank("sram").name(), "SRAM");
+}
+
+#[test]
+fn load_firmware_images() {
+    use panopticon_core::{CallTarget, Rvalue};
+    use panopticon_graph_algos::VertexListGraphTrait;
+
+    let entry = |proj: &panopticon_core::Project| {
+        proj.code[0]
+            .call_graph
+            .vertex_labels()
+            .filter_map(
+                |ct| match ct {
+                    &CallTarget::Todo(ref rv, _, _) => Some(rv.clone()),
+                    _ => None,
+                }
+            )
+            .collect::<Vec<_>>()
+    };
+    let (hex, hex_machine) = loader::load(Path::new("../test-data/hello-world.hex")).unwrap();
+    let (srec, srec_machine) = loader::load(Path::new("../test-data/hello-world.srec")).unwrap();
+
+    assert_eq!(hex_machine, Machine::Avr);
+    assert_eq!(srec_machine, Machine::Avr);
+    assert_eq!(hex.region().name(), "Flash");
+
+    // hex file uses an extended segment address record to move the image to 0x10000
+    let hex_cells = hex.region().iter().cut(&(0x10000..0x100ca)).collect::<Vec<_>>();
+    let srec_cells = srec.region().iter().cut(&(0..0xca)).collect::<Vec<_>>();
+
+    assert!(hex_cells.iter().all(|c| c.is_some()));
+    assert_eq!(hex_cells, srec_cells);
+    assert!(hex.region().iter().cut(&(0..0x10000)).all(|c| c.is_none()));
+    assert!(srec.region().iter().cut(&(0xca..0x100)).all(|c| c.is_none()));
+    assert_eq!(entry(&hex), vec![Rvalue::new_u64(0x10054)]);
+    assert_eq!(entry(&srec), vec![Rvalue::new_u64(0x54)]);
+}
+
+#[test]
+fn load_dos_executables() {
+    use panopticon_core::{CallTarget, Rvalue};
+    use panopticon_graph_algos::VertexListGraphTrait;
+    use std::fs;
+
+    let entry = |proj: &panopticon_core::Project| {
+        proj.code[0]
+            .call_graph
+            .vertex_labels()
+            .filter_map(
+                |ct| match ct {
+                    &CallTarget::Todo(ref rv, _, _) => Some(rv.clone()),
+                    _ => None,
+                }
+            )
+            .collect::<Vec<_>>()
+    };
+    let read_u16 = |reg: &panopticon_core::Region, addr: u64| {
+        let cells = reg.iter().cut(&(addr..addr + 2)).collect::<Vec<_>>();
+        cells[0].unwrap() as u16 | (cells[1].unwrap() as u16) << 8
+    };
+
+    // .com files are mapped to 0x100
+    let path = Path::new("../test-data/ia32.com");
+    let len = fs::metadata(path).unwrap().len();
+    let (com, com_machine) = loader::load(path).unwrap();
+
+    assert_eq!(com_machine, Machine::Ia16);
+    assert_eq!(entry(&com), vec![Rvalue::new_u64(0x100)]);
+    assert!(com.region().iter().cut(&(0..0x100)).all(|c| c.is_none()));
+    assert!(com.region().iter().cut(&(0x100..0x100 + len)).all(|c| c.is_some()));
+    assert_eq!(com.segment(0x100).unwrap().file_offset_of(0x100), Some(0));
+    assert!(loader::load_at(path, 0x1000).is_err());
+
+    // MZ image follows the PSP at 0x100, the segment of its data is relocated
+    let (exe, exe_machine) = loader::load(Path::new("../test-data/hello.exe")).unwrap();
+
+    assert_eq!(exe_machine, Machine::Ia16);
+    assert_eq!(entry(&exe), vec![Rvalue::new_u64(0x100)]);
+    assert_eq!(exe.segment(0x100).unwrap().file_offset, Some(0x20));
+    assert_eq!(exe.segment(0x12f).unwrap().bound.end, 0x130);
+    assert_eq!(read_u16(exe.region(), 0x101), 0x12);
+    assert_eq!(read_u16(&exe.raw_region(), 0x101), 0x2);
+    assert_eq!(exe.comments.get(&("RAM".to_string(), 0x100)).map(|s| s.as_str()), Some("Stack at 0013:0100"));
+    assert_eq!("8086".parse::<Machine>().unwrap(), Machine::Ia16);
+}
+
+#[test]
+fn segment_map() {
+    use panopticon_core::{CallTarget, Rvalue, SegmentKind};
+    use panopticon_graph_algos::{MutableGraphTrait, VertexListGraphTrait};
+    use uuid::Uuid;
+
+    let (elf, _) = loader::load(Path::new("../test-data/libfoo.so")).unwrap();
+    let text = elf.segment(0x700).unwrap();
+    let data = elf.segment(0x201040).unwrap();
+    let bss = elf.segment(0x201048).unwrap();
+
+    assert_eq!(text.name, ".text");
+    assert_eq!(text.permissions(), "r-x");
+    assert_eq!(text.file_offset_of(0x700), Some(0x700));
+    assert_eq!(data.name, ".data");
+    assert_eq!(data.permissions(), "rw-");
+    assert!(!elf.is_executable(0x201040));
+    assert_eq!(bss.name, ".bss");
+    assert_eq!(bss.file_offset, None);
+
+    let (pe, _) = loader::load(Path::new("../test-data/test.exe")).unwrap();
+    let text = pe.segment(0x411000).unwrap();
+
+    assert_eq!(text.name, ".text");
+    assert_eq!(text.file_offset, Some(0x400));
+    assert!(text.execute);
+    assert!(!pe.is_executable(0x41f000));
+    assert!(pe.segment(0x1000).is_none());
+
+    // entries pointing into data aren't analyzed
+    let (mut elf, _) = loader::load(Path::new("../test-data/libfoo.so")).unwrap();
+    let todos = elf.code[0].call_graph.num_vertices();
+
+    elf.code[0].call_graph.add_vertex(CallTarget::Todo(Rvalue::new_u64(0x201040), None, Uuid::new_v4()));
+    elf.code[0].call_graph.add_vertex(CallTarget::Todo(Rvalue::new_u64(0x700), None, Uuid::new_v4()));
+
+    assert_eq!(elf.remove_non_executable_entries(), vec![0x201040]);
+    assert_eq!(elf.code[0].call_graph.num_vertices(), todos + 1);
+
+    // sections reaching past the end of the address space are dropped, here .data
+    if let Ok((elf, _)) = loader::load_at(Path::new("../test-data/libfoo.so"), 0u64.wrapping_sub(0x201044)) {
+        assert!(elf.segments.iter().filter(|s| s.kind == SegmentKind::Section).all(|s| s.bound.start <= s.bound.end));
+        assert!(elf.segments.iter().all(|s| s.name != ".data"));
+    }
+}
+
+#[test]
+fn dynamic_relocations() {
+    let read_u64 = |proj: &panopticon_core::Region, addr: u64| {
+        proj.iter()
+            .cut(&(addr..addr + 8))
+            .enumerate()
+            .fold(0u64, |acc, (i, c)| acc | (c.unwrap() as u64) << (i * 8))
+    };
+
+    // R_X86_64_RELATIVE and R_X86_64_JUMP_SLOT of a local function
+    let (elf, _) = loader::load(Path::new("../test-data/libfoo.so")).unwrap();
+    assert!(elf.relocations.is_some());
+    assert_eq!(read_u64(elf.region(), 0x200e00), 0x780);
+    assert_eq!(read_u64(elf.region(), 0x201020), 0x7cc);
+    assert!(read_u64(&elf.raw_region(), 0x201020) != 0x7cc);
+
+    let (elf, _) = loader::load_at(Path::new("../test-data/libfoo.so"), 0x1000_0000).unwrap();
+    assert_eq!(read_u64(elf.region(), 0x1020_0e00), 0x1000_0780);
+    assert_eq!(read_u64(elf.region(), 0x1020_1020), 0x1000_07cc);
+    assert_eq!(elf.segment(0x1000_0700).unwrap().name, ".text");
+    assert!(elf.imports.keys().all(|&a| a >= 0x1000_0000));
+
+    // PE base relocations
+    let (pe, _) = loader::load(Path::new("../test-data/libbeef.dll")).unwrap();
+    assert!(pe.relocations.is_none());
+
+    let (pe, _) = loader::load_at(Path::new("../test-data/libbeef.dll"), 0x1000_0000).unwrap();
+    let idx = pe.relocations.unwrap();
+    let bound = pe.region().stack()[idx].0.clone();
+    let relocated = pe.region().iter().cut(&(bound.start..bound.end)).collect::<Vec<_>>();
+    let raw = pe.raw_region().iter().cut(&(bound.start..bound.end)).collect::<Vec<_>>();
+    assert!(relocated != raw);
+
+    // not position independent
+    assert!(loader::load_at(Path::new("../test-data/static"), 0x1000_0000).is_err());
+}
+
+#[test]
+fn load_dwarf() {
+    let (proj, _) = loader::load(Path::new("../test-data/dwarf")).unwrap();
+    let info = &proj.debug_info;
+    let names = info.functions.iter().map(|f| f.name.as_str()).collect::<Vec<_>>();
+
+    assert_eq!(names, vec!["add", "scale", "main"]);
+    assert_eq!(info.functions[0].bound.start, 0x1139);
+    assert_eq!(info.functions[1].parameters.len(), 2);
+    assert_eq!(info.functions[1].parameters[0].name, "p");
+    assert_eq!(info.functions[1].parameters[0].type_name, Some("point*".to_string()));
+    assert_eq!(info.functions[1].return_type, Some("int".to_string()));
+
+    let calls = info.variables.iter().find(|v| v.name == "calls").unwrap();
+    assert_eq!(calls.address, Some(0x4028));
+    assert_eq!(calls.type_name, Some("counter_t".to_string()));
+    assert!(info.types.iter().any(|t| t.name == "point" && t.size == Some(8)));
+
+    let line = proj.source_line(0x116e).unwrap();
+    assert_eq!(line.file, "/src/dwarf.c");
+    assert_eq!(line.line, 15);
+    assert!(proj.source_line(0x1000).is_none());
+
+    // debug information is part of the snapshot
+    let tmp = TempDir::new("panop-dwarf").unwrap();
+    let path = tmp.path().join("dwarf.panop");
+    proj.snapshot(&path).unwrap();
+    let reopened = panopticon_core::Project::open(&path).unwrap();
+    assert_eq!(&reopened.debug_info, info);
+}
+
+#[test]
+fn unwind_info_seeds() {
+    use panopticon_core::{Bound, CallTarget, Rvalue};
+    use panopticon_graph_algos::VertexListGraphTrait;
+    use std::fs::File;
+    use std::io::{Read, Write};
+
+    let (proj, _) = loader::load(Path::new("../test-data/stripped")).unwrap();
+    let prog = &proj.code[0];
+    let seeds = prog.call_graph
+        .vertex_labels()
+        .filter_map(
+            |ct| match ct {
+                &CallTarget::Todo(Rvalue::Constant { value, .. }, _, _) => Some(value),
+                _ => None,
+            }
+        )
+        .collect::<Vec<_>>();
+
+    for addr in &[0x1050, 0x1080, 0x1170] {
+        assert!(seeds.contains(addr), "no function at {:#x}", addr);
+    }
+    assert_eq!(seeds.iter().filter(|&&a| a == 0x1080).count(), 1);
+    assert_eq!(prog.extents.get(&0x1080), Some(&Bound::new(0x1080, 0x10a2)));
+
+    // a PT_GNU_EH_FRAME reaching past the end of the address space is ignored
+    let tmpdir = TempDir::new("test-panop").unwrap();
+    let path = tmpdir.path().join("stripped");
+    let mut elf = Vec::new();
+
+    File::open("../test-data/stripped").unwrap().read_to_end(&mut elf).unwrap();
+
+    let read = |buf: &[u8], off: usize, len: usize| (0..len).fold(0u64, |acc, i| acc | (buf[off + i] as u64) << (i * 8));
+    let phoff = read(&elf, 0x20, 8) as usize;
+    let phentsize = read(&elf, 0x36, 2) as usize;
+
+    for idx in 0..read(&elf, 0x38, 2) as usize {
+        let ph = phoff + idx * phentsize;
+
+        if read(&elf, ph, 4) == 0x6474_e550 {
+            for b in elf[ph + 0x20..ph + 0x28].iter_mut() {
+                *b = 0xff;
+            }
+        }
+    }
+    File::create(&path).unwrap().write_all(&elf).unwrap();
+
+    let (proj, _) = loader::load(&path).unwrap();
+    assert_eq!(proj.code[0].extents.get(&0x1080), Some(&Bound::new(0x1080, 0x10a2)));
+}
+
+#[test]
+fn load_core_dump() {
+    use panopticon_core::{CallTarget, Rvalue};
+    use panopticon_graph_algos::VertexListGraphTrait;
+
+    let (proj, machine) = loader::load(Path::new("../test-data/crash.core")).unwrap();
+    let core = proj.core_dump.as_ref().unwrap();
+
+    assert_eq!(machine, Machine::Amd64);
+    assert_eq!(core.threads.len(), 1);
+    assert_eq!(core.threads[0].pid, 6146);
+    assert_eq!(core.threads[0].signal, 11);
+    assert_eq!(core.threads[0].ip, 0x40100b);
+    assert_eq!(core.threads[0].register("rsp"), Some(0x7fff97e37248));
+    assert_eq!(core.files.len(), 3);
+    assert_eq!(core.files[1].file_offset, 0x1000);
+
+    // code as it was in memory
+    let code = proj.region().iter().cut(&(0x401000..0x401004)).map(|c| c.unwrap()).collect::<Vec<_>>();
+    assert_eq!(code, vec![0x55, 0x48, 0x89, 0xe5]);
+
+    let text = proj.segment(0x40100b).unwrap();
+    assert_eq!(text.name, "/tmp/core/crash");
+    assert!(text.execute);
+    assert!(proj.segment(0xffffffffff600000).is_none());
+
+    let seeds = proj.code[0]
+        .call_graph
+        .vertex_labels()
+        .filter_map(
+            |ct| match ct {
+                &CallTarget::Todo(Rvalue::Constant { value, .. }, Some(ref name), _) => Some((value, name.clone())),
+                _ => None,
+            }
+        )
+        .collect::<Vec<_>>();
+    assert_eq!(seeds, vec![(0x40100b, "thread_6146".to_string())]);
+}
 
 #[test]
 fn load_pe32() {
     let project = loader::load(Path::new("../test-data/test.exe"));
     match project {
-        Ok((proj, _)) => {
+        Ok((proj, machine)) => {
             println!("{:?}", proj);
-            assert_eq!(proj.imports.len(), 0);
+            assert_eq!(machine, Machine::Ia32);
+            assert_eq!(proj.imports.len(), 90);
+            assert_eq!(proj.code[0].imports.len(), 90);
         }
         Err(error) => {
             println!("{:?}", error);
@@ -122,9 +660,11 @@ fn load_pe32() {
 fn load_pe32_dll() {
     let project = loader::load(Path::new("../test-data/libbeef.dll"));
     match project {
-        Ok((proj, _)) => {
+        Ok((proj, machine)) => {
             println!("{:?}", proj);
-            assert_eq!(proj.imports.len(), 0);
+            assert_eq!(machine, Machine::Ia32);
+            assert_eq!(proj.imports.len(), 12);
+            assert!(proj.imports.values().all(|x| x.contains('!')));
         }
         Err(error) => {
             println!("{:?}", error);
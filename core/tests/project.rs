@@ -17,6 +17,8 @@
  */
 
 extern crate panopticon_core;
+extern crate panopticon_amd64;
+extern crate tempdir;
 
 use panopticon_core::Project;
 use std::path::Path;
@@ -27,3 +29,148 @@ fn project_open() {
 
     assert!(maybe_project.ok().is_some());
 }
+
+#[test]
+fn transform_layers_are_saved() {
+    use panopticon_core::{Bound, Layer, Region, Transform};
+    use tempdir::TempDir;
+
+    let reg = Region::wrap("base".to_string(), b"hELLO, World".to_vec());
+    let mut proj = Project::new("test".to_string(), reg);
+
+    assert_eq!(proj.cover(Bound::new(0, 5), Layer::Transform(Transform::Xor(vec![0x20]))).ok(), Some(1));
+    assert!(proj.cover(Bound::new(0, 13), Layer::Transform(Transform::Hex)).is_err());
+
+    let tmp = TempDir::new("panop-transform").unwrap();
+    let path = tmp.path().join("transform.panop");
+    proj.snapshot(&path).unwrap();
+
+    let reopened = Project::open(&path).unwrap();
+    let cells = reopened.region().iter().take(6).collect::<Vec<_>>();
+    assert_eq!(cells, b"Hello,".iter().map(|&b| Some(b)).collect::<Vec<_>>());
+}
+
+#[test]
+fn decompressed_regions() {
+    use panopticon_core::{Bound, Codec, Region};
+    use tempdir::TempDir;
+
+    // "Hello" compressed with zlib, starting at offset 4
+    let mut buf = vec![0xcc; 4];
+    buf.extend_from_slice(&[0x78, 0x9c, 0xf3, 0x48, 0xcd, 0xc9, 0xc9, 0x07, 0x00, 0x05, 0x8c, 0x01, 0xf5]);
+
+    let mut proj = Project::new("test".to_string(), Region::wrap("base".to_string(), buf));
+
+    // invalid entry points leave the project untouched
+    assert!(proj.decompress(Bound::new(4, 17), Codec::Zlib, &[5]).is_err());
+    assert_eq!(proj.data.regions().len(), 1);
+
+    let reg = proj.decompress(Bound::new(4, 17), Codec::Zlib, &[0]).unwrap();
+
+    assert_eq!(proj.data.region(reg).unwrap().name(), "zlib@4-11");
+    assert_eq!(proj.data.region(reg).unwrap().size(), 5);
+    assert_eq!(proj.code.len(), 1);
+    assert_eq!(proj.code[0].region, Some("zlib@4-11".to_string()));
+    assert_eq!(proj.region_of(&proj.code[0]).unwrap().size(), 5);
+    assert!(proj.decompress(Bound::new(4, 17), Codec::Lzma, &[]).is_err());
+    assert!(proj.decompress(Bound::new(4, 17), Codec::Zlib, &[]).is_err());
+
+    let tmp = TempDir::new("panop-decompress").unwrap();
+    let path = tmp.path().join("decompress.panop");
+    proj.snapshot(&path).unwrap();
+
+    let reopened = Project::open(&path).unwrap();
+    assert_eq!(reopened.region_of(&reopened.code[0]).unwrap().iter().collect::<Vec<_>>(), b"Hello".iter().map(|&b| Some(b)).collect::<Vec<_>>());
+    assert_eq!(reopened.region().size(), 17);
+}
+
+#[test]
+fn mapped_files_are_verified() {
+    use panopticon_core::Region;
+    use std::fs::{self, File};
+    use std::io::Write;
+    use tempdir::TempDir;
+
+    let tmp = TempDir::new("panop-mapped").unwrap();
+    let image = tmp.path().join("image.bin");
+    File::create(&image).unwrap().write_all(b"Hello, World").unwrap();
+
+    let proj = Project::new("test".to_string(), Region::map("base".to_string(), &image).unwrap());
+    let by_ref = tmp.path().join("by-ref.panop");
+    let embedded = tmp.path().join("embedded.panop");
+
+    proj.snapshot_by_reference(&by_ref).unwrap();
+    proj.snapshot(&embedded).unwrap();
+
+    let reopened = Project::open(&by_ref).unwrap();
+    assert_eq!(reopened.region().iter().collect::<Vec<_>>(), b"Hello, World".iter().map(|&b| Some(b)).collect::<Vec<_>>());
+    assert_eq!(reopened.region().mapped_files().len(), 1);
+    drop(reopened);
+
+    // same length, only the hash differs
+    File::create(&image).unwrap().write_all(b"Hello, Panop").unwrap();
+    assert!(Project::open(&by_ref).is_err());
+
+    fs::remove_file(&image).unwrap();
+    assert!(Project::open(&by_ref).is_err());
+
+    let reopened = Project::open(&embedded).unwrap();
+    assert_eq!(reopened.region().iter().collect::<Vec<_>>(), b"Hello, World".iter().map(|&b| Some(b)).collect::<Vec<_>>());
+    assert!(reopened.region().mapped_files().is_empty());
+}
+
+#[test]
+fn patches_are_exported() {
+    use panopticon_core::loader;
+    use std::fs::File;
+    use std::io::{Read, Write};
+    use tempdir::TempDir;
+
+    let tmp = TempDir::new("panop-patch").unwrap();
+    let image = tmp.path().join("image.bin");
+    File::create(&image).unwrap().write_all(&[0x55, 0x89, 0xe5, 0x31, 0xc0, 0xc3]).unwrap();
+
+    let (mut proj, _) = loader::load_raw::<panopticon_amd64::Amd64>(&image, 0x1000, &[], &panopticon_amd64::Mode::Protected).unwrap();
+    let first = proj.patch(0x1003, &[0x90, 0x90]).unwrap();
+    let second = proj.patch(0x1004, &[0xcc]).unwrap();
+
+    assert!(proj.patch(0x1005, &[0x90, 0x90]).is_err());
+    assert!(proj.patch(0x1000, &[]).is_err());
+    assert_eq!(proj.file_offset(0x1004), Some(4));
+    assert_eq!(proj.patched_bytes(), vec![(0x1003, 0x90), (0x1004, 0x90), (0x1004, 0xcc)]);
+    assert_eq!(proj.region().read(0x1000, 6), vec![Some(0x55), Some(0x89), Some(0xe5), Some(0x90), Some(0xcc), Some(0xc3)]);
+
+    let output = tmp.path().join("patched.bin");
+    let mut patched = Vec::new();
+    proj.write_patched(&image, &output).unwrap();
+    File::open(&output).unwrap().read_to_end(&mut patched).unwrap();
+    assert_eq!(patched, vec![0x55, 0x89, 0xe5, 0x90, 0xcc, 0xc3]);
+
+    let path = tmp.path().join("patch.panop");
+    proj.snapshot(&path).unwrap();
+    let mut proj = Project::open(&path).unwrap();
+    assert_eq!(proj.patches, vec![first, second]);
+
+    proj.revert_patch(first).unwrap();
+    // the second patch moved down
+    assert_eq!(proj.patches, vec![first]);
+    assert!(proj.revert_patch(second).is_err());
+    assert_eq!(proj.patched_bytes(), vec![(0x1004, 0xcc)]);
+    assert_eq!(proj.region().read(0x1003, 2), vec![Some(0x31), Some(0xcc)]);
+}
+
+#[test]
+fn zero_filled_tails_are_not_patched() {
+    use panopticon_core::{SegmentKind, loader};
+
+    let (mut proj, _) = loader::load(Path::new("../test-data/libfoo.so")).unwrap();
+
+    // only the PT_LOAD covers the .bss, its file contents end at 0x201048
+    proj.segments.retain(|s| s.kind == SegmentKind::Segment);
+
+    assert_eq!(proj.file_offset(0x201047), Some(0x1047));
+    assert_eq!(proj.file_offset(0x201048), None);
+    assert!(proj.patch(0x201048, &[0xcc]).is_err());
+    assert!(proj.patch(0x201046, &[0x90, 0x90, 0x90]).is_err());
+    assert!(proj.patch(0x201040, &[0x90]).is_ok());
+}
@@ -16,7 +16,7 @@
  * along with this program.  If not, see <http://www.gnu.org/licenses/>.
  */
 
-use panopticon_core::{ControlFlowRef, ControlFlowTarget, Function, Guard, Lvalue, Operation, Rvalue, Statement};
+use panopticon_core::{ControlFlowRef, ControlFlowTarget, Function, Guard, Lvalue, Operation, Rvalue};
 use panopticon_graph_algos::{GraphTrait, IncidenceGraphTrait};
 use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
@@ -46,12 +46,10 @@ pub fn liveness_sets(func: &Function) -> (HashMap<ControlFlowRef, HashSet<Cow<'s
                 }
 
                 for instr in mne.instructions.iter() {
-                    let &Statement { ref op, ref assignee } = instr;
-
-                    if let &Operation::Phi(_) = op {
+                    if let Operation::Phi(_) = instr.op {
 ;
                     } else {
-                        for &rv in op.operands().iter() {
+                        for &rv in instr.op.operands().iter() {
                             if let &Rvalue::Variable { ref name, .. } = rv {
                                 if !vk.contains(name) {
                                     uev.insert(name);
@@ -59,8 +57,10 @@ pub fn liveness_sets(func: &Function) -> (HashMap<ControlFlowRef, HashSet<Cow<'s
                             }
                         }
 
-                        if let &Lvalue::Variable { ref name, .. } = assignee {
-                            vk.insert(name.clone());
+                        for assignee in instr.assignees() {
+                            if let &Lvalue::Variable { ref name, .. } = assignee {
+                                vk.insert(name.clone());
+                            }
                         }
                     }
                 }
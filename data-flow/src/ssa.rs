@@ -50,16 +50,19 @@ pub fn type_check(func: &Function) -> Result<HashMap<Cow<'static, str>, usize>>
 
                 for instr in mne.instructions.iter() {
                     let ops = instr.op.operands();
-                    match ops.len() {
-                        0 => return Err("Operation w/o arguments".into()),
-                        _ => {
-                            for o in ops.iter() {
-                                set_len(o, &mut ret);
-                            }
-                        }
+                    match instr.op {
+                        Operation::Intrinsic(_, _, _) => {}
+                        _ if ops.is_empty() => return Err("Operation w/o arguments".into()),
+                        _ => {}
+                    }
+
+                    for o in ops.iter() {
+                        set_len(o, &mut ret);
                     }
 
-                    set_len(&instr.assignee.clone().into(), &mut ret);
+                    for a in instr.assignees() {
+                        set_len(&a.clone().into(), &mut ret);
+                    }
                 }
             }
         }
@@ -245,19 +248,19 @@ pub fn rename_variables(func: &mut Function) -> Result<()> {
                     }
 
                     for i in mne.instructions.iter_mut() {
-                        let &mut Statement { ref mut op, ref mut assignee } = i;
-
-                        if let &mut Operation::Phi(_) = op {
+                        if let Operation::Phi(_) = i.op {
                             return Err("Phi instruction outside __phi mnemonic".into());
                         } else {
-                            for o in op.operands_mut() {
+                            for o in i.op.operands_mut() {
                                 if let &mut Rvalue::Variable { ref name, ref mut subscript, .. } = o {
                                     *subscript = stack[name].last().cloned();
                                 }
                             }
 
-                            if let &mut Lvalue::Variable { ref name, ref mut subscript, .. } = assignee {
-                                *subscript = Some(new_name(name, counter, stack));
+                            for assignee in i.assignees_mut() {
+                                if let &mut Lvalue::Variable { ref name, ref mut subscript, .. } = assignee {
+                                    *subscript = Some(new_name(name, counter, stack));
+                                }
                             }
                         }
                     }
@@ -305,11 +308,10 @@ pub fn rename_variables(func: &mut Function) -> Result<()> {
 
         if let Some(&mut ControlFlowTarget::Resolved(ref mut bb)) = cfg.vertex_label_mut(b) {
             bb.execute(
-                |i| match i {
-                    &Statement { assignee: Lvalue::Variable { ref name, .. }, .. } => {
+                |i| for assignee in i.assignees() {
+                    if let &Lvalue::Variable { ref name, .. } = assignee {
                         stack.get_mut(name).map(|x| x.pop());
                     }
-                    _ => {}
                 }
             );
         }
@@ -1039,4 +1041,71 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn intrinsic() {
+        let a = Lvalue::Variable { name: Cow::Borrowed("a"), size: 32, subscript: None };
+        let b = Lvalue::Variable { name: Cow::Borrowed("b"), size: 32, subscript: None };
+        let c = Lvalue::Variable { name: Cow::Borrowed("c"), size: 32, subscript: None };
+
+        let mne0 = Mnemonic::new(
+            0..1,
+            "b0".to_string(),
+            "".to_string(),
+            vec![].iter(),
+            vec![
+                Statement { op: Operation::Move(Rvalue::new_u32(1)), assignee: a.clone() },
+                Statement { op: Operation::Intrinsic(Cow::Borrowed("cpuid"), vec![a.clone().into()], vec![a.clone(), b.clone()]), assignee: Lvalue::Undefined },
+                Statement { op: Operation::Intrinsic(Cow::Borrowed("rdtsc"), vec![], vec![c.clone()]), assignee: Lvalue::Undefined },
+            ]
+                .iter(),
+        )
+            .ok()
+            .unwrap();
+        let mne1 = Mnemonic::new(
+            1..2,
+            "b1".to_string(),
+            "".to_string(),
+            vec![].iter(),
+            vec![Statement { op: Operation::Add(a.clone().into(), b.clone().into()), assignee: c.clone() }].iter(),
+        )
+            .ok()
+            .unwrap();
+
+        let mut cfg = ControlFlowGraph::new();
+        let v0 = cfg.add_vertex(ControlFlowTarget::Resolved(BasicBlock::from_vec(vec![mne0])));
+        let v1 = cfg.add_vertex(ControlFlowTarget::Resolved(BasicBlock::from_vec(vec![mne1])));
+
+        cfg.add_edge(Guard::always(), v0, v1);
+
+        let mut func = Function::undefined(0, None, &Region::undefined("ram".to_owned(), 100), None);
+
+        *func.cfg_mut() = cfg;
+        func.set_entry_point_ref(v0);
+
+        assert!(ssa_convertion(&mut func).is_ok());
+
+        let mut outputs = vec![];
+        if let Some(&ControlFlowTarget::Resolved(ref bb)) = func.cfg().vertex_label(v0) {
+            let stmts = &bb.mnemonics.last().unwrap().instructions;
+
+            if let (&Lvalue::Variable { subscript: Some(a0), .. }, &Operation::Intrinsic(_, ref ins, ref outs)) = (&stmts[0].assignee, &stmts[1].op) {
+                assert_eq!(ins[0], Rvalue::Variable { name: Cow::Borrowed("a"), size: 32, offset: 0, subscript: Some(a0) });
+                assert!(outs.iter().all(|o| if let &Lvalue::Variable { subscript: Some(_), .. } = o { true } else { false }));
+                assert!(outs[0] != stmts[0].assignee);
+                outputs = outs.iter().cloned().map(Rvalue::from).collect();
+            } else {
+                unreachable!()
+            }
+        } else {
+            unreachable!()
+        }
+
+        if let Some(&ControlFlowTarget::Resolved(ref bb)) = func.cfg().vertex_label(v1) {
+            let op = &bb.mnemonics.last().unwrap().instructions[0].op;
+            assert_eq!(op.operands(), outputs.iter().collect::<Vec<_>>());
+        } else {
+            unreachable!()
+        }
+    }
 }
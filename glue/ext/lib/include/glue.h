@@ -50,6 +50,8 @@ struct SidebarItem {
 	const char* title;
 	const char* subtitle;
 	const char* uuid;
+	const char* mangled;
+	const char* section;
 };
 
 struct RecentSession {
@@ -63,13 +65,17 @@ typedef int32_t (*GetFunctionFunc)(const char* uuid, int8_t only_entry, int8_t d
 typedef int32_t (*SubscribeToFunc)(const char* uuid, int8_t subscribe);
 
 // session management
-typedef int32_t (*OpenProgramFunc)(const char* path);
+typedef int32_t (*OpenProgramFunc)(const char* path, int32_t slice);
+typedef int32_t (*ListSlicesFunc)(const char* path);
 typedef int32_t (*SaveSessionFunc)(const char* path);
 
 // actions
 typedef int32_t (*CommentOnFunc)(uint64_t address, const char* comment);
 typedef int32_t (*RenameFunctionFunc)(const char* uuid, const char* name);
 typedef int32_t (*SetValueForFunc)(const char* uuid, const char* variable, const char* value);
+typedef int32_t (*ApplyTransformFunc)(uint64_t start, uint64_t end, const char* transform);
+typedef int32_t (*PatchBytesFunc)(uint64_t address, const char* bytes);
+typedef int32_t (*SearchFunc)(const char* kind, const char* query);
 
 // undo/redo
 typedef int32_t (*UndoFunc)();
@@ -20,6 +20,7 @@
 #include <QQmlContext>
 #include <QQuickItem>
 #include <QVariant>
+#include <QStringList>
 #include <QSortFilterProxyModel>
 #include <vector>
 #include <memory>
@@ -46,12 +47,15 @@ public:
   Q_PROPERTY(QVariantList recentSessions MEMBER m_recentSessions NOTIFY recentSessionsChanged)
   Q_PROPERTY(bool hasRecentSessions READ hasRecentSessions NOTIFY hasRecentSessionsChanged)
   Q_PROPERTY(QString currentSession READ getCurrentSession NOTIFY currentSessionChanged)
+  Q_PROPERTY(QStringList slices READ getSlices NOTIFY slicesChanged)
+  Q_PROPERTY(QStringList searchResults READ getSearchResults NOTIFY searchResultsChanged)
 
   // sidebar
   Q_PROPERTY(QSidebar* sidebar READ getSidebar NOTIFY sidebarChanged)
   Q_PROPERTY(QSortFilterProxyModel* sortedSidebar READ getSortedSidebar NOTIFY sortedSidebarChanged)
   Q_PROPERTY(unsigned int sidebarSortRole READ getSidebarSortRole WRITE setSidebarSortRole NOTIFY sidebarSortRoleChanged)
   Q_PROPERTY(bool sidebarSortAscending READ getSidebarSortAscending WRITE setSidebarSortAscending NOTIFY sidebarSortAscendingChanged)
+  Q_PROPERTY(QString sidebarFilter READ getSidebarFilter WRITE setSidebarFilter NOTIFY sidebarFilterChanged)
 
   // basic block metrics
   Q_PROPERTY(unsigned int basicBlockPadding READ getBasicBlockPadding NOTIFY basicBlockPaddingChanged)
@@ -71,11 +75,14 @@ public:
   bool hasRecentSessions(void) const;
   QString getCurrentSession(void) const;
   QString getInitialFile(void) const;
+  QStringList getSlices(void) const;
+  QStringList getSearchResults(void) const;
 
   QSidebar* getSidebar(void) const;
   QSortFilterProxyModel* getSortedSidebar(void) const;
   unsigned int getSidebarSortRole(void) const;
   bool getSidebarSortAscending(void) const;
+  QString getSidebarFilter(void) const;
 
   int getBasicBlockPadding(void) const;
   int getBasicBlockMargin(void) const;
@@ -93,10 +100,14 @@ public:
   static SubscribeToFunc staticSubscribeTo;
   static GetFunctionFunc staticGetFunction;
   static OpenProgramFunc staticOpenProgram;
+  static ListSlicesFunc staticListSlices;
   static SaveSessionFunc staticSaveSession;
   static CommentOnFunc staticCommentOn;
   static RenameFunctionFunc staticRenameFunction;
   static SetValueForFunc staticSetValueFor;
+  static ApplyTransformFunc staticApplyTransform;
+  static PatchBytesFunc staticPatchBytes;
+  static SearchFunc staticSearch;
   static UndoFunc staticUndo;
   static RedoFunc staticRedo;
 
@@ -109,12 +120,17 @@ public:
 public slots:
   // session management
   int openProgram(QString path);
+  int openProgramSlice(QString path, int slice);
+  int listSlices(QString path);
   int saveSession(QString path);
 
   // actions
   int commentOn(QString address, QString comment);
   int renameFunction(QString uuid, QString name);
   int setValueFor(QString uuid, QString variable, QString value);
+  int applyTransform(QString start, QString end, QString transform);
+  int patchBytes(QString address, QString bytes);
+  int search(QString kind, QString query);
 
   // undo/redo
   int undo();
@@ -122,9 +138,12 @@ public slots:
 
   void setSidebarSortRole(unsigned int);
   void setSidebarSortAscending(bool);
+  void setSidebarFilter(QString);
 
   void updateUndoRedo(bool undo, bool redo);
   void updateCurrentSession(QString path);
+  void updateSlices(QStringList slices);
+  void updateSearchResults(QStringList results);
   void updateRecentSession(QRecentSession* sess);
   void updateLayoutTask(QString task);
 
@@ -132,11 +151,14 @@ signals:
   void recentSessionsChanged(void);
   void hasRecentSessionsChanged(void);
   void currentSessionChanged(void);
+  void slicesChanged(void);
+  void searchResultsChanged(void);
 
   void sidebarChanged(void);
   void sortedSidebarChanged(void);
   void sidebarSortRoleChanged(void);
   void sidebarSortAscendingChanged(void);
+  void sidebarFilterChanged(void);
 
   void basicBlockPaddingChanged(void);
   void basicBlockMarginChanged(void);
@@ -153,8 +175,11 @@ signals:
 protected:
   QVariantList m_recentSessions;
   QString m_currentSession;
+  QStringList m_slices;
+  QStringList m_searchResults;
   QSidebar* m_sidebar;
   QSortFilterProxyModel* m_sortedSidebar;
+  QString m_sidebarFilter;
   bool m_canUndo;
   bool m_canRedo;
   QString m_layoutTask;
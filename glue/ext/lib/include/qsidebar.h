@@ -37,8 +37,8 @@ public:
 	virtual QHash<int, QByteArray> roleNames(void) const override;
 
 public slots:
-	void insert(QString title,QString subtitle,QString uuid);
+	void insert(QString title,QString subtitle,QString uuid,QString mangled,QString section);
 
 protected:
-	std::vector<std::tuple<QString,QString,QString>> m_items;
+	std::vector<std::tuple<QString,QString,QString,QString,QString>> m_items;
 };
@@ -131,6 +131,8 @@ extern "C" void update_sidebar_items(const SidebarItem** items) {
 		QString title(item->title);
 		QString subtitle(item->subtitle);
 		QString uuid(item->uuid);
+		QString mangled(item->mangled);
+		QString section(item->section);
 
 		sidebar->metaObject()->invokeMethod(
 				sidebar,
@@ -138,7 +140,9 @@ extern "C" void update_sidebar_items(const SidebarItem** items) {
 				Qt::QueuedConnection,
 				Q_ARG(QString,title),
 				Q_ARG(QString,subtitle),
-				Q_ARG(QString,uuid));
+				Q_ARG(QString,uuid),
+				Q_ARG(QString,mangled),
+				Q_ARG(QString,section));
 		++idx;
 	}
 }
@@ -168,6 +172,42 @@ extern "C" void update_current_session(const char* path) {
 	}
 }
 
+extern "C" void update_slices(const char** names) {
+	QPanopticon *panop = QPanopticon::staticInstance;
+	QStringList list;
+
+	for(size_t idx = 0; names && names[idx]; ++idx) {
+		list.append(QString(names[idx]));
+	}
+
+	if(panop) {
+		// direct call if we're on the GUI thread so listSlices() returns with the property set
+		panop->metaObject()->invokeMethod(
+				panop,
+				"updateSlices",
+				Qt::AutoConnection,
+				Q_ARG(QStringList,list));
+	}
+}
+
+extern "C" void update_search_results(const char** lines) {
+	QPanopticon *panop = QPanopticon::staticInstance;
+	QStringList list;
+
+	for(size_t idx = 0; lines && lines[idx]; ++idx) {
+		list.append(QString(lines[idx]));
+	}
+
+	if(panop) {
+		// direct call if we're on the GUI thread so search() returns with the property set
+		panop->metaObject()->invokeMethod(
+				panop,
+				"updateSearchResults",
+				Qt::AutoConnection,
+				Q_ARG(QStringList,list));
+	}
+}
+
 extern "C" void update_layout_task(const char* task) {
 	QPanopticon *panop = QPanopticon::staticInstance;
 
@@ -182,8 +222,9 @@ extern "C" void update_layout_task(const char* task) {
 
 extern "C" void start_gui_loop(const char *dir, const char* f, const RecentSession** sess,
 															 GetFunctionFunc gf, SubscribeToFunc st,
-															 OpenProgramFunc op, SaveSessionFunc ss,
+															 OpenProgramFunc op, ListSlicesFunc ls, SaveSessionFunc ss,
 															 CommentOnFunc co, RenameFunctionFunc rf, SetValueForFunc svf,
+															 ApplyTransformFunc at, PatchBytesFunc pb, SearchFunc sr,
 															 UndoFunc u, RedoFunc r) {
 	int argc = 1;
 	char *argv[1] = { "Panopticon" };
@@ -195,10 +236,14 @@ extern "C" void start_gui_loop(const char *dir, const char* f, const RecentSessi
 	QPanopticon::staticSubscribeTo = st;
 	QPanopticon::staticGetFunction = gf;
 	QPanopticon::staticOpenProgram = op;
+	QPanopticon::staticListSlices = ls;
 	QPanopticon::staticSaveSession = ss;
 	QPanopticon::staticCommentOn = co;
 	QPanopticon::staticRenameFunction = rf;
 	QPanopticon::staticSetValueFor = svf;
+	QPanopticon::staticApplyTransform = at;
+	QPanopticon::staticPatchBytes = pb;
+	QPanopticon::staticSearch = sr;
 	QPanopticon::staticUndo = u;
 	QPanopticon::staticRedo = r;
 	QPanopticon::staticInitialFile = QString(f);
@@ -35,10 +35,14 @@ QObject *qpanopticon_provider(QQmlEngine *engine, QJSEngine *scriptEngine) {
 SubscribeToFunc QPanopticon::staticSubscribeTo = nullptr;
 GetFunctionFunc QPanopticon::staticGetFunction = nullptr;
 OpenProgramFunc QPanopticon::staticOpenProgram = nullptr;
+ListSlicesFunc QPanopticon::staticListSlices = nullptr;
 SaveSessionFunc QPanopticon::staticSaveSession = nullptr;
 CommentOnFunc QPanopticon::staticCommentOn = nullptr;
 RenameFunctionFunc QPanopticon::staticRenameFunction = nullptr;
 SetValueForFunc QPanopticon::staticSetValueFor = nullptr;
+ApplyTransformFunc QPanopticon::staticApplyTransform = nullptr;
+PatchBytesFunc QPanopticon::staticPatchBytes = nullptr;
+SearchFunc QPanopticon::staticSearch = nullptr;
 UndoFunc QPanopticon::staticUndo = nullptr;
 RedoFunc QPanopticon::staticRedo = nullptr;
 QPanopticon* QPanopticon::staticInstance = nullptr;
@@ -46,10 +50,12 @@ QString QPanopticon::staticInitialFile = QString();
 std::vector<QRecentSession*> QPanopticon::staticRecentSessions = {};
 
 QPanopticon::QPanopticon()
-: m_recentSessions(), m_currentSession(""),
+: m_recentSessions(), m_currentSession(""), m_slices(), m_searchResults(),
 	m_sidebar(new QSidebar(this)), m_sortedSidebar(new QSortFilterProxyModel(this)), m_canUndo(false), m_canRedo(false)
 {
   m_sortedSidebar->setSourceModel(m_sidebar);
+  m_sortedSidebar->setFilterRole(Qt::UserRole + 4);
+  m_sortedSidebar->setFilterCaseSensitivity(Qt::CaseInsensitive);
 
 	for(auto qobj: staticRecentSessions) {
 		updateRecentSession(qobj);
@@ -62,11 +68,14 @@ QPanopticon::~QPanopticon() {}
 bool QPanopticon::hasRecentSessions(void) const { return m_recentSessions.size() != 0; }
 QString QPanopticon::getCurrentSession(void) const { return m_currentSession; }
 QString QPanopticon::getInitialFile(void) const { return staticInitialFile; }
+QStringList QPanopticon::getSlices(void) const { return m_slices; }
+QStringList QPanopticon::getSearchResults(void) const { return m_searchResults; }
 
 QSidebar* QPanopticon::getSidebar(void) const { return m_sidebar; }
 QSortFilterProxyModel* QPanopticon::getSortedSidebar(void) const { return m_sortedSidebar; }
 unsigned int QPanopticon::getSidebarSortRole(void) const { return m_sortedSidebar->sortRole(); }
 bool QPanopticon::getSidebarSortAscending(void) const { return m_sortedSidebar->sortOrder() == Qt::AscendingOrder; }
+QString QPanopticon::getSidebarFilter(void) const { return m_sidebarFilter; }
 
 int QPanopticon::getBasicBlockPadding(void) const { return 3; }
 int QPanopticon::getBasicBlockMargin(void) const { return 8; }
@@ -90,8 +99,22 @@ void QPanopticon::setSidebarSortAscending(bool asc) {
   emit sidebarSortAscendingChanged();
 }
 
+void QPanopticon::setSidebarFilter(QString filter) {
+  m_sidebarFilter = filter;
+  m_sortedSidebar->setFilterFixedString(filter);
+  emit sidebarFilterChanged();
+}
+
 int QPanopticon::openProgram(QString path) {
-	return QPanopticon::staticOpenProgram(path.toStdString().c_str());
+	return QPanopticon::staticOpenProgram(path.toStdString().c_str(),-1);
+}
+
+int QPanopticon::openProgramSlice(QString path, int slice) {
+	return QPanopticon::staticOpenProgram(path.toStdString().c_str(),slice);
+}
+
+int QPanopticon::listSlices(QString path) {
+	return QPanopticon::staticListSlices(path.toStdString().c_str());
 }
 
 int QPanopticon::saveSession(QString path) {
@@ -113,6 +136,33 @@ int QPanopticon::setValueFor(QString uuid, QString variable, QString value) {
 			value.toStdString().c_str());
 }
 
+int QPanopticon::applyTransform(QString start, QString end, QString transform) {
+	bool ok_start, ok_end;
+	qulonglong s = start.toULongLong(&ok_start,16);
+	qulonglong e = end.toULongLong(&ok_end,16);
+
+	if(!ok_start || !ok_end) {
+		return -1;
+	}
+
+	return QPanopticon::staticApplyTransform(s,e,transform.toStdString().c_str());
+}
+
+int QPanopticon::patchBytes(QString address, QString bytes) {
+	bool ok;
+	qulonglong a = address.toULongLong(&ok,16);
+
+	if(!ok) {
+		return -1;
+	}
+
+	return QPanopticon::staticPatchBytes(a,bytes.toStdString().c_str());
+}
+
+int QPanopticon::search(QString kind, QString query) {
+	return QPanopticon::staticSearch(kind.toStdString().c_str(),query.toStdString().c_str());
+}
+
 int QPanopticon::undo() {
 	return QPanopticon::staticUndo();
 }
@@ -134,6 +184,16 @@ void QPanopticon::updateCurrentSession(QString path) {
 	emit currentSessionChanged();
 }
 
+void QPanopticon::updateSlices(QStringList slices) {
+	m_slices = slices;
+	emit slicesChanged();
+}
+
+void QPanopticon::updateSearchResults(QStringList results) {
+	m_searchResults = results;
+	emit searchResultsChanged();
+}
+
 void QPanopticon::updateRecentSession(QRecentSession* sess) {
 	sess->setParent(this);
 	m_recentSessions.append(QVariant::fromValue(sess));
@@ -39,6 +39,13 @@ QVariant QSidebar::data(const QModelIndex& idx, int role) const {
 			return QVariant(std::get<1>(m_items[idx.row()]));
 		case Qt::UserRole + 2:
 			return QVariant(std::get<2>(m_items[idx.row()]));
+		case Qt::UserRole + 3:
+			return QVariant(std::get<3>(m_items[idx.row()]));
+		case Qt::UserRole + 4:
+			// demangled and mangled name, used for filtering
+			return QVariant(std::get<0>(m_items[idx.row()]) + " " + std::get<3>(m_items[idx.row()]));
+		case Qt::UserRole + 5:
+			return QVariant(std::get<4>(m_items[idx.row()]));
 		default:
 			return QVariant();
 	}
@@ -50,12 +57,14 @@ QHash<int, QByteArray> QSidebar::roleNames(void) const {
 	ret.insert(Qt::UserRole, QByteArray("title"));
 	ret.insert(Qt::UserRole + 1, QByteArray("subtitle"));
 	ret.insert(Qt::UserRole + 2, QByteArray("uuid"));
+	ret.insert(Qt::UserRole + 3, QByteArray("mangled"));
+	ret.insert(Qt::UserRole + 5, QByteArray("section"));
 
 	return ret;
 }
 
-void QSidebar::insert(QString title,QString subtitle,QString uuid) {
-	auto tpl = std::make_tuple(title,subtitle,uuid);
+void QSidebar::insert(QString title,QString subtitle,QString uuid,QString mangled,QString section) {
+	auto tpl = std::make_tuple(title,subtitle,uuid,mangled,section);
 	size_t idx = 0;
 
 	for(; idx < m_items.size(); ++idx) {
@@ -25,11 +25,15 @@ extern "C" {
         recent_sessions: *const *const CRecentSession,
         get_function: extern "C" fn(*const i8, i8, i8, i8) -> i32,
         subscribe_to: extern "C" fn(*const i8, i8) -> i32,
-        open_program: extern "C" fn(*const i8) -> i32,
+        open_program: extern "C" fn(*const i8, i32) -> i32,
+        list_slices: extern "C" fn(*const i8) -> i32,
         save_session: extern "C" fn(*const i8) -> i32,
         comment_on: extern "C" fn(u64, *const i8) -> i32,
         rename_function: extern "C" fn(*const i8, *const i8) -> i32,
         set_value_for: extern "C" fn(*const i8, *const i8, *const i8) -> i32,
+        apply_transform: extern "C" fn(u64, u64, *const i8) -> i32,
+        patch_bytes: extern "C" fn(u64, *const i8) -> i32,
+        search: extern "C" fn(*const i8, *const i8) -> i32,
         undo: extern "C" fn() -> i32,
         redo: extern "C" fn() -> i32,
     );
@@ -59,6 +63,12 @@ extern "C" {
     // thread-safe
     pub fn update_current_session(path: *const i8);
 
+    // thread-safe
+    pub fn update_slices(names: *const *const i8);
+
+    // thread-safe
+    pub fn update_search_results(lines: *const *const i8);
+
     // thread-safe
     pub fn update_layout_task(task: *const i8);
 }
@@ -17,8 +17,8 @@
  */
 
 use errors::*;
-use ffi::{start_gui_loop, update_current_session, update_function_edges, update_function_node, update_layout_task, update_sidebar_items, update_undo_redo};
-use panopticon_core::Function;
+use ffi::{start_gui_loop, update_current_session, update_function_edges, update_function_node, update_layout_task, update_search_results, update_sidebar_items, update_slices, update_undo_redo};
+use panopticon_core::{Function, Segment, segment};
 use std::ffi::{CStr, CString};
 use std::path::{Path, PathBuf};
 use std::ptr;
@@ -29,11 +29,15 @@ use uuid::Uuid;
 pub trait Glue {
     fn get_function(uuid: &Uuid, only_entry: bool, do_nodes: bool, do_edges: bool) -> Result<()>;
     fn subscribe_to(uuid: &Uuid, state: bool) -> Result<()>;
-    fn open_program(path: &str) -> Result<()>;
+    fn open_program(path: &str, slice: Option<usize>) -> Result<()>;
+    fn list_slices(path: &str) -> Result<usize>;
     fn save_session(path: &str) -> Result<()>;
     fn comment_on(address: u64, comment: &str) -> Result<()>;
     fn rename_function(uuid: &Uuid, name: &str) -> Result<()>;
     fn set_value_for(uuid: &Uuid, variable: &str, value: &str) -> Result<()>;
+    fn apply_transform(start: u64, end: u64, transform: &str) -> Result<()>;
+    fn patch_bytes(address: u64, bytes: &str) -> Result<()>;
+    fn search(kind: &str, query: &str) -> Result<usize>;
     fn undo() -> Result<()>;
     fn redo() -> Result<()>;
 
@@ -53,10 +57,14 @@ pub trait Glue {
                 Self::get_function_plumbing,
                 Self::subscribe_to_plumbing,
                 Self::open_program_plumbing,
+                Self::list_slices_plumbing,
                 Self::save_session_plumbing,
                 Self::comment_on_plumbing,
                 Self::rename_function_plumbing,
                 Self::set_value_for_plumbing,
+                Self::apply_transform_plumbing,
+                Self::patch_bytes_plumbing,
+                Self::search_plumbing,
                 Self::undo_plumbing,
                 Self::redo_plumbing,
             );
@@ -65,8 +73,8 @@ pub trait Glue {
         Ok(())
     }
 
-    fn update_sidebar(funcs: &[Function]) {
-        let items = funcs.iter().filter_map(|f| CSidebarItem::new(f).ok()).collect::<Vec<_>>();
+    fn update_sidebar(funcs: &[Function], segments: &[Segment]) {
+        let items = funcs.iter().filter_map(|f| CSidebarItem::new(f, segment::segment_at(segments, f.start())).ok()).collect::<Vec<_>>();
         let mut ptrs: Vec<*const CSidebarItem> = items.iter().map(|i| -> *const CSidebarItem { i }).collect();
 
         ptrs.push(ptr::null());
@@ -143,6 +151,28 @@ pub trait Glue {
         Ok(())
     }
 
+    fn send_slices(names: &[CString]) -> Result<()> {
+        let mut ptrs: Vec<*const i8> = names.iter().map(|i| -> *const i8 { i.as_ptr() }).collect();
+
+        ptrs.push(ptr::null());
+        unsafe {
+            update_slices(ptrs.as_slice().as_ptr());
+        }
+
+        Ok(())
+    }
+
+    fn send_search_results(lines: &[CString]) -> Result<()> {
+        let mut ptrs: Vec<*const i8> = lines.iter().map(|i| -> *const i8 { i.as_ptr() }).collect();
+
+        ptrs.push(ptr::null());
+        unsafe {
+            update_search_results(ptrs.as_slice().as_ptr());
+        }
+
+        Ok(())
+    }
+
     fn send_layout_task(t: &CString) -> Result<()> {
         unsafe {
             update_layout_task(t.as_ptr());
@@ -189,9 +219,10 @@ pub trait Glue {
         }
     }
 
-    extern "C" fn open_program_plumbing(path: *const i8) -> i32 {
+    extern "C" fn open_program_plumbing(path: *const i8, slice: i32) -> i32 {
         let path = unsafe { CStr::from_ptr(path) }.to_string_lossy().to_string();
-        match Self::open_program(&path) {
+        let slice = if slice < 0 { None } else { Some(slice as usize) };
+        match Self::open_program(&path, slice) {
             Ok(()) => 0,
             Err(s) => {
                 error!("open_program(): {}", s);
@@ -200,6 +231,17 @@ pub trait Glue {
         }
     }
 
+    extern "C" fn list_slices_plumbing(path: *const i8) -> i32 {
+        let path = unsafe { CStr::from_ptr(path) }.to_string_lossy().to_string();
+        match Self::list_slices(&path) {
+            Ok(num) => num as i32,
+            Err(s) => {
+                error!("list_slices(): {}", s);
+                -1
+            }
+        }
+    }
+
     extern "C" fn save_session_plumbing(path: *const i8) -> i32 {
         let path = unsafe { CStr::from_ptr(path) }.to_string_lossy().to_string();
         match Self::save_session(&path) {
@@ -263,6 +305,40 @@ pub trait Glue {
         }
     }
 
+    extern "C" fn apply_transform_plumbing(start: u64, end: u64, transform: *const i8) -> i32 {
+        let transform = unsafe { CStr::from_ptr(transform) }.to_string_lossy().to_string();
+        match Self::apply_transform(start, end, &transform) {
+            Ok(()) => 0,
+            Err(s) => {
+                error!("apply_transform(): {}", s);
+                -1
+            }
+        }
+    }
+
+    extern "C" fn patch_bytes_plumbing(address: u64, bytes: *const i8) -> i32 {
+        let bytes = unsafe { CStr::from_ptr(bytes) }.to_string_lossy().to_string();
+        match Self::patch_bytes(address, &bytes) {
+            Ok(()) => 0,
+            Err(s) => {
+                error!("patch_bytes(): {}", s);
+                -1
+            }
+        }
+    }
+
+    extern "C" fn search_plumbing(kind: *const i8, query: *const i8) -> i32 {
+        let kind = unsafe { CStr::from_ptr(kind) }.to_string_lossy().to_string();
+        let query = unsafe { CStr::from_ptr(query) }.to_string_lossy().to_string();
+        match Self::search(&kind, &query) {
+            Ok(num) => num as i32,
+            Err(s) => {
+                error!("search(): {}", s);
+                -1
+            }
+        }
+    }
+
     extern "C" fn undo_plumbing() -> i32 {
         match Self::undo() {
             Ok(()) => 0,
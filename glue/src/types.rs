@@ -17,7 +17,7 @@
  */
 
 use errors::*;
-use panopticon_core::Function;
+use panopticon_core::{Function, Segment};
 use std::ffi::CString;
 use std::path::Path;
 use std::ptr;
@@ -27,19 +27,25 @@ pub struct CSidebarItem {
     title: *const i8,
     subtitle: *const i8,
     uuid: *const i8,
+    mangled: *const i8,
+    section: *const i8,
 }
 
 impl CSidebarItem {
-    pub fn new(func: &Function) -> Result<CSidebarItem> {
+    pub fn new(func: &Function, segment: Option<&Segment>) -> Result<CSidebarItem> {
         let str_entry = CString::new(format!("0x{:x}", func.start()))?;
-        let name = CString::new(func.name.to_string().into_bytes())?;
+        let name = CString::new(func.display_name().into_bytes())?;
         let uuid = CString::new(func.uuid().to_string().into_bytes())?;
+        let mangled = CString::new(func.name.to_string().into_bytes())?;
+        let section = CString::new(segment.map(|s| s.name.clone()).unwrap_or_default().into_bytes())?;
 
         Ok(
             CSidebarItem {
                 title: name.into_raw(),
                 subtitle: str_entry.into_raw(),
                 uuid: uuid.into_raw(),
+                mangled: mangled.into_raw(),
+                section: section.into_raw(),
             }
         )
     }
@@ -51,6 +57,8 @@ impl Drop for CSidebarItem {
             CString::from_raw(self.title as *mut i8);
             CString::from_raw(self.subtitle as *mut i8);
             CString::from_raw(self.uuid as *mut i8);
+            CString::from_raw(self.mangled as *mut i8);
+            CString::from_raw(self.section as *mut i8);
         }
     }
 }
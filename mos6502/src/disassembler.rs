@@ -56,7 +56,7 @@ impl Architecture for Mos {
         info!("disass @ {:x}", addr);
         let disass = syntax::disassembler();
 
-        if let Some(st) = disass.next_match(&mut reg.iter().seek(addr), addr, cfg.clone()) {
+        if let Some(st) = disass.next_match(&mut reg.read(addr, 3).into_iter(), addr, cfg.clone()) {
             info!("    res: {:?}", st);
             Ok(st.into())
         } else {
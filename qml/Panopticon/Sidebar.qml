@@ -33,11 +33,27 @@ Rectangle {
     }
   }
 
+  // matches mangled and demangled function names
+  Ctrl.TextField {
+    id: filterInput
+    anchors.left: parent.left
+    anchors.right: parent.right
+    anchors.top: parent.top
+    anchors.margins: 5
+
+    placeholderText: "Filter functions"
+    font { pointSize: 11; family: "Source Sans Pro" }
+    onTextChanged: {
+      Panopticon.sidebarFilter = text
+    }
+  }
+
   Ctrl.TableView {
     id: listView
     anchors.left: parent.left
     anchors.right: parent.right
-    anchors.top: parent.top
+    anchors.top: filterInput.bottom
+    anchors.topMargin: 5
     anchors.bottom: parent.bottom
 
     backgroundVisible: false
@@ -212,7 +228,7 @@ Rectangle {
         fillMode: Image.PreserveAspectFit
         source: "../icons/chevron-down.svg"
         mipmap: true
-        visible: (Panopticon.sidebarSortRole === 0 && styleData.column === 0) || (Panopticon.sidebarSortRole === 0x101 && styleData.column === 1)
+        visible: (Panopticon.sidebarSortRole === 0 && styleData.column === 0) || (Panopticon.sidebarSortRole === 0x101 && styleData.column === 1) || (Panopticon.sidebarSortRole === 0x105 && styleData.column === 2)
         rotation: (!Panopticon.sidebarSortAscending ? 180 : 0)
       }
     }
@@ -227,6 +243,11 @@ Rectangle {
       title: "Start"
       width: 100
     }
+    Ctrl.TableViewColumn {
+      role: "section"
+      title: "Section"
+      width: 80
+    }
 
     selection {
       onSelectionChanged: {
@@ -250,6 +271,10 @@ Rectangle {
         case 1:
         Panopticon.sidebarSortRole = 0x101;
         break;
+
+        case 2:
+        Panopticon.sidebarSortRole = 0x105;
+        break;
       }
     }
 
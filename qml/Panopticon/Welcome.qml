@@ -1,7 +1,7 @@
 import QtQuick 2.4
 import QtQuick.Controls 1.3 as Ctrl
 import QtQuick.Layouts 1.1
-import QtQuick.Dialogs 1.0
+import QtQuick.Dialogs 1.2
 import Panopticon 1.0
 
 Rectangle {
@@ -47,12 +47,51 @@ Rectangle {
 					selectMultiple: false
 					onAccepted: {
 						var p = fileDialog.fileUrls.toString().substring(7);
-						Panopticon.openProgram(p)
+
+						// fat Mach-o binaries: let the user choose the architecture
+						if(Panopticon.listSlices(p) > 1) {
+							var diag = sliceDialog.createObject(view,{ "path": p })
+							diag.open();
+						} else {
+							Panopticon.openProgram(p)
+						}
 					}
 					Component.onCompleted: visible = true
 				}
 			}
 
+			Component {
+				id: sliceDialog
+
+				Dialog {
+					id: sliceDialog
+
+					property string path: ""
+
+					title: "Choose the architecture to load"
+					standardButtons: StandardButton.Open | StandardButton.Cancel
+
+					Ctrl.ComboBox {
+						id: sliceBox
+						width: 300
+						model: Panopticon.slices
+						Component.onCompleted: {
+							// preselect the first slice we have a disassembler for
+							for(var i = 0; i < Panopticon.slices.length; ++i) {
+								if(Panopticon.slices[i].indexOf("(unsupported)") == -1) {
+									currentIndex = i;
+									break;
+								}
+							}
+						}
+					}
+
+					onAccepted: {
+						Panopticon.openProgramSlice(sliceDialog.path,sliceBox.currentIndex)
+					}
+				}
+			}
+
 			// Welcome text
 			Ctrl.Label {
 				id: view
@@ -1,7 +1,7 @@
 import QtQuick 2.4
 import QtQuick.Controls 1.3 as Ctrl
 import QtQuick.Layouts 1.1
-import QtQuick.Dialogs 1.0
+import QtQuick.Dialogs 1.2
 import QtGraphicalEffects 1.0
 import Panopticon 1.0
 
@@ -72,6 +72,39 @@ Ctrl.ApplicationWindow {
 					onTriggered: { Panopticon.redo() }
 				}
 			}
+			Ctrl.MenuSeparator {}
+			Ctrl.MenuItem {
+				action: Ctrl.Action {
+					text: "Apply Transform..."
+					enabled: Panopticon.currentSession != ""
+					onTriggered: {
+						var diag = transformDialog.createObject(mainWindow)
+						diag.open();
+					}
+				}
+			}
+			Ctrl.MenuItem {
+				action: Ctrl.Action {
+					text: "Patch Bytes..."
+					enabled: Panopticon.currentSession != ""
+					onTriggered: {
+						var diag = patchDialog.createObject(mainWindow)
+						diag.open();
+					}
+				}
+			}
+			Ctrl.MenuSeparator {}
+			Ctrl.MenuItem {
+				action: Ctrl.Action {
+					text: "Search..."
+					shortcut: StandardKey.Find
+					enabled: Panopticon.currentSession != ""
+					onTriggered: {
+						var diag = searchDialog.createObject(mainWindow)
+						diag.open();
+					}
+				}
+			}
 			//Ctrl.MenuItem { text: "Erase Values" }
 		}
 
@@ -124,6 +157,118 @@ Ctrl.ApplicationWindow {
 		}
 	}
 
+	Component {
+		id: transformDialog
+
+		Dialog {
+			id: transformDialog
+			title: "Apply Transform"
+			standardButtons: StandardButton.Ok | StandardButton.Cancel
+
+			GridLayout {
+				columns: 2
+
+				Ctrl.Label { text: "Start (hex)" }
+				Ctrl.TextField { id: transformStart; placeholderText: "1000" }
+				Ctrl.Label { text: "End (hex)" }
+				Ctrl.TextField { id: transformEnd; placeholderText: "1200" }
+				Ctrl.Label { text: "Transform" }
+				Ctrl.TextField {
+					id: transformSpec
+					placeholderText: "xor:5a, add:1, sub:1, rol:1, ror:1, rc4:6b6579, base64 or hex"
+					Layout.minimumWidth: 300
+				}
+			}
+
+			onAccepted: {
+				Panopticon.applyTransform(transformStart.text,transformEnd.text,transformSpec.text)
+			}
+		}
+	}
+
+	Component {
+		id: patchDialog
+
+		Dialog {
+			id: patchDialog
+			title: "Patch Bytes"
+			standardButtons: StandardButton.Ok | StandardButton.Cancel
+
+			GridLayout {
+				columns: 2
+
+				Ctrl.Label { text: "Address (hex)" }
+				Ctrl.TextField { id: patchAddress; placeholderText: "1000" }
+				Ctrl.Label { text: "Bytes (hex)" }
+				Ctrl.TextField {
+					id: patchBytes
+					placeholderText: "90 90"
+					Layout.minimumWidth: 300
+				}
+			}
+
+			onAccepted: {
+				Panopticon.patchBytes(patchAddress.text,patchBytes.text)
+			}
+		}
+	}
+
+	Component {
+		id: searchDialog
+
+		Dialog {
+			id: searchDialog
+			title: "Search"
+			modality: Qt.NonModal
+			standardButtons: StandardButton.Close
+
+			ColumnLayout {
+				width: 600
+
+				RowLayout {
+					Layout.fillWidth: true
+
+					Ctrl.ComboBox {
+						id: searchKind
+						model: [ "Bytes", "ASCII Strings", "UTF-16 Strings" ]
+						onCurrentIndexChanged: { searchQuery.text = (currentIndex == 0 ? "" : "4") }
+					}
+					Ctrl.TextField {
+						id: searchQuery
+						Layout.fillWidth: true
+						placeholderText: searchKind.currentIndex == 0 ? "48 8b ?5 ??" : "Minimum length"
+						onAccepted: searchButton.clicked()
+					}
+					Ctrl.Button {
+						id: searchButton
+						text: "Search"
+						onClicked: {
+							var kind = [ "bytes", "ascii", "utf16" ][searchKind.currentIndex]
+							var num = Panopticon.search(kind,searchQuery.text)
+
+							searchStatus.text = (num < 0 ? "Invalid query" : num + " hits")
+						}
+					}
+				}
+
+				Ctrl.Label {
+					id: searchStatus
+					text: ""
+				}
+
+				Ctrl.ScrollView {
+					Layout.fillWidth: true
+					Layout.preferredHeight: 400
+
+					ListView {
+						model: Panopticon.searchResults
+						delegate: Monospace { text: modelData }
+					}
+				}
+			}
+		}
+	}
+
 	Item {
 		id: workspace
 
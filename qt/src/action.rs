@@ -18,7 +18,7 @@
 
 use errors::*;
 use panopticon_abstract_interp::{Kset, approximate};
-use panopticon_core::{BasicBlock, ControlFlowTarget, Function, Lvalue, Rvalue};
+use panopticon_core::{BasicBlock, Bound, ControlFlowTarget, Function, Layer, Lvalue, Rvalue, Transform};
 use panopticon_graph_algos::GraphTrait;
 use singleton::{AbstractInterpretation, Panopticon, VarName};
 use std::collections::{HashMap, HashSet};
@@ -36,6 +36,8 @@ pub enum Action {
         after: Option<AbstractInterpretation>,
         modified_basic_blocks: Vec<u64>,
     },
+    Transform { area: Bound, transform: Transform, layer: usize },
+    Patch { address: u64, bytes: Vec<u8>, layer: usize },
 }
 
 impl Action {
@@ -119,6 +121,32 @@ impl Action {
         )
     }
 
+    pub fn new_transform(panopticon: &mut Panopticon, area: Bound, transform: Transform) -> Result<Action> {
+        match panopticon.region_mut() {
+            Some(region) => {
+                if area.start >= area.end || area.end > region.size() {
+                    return Err(format!("{:?} is not inside {}", area, region.name()).into());
+                }
+
+                Ok(Action::Transform { area: area, transform: transform, layer: region.stack().len() })
+            }
+            None => Err("no region to transform".into()),
+        }
+    }
+
+    pub fn new_patch(panopticon: &mut Panopticon, address: u64, bytes: Vec<u8>) -> Result<Action> {
+        match panopticon.region_mut() {
+            Some(region) => {
+                if bytes.is_empty() || address.checked_add(bytes.len() as u64).map(|end| end > region.size()).unwrap_or(true) {
+                    return Err(format!("{} bytes at {:#x} are not inside {}", bytes.len(), address, region.name()).into());
+                }
+
+                Ok(Action::Patch { address: address, bytes: bytes, layer: region.stack().len() })
+            }
+            None => Err("no region to patch".into()),
+        }
+    }
+
     pub fn undo(&self, panopticon: &mut Panopticon) -> Result<()> {
         match self {
             &Action::Comment { ref function, address, ref before, ref after } => {
@@ -146,6 +174,22 @@ impl Action {
                 }
                 panopticon.update_control_flow_nodes(function, Some(modified_basic_blocks))
             }
+            &Action::Transform { ref area, layer, .. } => {
+                if let Some(region) = panopticon.region_mut() {
+                    debug_assert!(region.stack().len() == layer + 1);
+                    *region = region.without_layer(layer);
+                }
+                panopticon.update_functions_in(area)
+            }
+            &Action::Patch { address, ref bytes, layer } => {
+                if let Some(ref mut proj) = panopticon.project {
+                    proj.revert_patch(layer)?;
+                } else if let Some(ref mut region) = panopticon.region {
+                    debug_assert!(region.stack().len() == layer + 1);
+                    *region = region.without_layer(layer);
+                }
+                panopticon.update_functions_in(&Bound::new(address, address + bytes.len() as u64))
+            }
         }
     }
 
@@ -175,6 +219,27 @@ impl Action {
                 }
                 panopticon.update_control_flow_nodes(function, Some(modified_basic_blocks))
             }
+            &Action::Transform { ref area, ref transform, layer } => {
+                if let Some(region) = panopticon.region_mut() {
+                    debug_assert!(region.stack().len() == layer);
+                    if !region.cover(area.clone(), Layer::Transform(transform.clone())) {
+                        return Err(format!("cannot apply {} to {:?}", transform, area).into());
+                    }
+                }
+                panopticon.update_functions_in(area)
+            }
+            &Action::Patch { address, ref bytes, layer } => {
+                if let Some(ref mut proj) = panopticon.project {
+                    debug_assert!(proj.region().stack().len() == layer);
+                    proj.patch(address, bytes)?;
+                } else if let Some(ref mut region) = panopticon.region {
+                    debug_assert!(region.stack().len() == layer);
+                    if !region.patch(address, bytes) {
+                        return Err(format!("cannot patch {} bytes at {:#x}", bytes.len(), address).into());
+                    }
+                }
+                panopticon.update_functions_in(&Bound::new(address, address + bytes.len() as u64))
+            }
         }
     }
 }
@@ -209,8 +274,10 @@ fn diff_abstract_interpretations(a: Option<&AbstractInterpretation>, b: Option<&
 
         bb.execute(
             |stmt| {
-                if let Lvalue::Variable { ref name, subscript: Some(subscript), .. } = stmt.assignee {
-                    hit |= nams.contains(&VarName { name: name.clone(), subscript: subscript });
+                for assignee in stmt.assignees() {
+                    if let &Lvalue::Variable { ref name, subscript: Some(subscript), .. } = assignee {
+                        hit |= nams.contains(&VarName { name: name.clone(), subscript: subscript });
+                    }
                 }
 
                 hit |= stmt.op
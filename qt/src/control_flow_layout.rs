@@ -452,6 +452,17 @@ impl ControlFlowLayout {
                     data: s,
                 }
             }
+            &Rvalue::Wide { .. } => {
+                let s = format!("{}", rv);
+                let s = s[..s.rfind(':').unwrap_or(s.len())].to_string();
+
+                BasicBlockOperand {
+                    kind: "constant",
+                    display: s.clone(),
+                    alt: "".to_string(),
+                    data: s,
+                }
+            }
             &Rvalue::Variable { ref name, subscript, .. } => {
                 let data = if let Some(subscript) = subscript {
                     format!("{}_{}", *name, subscript)
@@ -574,6 +585,18 @@ impl ControlFlowLayout {
                                     }
                                 )
                             }
+                            Some(rv @ Rvalue::Wide { .. }) => {
+                                let s = format!("{}", rv);
+
+                                Some(
+                                    BasicBlockOperand {
+                                        kind: "pointer",
+                                        display: s[..s.rfind(':').unwrap_or(s.len())].to_string(),
+                                        alt: "".to_string(),
+                                        data: "".to_string(),
+                                    }
+                                )
+                            }
                             Some(Rvalue::Undefined) => {
                                 Some(
                                     BasicBlockOperand {
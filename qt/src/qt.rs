@@ -280,8 +280,12 @@ impl Glue for Qt {
         Ok(())
     }
 
-    fn open_program(path: &str) -> glue::Result<()> {
-        PANOPTICON.lock().open_program(path.to_string()).map_err(|e| format!("{}", e).into())
+    fn open_program(path: &str, slice: Option<usize>) -> glue::Result<()> {
+        PANOPTICON.lock().open_program(path.to_string(), slice).map_err(|e| format!("{}", e).into())
+    }
+
+    fn list_slices(path: &str) -> glue::Result<usize> {
+        PANOPTICON.lock().list_slices(path.to_string()).map_err(|e| format!("{}", e).into())
     }
 
     fn save_session(path: &str) -> glue::Result<()> {
@@ -300,6 +304,18 @@ impl Glue for Qt {
         PANOPTICON.lock().set_value_for(uuid.to_string(), variable.to_string(), value.to_string()).map_err(|e| format!("{}", e).into())
     }
 
+    fn apply_transform(start: u64, end: u64, transform: &str) -> glue::Result<()> {
+        PANOPTICON.lock().apply_transform(start, end, transform.to_string()).map_err(|e| format!("{}", e).into())
+    }
+
+    fn patch_bytes(address: u64, bytes: &str) -> glue::Result<()> {
+        PANOPTICON.lock().patch_bytes(address, bytes.to_string()).map_err(|e| format!("{}", e).into())
+    }
+
+    fn search(kind: &str, query: &str) -> glue::Result<usize> {
+        PANOPTICON.lock().search(kind.to_string(), query.to_string()).map_err(|e| format!("{}", e).into())
+    }
+
     fn undo() -> glue::Result<()> {
         PANOPTICON.lock().undo().map_err(|e| format!("{}", e).into())
     }
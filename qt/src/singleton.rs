@@ -22,7 +22,7 @@ use errors::*;
 use futures::{Future, future};
 use multimap::MultiMap;
 use panopticon_abstract_interp::Kset;
-use panopticon_core::{Function, Program, Project, Region, loader};
+use panopticon_core::{Bound, Function, Program, Project, Region, Segment, Transform, loader, search};
 use panopticon_glue::Glue;
 use panopticon_graph_algos::{GraphTrait, VertexListGraphTrait};
 use parking_lot::Mutex;
@@ -65,6 +65,7 @@ pub struct Panopticon {
     pub unresolved_calls: MultiMap<Option<u64>, (Uuid, u64)>,
     pub resolved_calls: MultiMap<Uuid, (Uuid, u64)>, // callee -> caller
     pub region: Option<Region>,
+    pub segments: Vec<Segment>,
     pub project: Option<Project>,
 
     pub undo_stack: Vec<Action>,
@@ -124,7 +125,28 @@ impl Panopticon {
         Ok(cfl.get_all_nodes())
     }
 
-    pub fn open_program(&mut self, path: String) -> Result<()> {
+    pub fn list_slices(&mut self, path: String) -> Result<usize> {
+        use std::ffi::CString;
+        use std::path::Path;
+
+        debug!("list_slices() path={}", path);
+
+        let slices = loader::slices(&Path::new(&path)).unwrap_or(vec![]);
+        let names = slices
+            .iter()
+            .map(
+                |s| {
+                    let supported = if s.machine().is_some() { "" } else { " (unsupported)" };
+                    CString::new(format!("{} at {:#x}, {} bytes{}", s.cpu_name(), s.offset, s.size, supported))
+                }
+            )
+            .collect::<::std::result::Result<Vec<_>, _>>()?;
+
+        Qt::send_slices(&names)?;
+        Ok(slices.len())
+    }
+
+    pub fn open_program(&mut self, path: String, slice: Option<usize>) -> Result<()> {
         use std::path::Path;
         use panopticon_core::{CallTarget, Machine};
         use panopticon_amd64 as amd64;
@@ -133,7 +155,7 @@ impl Panopticon {
         use futures::Stream;
         use std::ffi::CString;
 
-        debug!("open_program() path={}", path);
+        debug!("open_program() path={}, slice={:?}", path, slice);
 
         if let Ok(proj) = Project::open(&Path::new(&path)) {
             if !proj.code.is_empty() {
@@ -143,7 +165,7 @@ impl Panopticon {
                     for f in cg.vertices() {
                         if let Some(&CallTarget::Concrete(ref func)) = cg.vertex_label(f) {
                             self.functions.insert(func.uuid().clone(), func.clone());
-                            Qt::update_sidebar(&[func.clone()]);
+                            Qt::update_sidebar(&[func.clone()], segments_of(func, proj.region().name(), &proj.segments));
                         }
                     }
                 }
@@ -153,33 +175,48 @@ impl Panopticon {
             } else {
                 Ok(())
             }
-        } else if let Ok((mut proj, machine)) = loader::load(&Path::new(&path)) {
-            let maybe_prog = proj.code.pop();
-            let reg = proj.region().clone();
-
-            if let Some(prog) = maybe_prog {
-                let prog = ::std::sync::Arc::new(prog);
-                let pipe = match machine {
-                    Machine::Avr => pipeline::<avr::Avr>(prog, reg.clone(), avr::Mcu::atmega103()),
-                    Machine::Ia32 => pipeline::<amd64::Amd64>(prog, reg.clone(), amd64::Mode::Protected),
-                    Machine::Amd64 => pipeline::<amd64::Amd64>(prog, reg.clone(), amd64::Mode::Long),
-                };
-                self.region = Some(reg);
-
-                thread::spawn(
-                    || -> Result<()> {
-                        info!("disassembly thread started");
-                        for i in pipe.wait() {
-                            if let Ok(func) = i {
-                                PANOPTICON.lock().new_function(func.clone())?;
-                                Qt::update_sidebar(&[func]);
+        } else if let Ok((mut proj, machine)) = slice.map(|s| loader::load_slice(&Path::new(&path), s)).unwrap_or_else(|| loader::load(&Path::new(&path))) {
+            for addr in proj.remove_non_executable_entries() {
+                info!("skipping entry point {:#x}, it's not executable", addr);
+            }
+
+            // decompressed code lives in its own region
+            let regs = proj.code.iter().map(|prog| proj.region_of(prog).cloned().unwrap_or(proj.region().clone())).collect::<Vec<_>>();
+            let progs = regs.into_iter().zip(proj.code.drain(..)).collect::<Vec<_>>();
+
+            if !progs.is_empty() {
+                // static libraries have one program per member object
+                for (reg, prog) in progs {
+                    let prog = ::std::sync::Arc::new(prog);
+                    let pipe = match machine {
+                        Machine::Avr => pipeline::<avr::Avr>(prog, reg.clone(), avr::Mcu::atmega103()),
+                        Machine::Ia32 => pipeline::<amd64::Amd64>(prog, reg.clone(), amd64::Mode::Protected),
+                        Machine::Amd64 => pipeline::<amd64::Amd64>(prog, reg.clone(), amd64::Mode::Long),
+                        Machine::Ia16 => pipeline::<amd64::Amd64>(prog, reg.clone(), amd64::Mode::Real),
+                    };
+
+                    let main = proj.region().name().clone();
+                    let segments = proj.segments.clone();
+
+                    thread::spawn(
+                        move || -> Result<()> {
+                            info!("disassembly thread started");
+                            for i in pipe.wait() {
+                                if let Ok(func) = i {
+                                    let segments = segments_of(&func, &main, &segments);
+
+                                    PANOPTICON.lock().new_function(func.clone())?;
+                                    Qt::update_sidebar(&[func], segments);
+                                }
                             }
-                        }
-                        info!("disassembly thread finished");
+                            info!("disassembly thread finished");
 
-                        Ok(())
-                    }
-                );
+                            Ok(())
+                        }
+                    );
+                }
+                self.region = Some(proj.region().clone());
+                self.segments = proj.segments.clone();
 
                 use paths::session_directory;
                 use tempdir::TempDir;
@@ -284,6 +321,96 @@ impl Panopticon {
         }
     }
 
+    pub fn apply_transform(&mut self, start: u64, end: u64, transform: String) -> Result<()> {
+        debug!("apply_transform(): start={:x}, end={:x}, transform={}", start, end, transform);
+
+        let transform = transform.trim().parse::<Transform>()?;
+        let act = Action::new_transform(self, Bound::new(start, end), transform)?;
+        self.push_action(act)?;
+
+        Ok(())
+    }
+
+    pub fn patch_bytes(&mut self, address: u64, bytes: String) -> Result<()> {
+        debug!("patch_bytes(): address={:x}, bytes={}", address, bytes);
+
+        let bytes = search::parse_hex(&bytes)?;
+        let act = Action::new_patch(self, address, bytes)?;
+        self.push_action(act)?;
+
+        Ok(())
+    }
+
+    /// Searches all regions for a byte pattern (`kind` is "bytes") or extracts "ascii" and "utf16"
+    /// strings at least `query` characters long. Returns the number of hits.
+    pub fn search(&mut self, kind: String, query: String) -> Result<usize> {
+        use panopticon_core::{Pattern, StringKind};
+        use std::ffi::CString;
+
+        debug!("search(): kind={}, query={}", kind, query);
+
+        let mut found = {
+            let regions = match self.project {
+                Some(ref proj) => proj.data.regions(),
+                None => self.region.iter().collect(),
+            };
+            let pattern = if kind == "bytes" { Some(query.parse::<Pattern>()?) } else { None };
+            let strings = if pattern.is_none() {
+                let min_len = query.trim().parse::<usize>().map_err(|_| format!("'{}' is not a string length", query))?;
+                Some((kind.parse::<StringKind>()?, min_len))
+            } else {
+                None
+            };
+
+            regions
+                .into_iter()
+                .map(
+                    |reg| match (&pattern, strings) {
+                        (&Some(ref pattern), _) => pattern.find(reg),
+                        (&None, Some((kind, min_len))) => search::strings(reg, kind, min_len),
+                        (&None, None) => vec![],
+                    }
+                )
+                .collect::<Vec<_>>()
+        };
+        let segments = match self.project {
+            Some(ref proj) => &proj.segments[..],
+            None => &self.segments[..],
+        };
+        let mut lines = vec![];
+
+        // the root region comes first. Sections only describe it.
+        for (idx, hits) in found.iter_mut().enumerate() {
+            let region = match hits.first() {
+                Some(hit) => hit.region.clone(),
+                None => continue,
+            };
+            let functions = self.functions.values().filter(|f| f.region() == &region).collect::<Vec<_>>();
+
+            search::locate(hits, if idx == 0 { segments } else { &[] }, &functions);
+
+            for hit in hits.iter() {
+                let text = match hit.text {
+                    Some(ref text) => format!("{:?}", text),
+                    None => hit.bytes.iter().map(|b| format!("{:02x}", b)).collect::<Vec<_>>().join(" "),
+                };
+                let line = format!(
+                    "{}:{:x}  {}  {}  {}",
+                    hit.region,
+                    hit.address,
+                    hit.section.as_ref().map(|s| &s[..]).unwrap_or("-"),
+                    hit.function.as_ref().map(|s| &s[..]).unwrap_or("-"),
+                    text
+                );
+
+                lines.push(CString::new(line)?);
+            }
+        }
+
+        Qt::send_search_results(&lines)?;
+        Ok(lines.len())
+    }
+
     pub fn save_session(&mut self, path: String) -> Result<()> {
         use std::path::Path;
 
@@ -402,13 +529,42 @@ impl Panopticon {
         Ok(())
     }
 
+    /// Region of the opened session or binary.
+    pub fn region_mut(&mut self) -> Option<&mut Region> {
+        match self.project {
+            Some(ref mut proj) => Some(proj.region_mut()),
+            None => self.region.as_mut(),
+        }
+    }
+
+    /// Redraws all functions with basic blocks inside `area`.
+    pub fn update_functions_in(&mut self, area: &Bound) -> Result<()> {
+        let uuids = self.functions
+            .iter()
+            .filter(|&(_, f)| f.basic_blocks().any(|bb| bb.area.start < area.end && area.start < bb.area.end))
+            .map(|(uuid, _)| uuid.clone())
+            .collect::<Vec<_>>();
+
+        for uuid in uuids {
+            self.update_control_flow_nodes(&uuid, None)?;
+        }
+
+        Ok(())
+    }
+
     pub fn update_sidebar(&mut self, uuid: &Uuid) -> Result<()> {
         debug!("update_sidebar() func={}", uuid);
 
         let func = self.functions.get(uuid).cloned();
         match func {
             Some(func) => {
-                Qt::update_sidebar(&[func]);
+                let (main, segments) = match self.project {
+                    Some(ref proj) => (proj.region().name().clone(), &proj.segments[..]),
+                    None => (self.region.as_ref().map(|r| r.name().clone()).unwrap_or_default(), &self.segments[..]),
+                };
+                let segments = segments_of(&func, &main, segments);
+
+                Qt::update_sidebar(&[func], segments);
                 Ok(())
             }
             None => Err(format!("unknown function {}", uuid).into()),
@@ -490,6 +646,7 @@ impl Default for Panopticon {
             resolved_calls: MultiMap::new(),
             project: None,
             region: None,
+            segments: Vec::new(),
             undo_stack: Vec::new(),
             undo_stack_top: 0,
             layout_task: None,
@@ -497,6 +654,12 @@ impl Default for Panopticon {
     }
 }
 
+/// Sections and segments of `segments` that can contain `func`. They only describe the `main`
+/// region.
+fn segments_of<'a>(func: &Function, main: &str, segments: &'a [Segment]) -> &'a [Segment] {
+    if func.region() == main { segments } else { &[] }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -509,6 +672,6 @@ mod tests {
     #[test]
     fn open_save() {
         let mut panop = Panopticon::default();
-        panop.open_program("../test-data/save.panop".to_string()).unwrap();
+        panop.open_program("../test-data/save.panop".to_string(), None).unwrap();
     }
 }